@@ -0,0 +1,72 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use er_save_lib::{EquipParamWeapon::EquipParamWeapon, Save, SaveApi};
+
+const PC_SAVE: &str = "./test/ER0000.sl2";
+const PS_SAVE: &str = "./test/PS_Save.txt";
+
+fn parse(c: &mut Criterion) {
+    let pc = std::fs::read(PC_SAVE).unwrap();
+    let ps = std::fs::read(PS_SAVE).unwrap();
+
+    c.bench_function("Save::from_slice (PC)", |b| {
+        b.iter(|| Save::from_slice(black_box(&pc)).unwrap())
+    });
+    c.bench_function("Save::from_slice (PS)", |b| {
+        b.iter(|| Save::from_slice(black_box(&ps)).unwrap())
+    });
+    c.bench_function("SaveApi::quick_stats (PC)", |b| {
+        b.iter(|| SaveApi::quick_stats(black_box(PC_SAVE)).unwrap())
+    });
+}
+
+fn serialize(c: &mut Criterion) {
+    let pc = std::fs::read(PC_SAVE).unwrap();
+    let save = Save::from_slice(&pc).unwrap();
+    let mut save_api = SaveApi::from_slice(&pc).unwrap();
+
+    c.bench_function("Save::write_to_vec (PC)", |b| {
+        b.iter(|| save.write_to_vec().unwrap())
+    });
+    c.bench_function("SaveApi::to_vec (PC, untouched)", |b| {
+        b.iter(|| save_api.to_vec().unwrap())
+    });
+    save_api.set_vigor(0, 50).unwrap();
+    c.bench_function("SaveApi::to_vec (PC, one slot edited)", |b| {
+        b.iter(|| save_api.to_vec().unwrap())
+    });
+}
+
+fn params(c: &mut Criterion) {
+    let save_api = SaveApi::from_path(PC_SAVE).unwrap();
+
+    c.bench_function("SaveApi::get_param::<EquipParamWeapon>", |b| {
+        b.iter(|| save_api.get_param::<EquipParamWeapon>().unwrap())
+    });
+}
+
+fn event_flags(c: &mut Criterion) {
+    let first_step_grace = 76101;
+    let mut save_api = SaveApi::from_path(PC_SAVE).unwrap();
+
+    c.bench_function("SaveApi::get_event_flag", |b| {
+        b.iter(|| {
+            save_api
+                .get_event_flag(black_box(first_step_grace), 0)
+                .unwrap()
+        })
+    });
+    c.bench_function("SaveApi::set_event_flag", |b| {
+        b.iter(|| {
+            save_api
+                .set_event_flag(black_box(first_step_grace), 0, true)
+                .unwrap()
+        })
+    });
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = parse, serialize, params, event_flags
+}
+criterion_main!(benches);
@@ -0,0 +1,197 @@
+use std::{env, fmt::Write, fs, path::Path};
+
+fn main() {
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    event_flag_blocks(Path::new(&out_dir));
+    param_fields(Path::new(&out_dir));
+}
+
+// Turns the event flag block table into a static array sorted by block, so flag lookups
+// are a binary search instead of parsing the table on first use.
+fn event_flag_blocks(out_dir: &Path) {
+    let source = "src/res/eventflag_bst.txt";
+    println!("cargo:rerun-if-changed={source}");
+    let table = fs::read_to_string(source).expect("Failed to read the event flag table");
+
+    let mut blocks: Vec<(u32, u32)> = table
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let parse = |value: Option<&str>| {
+                value
+                    .and_then(|value| value.trim().parse::<u32>().ok())
+                    .unwrap_or_else(|| panic!("Invalid event flag table line {line:?}"))
+            };
+            let mut values = line.split(',');
+            (parse(values.next()), parse(values.next()))
+        })
+        .collect();
+    blocks.sort_unstable();
+
+    let mut code = format!(
+        "// Generated by build.rs from {source}\nstatic EVENT_FLAG_BLOCKS: [(u32, u32); {}] = [\n",
+        blocks.len()
+    );
+    for (block, offset) in blocks {
+        writeln!(code, "    ({block}, {offset}),").unwrap();
+    }
+    code.push_str("];\n");
+
+    fs::write(out_dir.join("event_flag_blocks.rs"), code)
+        .expect("Failed to write the event flag table");
+}
+
+// A field of a param def struct
+struct ParamField {
+    name: String,
+    // Condition under which the field is present, None if it always is
+    present: Option<String>,
+    bits: Option<u32>,
+}
+
+// Implements `ParamFields` for every param def struct, listing the fields in declaration
+// order along with the regulation versions they exist in.
+fn param_fields(out_dir: &Path) {
+    let source = "src/regulation/params/param_structs/defs";
+    println!("cargo:rerun-if-changed={source}");
+    let mut paths: Vec<_> = fs::read_dir(source)
+        .expect("Failed to read the param defs")
+        .map(|entry| entry.expect("Failed to read the param defs").path())
+        .filter(|path| path.file_name().is_some_and(|name| name != "mod.rs"))
+        .collect();
+    paths.sort();
+
+    let mut code = format!(
+        "// Generated by build.rs from {source}\nuse super::{{defs, param_trait::{{CsvFields, ParamFields}}}};\n"
+    );
+    for path in paths {
+        let def = fs::read_to_string(&path).expect("Failed to read a param def");
+        let (name, fields) = parse_param_def(&def)
+            .unwrap_or_else(|line| panic!("Unexpected line in {}: {line:?}", path.display()));
+        write_param_fields(&mut code, &name, &fields);
+    }
+
+    fs::write(out_dir.join("param_fields.rs"), code).expect("Failed to write the param fields");
+}
+
+// Name and fields of the struct in a param def, or the line that couldn't be parsed
+fn parse_param_def(def: &str) -> Result<(String, Vec<ParamField>), &str> {
+    let mut lines = def.lines().map(str::trim);
+    let name = lines
+        .find_map(|line| line.strip_prefix("pub struct "))
+        .and_then(|line| line.strip_suffix(" {"))
+        .ok_or("pub struct")?;
+
+    let mut fields = Vec::new();
+    let (mut present, mut bits) = (None, None);
+    for line in lines.take_while(|line| *line != "}") {
+        if let Some(attribute) = line
+            .strip_prefix("#[deku(")
+            .and_then(|line| line.strip_suffix(")]"))
+        {
+            let value = |key: &str| {
+                let start = attribute.find(key)? + key.len();
+                let end = attribute[start..]
+                    .find(['"', ','])
+                    .map_or(attribute.len(), |end| start + end);
+                Some(&attribute[start..end])
+            };
+            present = match value("cond = \"") {
+                Some(skip) => Some(negate_condition(skip).ok_or(line)?),
+                None => None,
+            };
+            bits = match value("bits = ") {
+                Some(bits) => Some(bits.parse().map_err(|_| line)?),
+                None => None,
+            };
+        } else if let Some((name, _)) = line
+            .strip_prefix("pub ")
+            .and_then(|line| line.split_once(':'))
+        {
+            fields.push(ParamField {
+                name: name.to_string(),
+                present: present.take(),
+                bits: bits.take(),
+            });
+        } else if !line.is_empty() {
+            return Err(line);
+        }
+    }
+    Ok((name.to_string(), fields))
+}
+
+// The defs skip a field under a condition on the version, this is the condition under
+// which it's read
+fn negate_condition(skip: &str) -> Option<String> {
+    let version = |condition: &str, operator: &str| {
+        condition
+            .strip_prefix("version ")?
+            .strip_prefix(operator)?
+            .trim()
+            .parse::<u32>()
+            .ok()
+    };
+    if let Some((newer, older)) = skip.split_once(" || ") {
+        let (end, start) = (version(newer, ">=")?, version(older, "<")?);
+        return Some(format!("({start}..{end}).contains(&version)"));
+    }
+    if let Some(start) = version(skip, ">=") {
+        return Some(format!("version < {start}"));
+    }
+    version(skip, "<").map(|end| format!("version >= {end}"))
+}
+
+fn write_param_fields(code: &mut String, name: &str, fields: &[ParamField]) {
+    let versioned = fields.iter().any(|field| field.present.is_some());
+    let version = if versioned { "version" } else { "_version" };
+    let name_of = |field: &ParamField| format!("{:?}", field.name.trim_start_matches("r#"));
+
+    writeln!(code, "impl ParamFields for defs::{name}::{name} {{").unwrap();
+    writeln!(
+        code,
+        "    fn field_names({version}: u32) -> Vec<&'static str> {{"
+    )
+    .unwrap();
+    if versioned {
+        // Runs of fields present in every version are added at once
+        let runs = fields.chunk_by(|a, b| a.present.is_none() && b.present.is_none());
+        for (index, run) in runs.enumerate() {
+            let names: Vec<String> = run.iter().map(name_of).collect();
+            let statement = match &run[0].present {
+                None if index == 0 => format!("let mut fields = vec![{}];", names.join(", ")),
+                None => format!("fields.extend([{}]);", names.join(", ")),
+                Some(present) => {
+                    if index == 0 {
+                        code.push_str("        let mut fields = Vec::new();\n");
+                    }
+                    format!("if {present} {{ fields.push({}); }}", names[0])
+                }
+            };
+            writeln!(code, "        {statement}").unwrap();
+        }
+        code.push_str("        fields\n");
+    } else {
+        let names: Vec<String> = fields.iter().map(name_of).collect();
+        writeln!(code, "        vec![{}]", names.join(", ")).unwrap();
+    }
+    code.push_str("    }\n");
+
+    writeln!(
+        code,
+        "    fn field_values(&self, {version}: u32) -> Vec<String> {{"
+    )
+    .unwrap();
+    code.push_str("        let mut fields = CsvFields::default();\n");
+    for field in fields {
+        let push = match field.bits {
+            Some(bits) => format!("fields.push_bits(self.{}, {bits});", field.name),
+            None => format!("fields.push(&self.{});", field.name),
+        };
+        match &field.present {
+            Some(present) => writeln!(code, "        if {present} {{ {push} }}"),
+            None => writeln!(code, "        {push}"),
+        }
+        .unwrap();
+    }
+    code.push_str("        fields.into_values()\n    }\n}\n");
+}
@@ -0,0 +1,351 @@
+use super::{bosses, item::ItemCategory};
+use crate::{SaveApi, SaveApiError};
+
+/// A single condition of an achievement.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Requirement {
+    /// The event flag must be set.
+    EventFlag(u32),
+    /// An item with this category and id must be in the inventory or the storage box.
+    /// Weapon upgrade levels and affinities are ignored.
+    Item(ItemCategory, u32),
+}
+
+/// An achievement and the conditions needed to unlock it.
+#[derive(Clone, Copy, Debug)]
+pub struct Achievement {
+    pub name: &'static str,
+    /// Every condition along with the label reported when it isn't met.
+    pub requirements: &'static [(&'static str, Requirement)],
+}
+
+/// Result of checking an achievement against a character.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct AchievementStatus {
+    pub name: &'static str,
+    /// Labels of the requirements that aren't met yet.
+    pub missing: Vec<&'static str>,
+}
+
+impl AchievementStatus {
+    /// Returns true when every requirement is met.
+    pub fn is_unlocked(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
+
+macro_rules! boss_achievement {
+    ($name:literal, $boss:literal, $flag:expr) => {
+        Achievement {
+            name: $name,
+            requirements: &[($boss, Requirement::EventFlag($flag))],
+        }
+    };
+}
+
+/// Steam achievements that are unlocked by defeating a boss or by collecting a set of items.
+///
+/// The item sets are the ones the regulation marks with a trophy id: `trophySGradeId` for
+/// weapons, `compTrophySedId` for talismans and goods.
+pub const ACHIEVEMENTS: &[Achievement] = &[
+    boss_achievement!(
+        "Shardbearer Godrick",
+        "Godrick the Grafted",
+        bosses::GODRICK
+    ),
+    boss_achievement!("Shardbearer Radahn", "Starscourge Radahn", bosses::RADAHN),
+    boss_achievement!(
+        "Shardbearer Morgott",
+        "Morgott, the Omen King",
+        bosses::MORGOTT
+    ),
+    boss_achievement!(
+        "Shardbearer Rykard",
+        "Rykard, Lord of Blasphemy",
+        bosses::RYKARD
+    ),
+    boss_achievement!(
+        "Shardbearer Malenia",
+        "Malenia, Blade of Miquella",
+        bosses::MALENIA
+    ),
+    boss_achievement!("Shardbearer Mohg", "Mohg, Lord of Blood", bosses::MOHG),
+    boss_achievement!(
+        "Maliketh the Black Blade",
+        "Maliketh, the Black Blade",
+        bosses::MALIKETH
+    ),
+    boss_achievement!(
+        "Hoarah Loux the Warrior",
+        "Hoarah Loux, Warrior",
+        bosses::HOARAH_LOUX
+    ),
+    boss_achievement!(
+        "Dragonlord Placidusax",
+        "Dragonlord Placidusax",
+        bosses::PLACIDUSAX
+    ),
+    boss_achievement!(
+        "Rennala Queen of the Full Moon",
+        "Rennala, Queen of the Full Moon",
+        bosses::RENNALA
+    ),
+    boss_achievement!(
+        "Lichdragon Fortissax",
+        "Lichdragon Fortissax",
+        bosses::LICHDRAGON_FORTISSAX
+    ),
+    boss_achievement!("Godskin Duo", "Godskin Duo", bosses::GODSKIN_DUO),
+    boss_achievement!("Fire Giant", "Fire Giant", bosses::FIRE_GIANT),
+    boss_achievement!(
+        "Regal Ancestor Spirit",
+        "Regal Ancestor Spirit",
+        bosses::REGAL_ANCESTOR_SPIRIT
+    ),
+    boss_achievement!(
+        "Margit the Fell Omen",
+        "Margit, the Fell Omen",
+        bosses::MARGIT
+    ),
+    boss_achievement!(
+        "Red Wolf of Radagon",
+        "Red Wolf of Radagon",
+        bosses::RED_WOLF_OF_RADAGON
+    ),
+    boss_achievement!(
+        "Astel Naturalborn of the Void",
+        "Astel, Naturalborn of the Void",
+        bosses::ASTEL
+    ),
+    Achievement {
+        name: "Legendary Armaments",
+        requirements: &[
+            (
+                "Sword of Night and Flame",
+                Requirement::Item(ItemCategory::Weapon, 2140000),
+            ),
+            (
+                "Dark Moon Greatsword",
+                Requirement::Item(ItemCategory::Weapon, 3090000),
+            ),
+            (
+                "Marais Executioner's Sword",
+                Requirement::Item(ItemCategory::Weapon, 3150000),
+            ),
+            (
+                "Golden Order Greatsword",
+                Requirement::Item(ItemCategory::Weapon, 3170000),
+            ),
+            (
+                "Ruins Greatsword",
+                Requirement::Item(ItemCategory::Weapon, 4080000),
+            ),
+            (
+                "Grafted Blade Greatsword",
+                Requirement::Item(ItemCategory::Weapon, 4100000),
+            ),
+            (
+                "Eclipse Shotel",
+                Requirement::Item(ItemCategory::Weapon, 7100000),
+            ),
+            (
+                "Devourer's Scepter",
+                Requirement::Item(ItemCategory::Weapon, 12200000),
+            ),
+            (
+                "Bolt of Gransax",
+                Requirement::Item(ItemCategory::Weapon, 16090000),
+            ),
+        ],
+    },
+    Achievement {
+        name: "Legendary Talismans",
+        requirements: &[
+            (
+                "Erdtree's Favor +2",
+                Requirement::Item(ItemCategory::Talisman, 1042),
+            ),
+            (
+                "Radagon's Soreseal",
+                Requirement::Item(ItemCategory::Talisman, 1051),
+            ),
+            (
+                "Moon of Nokstella",
+                Requirement::Item(ItemCategory::Talisman, 1140),
+            ),
+            (
+                "Marika's Soreseal",
+                Requirement::Item(ItemCategory::Talisman, 1221),
+            ),
+            (
+                "Old Lord's Talisman",
+                Requirement::Item(ItemCategory::Talisman, 3060),
+            ),
+            (
+                "Radagon Icon",
+                Requirement::Item(ItemCategory::Talisman, 3070),
+            ),
+            (
+                "Godfrey Icon",
+                Requirement::Item(ItemCategory::Talisman, 3090),
+            ),
+            (
+                "Dragoncrest Greatshield Talisman",
+                Requirement::Item(ItemCategory::Talisman, 4003),
+            ),
+        ],
+    },
+    Achievement {
+        name: "Legendary Sorceries and Incantations",
+        requirements: &[
+            ("Comet Azur", Requirement::Item(ItemCategory::Goods, 4200)),
+            (
+                "Founding Rain of Stars",
+                Requirement::Item(ItemCategory::Goods, 4210),
+            ),
+            (
+                "Stars of Ruin",
+                Requirement::Item(ItemCategory::Goods, 4220),
+            ),
+            (
+                "Ranni's Dark Moon",
+                Requirement::Item(ItemCategory::Goods, 4361),
+            ),
+            (
+                "Flame of the Fell God",
+                Requirement::Item(ItemCategory::Goods, 6110),
+            ),
+            ("Elden Stars", Requirement::Item(ItemCategory::Goods, 6720)),
+            (
+                "Greyoll's Roar",
+                Requirement::Item(ItemCategory::Goods, 7090),
+            ),
+        ],
+    },
+];
+
+impl SaveApi {
+    /// Checks the built-in achievements against the character at the specified index.
+    ///
+    /// The built-in list covers the boss achievements and the legendary armaments,
+    /// talismans, and sorceries and incantations, whose missing items are reported by
+    /// name. Other achievements can be checked with `achievement_status_with`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{MockCharacter, MockSave, SaveApi};
+    /// use std::collections::HashSet;
+    /// let mock = MockSave::new(vec![MockCharacter {
+    ///     level: 1,
+    ///     event_flags: HashSet::from([10000850]),
+    ///     ..Default::default()
+    /// }]);
+    /// let save_api = SaveApi::from_mock(&mock).unwrap();
+    /// let statuses = save_api.achievement_status(0).unwrap();
+    /// let margit = statuses
+    ///     .iter()
+    ///     .find(|status| status.name == "Margit the Fell Omen")
+    ///     .unwrap();
+    /// assert!(margit.is_unlocked());
+    /// let godrick = statuses
+    ///     .iter()
+    ///     .find(|status| status.name == "Shardbearer Godrick")
+    ///     .unwrap();
+    /// assert_eq!(godrick.missing, vec!["Godrick the Grafted"]);
+    /// ```
+    pub fn achievement_status(&self, index: usize) -> Result<Vec<AchievementStatus>, SaveApiError> {
+        self.achievement_status_with(index, ACHIEVEMENTS)
+    }
+
+    /// Checks the given achievements against the character at the specified index.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::achievements::{Achievement, Requirement};
+    /// use er_save_lib::item::ItemCategory;
+    /// use er_save_lib::{MockCharacter, MockSave, SaveApi};
+    /// let mock = MockSave::new(vec![MockCharacter {
+    ///     level: 1,
+    ///     items: vec![(ItemCategory::Talisman, 1000)],
+    ///     ..Default::default()
+    /// }]);
+    /// let save_api = SaveApi::from_mock(&mock).unwrap();
+    /// let achievements = [Achievement {
+    ///     name: "Crimson collector",
+    ///     requirements: &[
+    ///         ("Medallion", Requirement::Item(ItemCategory::Talisman, 1000)),
+    ///         ("Medallion +1", Requirement::Item(ItemCategory::Talisman, 1001)),
+    ///     ],
+    /// }];
+    /// let status = save_api.achievement_status_with(0, &achievements).unwrap();
+    /// assert_eq!(status[0].missing, vec!["Medallion +1"]);
+    /// ```
+    pub fn achievement_status_with(
+        &self,
+        index: usize,
+        achievements: &[Achievement],
+    ) -> Result<Vec<AchievementStatus>, SaveApiError> {
+        let character = self.character(index)?;
+        let owned_items = character.owned_items();
+
+        let mut statuses = Vec::with_capacity(achievements.len());
+        for achievement in achievements {
+            let mut missing = Vec::new();
+            for (label, requirement) in achievement.requirements {
+                let met = match *requirement {
+                    Requirement::EventFlag(event_id) => character.event_flag(event_id)?,
+                    Requirement::Item(category, id) => {
+                        owned_items.iter().any(|(owned_category, owned_id)| {
+                            *owned_category == category
+                                && base_id(category, *owned_id) == base_id(category, id)
+                        })
+                    }
+                };
+                if !met {
+                    missing.push(*label);
+                }
+            }
+            statuses.push(AchievementStatus {
+                name: achievement.name,
+                missing,
+            });
+        }
+        Ok(statuses)
+    }
+}
+
+// Weapon ids encode the affinity and upgrade level in their last four digits
+fn base_id(category: ItemCategory, id: u32) -> u32 {
+    match category {
+        ItemCategory::Weapon => id / 10000 * 10000,
+        _ => id,
+    }
+}
+
+#[cfg(feature = "regulation")]
+#[test]
+fn legendary_sets_report_missing_items() {
+    use crate::{MockCharacter, MockSave};
+
+    let mock = MockSave::new(vec![MockCharacter {
+        level: 1,
+        items: [1042, 1051, 1140, 1221, 3060, 3070, 3090]
+            .into_iter()
+            .map(|id| (ItemCategory::Talisman, id))
+            .chain([(ItemCategory::Goods, 4200)])
+            .collect(),
+        ..Default::default()
+    }]);
+    let save_api = SaveApi::from_mock(&mock).unwrap();
+    let statuses = save_api.achievement_status(0).unwrap();
+    let status = |name| statuses.iter().find(|status| status.name == name).unwrap();
+
+    assert_eq!(
+        status("Legendary Talismans").missing,
+        vec!["Dragoncrest Greatshield Talisman"]
+    );
+    let spells = status("Legendary Sorceries and Incantations");
+    assert_eq!(spells.missing.len(), 6);
+    assert!(!spells.missing.contains(&"Comet Azur"));
+    assert_eq!(status("Legendary Armaments").missing.len(), 9);
+}
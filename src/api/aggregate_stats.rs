@@ -0,0 +1,53 @@
+use crate::{SaveApi, SaveApiError};
+
+/// Totals over every active character of a save, returned by `SaveApi::aggregate_stats`.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct AggregateStats {
+    /// Number of active characters, corrupt slots excluded.
+    pub characters: usize,
+    pub seconds_played: u64,
+    pub deaths: u64,
+    /// Highest level of a character, 0 without any.
+    pub highest_level: u32,
+    /// Index of the character with the highest level, the first one on a tie.
+    pub highest_level_index: Option<usize>,
+    /// Indices of the characters whose slot carries another Steam ID than the save. The
+    /// game refuses to load those, an empty list confirms the characters share the ID.
+    pub steam_id_mismatches: Vec<usize>,
+}
+
+impl SaveApi {
+    /// Sums the play time and deaths of every active character and finds the highest
+    /// level, for account overviews. Corrupt slots are skipped, see `SaveApi::characters`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{MockCharacter, MockSave, SaveApi};
+    /// let mock = MockSave::new(vec![
+    ///     MockCharacter { level: 40, ..Default::default() },
+    ///     MockCharacter { level: 90, ..Default::default() },
+    /// ]);
+    /// let save_api = SaveApi::from_mock(&mock).unwrap();
+    /// let stats = save_api.aggregate_stats().unwrap();
+    /// assert_eq!(stats.characters, 2);
+    /// assert_eq!(stats.highest_level, 90);
+    /// assert_eq!(stats.highest_level_index, Some(1));
+    /// ```
+    pub fn aggregate_stats(&self) -> Result<AggregateStats, SaveApiError> {
+        let steam_id = self.steam_id();
+        let mut stats = AggregateStats::default();
+        for (index, character) in self.characters() {
+            stats.characters += 1;
+            stats.seconds_played += u64::from(character.seconds_played());
+            stats.deaths += u64::from(character.deaths());
+            if stats.highest_level_index.is_none() || character.level() > stats.highest_level {
+                stats.highest_level = character.level();
+                stats.highest_level_index = Some(index);
+            }
+            if self.user_data_x(index)?.steam_id != steam_id {
+                stats.steam_id_mismatches.push(index);
+            }
+        }
+        Ok(stats)
+    }
+}
@@ -0,0 +1,137 @@
+use std::fmt::Display;
+
+/// The starting class a character was created with.
+///
+/// # Example
+/// ```rust
+/// use er_save_lib::Archetype;
+/// assert_eq!(Archetype::try_from(9).unwrap(), Archetype::Wretch);
+/// assert_eq!(Archetype::Samurai.to_string(), "Samurai");
+/// assert!(Archetype::try_from(10).is_err());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Archetype {
+    Vagabond,
+    Warrior,
+    Hero,
+    Bandit,
+    Astrologer,
+    Prophet,
+    Confessor,
+    Samurai,
+    Prisoner,
+    Wretch,
+}
+
+/// The body type picked at character creation, Type A being `Male` and Type B `Female`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Gender {
+    Female,
+    Male,
+}
+
+/// Error returned when a stored value has no variant, by `Archetype::try_from` and
+/// `Gender::try_from` for instance.
+#[derive(thiserror::Error, PartialEq, Eq, Debug)]
+#[error("Value {0} has no variant")]
+pub struct UnknownValue(pub u8);
+
+impl Archetype {
+    /// Every archetype in the order of their ids, which is the order of the character
+    /// creation menu.
+    pub const ALL: [Archetype; 10] = [
+        Archetype::Vagabond,
+        Archetype::Warrior,
+        Archetype::Hero,
+        Archetype::Bandit,
+        Archetype::Astrologer,
+        Archetype::Prophet,
+        Archetype::Confessor,
+        Archetype::Samurai,
+        Archetype::Prisoner,
+        Archetype::Wretch,
+    ];
+
+    /// Returns the id of the archetype.
+    pub fn id(&self) -> u8 {
+        match self {
+            Archetype::Vagabond => 0,
+            Archetype::Warrior => 1,
+            Archetype::Hero => 2,
+            Archetype::Bandit => 3,
+            Archetype::Astrologer => 4,
+            Archetype::Prophet => 5,
+            Archetype::Confessor => 6,
+            Archetype::Samurai => 7,
+            Archetype::Prisoner => 8,
+            Archetype::Wretch => 9,
+        }
+    }
+
+    /// Returns the in-game name of the archetype.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Archetype::Vagabond => "Vagabond",
+            Archetype::Warrior => "Warrior",
+            Archetype::Hero => "Hero",
+            Archetype::Bandit => "Bandit",
+            Archetype::Astrologer => "Astrologer",
+            Archetype::Prophet => "Prophet",
+            Archetype::Confessor => "Confessor",
+            Archetype::Samurai => "Samurai",
+            Archetype::Prisoner => "Prisoner",
+            Archetype::Wretch => "Wretch",
+        }
+    }
+}
+
+impl Gender {
+    /// Returns the value the gender is stored as.
+    pub fn id(&self) -> u8 {
+        match self {
+            Gender::Female => 0,
+            Gender::Male => 1,
+        }
+    }
+}
+
+impl TryFrom<u8> for Archetype {
+    type Error = UnknownValue;
+
+    fn try_from(id: u8) -> Result<Self, Self::Error> {
+        Archetype::ALL
+            .into_iter()
+            .find(|archetype| archetype.id() == id)
+            .ok_or(UnknownValue(id))
+    }
+}
+
+impl TryFrom<u8> for Gender {
+    type Error = UnknownValue;
+
+    fn try_from(id: u8) -> Result<Self, Self::Error> {
+        match id {
+            0 => Ok(Gender::Female),
+            1 => Ok(Gender::Male),
+            _ => Err(UnknownValue(id)),
+        }
+    }
+}
+
+impl From<Archetype> for u8 {
+    fn from(archetype: Archetype) -> Self {
+        archetype.id()
+    }
+}
+
+impl From<Gender> for u8 {
+    fn from(gender: Gender) -> Self {
+        gender.id()
+    }
+}
+
+impl Display for Archetype {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
@@ -0,0 +1,101 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::{
+    calc::lsb_first_flags,
+    item::{self, ItemCategory},
+};
+use crate::{EquipParamProtector::EquipParamProtector, SaveApi, SaveApiError};
+
+// Armor row ids are the id of their set times this, plus 100 times the slot of the piece
+const SET_ID_MULTIPLIER: u32 = 1000;
+
+/// The pieces of an armor set a character owns and the ones it lacks, returned by
+/// `SaveApi::owned_armor_sets`.
+///
+/// Ids are `EquipParamProtector` row ids, as stored in the save before any
+/// `ItemIdMapper`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ArmorSet {
+    /// Id of the set, shared by the row ids of its pieces: 40 for 40000 to 40399.
+    pub id: u32,
+    /// Pieces of the set the character owns.
+    pub owned: Vec<u32>,
+    /// For every slot (head, chest, arms, legs) of the set without an owned piece, the
+    /// first piece of the slot.
+    pub missing: Vec<u32>,
+}
+
+impl ArmorSet {
+    /// Returns whether the character owns a piece of every slot of the set. Altered
+    /// pieces count for their slot, so owning one of the variants is enough.
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
+
+impl SaveApi {
+    /// Groups the armor held or stored by the character at the specified index into sets,
+    /// by their `EquipParamProtector` row ids. Only sets with at least one owned piece are
+    /// returned, sorted by id. Pieces whose row is disabled aren't part of any set.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use er_save_lib::SaveApi;
+    /// let save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+    /// for set in save_api.owned_armor_sets(0).unwrap() {
+    ///     if !set.is_complete() {
+    ///         println!("Set {} lacks {:?}", set.id, set.missing);
+    ///     }
+    /// }
+    /// ```
+    pub fn owned_armor_sets(&self, index: usize) -> Result<Vec<ArmorSet>, SaveApiError> {
+        let owned: BTreeSet<u32> = item::owned_items(self.user_data_x(index)?)
+            .into_iter()
+            .filter(|(category, _)| *category == ItemCategory::Armor)
+            .map(|(_, id)| id)
+            .collect();
+        let owned_sets: BTreeSet<u32> = owned.iter().map(|id| id / SET_ID_MULTIPLIER).collect();
+
+        // Enabled pieces of every set with an owned piece, by slot
+        let mut sets: BTreeMap<u32, BTreeMap<u8, Vec<u32>>> = BTreeMap::new();
+        for (row_id, row) in self.get_param::<EquipParamProtector>()?.rows {
+            let Ok(id) = u32::try_from(row_id) else {
+                continue;
+            };
+            let set = id / SET_ID_MULTIPLIER;
+            let disabled = lsb_first_flags([row.disableParam_NT], row.disableParamReserve1)[0];
+            if disabled || !owned_sets.contains(&set) {
+                continue;
+            }
+            sets.entry(set)
+                .or_default()
+                .entry(row.protectorCategory)
+                .or_default()
+                .push(id);
+        }
+        for pieces in sets.values_mut().flat_map(BTreeMap::values_mut) {
+            pieces.sort_unstable();
+        }
+
+        Ok(sets
+            .into_iter()
+            .map(|(id, slots)| {
+                let mut set = ArmorSet {
+                    id,
+                    owned: Vec::new(),
+                    missing: Vec::new(),
+                };
+                for pieces in slots.values() {
+                    let before = set.owned.len();
+                    set.owned
+                        .extend(pieces.iter().filter(|piece| owned.contains(*piece)));
+                    if set.owned.len() == before {
+                        set.missing.extend(pieces.first());
+                    }
+                }
+                set
+            })
+            .filter(|set| !set.owned.is_empty())
+            .collect())
+    }
+}
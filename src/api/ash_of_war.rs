@@ -0,0 +1,407 @@
+use std::fmt::Display;
+
+#[cfg(feature = "params")]
+use super::calc::lsb_first_flags;
+#[cfg(feature = "params")]
+use crate::{
+    defs::EQUIP_PARAM_GEM_ST::EQUIP_PARAM_GEM_ST, EquipParamGem::EquipParamGem,
+    EquipParamWeapon::EquipParamWeapon, SaveApi, SaveApiError,
+};
+
+/// Affinity of a weapon, stored in the hundreds of its item id.
+///
+/// # Example
+/// ```rust
+/// use er_save_lib::ash_of_war::Affinity;
+/// assert_eq!(Affinity::from_weapon_id(2000125), Some(Affinity::Heavy));
+/// assert_eq!(Affinity::Heavy.to_string(), "Heavy");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Affinity {
+    Standard,
+    Heavy,
+    Keen,
+    Quality,
+    Fire,
+    FlameArt,
+    Lightning,
+    Sacred,
+    Magic,
+    Cold,
+    Poison,
+    Blood,
+    Occult,
+}
+
+impl Affinity {
+    /// Every affinity in the order of their ids.
+    pub const ALL: [Affinity; 13] = [
+        Affinity::Standard,
+        Affinity::Heavy,
+        Affinity::Keen,
+        Affinity::Quality,
+        Affinity::Fire,
+        Affinity::FlameArt,
+        Affinity::Lightning,
+        Affinity::Sacred,
+        Affinity::Magic,
+        Affinity::Cold,
+        Affinity::Poison,
+        Affinity::Blood,
+        Affinity::Occult,
+    ];
+
+    /// Returns the id of the affinity, added to a weapon id in hundreds.
+    pub fn id(&self) -> u32 {
+        Affinity::ALL
+            .iter()
+            .position(|affinity| affinity == self)
+            .unwrap_or_default() as u32
+    }
+
+    /// Returns the affinity of a weapon from its item id, or `None` if the id doesn't hold
+    /// a valid one.
+    pub fn from_weapon_id(id: u32) -> Option<Affinity> {
+        Affinity::ALL.get((id % 10000 / 100) as usize).copied()
+    }
+
+    /// Returns the in-game name of the affinity.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Affinity::Standard => "Standard",
+            Affinity::Heavy => "Heavy",
+            Affinity::Keen => "Keen",
+            Affinity::Quality => "Quality",
+            Affinity::Fire => "Fire",
+            Affinity::FlameArt => "Flame Art",
+            Affinity::Lightning => "Lightning",
+            Affinity::Sacred => "Sacred",
+            Affinity::Magic => "Magic",
+            Affinity::Cold => "Cold",
+            Affinity::Poison => "Poison",
+            Affinity::Blood => "Blood",
+            Affinity::Occult => "Occult",
+        }
+    }
+}
+
+impl Display for Affinity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Why an ash of war can't be applied, see `SaveApi::apply_ash_of_war`.
+#[derive(thiserror::Error, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AshOfWarError {
+    #[error("weapon slot {0} is empty")]
+    NoWeapon(usize),
+    #[error("the ash of war isn't in the regulation")]
+    UnknownAshOfWar,
+    #[error("weapon {0} has a skill that can't be changed")]
+    FixedSkill(u32),
+    #[error("it can't be applied to the weapon type of weapon {0}")]
+    WeaponType(u32),
+    #[error("it doesn't allow the {0} affinity")]
+    AshAffinity(Affinity),
+    #[error("weapon {0} has no {1} variant")]
+    WeaponAffinity(u32, Affinity),
+    #[error("it isn't owned or is applied to another weapon")]
+    NotOwned,
+}
+
+// `wepType` of EquipParamWeapon matching every `canMountWep` flag of EquipParamGem, in
+// field order. Flags of weapon types the game doesn't use hold -1.
+#[cfg(feature = "params")]
+const MOUNT_WEAPON_TYPES: [i16; 44] = [
+    1,  // Dagger
+    3,  // Straight sword
+    5,  // Greatsword
+    7,  // Colossal sword
+    9,  // Curved sword
+    11, // Curved greatsword
+    13, // Katana
+    14, // Twinblade
+    15, // Thrusting sword
+    16, // Heavy thrusting sword
+    17, // Axe
+    19, // Greataxe
+    21, // Hammer
+    23, // Great hammer
+    24, // Flail
+    25, // Spear
+    28, // Great spear
+    -1, // Heavy spear
+    29, // Halberd
+    31, // Reaper
+    35, // Fist
+    37, // Claw
+    39, // Whip
+    41, // Colossal weapon
+    50, // Light bow
+    51, // Bow
+    53, // Greatbow
+    55, // Crossbow
+    56, // Ballista
+    57, // Glintstone staff
+    -1, // Sorcery
+    61, // Sacred seal
+    65, // Small shield
+    67, // Medium shield
+    69, // Greatshield
+    87, // Torch
+    88, // Hand-to-hand art
+    89, // Perfume bottle
+    90, // Thrusting shield
+    91, // Throwing blade
+    92, // Backhand blade
+    93, // Light greatsword
+    94, // Great katana
+    95, // Beast claw
+];
+
+// Ashes of war can only be swapped on weapons of this `gemMountType`
+#[cfg(feature = "params")]
+const SWAPPABLE_GEM_MOUNT_TYPE: u8 = 2;
+
+// Weapon slots of the equipment structs of a slot, in the order of
+// `Character::equipped_items`: left hand then right hand
+#[cfg(feature = "params")]
+macro_rules! weapon_slots {
+    ($equipped:expr) => {
+        [
+            $equipped.left_hand_armament1,
+            $equipped.left_hand_armament2,
+            $equipped.left_hand_armament3,
+            $equipped.right_hand_armament1,
+            $equipped.right_hand_armament2,
+            $equipped.right_hand_armament3,
+        ]
+    };
+}
+
+#[cfg(feature = "params")]
+macro_rules! weapon_slot_mut {
+    ($equipped:expr, $slot:expr) => {
+        match $slot {
+            0 => &mut $equipped.left_hand_armament1,
+            1 => &mut $equipped.left_hand_armament2,
+            2 => &mut $equipped.left_hand_armament3,
+            3 => &mut $equipped.right_hand_armament1,
+            4 => &mut $equipped.right_hand_armament2,
+            _ => &mut $equipped.right_hand_armament3,
+        }
+    };
+}
+
+#[cfg(feature = "params")]
+impl SaveApi {
+    /// Applies an ash of war owned by the character at the specified index to one of its
+    /// equipped weapons, with the specified affinity. The weapon keeps its upgrade level.
+    ///
+    /// `slot` is the index of the weapon in `Character::equipped_items`, as for
+    /// `SaveApi::weapon_ar`, and `ash` the id of the ash of war, without the category bits.
+    /// The combination is checked against the regulation the way the game does: the weapon
+    /// has to accept ashes of war, the ash has to allow the weapon's type and the affinity,
+    /// and the weapon has to exist with that affinity.
+    ///
+    /// # Errors
+    /// - `SaveApiError::WeaponSlotOutOfRange` if `slot` isn't a weapon slot.
+    /// - `SaveApiError::IllegalAshOfWar` with the reason when the combination isn't legal,
+    ///   the slot is empty or the character has no free copy of the ash of war.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use er_save_lib::ash_of_war::{Affinity, AshOfWarError};
+    /// use er_save_lib::{SaveApi, SaveApiError};
+    /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+    /// match save_api.apply_ash_of_war(0, 3, 10000, Affinity::Heavy) {
+    ///     Ok(()) => println!("Right hand weapon is now Heavy"),
+    ///     Err(SaveApiError::IllegalAshOfWar(_, AshOfWarError::AshAffinity(affinity))) => {
+    ///         println!("This ash of war can't be {affinity}")
+    ///     }
+    ///     Err(err) => panic!("{err}"),
+    /// }
+    /// ```
+    pub fn apply_ash_of_war(
+        &mut self,
+        index: usize,
+        slot: usize,
+        ash: u32,
+        affinity: Affinity,
+    ) -> Result<(), SaveApiError> {
+        let illegal = |reason| SaveApiError::IllegalAshOfWar(ash, reason);
+        let user_data_x = self.user_data_x(index)?;
+        let handle = *weapon_slots!(user_data_x.equipped_items_gaitem_handle)
+            .get(slot)
+            .ok_or(SaveApiError::WeaponSlotOutOfRange(slot))?;
+        let Some(weapon) = user_data_x
+            .gaitem_map
+            .iter()
+            .find(|gaitem| gaitem.gaitem_handle == handle && handle & 0xf0000000 == 0x80000000)
+        else {
+            return Err(illegal(AshOfWarError::NoWeapon(slot)));
+        };
+        let weapon_id = weapon.item_id;
+        let base_id = weapon_id - weapon_id % 10000;
+
+        let weapons = self.get_param::<EquipParamWeapon>()?.rows;
+        let base_row = weapons
+            .get(&(base_id as i32))
+            .ok_or(illegal(AshOfWarError::FixedSkill(weapon_id)))?;
+        if base_row.gemMountType != SWAPPABLE_GEM_MOUNT_TYPE {
+            return Err(illegal(AshOfWarError::FixedSkill(weapon_id)));
+        }
+        let gems = self.get_param::<EquipParamGem>()?.rows;
+        let gem = gems
+            .get(&(ash as i32))
+            .ok_or(illegal(AshOfWarError::UnknownAshOfWar))?;
+        if !mountable_weapon_types(gem).contains(&base_row.wepType) {
+            return Err(illegal(AshOfWarError::WeaponType(weapon_id)));
+        }
+        if !configurable_affinities(gem)[affinity.id() as usize] {
+            return Err(illegal(AshOfWarError::AshAffinity(affinity)));
+        }
+        let affinity_id = base_id + affinity.id() * 100;
+        if !weapons.contains_key(&(affinity_id as i32)) {
+            return Err(illegal(AshOfWarError::WeaponAffinity(weapon_id, affinity)));
+        }
+
+        // A copy of the ash of war that no other weapon holds
+        let mounted: Vec<i32> = user_data_x
+            .gaitem_map
+            .iter()
+            .filter(|gaitem| gaitem.gaitem_handle != handle)
+            .filter_map(|gaitem| gaitem.gem_gaitem_handle)
+            .collect();
+        let gem_handle = user_data_x
+            .gaitem_map
+            .iter()
+            .find(|gaitem| {
+                gaitem.gaitem_handle & 0xf0000000 == 0xc0000000
+                    && gaitem.item_id & 0x0fffffff == ash
+                    && !mounted.contains(&(gaitem.gaitem_handle as i32))
+            })
+            .ok_or(illegal(AshOfWarError::NotOwned))?
+            .gaitem_handle;
+
+        let new_id = affinity_id + weapon_id % 100;
+        let user_data_x = self.user_data_x_mut(index)?;
+        if let Some(weapon) = user_data_x
+            .gaitem_map
+            .iter_mut()
+            .find(|gaitem| gaitem.gaitem_handle == handle)
+        {
+            weapon.item_id = new_id;
+            weapon.gem_gaitem_handle = Some(gem_handle as i32);
+        }
+        *weapon_slot_mut!(user_data_x.equipped_items_item_id, slot) = new_id;
+        *weapon_slot_mut!(user_data_x.equipped_armaments_and_items, slot) = new_id;
+        Ok(())
+    }
+}
+
+// Weapon types the ash of war can be applied to
+#[cfg(feature = "params")]
+fn mountable_weapon_types(gem: &EQUIP_PARAM_GEM_ST) -> Vec<i16> {
+    // Before the DLC regulation the last four flags of the first group were reserved bits,
+    // read as a single field
+    let reserved = gem.reserved_canMountWep;
+    let flags = lsb_first_flags(
+        [
+            gem.canMountWep_Dagger,
+            gem.canMountWep_SwordNormal,
+            gem.canMountWep_SwordLarge,
+            gem.canMountWep_SwordGigantic,
+            gem.canMountWep_SaberNormal,
+            gem.canMountWep_SaberLarge,
+            gem.canMountWep_katana,
+            gem.canMountWep_SwordDoubleEdge,
+            gem.canMountWep_SwordPierce,
+            gem.canMountWep_RapierHeavy,
+            gem.canMountWep_AxeNormal,
+            gem.canMountWep_AxeLarge,
+            gem.canMountWep_HammerNormal,
+            gem.canMountWep_HammerLarge,
+            gem.canMountWep_Flail,
+            gem.canMountWep_SpearNormal,
+            gem.canMountWep_SpearLarge,
+            gem.canMountWep_SpearHeavy,
+            gem.canMountWep_SpearAxe,
+            gem.canMountWep_Sickle,
+            gem.canMountWep_Knuckle,
+            gem.canMountWep_Claw,
+            gem.canMountWep_Whip,
+            gem.canMountWep_AxhammerLarge,
+            gem.canMountWep_BowSmall,
+            gem.canMountWep_BowNormal,
+            gem.canMountWep_BowLarge,
+            gem.canMountWep_ClossBow,
+            gem.canMountWep_Ballista,
+            gem.canMountWep_Staff,
+            gem.canMountWep_Sorcery,
+            gem.canMountWep_Talisman,
+            gem.canMountWep_ShieldSmall,
+            gem.canMountWep_ShieldNormal,
+            gem.canMountWep_ShieldLarge,
+            gem.canMountWep_Torch,
+            gem.canMountWep_HandToHand | ((reserved >> 3) & 1),
+            gem.canMountWep_PerfumeBottle | ((reserved >> 2) & 1),
+            gem.canMountWep_ThrustingShield | ((reserved >> 1) & 1),
+            gem.canMountWep_ThrowingWeapon | (reserved & 1),
+        ],
+        0,
+    );
+    let dlc_flags = lsb_first_flags(
+        [
+            gem.canMountWep_ReverseHandSword,
+            gem.canMountWep_LightGreatsword,
+            gem.canMountWep_GreatKatana,
+            gem.canMountWep_BeastClaw,
+        ],
+        gem.reserved_canMountWep_0x3d_4,
+    );
+
+    flags
+        .into_iter()
+        .chain(dlc_flags)
+        .zip(MOUNT_WEAPON_TYPES)
+        .filter(|(flag, weapon_type)| *flag && *weapon_type != -1)
+        .map(|(_, weapon_type)| weapon_type)
+        .collect()
+}
+
+// Affinities the ash of war allows, indexed by affinity id
+#[cfg(feature = "params")]
+fn configurable_affinities(gem: &EQUIP_PARAM_GEM_ST) -> [bool; 16] {
+    lsb_first_flags(
+        [
+            gem.configurableWepAttr00,
+            gem.configurableWepAttr01,
+            gem.configurableWepAttr02,
+            gem.configurableWepAttr03,
+            gem.configurableWepAttr04,
+            gem.configurableWepAttr05,
+            gem.configurableWepAttr06,
+            gem.configurableWepAttr07,
+            gem.configurableWepAttr08,
+            gem.configurableWepAttr09,
+            gem.configurableWepAttr10,
+            gem.configurableWepAttr11,
+            gem.configurableWepAttr12,
+            gem.configurableWepAttr13,
+            gem.configurableWepAttr14,
+            gem.configurableWepAttr15,
+        ],
+        0,
+    )
+}
+
+#[test]
+fn affinity_ids_round_trip() {
+    for affinity in Affinity::ALL {
+        let weapon_id = 2000000 + affinity.id() * 100 + 25;
+        assert_eq!(Affinity::from_weapon_id(weapon_id), Some(affinity));
+    }
+    assert_eq!(Affinity::from_weapon_id(2001325), None);
+}
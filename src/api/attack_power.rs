@@ -0,0 +1,241 @@
+use super::calc::{correct_graph, lsb_first_flags, weapon_row_id};
+use crate::{
+    AttackElementCorrectParam::AttackElementCorrectParam, CalcCorrectGraph::CalcCorrectGraph,
+    EquipParamWeapon::EquipParamWeapon, ParamProvider, ReinforceParamWeapon::ReinforceParamWeapon,
+    SaveApi, SaveApiError,
+};
+
+/// Attack rating of a weapon, split by damage type.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct AttackPower {
+    pub physical: f32,
+    pub magic: f32,
+    pub fire: f32,
+    pub lightning: f32,
+    pub holy: f32,
+}
+
+impl AttackPower {
+    /// Returns the attack rating shown on the status screen, the sum of every damage type
+    /// rounded down.
+    pub fn total(&self) -> u32 {
+        [
+            self.physical,
+            self.magic,
+            self.fire,
+            self.lightning,
+            self.holy,
+        ]
+        .iter()
+        .map(|value| value.floor() as u32)
+        .sum()
+    }
+}
+
+// Scaling lost for every attribute below the weapon's requirement
+const REQUIREMENT_PENALTY: f32 = -0.4;
+
+impl SaveApi {
+    /// Computes the attack rating of a weapon equipped by the character at the specified
+    /// index, from the weapon's params, upgrade level, affinity and the character's
+    /// attributes, using the save's regulation.
+    ///
+    /// `slot` is the index of the weapon in `Character::equipped_items`: 0 to 2 for the
+    /// left hand, 3 to 5 for the right hand. The weapon is assumed to be held one-handed
+    /// and buffs aren't taken into account.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use er_save_lib::SaveApi;
+    /// let save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+    /// let right_hand = save_api.weapon_ar(0, 3).unwrap();
+    /// assert!(right_hand.total() > 100);
+    /// ```
+    pub fn weapon_ar(&self, index: usize, slot: usize) -> Result<AttackPower, SaveApiError> {
+        self.weapon_ar_with(index, slot, self)
+    }
+
+    /// Computes the attack rating of a weapon like `SaveApi::weapon_ar`, reading the params
+    /// from `params` instead of the save's regulation.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use er_save_lib::regulation_api::RegulationApi;
+    /// use er_save_lib::SaveApi;
+    /// let save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+    /// let regulation = RegulationApi::from_path("regulation.bin").unwrap();
+    /// let right_hand = save_api.weapon_ar_with(0, 3, &regulation).unwrap();
+    /// ```
+    pub fn weapon_ar_with(
+        &self,
+        index: usize,
+        slot: usize,
+        params: &impl ParamProvider,
+    ) -> Result<AttackPower, SaveApiError> {
+        let character = self.character(index)?;
+        let (_, _, id) = character
+            .equipped_items()
+            .into_iter()
+            .take(6)
+            .nth(slot)
+            .ok_or(SaveApiError::WeaponSlotOutOfRange(slot))?;
+        if id == u32::MAX {
+            return Ok(AttackPower::default());
+        }
+
+        let weapons = params.get_param::<EquipParamWeapon>()?.rows;
+        let Some(weapon) = weapons.get(&weapon_row_id(id)) else {
+            return Ok(AttackPower::default());
+        };
+        let reinforce_id = i32::from(weapon.reinforceTypeId) + (id % 100) as i32;
+        let reinforce = params.get_param::<ReinforceParamWeapon>()?.rows;
+        let Some(reinforce) = reinforce.get(&reinforce_id) else {
+            return Ok(AttackPower::default());
+        };
+        let element_corrections = params.get_param::<AttackElementCorrectParam>()?.rows;
+        let Some(element_correct) = element_corrections.get(&weapon.attackElementCorrectId) else {
+            return Ok(AttackPower::default());
+        };
+        let graphs = params.get_param::<CalcCorrectGraph>()?.rows;
+
+        // Damage types: physical, magic, fire, lightning, holy
+        let base = [
+            f32::from(weapon.attackBasePhysics) * reinforce.physicsAtkRate,
+            f32::from(weapon.attackBaseMagic) * reinforce.magicAtkRate,
+            f32::from(weapon.attackBaseFire) * reinforce.fireAtkRate,
+            f32::from(weapon.attackBaseThunder) * reinforce.thunderAtkRate,
+            f32::from(weapon.attackBaseDark) * reinforce.darkAtkRate,
+        ];
+        let graph_ids = [
+            weapon.correctType_Physics,
+            weapon.correctType_Magic,
+            weapon.correctType_Fire,
+            weapon.correctType_Thunder,
+            weapon.correctType_Dark,
+        ];
+
+        // Attributes: strength, dexterity, intelligence, faith, arcane
+        let attributes = [
+            character.strength(),
+            character.dexterity(),
+            character.intelligence(),
+            character.faith(),
+            character.arcane(),
+        ];
+        let requirements = [
+            weapon.properStrength,
+            weapon.properAgility,
+            weapon.properMagic,
+            weapon.properFaith,
+            weapon.properLuck,
+        ];
+        let scaling = [
+            weapon.correctStrength,
+            weapon.correctAgility,
+            weapon.correctMagic,
+            weapon.correctFaith,
+            weapon.correctLuck,
+        ];
+        let scaling_rates = [
+            reinforce.correctStrengthRate,
+            reinforce.correctAgilityRate,
+            reinforce.correctMagicRate,
+            reinforce.correctFaithRate,
+            reinforce.correctLuckRate,
+        ];
+
+        let e = element_correct;
+        let scales = lsb_first_flags(
+            [
+                e.isStrengthCorrect_byPhysics,
+                e.isDexterityCorrect_byPhysics,
+                e.isMagicCorrect_byPhysics,
+                e.isFaithCorrect_byPhysics,
+                e.isLuckCorrect_byPhysics,
+                e.isStrengthCorrect_byMagic,
+                e.isDexterityCorrect_byMagic,
+                e.isMagicCorrect_byMagic,
+                e.isFaithCorrect_byMagic,
+                e.isLuckCorrect_byMagic,
+                e.isStrengthCorrect_byFire,
+                e.isDexterityCorrect_byFire,
+                e.isMagicCorrect_byFire,
+                e.isFaithCorrect_byFire,
+                e.isLuckCorrect_byFire,
+                e.isStrengthCorrect_byThunder,
+                e.isDexterityCorrect_byThunder,
+                e.isMagicCorrect_byThunder,
+                e.isFaithCorrect_byThunder,
+                e.isLuckCorrect_byThunder,
+                e.isStrengthCorrect_byDark,
+                e.isDexterityCorrect_byDark,
+                e.isMagicCorrect_byDark,
+                e.isFaithCorrect_byDark,
+                e.isLuckCorrect_byDark,
+            ],
+            e.pad1,
+        );
+        let overwrites = [
+            e.overwriteStrengthCorrectRate_byPhysics,
+            e.overwriteDexterityCorrectRate_byPhysics,
+            e.overwriteMagicCorrectRate_byPhysics,
+            e.overwriteFaithCorrectRate_byPhysics,
+            e.overwriteLuckCorrectRate_byPhysics,
+            e.overwriteStrengthCorrectRate_byMagic,
+            e.overwriteDexterityCorrectRate_byMagic,
+            e.overwriteMagicCorrectRate_byMagic,
+            e.overwriteFaithCorrectRate_byMagic,
+            e.overwriteLuckCorrectRate_byMagic,
+            e.overwriteStrengthCorrectRate_byFire,
+            e.overwriteDexterityCorrectRate_byFire,
+            e.overwriteMagicCorrectRate_byFire,
+            e.overwriteFaithCorrectRate_byFire,
+            e.overwriteLuckCorrectRate_byFire,
+            e.overwriteStrengthCorrectRate_byThunder,
+            e.overwriteDexterityCorrectRate_byThunder,
+            e.overwriteMagicCorrectRate_byThunder,
+            e.overwriteFaithCorrectRate_byThunder,
+            e.overwriteLuckCorrectRate_byThunder,
+            e.overwriteStrengthCorrectRate_byDark,
+            e.overwriteDexterityCorrectRate_byDark,
+            e.overwriteMagicCorrectRate_byDark,
+            e.overwriteFaithCorrectRate_byDark,
+            e.overwriteLuckCorrectRate_byDark,
+        ];
+
+        let attack = std::array::from_fn::<f32, 5, _>(|element| {
+            let base = base[element];
+            let Some(graph) = graphs.get(&i32::from(graph_ids[element])) else {
+                return base;
+            };
+            let mut bonus = 0.;
+            for attribute in 0..5 {
+                let i = element * 5 + attribute;
+                if !scales[i] {
+                    continue;
+                }
+                if attributes[attribute] < u32::from(requirements[attribute]) {
+                    bonus += base * REQUIREMENT_PENALTY;
+                    continue;
+                }
+                // Some affinities replace the weapon's scaling for a single damage type
+                let scaling = if overwrites[i] == -1 {
+                    scaling[attribute]
+                } else {
+                    f32::from(overwrites[i])
+                } * scaling_rates[attribute];
+                let curve = correct_graph(graph, attributes[attribute] as f32);
+                bonus += base * scaling / 100. * curve / 100.;
+            }
+            base + bonus
+        });
+
+        Ok(AttackPower {
+            physical: attack[0],
+            magic: attack[1],
+            fire: attack[2],
+            lightning: attack[3],
+            holy: attack[4],
+        })
+    }
+}
@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+
+use super::{bosses, item::ItemCategory};
+use crate::{SaveApi, SaveApiError};
+
+/// A state the game can't produce on its own, found by `SaveApi::audit`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Suspicion {
+    /// Every level adds exactly one attribute point, so the attributes always add up to
+    /// the level plus 79.
+    StatsDontMatchLevel { level: u32, stat_total: u32 },
+    /// Rune memory counts every rune ever acquired and can't be lower than the runes
+    /// spent to reach the current level.
+    RuneMemoryBelowLevelCost { runes_memory: u32, required: u64 },
+    /// A great rune, restored or not, is owned although the boss dropping it hasn't been
+    /// defeated. `id` is the goods id owned and `event_id` the defeat flag of the boss.
+    GreatRuneBeforeBoss { id: u32, event_id: u32 },
+    /// A great rune drops once per playthrough but is owned more than once, counting the
+    /// restored version. `id` is the goods id of the unrestored great rune.
+    DuplicateGreatRune { id: u32, count: usize },
+}
+
+// Great runes as (unrestored id, restored id, boss defeat flag). Each one drops once from
+// its boss and is swapped for the restored version at a divine tower.
+pub(crate) const GREAT_RUNES: [(u32, u32, u32); 6] = [
+    (191, 8148, bosses::GODRICK),
+    (192, 8149, bosses::RADAHN),
+    (193, 8150, bosses::MORGOTT),
+    (194, 8151, bosses::RYKARD),
+    (195, 8152, bosses::MOHG),
+    (196, 8153, bosses::MALENIA),
+];
+
+// Highest starting level among the classes (Confessor)
+const MAX_STARTING_LEVEL: u32 = 10;
+
+impl SaveApi {
+    /// Checks the character at the specified index for states that can't be reached
+    /// without editing the save.
+    ///
+    /// The checks are:
+    /// - the attributes add up to the level,
+    /// - rune memory covers the runes needed to reach the level from the highest starting
+    ///   class level,
+    /// - great runes are only owned once their boss is defeated,
+    /// - great runes, restored or not, are owned at most once.
+    ///
+    /// Other items aren't checked against the flags that award them. An empty result
+    /// doesn't prove a save is legitimate, only that none of these checks failed.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{audit::Suspicion, MockCharacter, MockSave, SaveApi};
+    /// // A level 9 Vagabond
+    /// let mock = MockSave::new(vec![MockCharacter {
+    ///     level: 9,
+    ///     stats: [15, 10, 11, 14, 13, 9, 9, 7],
+    ///     ..Default::default()
+    /// }]);
+    /// let mut save_api = SaveApi::from_mock(&mock).unwrap();
+    /// assert!(save_api.audit(0).unwrap().is_empty());
+    ///
+    /// save_api.set_level(0, 200).unwrap();
+    /// let suspicions = save_api.audit(0).unwrap();
+    /// assert!(matches!(suspicions[0], Suspicion::StatsDontMatchLevel { level: 200, .. }));
+    /// ```
+    pub fn audit(&self, index: usize) -> Result<Vec<Suspicion>, SaveApiError> {
+        let character = self.character(index)?;
+        let mut suspicions = Vec::new();
+
+        let level = character.level();
+        let stat_total = character.stats().iter().map(|(_, value)| value).sum();
+        if stat_total != level + 79 {
+            suspicions.push(Suspicion::StatsDontMatchLevel { level, stat_total });
+        }
+
+        let required = (MAX_STARTING_LEVEL..level).map(level_up_cost).sum();
+        let runes_memory = character.runes_memory();
+        if u64::from(runes_memory) < required {
+            suspicions.push(Suspicion::RuneMemoryBelowLevelCost {
+                runes_memory,
+                required,
+            });
+        }
+
+        let mut counts = HashMap::new();
+        for (category, id) in character.owned_items() {
+            *counts.entry((category, id)).or_insert(0) += 1;
+        }
+        for (unrestored, restored, event_id) in GREAT_RUNES {
+            let owned = [unrestored, restored]
+                .into_iter()
+                .filter_map(|id| {
+                    counts
+                        .get(&(ItemCategory::Goods, id))
+                        .map(|count| (id, *count))
+                })
+                .collect::<Vec<_>>();
+            if owned.is_empty() {
+                continue;
+            }
+            if !character.event_flag(event_id)? {
+                for (id, _) in &owned {
+                    suspicions.push(Suspicion::GreatRuneBeforeBoss { id: *id, event_id });
+                }
+            }
+            let count = owned.iter().map(|(_, count)| count).sum();
+            if count > 1 {
+                suspicions.push(Suspicion::DuplicateGreatRune {
+                    id: unrestored,
+                    count,
+                });
+            }
+        }
+
+        Ok(suspicions)
+    }
+}
+
+// Runes needed to go from `level` to the next one
+fn level_up_cost(level: u32) -> u64 {
+    let level = f64::from(level);
+    let multiplier = ((level - 11.) * 0.02).max(0.) + 0.1;
+    (multiplier * (level + 81.).powi(2)).floor() as u64 + 1
+}
+
+#[test]
+fn level_up_cost_matches_the_game() {
+    assert_eq!(level_up_cost(1), 673);
+    assert_eq!(level_up_cost(11), 847);
+}
+
+#[cfg(feature = "regulation")]
+#[test]
+fn great_runes_are_checked_against_their_boss() {
+    use crate::{MockCharacter, MockSave};
+
+    let mock = MockSave::new(vec![MockCharacter {
+        level: 9,
+        stats: [15, 10, 11, 14, 13, 9, 9, 7],
+        items: vec![(ItemCategory::Goods, 191), (ItemCategory::Goods, 8148)],
+        ..Default::default()
+    }]);
+    let save_api = SaveApi::from_mock(&mock).unwrap();
+    assert_eq!(
+        save_api.audit(0).unwrap(),
+        vec![
+            Suspicion::GreatRuneBeforeBoss {
+                id: 191,
+                event_id: bosses::GODRICK
+            },
+            Suspicion::GreatRuneBeforeBoss {
+                id: 8148,
+                event_id: bosses::GODRICK
+            },
+            Suspicion::DuplicateGreatRune { id: 191, count: 2 },
+        ]
+    );
+}
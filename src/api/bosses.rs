@@ -0,0 +1,53 @@
+// Event flags set when a boss is defeated
+pub(crate) const MARGIT: u32 = 10000850;
+pub(crate) const GODRICK: u32 = 10000800;
+pub(crate) const RED_WOLF_OF_RADAGON: u32 = 14000850;
+pub(crate) const RENNALA: u32 = 14000800;
+pub(crate) const RADAHN: u32 = 1252380800;
+pub(crate) const RYKARD: u32 = 16000800;
+pub(crate) const ASTEL: u32 = 12040800;
+pub(crate) const REGAL_ANCESTOR_SPIRIT: u32 = 12090800;
+pub(crate) const LICHDRAGON_FORTISSAX: u32 = 12030850;
+pub(crate) const MORGOTT: u32 = 11000800;
+pub(crate) const FIRE_GIANT: u32 = 1252520800;
+pub(crate) const GODSKIN_DUO: u32 = 13000850;
+pub(crate) const MALIKETH: u32 = 13000800;
+pub(crate) const PLACIDUSAX: u32 = 13000830;
+pub(crate) const GIDEON: u32 = 11050850;
+pub(crate) const HOARAH_LOUX: u32 = 11050800;
+pub(crate) const ELDEN_BEAST: u32 = 19000800;
+pub(crate) const MALENIA: u32 = 15000800;
+pub(crate) const MOHG: u32 = 12050800;
+
+// Main bosses in the order they're usually met
+#[cfg(feature = "names")]
+pub(crate) const BOSSES: [(&str, u32); 19] = [
+    ("Margit, the Fell Omen", MARGIT),
+    ("Godrick the Grafted", GODRICK),
+    ("Red Wolf of Radagon", RED_WOLF_OF_RADAGON),
+    ("Rennala, Queen of the Full Moon", RENNALA),
+    ("Starscourge Radahn", RADAHN),
+    ("Rykard, Lord of Blasphemy", RYKARD),
+    ("Astel, Naturalborn of the Void", ASTEL),
+    ("Regal Ancestor Spirit", REGAL_ANCESTOR_SPIRIT),
+    ("Lichdragon Fortissax", LICHDRAGON_FORTISSAX),
+    ("Morgott, the Omen King", MORGOTT),
+    ("Fire Giant", FIRE_GIANT),
+    ("Godskin Duo", GODSKIN_DUO),
+    ("Maliketh, the Black Blade", MALIKETH),
+    ("Dragonlord Placidusax", PLACIDUSAX),
+    ("Sir Gideon Ofnir, the All-Knowing", GIDEON),
+    ("Hoarah Loux, Warrior", HOARAH_LOUX),
+    ("Radagon of the Golden Order / Elden Beast", ELDEN_BEAST),
+    ("Malenia, Blade of Miquella", MALENIA),
+    ("Mohg, Lord of Blood", MOHG),
+];
+
+// Name of the boss whose defeat sets the flag
+#[cfg(feature = "names")]
+pub(crate) fn boss_name(event_id: u32) -> Option<&'static str> {
+    BOSSES
+        .iter()
+        .find(|(_, flag)| *flag == event_id)
+        .map(|(name, _)| *name)
+}
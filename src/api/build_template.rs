@@ -0,0 +1,242 @@
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "params")]
+use super::{
+    character::equipment_slots,
+    inventory::{held_equip_index, held_handle},
+    item::{IdentityMapper, ItemCategory},
+    manifest::{ManifestEntry, Rejection},
+};
+use crate::{SaveApi, SaveApiError, Stat};
+
+// Number of memorized spell slots
+const SPELL_SLOTS: usize = 14;
+
+/// The loadout of a character, without anything tied to its progress, returned by
+/// `SaveApi::export_build` and applied by `SaveApi::apply_build`.
+///
+/// Ids are the ones stored in the save, before any `ItemIdMapper`, so a template can be
+/// applied to any save. With the `serde` feature the template can be serialized, to JSON
+/// for instance.
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct BuildTemplate {
+    pub level: u32,
+    /// Attributes in the order of `Stat::ALL`.
+    pub stats: [u32; 8],
+    /// Item ids of every equipment slot, in the order of `Character::equipped_items`.
+    pub equipment: [Option<u32>; 18],
+    /// Goods ids of the memorized spells.
+    pub spells: [Option<u32>; SPELL_SLOTS],
+    /// Goods ids of the crystal tears mixed in the Flask of Wondrous Physick.
+    pub physick: [Option<u32>; 2],
+}
+
+/// What `SaveApi::apply_build` does with items of the template the character doesn't hold.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Strictness {
+    /// Fail with `SaveApiError::ItemNotOwned`.
+    RequireOwned,
+    /// Add the missing talismans and goods. Weapons and armor can't be added, see
+    /// `manifest::Rejection::UnsupportedCategory`, so they still fail.
+    AddMissing,
+}
+
+// Field of an equipment struct holding a slot, in the order of `Character::equipped_items`
+#[cfg(feature = "params")]
+macro_rules! equipment_slot_mut {
+    ($equipped:expr, $slot:expr) => {
+        match $slot {
+            0 => &mut $equipped.left_hand_armament1,
+            1 => &mut $equipped.left_hand_armament2,
+            2 => &mut $equipped.left_hand_armament3,
+            3 => &mut $equipped.right_hand_armament1,
+            4 => &mut $equipped.right_hand_armament2,
+            5 => &mut $equipped.right_hand_armament3,
+            6 => &mut $equipped.arrows1,
+            7 => &mut $equipped.arrows2,
+            8 => &mut $equipped.bolts1,
+            9 => &mut $equipped.bolts2,
+            10 => &mut $equipped.head,
+            11 => &mut $equipped.chest,
+            12 => &mut $equipped.arms,
+            13 => &mut $equipped.legs,
+            14 => &mut $equipped.talisman1,
+            15 => &mut $equipped.talisman2,
+            16 => &mut $equipped.talisman3,
+            _ => &mut $equipped.talisman4,
+        }
+    };
+}
+
+impl SaveApi {
+    /// Returns the level, attributes, equipment, spells and physick of the character at the
+    /// specified index as a template.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// save_api.set_level(0, 150).unwrap();
+    /// let build = save_api.export_build(0).unwrap();
+    /// assert_eq!(build.level, 150);
+    /// ```
+    pub fn export_build(&self, index: usize) -> Result<BuildTemplate, SaveApiError> {
+        let user_data_x = self.user_data_x(index)?;
+        let character = self.character(index)?;
+        let id = |id: u32| (id != u32::MAX && id != 0).then_some(id);
+
+        let ids = &user_data_x.equipped_items_item_id;
+        let equipment = [
+            ids.left_hand_armament1,
+            ids.left_hand_armament2,
+            ids.left_hand_armament3,
+            ids.right_hand_armament1,
+            ids.right_hand_armament2,
+            ids.right_hand_armament3,
+            ids.arrows1,
+            ids.arrows2,
+            ids.bolts1,
+            ids.bolts2,
+            ids.head,
+            ids.chest,
+            ids.arms,
+            ids.legs,
+            ids.talisman1,
+            ids.talisman2,
+            ids.talisman3,
+            ids.talisman4,
+        ];
+        let mut spells = [None; SPELL_SLOTS];
+        for (slot, spell) in spells
+            .iter_mut()
+            .zip(&user_data_x.equipped_spells.spellslot)
+        {
+            *slot = id(spell.spell_id);
+        }
+        let physick = &user_data_x.equipped_physics;
+
+        Ok(BuildTemplate {
+            level: character.level(),
+            stats: Stat::ALL.map(|stat| character.stat(stat)),
+            equipment: equipment.map(id),
+            spells,
+            physick: [id(physick.slot1), id(physick.slot2)],
+        })
+    }
+
+    /// Gives the character at the specified index the level, attributes, equipment, spells
+    /// and physick of `build`. Every item has to be held, or is added as allowed by
+    /// `strictness` before anything else is changed.
+    ///
+    /// # Errors
+    /// - `SaveApiError::ItemNotOwned` if an item isn't held and can't be added.
+    /// - `SaveApiError::UnsupportedItem` if a missing item is a weapon or armor.
+    /// - `SaveApiError::InventoryFull` if there is no room to add a missing item.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use er_save_lib::build_template::Strictness;
+    /// use er_save_lib::SaveApi;
+    /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+    /// let build = save_api.export_build(0).unwrap();
+    /// save_api
+    ///     .apply_build(1, &build, Strictness::AddMissing)
+    ///     .unwrap();
+    /// assert_eq!(save_api.export_build(1).unwrap(), build);
+    /// ```
+    #[cfg(feature = "params")]
+    pub fn apply_build(
+        &mut self,
+        index: usize,
+        build: &BuildTemplate,
+        strictness: Strictness,
+    ) -> Result<(), SaveApiError> {
+        // Categories are taken from the slots, ids from the template
+        let slots = equipment_slots(
+            &self.user_data_x(index)?.equipped_items_item_id,
+            &IdentityMapper,
+        );
+        let equipment: Vec<(usize, ItemCategory, u32)> = slots
+            .iter()
+            .zip(build.equipment)
+            .enumerate()
+            .filter_map(|(slot, ((_, category, _), id))| Some((slot, *category, id?)))
+            .collect();
+        let goods = build.spells.iter().chain(&build.physick).flatten();
+        let needed: Vec<(ItemCategory, u32)> = equipment
+            .iter()
+            .map(|(_, category, id)| (*category, *id))
+            .chain(goods.map(|id| (ItemCategory::Goods, *id)))
+            .collect();
+
+        let user_data_x = self.user_data_x(index)?;
+        let missing: Vec<ManifestEntry> = needed
+            .into_iter()
+            .filter(|(category, id)| held_handle(user_data_x, *category, *id).is_none())
+            .map(|(category, id)| ManifestEntry {
+                category,
+                id,
+                quantity: 1,
+            })
+            .collect();
+        if let Some(entry) = missing.first() {
+            if strictness == Strictness::RequireOwned {
+                return Err(SaveApiError::ItemNotOwned(entry.category, entry.id));
+            }
+        }
+        if let Some(entry) = missing
+            .iter()
+            .find(|entry| !matches!(entry.category, ItemCategory::Talisman | ItemCategory::Goods))
+        {
+            return Err(SaveApiError::UnsupportedItem(entry.category, entry.id));
+        }
+        if !missing.is_empty() {
+            let report = self.import_inventory(index, &missing)?;
+            if let Some((entry, rejection)) = report.rejected.first() {
+                return Err(match rejection {
+                    Rejection::InventoryFull => SaveApiError::InventoryFull(entry.id),
+                    _ => SaveApiError::ItemNotOwned(entry.category, entry.id),
+                });
+            }
+        }
+
+        let mut character = self.character_mut(index)?;
+        character.set_level(build.level)?;
+        for (stat, value) in Stat::ALL.into_iter().zip(build.stats) {
+            character.set_stat(stat, value)?;
+        }
+
+        let user_data_x = self.user_data_x_mut(index)?;
+        for slot in 0..build.equipment.len() {
+            let held = equipment
+                .iter()
+                .find(|(equipped, _, _)| *equipped == slot)
+                .and_then(|(_, category, id)| {
+                    let handle = held_handle(user_data_x, *category, *id)?;
+                    Some((*id, handle, held_equip_index(user_data_x, handle)?))
+                });
+            let (id, handle, equip_index) = held.unwrap_or((u32::MAX, u32::MAX, u32::MAX));
+            *equipment_slot_mut!(user_data_x.equipped_items_item_id, slot) = id;
+            *equipment_slot_mut!(user_data_x.equipped_armaments_and_items, slot) = id;
+            *equipment_slot_mut!(user_data_x.equipped_items_gaitem_handle, slot) = handle;
+            *equipment_slot_mut!(user_data_x.equipped_items_equip_index, slot) = equip_index;
+        }
+        for (spell, id) in user_data_x
+            .equipped_spells
+            .spellslot
+            .iter_mut()
+            .zip(build.spells)
+        {
+            spell.spell_id = id.unwrap_or(u32::MAX);
+        }
+        let physick = &mut user_data_x.equipped_physics;
+        physick.slot1 = build.physick[0].unwrap_or(u32::MAX);
+        physick.slot2 = build.physick[1].unwrap_or(u32::MAX);
+        Ok(())
+    }
+}
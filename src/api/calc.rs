@@ -0,0 +1,92 @@
+// Curves and lookups shared by the stat calculators (equip load, defenses, attack power).
+
+use crate::defs::CACL_CORRECT_GRAPH_ST::CACL_CORRECT_GRAPH_ST;
+
+// Evaluates a piecewise curve in the format of `CalcCorrectGraph`. `points` are the
+// (input, output) stage bounds in increasing input order and `exponents` shape the segment
+// starting at the same index: a positive exponent `e` grows as `t^e`, a negative one as
+// `1 - (1 - t)^-e`. Inputs outside the stages are clamped.
+pub(crate) fn graph(points: &[(f32, f32)], exponents: &[f32], value: f32) -> f32 {
+    let Some(&(first_input, first_output)) = points.first() else {
+        return 0.;
+    };
+    if value <= first_input {
+        return first_output;
+    }
+    for (i, pair) in points.windows(2).enumerate() {
+        let ((start, low), (end, high)) = (pair[0], pair[1]);
+        if value > end {
+            continue;
+        }
+        let t = if end > start {
+            (value - start) / (end - start)
+        } else {
+            1.
+        };
+        let exponent = exponents.get(i).copied().unwrap_or(1.);
+        let t = if exponent >= 0. {
+            t.powf(exponent)
+        } else {
+            1. - (1. - t).powf(-exponent)
+        };
+        return low + (high - low) * t;
+    }
+    points.last().map_or(0., |(_, output)| *output)
+}
+
+// Id of the `EquipParamWeapon` row of an equipped weapon. Item ids add the upgrade level
+// to the last two digits and the affinity to the hundreds.
+pub(crate) fn weapon_row_id(item_id: u32) -> i32 {
+    (item_id - item_id % 100) as i32
+}
+
+// Evaluates a `CalcCorrectGraph` row
+pub(crate) fn correct_graph(row: &CACL_CORRECT_GRAPH_ST, value: f32) -> f32 {
+    graph(
+        &[
+            (row.stageMaxVal0, row.stageMaxGrowVal0),
+            (row.stageMaxVal1, row.stageMaxGrowVal1),
+            (row.stageMaxVal2, row.stageMaxGrowVal2),
+            (row.stageMaxVal3, row.stageMaxGrowVal3),
+            (row.stageMaxVal4, row.stageMaxGrowVal4),
+        ],
+        &[
+            row.adjPt_maxGrowVal0,
+            row.adjPt_maxGrowVal1,
+            row.adjPt_maxGrowVal2,
+            row.adjPt_maxGrowVal3,
+        ],
+        value,
+    )
+}
+
+// Param bitfields are stored least significant bit first, but deku 0.17 reads them most
+// significant bit first. Takes one-bit fields as parsed, followed by the padding bits that
+// complete the last byte (most significant first), and returns the flags in field order.
+pub(crate) fn lsb_first_flags<const N: usize>(parsed: [u8; N], padding: u8) -> [bool; N] {
+    let padding_bits = (8 - N % 8) % 8;
+    let mut bits = parsed.iter().map(|bit| bit & 1).collect::<Vec<_>>();
+    bits.extend((0..padding_bits).rev().map(|i| (padding >> i) & 1));
+    std::array::from_fn(|i| bits[i / 8 * 8 + 7 - i % 8] == 1)
+}
+
+#[test]
+fn graph_interpolates_segments() {
+    let points = [(1., 0.), (11., 10.), (21., 20.)];
+    assert_eq!(graph(&points, &[1., 1.], 0.), 0.);
+    assert_eq!(graph(&points, &[1., 1.], 6.), 5.);
+    assert_eq!(graph(&points, &[1., 2.], 16.), 12.5);
+    assert_eq!(graph(&points, &[1., -2.], 16.), 17.5);
+    assert_eq!(graph(&points, &[1., 1.], 99.), 20.);
+}
+
+#[test]
+fn lsb_first_flags_reorders_bits() {
+    // Byte 0x83 followed by a 0x01 byte holding a single flag and 7 padding bits
+    let parsed = [1, 0, 0, 0, 0, 0, 1, 1, 0];
+    let flags = lsb_first_flags(parsed, 1);
+    assert_eq!(
+        flags,
+        [true, true, false, false, false, false, false, true, true]
+    );
+}
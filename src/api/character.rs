@@ -0,0 +1,923 @@
+use std::collections::HashSet;
+
+use super::{
+    archetype::{Archetype, Gender, UnknownValue},
+    cookbooks,
+    event_flags::EventFlagsApi,
+    inventory::{InventoryIntegrity, InventoryIssue},
+    item::{self, ItemCategory, ItemIdMapper},
+    save_api::check_parsed,
+    starting_gift::StartingGift,
+    stat::Stat,
+    world_state::{TimeOfDay, Weather},
+};
+use crate::{
+    save::{
+        user_data_10::Profile,
+        user_data_x::{EquippedItemsItemIds, UserDataX},
+    },
+    Save, SaveApiError, SaveType,
+};
+
+/// A read-only view of a single character slot.
+///
+/// Obtained through `SaveApi::character`, which validates the slot index once so
+/// the accessors on this type don't need to.
+///
+/// # Example
+/// ```rust
+/// use er_save_lib::{SaveApi, SaveType};
+/// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+/// let chr = save_api.character(0).unwrap();
+/// println!("{} is level {}", chr.character_name(), chr.level());
+/// ```
+pub struct Character<'a> {
+    index: usize,
+    user_data_x: &'a UserDataX,
+    profile: &'a Profile,
+    mapper: &'a dyn ItemIdMapper,
+}
+
+/// A mutable view of a single character slot.
+///
+/// Obtained through `SaveApi::character_mut`. Setters that have a copy of the value in the
+/// profile summary (level, name, etc..) keep both in sync.
+///
+/// # Example
+/// ```rust
+/// use er_save_lib::{SaveApi, SaveType};
+/// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+/// let mut chr = save_api.character_mut(0).unwrap();
+/// chr.set_vigor(40).unwrap();
+/// assert_eq!(chr.vigor(), 40);
+/// ```
+pub struct CharacterMut<'a> {
+    index: usize,
+    user_data_x: &'a mut UserDataX,
+    profile: &'a mut Profile,
+    mapper: &'a dyn ItemIdMapper,
+}
+
+// Getters shared by both views
+macro_rules! character_getters {
+    () => {
+        /// Returns the slot index of the character.
+        pub fn index(&self) -> usize {
+            self.index
+        }
+
+        /// Returns the hp of the character.
+        pub fn hp(&self) -> u32 {
+            self.user_data_x.player_game_data.hp
+        }
+
+        /// Returns the max hp of the character.
+        pub fn max_hp(&self) -> u32 {
+            self.user_data_x.player_game_data.max_hp
+        }
+
+        /// Returns the base max hp of the character.
+        pub fn base_max_hp(&self) -> u32 {
+            self.user_data_x.player_game_data.base_max_hp
+        }
+
+        /// Returns the fp of the character.
+        pub fn fp(&self) -> u32 {
+            self.user_data_x.player_game_data.fp
+        }
+
+        /// Returns the max fp of the character.
+        pub fn max_fp(&self) -> u32 {
+            self.user_data_x.player_game_data.max_fp
+        }
+
+        /// Returns the base max fp of the character.
+        pub fn base_max_fp(&self) -> u32 {
+            self.user_data_x.player_game_data.base_max_fp
+        }
+
+        /// Returns the sp of the character.
+        pub fn sp(&self) -> u32 {
+            self.user_data_x.player_game_data.sp
+        }
+
+        /// Returns the max sp of the character.
+        pub fn max_sp(&self) -> u32 {
+            self.user_data_x.player_game_data.max_sp
+        }
+
+        /// Returns the base max sp of the character.
+        pub fn base_max_sp(&self) -> u32 {
+            self.user_data_x.player_game_data.base_max_sp
+        }
+
+        /// Returns the level of the character.
+        pub fn level(&self) -> u32 {
+            self.user_data_x.player_game_data.level
+        }
+
+        /// Returns the vigor of the character.
+        pub fn vigor(&self) -> u32 {
+            self.user_data_x.player_game_data.vigor
+        }
+
+        /// Returns the mind of the character.
+        pub fn mind(&self) -> u32 {
+            self.user_data_x.player_game_data.mind
+        }
+
+        /// Returns the endurance of the character.
+        pub fn endurance(&self) -> u32 {
+            self.user_data_x.player_game_data.endurance
+        }
+
+        /// Returns the strength of the character.
+        pub fn strength(&self) -> u32 {
+            self.user_data_x.player_game_data.strength
+        }
+
+        /// Returns the dexterity of the character.
+        pub fn dexterity(&self) -> u32 {
+            self.user_data_x.player_game_data.dexterity
+        }
+
+        /// Returns the intelligence of the character.
+        pub fn intelligence(&self) -> u32 {
+            self.user_data_x.player_game_data.intelligence
+        }
+
+        /// Returns the faith of the character.
+        pub fn faith(&self) -> u32 {
+            self.user_data_x.player_game_data.faith
+        }
+
+        /// Returns the arcane of the character.
+        pub fn arcane(&self) -> u32 {
+            self.user_data_x.player_game_data.arcane
+        }
+
+        /// Returns the value of the given stat.
+        pub fn stat(&self, stat: Stat) -> u32 {
+            let player_game_data = &self.user_data_x.player_game_data;
+            match stat {
+                Stat::Vigor => player_game_data.vigor,
+                Stat::Mind => player_game_data.mind,
+                Stat::Endurance => player_game_data.endurance,
+                Stat::Strength => player_game_data.strength,
+                Stat::Dexterity => player_game_data.dexterity,
+                Stat::Intelligence => player_game_data.intelligence,
+                Stat::Faith => player_game_data.faith,
+                Stat::Arcane => player_game_data.arcane,
+            }
+        }
+
+        /// Returns every stat paired with its current value.
+        pub fn stats(&self) -> [(Stat, u32); 8] {
+            Stat::ALL.map(|stat| (stat, self.stat(stat)))
+        }
+
+        /// Returns the runes held by the character.
+        pub fn runes(&self) -> u32 {
+            self.user_data_x.player_game_data.runes
+        }
+
+        /// Returns the rune memory of the character.
+        pub fn runes_memory(&self) -> u32 {
+            self.user_data_x.player_game_data.runes_memory
+        }
+
+        /// Returns the number of times the character died.
+        pub fn deaths(&self) -> u32 {
+            self.user_data_x.total_deaths_count
+        }
+
+        /// Returns the runes left on the bloodstain of the character.
+        pub fn bloodstain_runes(&self) -> u32 {
+            self.user_data_x.blood_stain.runes.max(0) as u32
+        }
+
+        /// Returns the highest weapon upgrade level the character ever reached, on the scale
+        /// of regular weapons (0 to 25). Matchmaking is based on it.
+        pub fn matchmaking_weapon_level(&self) -> u8 {
+            self.user_data_x.player_game_data.matchmaking_weapon_level
+        }
+
+        /// Returns the id of the archetype of the character.
+        pub fn archetype(&self) -> u8 {
+            self.user_data_x.player_game_data.archetype
+        }
+
+        /// Returns the gender of the character.
+        pub fn gender(&self) -> u8 {
+            self.user_data_x.player_game_data.gender
+        }
+
+        /// Returns the archetype of the character, or `UnknownValue` if the save holds an
+        /// id without an `Archetype` variant. `Character::archetype` returns the raw id.
+        pub fn archetype_enum(&self) -> Result<Archetype, UnknownValue> {
+            Archetype::try_from(self.archetype())
+        }
+
+        /// Returns the gender of the character, or `UnknownValue` if the save holds a
+        /// value without a `Gender` variant. `Character::gender` returns the raw value.
+        pub fn gender_enum(&self) -> Result<Gender, UnknownValue> {
+            Gender::try_from(self.gender())
+        }
+
+        /// Returns the gift picked when the character was created, or `None` if the save
+        /// holds an unknown gift id.
+        pub fn starting_gift(&self) -> Option<StartingGift> {
+            StartingGift::from_id(self.user_data_x.player_game_data.gift)
+        }
+
+        /// Returns the name of the character.
+        pub fn character_name(&self) -> &str {
+            &self.user_data_x.player_game_data.character_name
+        }
+
+        /// Returns the equipped gestures of the character.
+        pub fn equipped_gestures(&self) -> &Vec<u32> {
+            &self.user_data_x.equipped_gestures.equipped_gesture
+        }
+
+        /// Returns the unlocked regions of the character.
+        pub fn regions(&self) -> &Vec<u32> {
+            &self.user_data_x.unlocked_regions.ids
+        }
+
+        /// Returns the unlocked regions count of the character.
+        pub fn regions_count(&self) -> u32 {
+            self.user_data_x.unlocked_regions.count
+        }
+
+        /// Returns the seconds played as recorded in the profile summary.
+        pub fn seconds_played(&self) -> u32 {
+            self.profile.seconds_played
+        }
+
+        /// Returns the in-game time of the character's world.
+        pub fn time_of_day(&self) -> TimeOfDay {
+            let time = &self.user_data_x.world_area_time;
+            TimeOfDay {
+                hour: time.hour,
+                minute: time.minute,
+                second: time.second,
+            }
+        }
+
+        /// Returns the weather of the area the character was saved in.
+        pub fn weather(&self) -> Weather {
+            let weather = &self.user_data_x.world_area_weather;
+            Weather {
+                area_id: weather.area_id,
+                weather_type: weather.weather_type,
+                timer: weather.timer,
+            }
+        }
+
+        /// Returns the value of an event flag for the character.
+        pub fn event_flag(&self, event_id: u32) -> Result<bool, SaveApiError> {
+            EventFlagsApi::get(&self.user_data_x.event_flags, event_id)
+        }
+
+        /// Returns every item in the inventory and the storage box, with ids translated by
+        /// the save's `ItemIdMapper`.
+        pub fn owned_items(&self) -> Vec<(ItemCategory, u32)> {
+            item::owned_items(self.user_data_x)
+                .into_iter()
+                .map(|(category, id)| (category, self.mapper.map_id(category, id)))
+                .collect()
+        }
+
+        /// Returns the inconsistencies between the character's inventories and its item
+        /// handles, see `SaveApi::check_inventory`.
+        pub fn check_inventory(&self) -> Vec<InventoryIssue> {
+            InventoryIntegrity::new(self.user_data_x).check()
+        }
+
+        /// Returns the regions of the character's slot whose meaning isn't known yet, by name
+        /// and in the order they're stored, ending with the unparsed bytes at the end of the
+        /// slot. They are written back bit for bit, see `CharacterMut::opaque_region_mut`.
+        pub fn opaque_regions(&self) -> Vec<(&'static str, &[u8])> {
+            self.user_data_x.opaque_regions().to_vec()
+        }
+
+        /// Returns the item equipped in every slot, labelled with the slot name and with ids
+        /// translated by the save's `ItemIdMapper`. Empty slots hold `u32::MAX`.
+        pub fn equipped_items(&self) -> [(&'static str, ItemCategory, u32); 18] {
+            equipment_slots(&self.user_data_x.equipped_items_item_id, self.mapper)
+        }
+    };
+}
+
+// Labels the item ids of every equipment slot, with ids translated by `mapper`. Empty
+// slots hold `u32::MAX`.
+pub(crate) fn equipment_slots(
+    ids: &EquippedItemsItemIds,
+    mapper: &dyn ItemIdMapper,
+) -> [(&'static str, ItemCategory, u32); 18] {
+    [
+        ("Left hand 1", ItemCategory::Weapon, ids.left_hand_armament1),
+        ("Left hand 2", ItemCategory::Weapon, ids.left_hand_armament2),
+        ("Left hand 3", ItemCategory::Weapon, ids.left_hand_armament3),
+        (
+            "Right hand 1",
+            ItemCategory::Weapon,
+            ids.right_hand_armament1,
+        ),
+        (
+            "Right hand 2",
+            ItemCategory::Weapon,
+            ids.right_hand_armament2,
+        ),
+        (
+            "Right hand 3",
+            ItemCategory::Weapon,
+            ids.right_hand_armament3,
+        ),
+        ("Arrows 1", ItemCategory::Weapon, ids.arrows1),
+        ("Arrows 2", ItemCategory::Weapon, ids.arrows2),
+        ("Bolts 1", ItemCategory::Weapon, ids.bolts1),
+        ("Bolts 2", ItemCategory::Weapon, ids.bolts2),
+        ("Head", ItemCategory::Armor, ids.head),
+        ("Chest", ItemCategory::Armor, ids.chest),
+        ("Arms", ItemCategory::Armor, ids.arms),
+        ("Legs", ItemCategory::Armor, ids.legs),
+        ("Talisman 1", ItemCategory::Talisman, ids.talisman1),
+        ("Talisman 2", ItemCategory::Talisman, ids.talisman2),
+        ("Talisman 3", ItemCategory::Talisman, ids.talisman3),
+        ("Talisman 4", ItemCategory::Talisman, ids.talisman4),
+    ]
+    .map(|(slot, category, id)| {
+        let id = if id == u32::MAX {
+            id
+        } else {
+            mapper.map_id(category, id)
+        };
+        (slot, category, id)
+    })
+}
+
+/// Reason a character name was rejected by `CharacterMut::set_character_name`.
+#[derive(thiserror::Error, PartialEq, Eq, Debug)]
+pub enum CharacterNameError {
+    #[error("Character name is empty")]
+    Empty,
+    #[error("Character name is {length} UTF-16 code units long, at most {max} fit in the save")]
+    TooLong { length: usize, max: usize },
+    /// Control characters, including NUL which would end the name early.
+    #[error("Character name contains the control character {0:?}")]
+    ControlCharacter(char),
+    /// Characters outside of the Basic Multilingual Plane, private use characters and
+    /// invisible formatting characters. Allowed by `set_character_name_unrestricted`.
+    #[error("Character name contains {0:?}, which the in-game keyboard can't type")]
+    Untypeable(char),
+}
+
+// Names are stored as 32 bytes of UTF-16, followed by a terminator
+const MAX_NAME_LENGTH: usize = 16;
+
+/// Most UTF-16 code units a character name holds in a PC save.
+pub const MAX_NAME_LENGTH_PC: usize = MAX_NAME_LENGTH;
+/// Most UTF-16 code units a character name holds in a PlayStation save. The name field
+/// has the same size as on PC.
+pub const MAX_NAME_LENGTH_PLAYSTATION: usize = MAX_NAME_LENGTH;
+
+/// Rules a character name is checked against by `CharacterMut::set_character_name_with`.
+///
+/// # Example
+/// ```rust
+/// use er_save_lib::{CharacterNameError, NamePolicy, SaveType};
+/// let policy = NamePolicy::for_platform(SaveType::PC);
+/// assert!(policy.validate("Tarnished").is_ok());
+/// assert_eq!(
+///     policy.validate("Tarnished 🗡"),
+///     Err(CharacterNameError::Untypeable('🗡'))
+/// );
+///
+/// let policy = NamePolicy {
+///     max_length: 8,
+///     allow_extended_glyphs: true,
+/// };
+/// assert!(policy.validate("Tarnished 🗡").is_err());
+/// assert!(policy.validate("Ranni 🗡").is_ok());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct NamePolicy {
+    /// Most UTF-16 code units a name may take. Values above what the save holds are
+    /// capped.
+    pub max_length: usize,
+    /// Allows characters the in-game keyboard can't type, see
+    /// `CharacterNameError::Untypeable`. Names are still written as valid UTF-16, a
+    /// character outside of the Basic Multilingual Plane taking two code units.
+    pub allow_extended_glyphs: bool,
+}
+
+impl NamePolicy {
+    /// Returns the policy of the in-game keyboard for saves of a platform.
+    pub const fn for_platform(platform: SaveType) -> Self {
+        let max_length = match platform {
+            SaveType::PC => MAX_NAME_LENGTH_PC,
+            SaveType::Playstation => MAX_NAME_LENGTH_PLAYSTATION,
+        };
+        NamePolicy {
+            max_length,
+            allow_extended_glyphs: false,
+        }
+    }
+
+    /// Checks a name against the policy.
+    pub fn validate(&self, name: &str) -> Result<(), CharacterNameError> {
+        if name.is_empty() {
+            return Err(CharacterNameError::Empty);
+        }
+        let max = self.max_length.min(MAX_NAME_LENGTH);
+        let length = name.encode_utf16().count();
+        if length > max {
+            return Err(CharacterNameError::TooLong { length, max });
+        }
+        if let Some(c) = name.chars().find(|c| c.is_control()) {
+            return Err(CharacterNameError::ControlCharacter(c));
+        }
+        if !self.allow_extended_glyphs {
+            if let Some(c) = name.chars().find(|c| !is_typeable(*c)) {
+                return Err(CharacterNameError::Untypeable(c));
+            }
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn validate_character_name(
+    name: &str,
+    allow_untypeable: bool,
+) -> Result<(), CharacterNameError> {
+    NamePolicy {
+        max_length: MAX_NAME_LENGTH,
+        allow_extended_glyphs: allow_untypeable,
+    }
+    .validate(name)
+}
+
+fn is_typeable(c: char) -> bool {
+    !matches!(c,
+        // Outside of the Basic Multilingual Plane (emoji and the like)
+        '\u{10000}'..
+        // Private use area
+        | '\u{e000}'..='\u{f8ff}'
+        // Zero width characters and directional marks
+        | '\u{200b}'..='\u{200f}'
+        | '\u{202a}'..='\u{202e}'
+        | '\u{2060}'..='\u{206f}'
+        | '\u{feff}'
+    )
+}
+
+impl<'a> Character<'a> {
+    pub(crate) fn from_save(
+        save: &'a Save,
+        index: usize,
+        mapper: &'a dyn ItemIdMapper,
+    ) -> Result<Self, SaveApiError> {
+        let user_data_x = save
+            .user_data_x
+            .get(index)
+            .ok_or(SaveApiError::CharacterIndexOutOfRange(index))?;
+        check_parsed(user_data_x, index)?;
+        let profile = save
+            .user_data_10
+            .profile_summary
+            .profiles
+            .get(index)
+            .ok_or(SaveApiError::CharacterIndexOutOfRange(index))?;
+        Ok(Character {
+            index,
+            user_data_x,
+            profile,
+            mapper,
+        })
+    }
+
+    character_getters!();
+}
+
+impl<'a> CharacterMut<'a> {
+    pub(crate) fn new(
+        index: usize,
+        user_data_x: &'a mut UserDataX,
+        profile: &'a mut Profile,
+        mapper: &'a dyn ItemIdMapper,
+    ) -> Self {
+        CharacterMut {
+            index,
+            user_data_x,
+            profile,
+            mapper,
+        }
+    }
+
+    character_getters!();
+
+    /// Returns a read-only view of the same character.
+    pub fn as_character(&self) -> Character<'_> {
+        Character {
+            index: self.index,
+            user_data_x: self.user_data_x,
+            profile: self.profile,
+            mapper: self.mapper,
+        }
+    }
+
+    /// Sets the hp of the character.
+    pub fn set_hp(&mut self, hp: u32) -> Result<(), SaveApiError> {
+        self.user_data_x.player_game_data.hp = hp;
+        Ok(())
+    }
+
+    /// Sets the max hp of the character.
+    pub fn set_max_hp(&mut self, max_hp: u32) -> Result<(), SaveApiError> {
+        self.user_data_x.player_game_data.max_hp = max_hp;
+        Ok(())
+    }
+
+    /// Sets the base max hp of the character.
+    pub fn set_base_max_hp(&mut self, base_max_hp: u32) -> Result<(), SaveApiError> {
+        self.user_data_x.player_game_data.base_max_hp = base_max_hp;
+        Ok(())
+    }
+
+    /// Sets the fp of the character.
+    pub fn set_fp(&mut self, fp: u32) -> Result<(), SaveApiError> {
+        self.user_data_x.player_game_data.fp = fp;
+        Ok(())
+    }
+
+    /// Sets the max fp of the character.
+    pub fn set_max_fp(&mut self, max_fp: u32) -> Result<(), SaveApiError> {
+        self.user_data_x.player_game_data.max_fp = max_fp;
+        Ok(())
+    }
+
+    /// Sets the base max fp of the character.
+    pub fn set_base_max_fp(&mut self, base_max_fp: u32) -> Result<(), SaveApiError> {
+        self.user_data_x.player_game_data.base_max_fp = base_max_fp;
+        Ok(())
+    }
+
+    /// Sets the sp of the character.
+    pub fn set_sp(&mut self, sp: u32) -> Result<(), SaveApiError> {
+        self.user_data_x.player_game_data.sp = sp;
+        Ok(())
+    }
+
+    /// Sets the max sp of the character.
+    pub fn set_max_sp(&mut self, max_sp: u32) -> Result<(), SaveApiError> {
+        self.user_data_x.player_game_data.max_sp = max_sp;
+        Ok(())
+    }
+
+    /// Sets the base max sp of the character.
+    pub fn set_base_max_sp(&mut self, base_max_sp: u32) -> Result<(), SaveApiError> {
+        self.user_data_x.player_game_data.base_max_sp = base_max_sp;
+        Ok(())
+    }
+
+    /// Sets the level of the character.
+    pub fn set_level(&mut self, level: u32) -> Result<(), SaveApiError> {
+        self.user_data_x.player_game_data.level = level;
+        self.profile.level = level;
+        Ok(())
+    }
+
+    /// Sets the vigor of the character.
+    pub fn set_vigor(&mut self, vigor: u32) -> Result<(), SaveApiError> {
+        self.user_data_x.player_game_data.vigor = vigor;
+        Ok(())
+    }
+
+    /// Sets the mind of the character.
+    pub fn set_mind(&mut self, mind: u32) -> Result<(), SaveApiError> {
+        self.user_data_x.player_game_data.mind = mind;
+        Ok(())
+    }
+
+    /// Sets the endurance of the character.
+    pub fn set_endurance(&mut self, endurance: u32) -> Result<(), SaveApiError> {
+        self.user_data_x.player_game_data.endurance = endurance;
+        Ok(())
+    }
+
+    /// Sets the strength of the character.
+    pub fn set_strength(&mut self, strength: u32) -> Result<(), SaveApiError> {
+        self.user_data_x.player_game_data.strength = strength;
+        Ok(())
+    }
+
+    /// Sets the dexterity of the character.
+    pub fn set_dexterity(&mut self, dexterity: u32) -> Result<(), SaveApiError> {
+        self.user_data_x.player_game_data.dexterity = dexterity;
+        Ok(())
+    }
+
+    /// Sets the intelligence of the character.
+    pub fn set_intelligence(&mut self, intelligence: u32) -> Result<(), SaveApiError> {
+        self.user_data_x.player_game_data.intelligence = intelligence;
+        Ok(())
+    }
+
+    /// Sets the faith of the character.
+    pub fn set_faith(&mut self, faith: u32) -> Result<(), SaveApiError> {
+        self.user_data_x.player_game_data.faith = faith;
+        Ok(())
+    }
+
+    /// Sets the arcane of the character.
+    pub fn set_arcane(&mut self, arcane: u32) -> Result<(), SaveApiError> {
+        self.user_data_x.player_game_data.arcane = arcane;
+        Ok(())
+    }
+
+    /// Sets the value of the given stat.
+    pub fn set_stat(&mut self, stat: Stat, value: u32) -> Result<(), SaveApiError> {
+        let player_game_data = &mut self.user_data_x.player_game_data;
+        match stat {
+            Stat::Vigor => player_game_data.vigor = value,
+            Stat::Mind => player_game_data.mind = value,
+            Stat::Endurance => player_game_data.endurance = value,
+            Stat::Strength => player_game_data.strength = value,
+            Stat::Dexterity => player_game_data.dexterity = value,
+            Stat::Intelligence => player_game_data.intelligence = value,
+            Stat::Faith => player_game_data.faith = value,
+            Stat::Arcane => player_game_data.arcane = value,
+        }
+        Ok(())
+    }
+
+    /// Sets the runes held by the character.
+    pub fn set_runes(&mut self, runes: u32) -> Result<(), SaveApiError> {
+        self.user_data_x.player_game_data.runes = runes;
+        Ok(())
+    }
+
+    /// Sets the rune memory of the character.
+    pub fn set_runes_memory(&mut self, runes_memory: u32) -> Result<(), SaveApiError> {
+        self.user_data_x.player_game_data.runes_memory = runes_memory;
+        self.profile.runes_memory = runes_memory;
+        Ok(())
+    }
+
+    /// Sets the number of times the character died.
+    pub fn set_deaths(&mut self, deaths: u32) -> Result<(), SaveApiError> {
+        self.user_data_x.total_deaths_count = deaths;
+        Ok(())
+    }
+
+    /// Sets the runes left on the bloodstain of the character. The game stores them as a
+    /// signed value, so larger amounts are capped at `i32::MAX`.
+    pub fn set_bloodstain_runes(&mut self, runes: u32) -> Result<(), SaveApiError> {
+        self.user_data_x.blood_stain.runes = runes.min(i32::MAX as u32) as i32;
+        Ok(())
+    }
+
+    /// Sets the archetype of the character, as an `Archetype` or its id. Ids without an
+    /// `Archetype` variant are rejected with `SaveApiError::UnknownValue`.
+    pub fn set_archetype(&mut self, archetype: impl Into<u8>) -> Result<(), SaveApiError> {
+        let archetype = Archetype::try_from(archetype.into())?.id();
+        self.user_data_x.player_game_data.archetype = archetype;
+        self.profile.archetype = archetype;
+        Ok(())
+    }
+
+    /// Sets the gender of the character, as a `Gender` or its value. Values without a
+    /// `Gender` variant are rejected with `SaveApiError::UnknownValue`.
+    pub fn set_gender(&mut self, gender: impl Into<u8>) -> Result<(), SaveApiError> {
+        let gender = Gender::try_from(gender.into())?.id();
+        self.user_data_x.player_game_data.gender = gender;
+        self.profile.gender = gender;
+        Ok(())
+    }
+
+    /// Sets the gift picked when the character was created. The inventory is left as is,
+    /// `SaveApi::set_starting_gift` also gives the gift's items.
+    pub fn set_starting_gift(&mut self, gift: StartingGift) -> Result<(), SaveApiError> {
+        self.user_data_x.player_game_data.gift = gift.id();
+        self.profile.starting_gift = gift.id();
+        Ok(())
+    }
+
+    /// Sets the name of the character.
+    ///
+    /// The name must be 1 to 16 UTF-16 code units long and only hold characters the
+    /// in-game keyboard can type, see `CharacterNameError`.
+    pub fn set_character_name(&mut self, new_name: &str) -> Result<(), SaveApiError> {
+        validate_character_name(new_name, false)?;
+        self.user_data_x.player_game_data.character_name = new_name.to_string();
+        self.profile.character_name = new_name.to_string();
+        Ok(())
+    }
+
+    /// Sets the name of the character, allowing characters the in-game keyboard can't
+    /// type, such as emoji or invisible formatting characters. The game displays them, but
+    /// the player can't type the name back when renaming the character.
+    ///
+    /// Length and control characters are still checked, as they would corrupt the save.
+    pub fn set_character_name_unrestricted(&mut self, new_name: &str) -> Result<(), SaveApiError> {
+        validate_character_name(new_name, true)?;
+        self.user_data_x.player_game_data.character_name = new_name.to_string();
+        self.profile.character_name = new_name.to_string();
+        Ok(())
+    }
+
+    /// Sets the name of the character after checking it against `policy`.
+    pub fn set_character_name_with(
+        &mut self,
+        new_name: &str,
+        policy: &NamePolicy,
+    ) -> Result<(), SaveApiError> {
+        policy.validate(new_name)?;
+        self.user_data_x.player_game_data.character_name = new_name.to_string();
+        self.profile.character_name = new_name.to_string();
+        Ok(())
+    }
+
+    /// Sets the equipped gestures of the character.
+    pub fn set_equipped_gestures(&mut self, new_gestures: Vec<u32>) -> Result<(), SaveApiError> {
+        self.user_data_x.equipped_gestures.equipped_gesture = new_gestures;
+        Ok(())
+    }
+
+    /// Copies the character's equipment and active weapon slots to the profile summary,
+    /// which the load screen draws the character from.
+    pub fn refresh_slot_preview(&mut self) -> Result<(), SaveApiError> {
+        let preview = &mut self.profile.equipment;
+        preview.active_weapon_slots_and_arm_style =
+            self.user_data_x.active_weapon_slots_and_arm_style.clone();
+        preview.equipped_items_gaitem_handle =
+            self.user_data_x.equipped_items_gaitem_handle.clone();
+        preview.equipped_items_item_id = self.user_data_x.equipped_items_item_id.clone();
+        Ok(())
+    }
+
+    /// Adds a region to the character's unlocked regions.
+    ///
+    /// # Errors
+    /// `SaveApiError::SlotFull` if the slot has no zero padding left at its end for the
+    /// longer list.
+    pub fn add_region(&mut self, region_id: impl Into<u32>) -> Result<(), SaveApiError> {
+        let region_id = region_id.into();
+        let ids = &self.user_data_x.unlocked_regions.ids;
+        if !ids.contains(&region_id) {
+            let mut ids = ids.clone();
+            ids.push(region_id);
+            self.replace_regions(ids)?;
+        }
+        Ok(())
+    }
+
+    /// Removes a region from the character's unlocked regions.
+    pub fn remove_region(&mut self, region_id: impl Into<u32>) -> Result<(), SaveApiError> {
+        let region_id = region_id.into();
+        let mut ids = self.user_data_x.unlocked_regions.ids.clone();
+        if let Some(region_index) = ids.iter().position(|id| *id == region_id) {
+            ids.remove(region_index);
+            self.replace_regions(ids)?;
+        }
+        Ok(())
+    }
+
+    // The slot keeps its size, see `UserDataX::set_region_ids`
+    fn replace_regions(&mut self, ids: Vec<u32>) -> Result<(), SaveApiError> {
+        self.user_data_x
+            .set_region_ids(ids)
+            .map_err(|missing| SaveApiError::SlotFull(self.index, missing))
+    }
+
+    /// Sets the in-game time of the character's world. Fails with
+    /// `SaveApiError::InvalidTimeOfDay` for times past 23:59:59.
+    pub fn set_time_of_day(&mut self, time: TimeOfDay) -> Result<(), SaveApiError> {
+        if !time.is_valid() {
+            return Err(SaveApiError::InvalidTimeOfDay(
+                time.hour,
+                time.minute,
+                time.second,
+            ));
+        }
+        let world_area_time = &mut self.user_data_x.world_area_time;
+        world_area_time.hour = time.hour;
+        world_area_time.minute = time.minute;
+        world_area_time.second = time.second;
+        Ok(())
+    }
+
+    /// Sets the weather of the area the character was saved in.
+    pub fn set_weather(&mut self, weather: Weather) -> Result<(), SaveApiError> {
+        let world_area_weather = &mut self.user_data_x.world_area_weather;
+        world_area_weather.area_id = weather.area_id;
+        world_area_weather.weather_type = weather.weather_type;
+        world_area_weather.timer = weather.timer;
+        Ok(())
+    }
+
+    /// Replaces the character's unlocked regions. Duplicates are ignored.
+    ///
+    /// # Errors
+    /// `SaveApiError::SlotFull` if the slot has no zero padding left at its end for the
+    /// longer list. The regions are left unchanged.
+    pub fn set_regions<R: Into<u32> + Copy>(&mut self, regions: &[R]) -> Result<(), SaveApiError> {
+        let mut seen = HashSet::with_capacity(regions.len());
+        let ids: Vec<u32> = regions
+            .iter()
+            .map(|region| (*region).into())
+            .filter(|id| seen.insert(*id))
+            .collect();
+
+        self.replace_regions(ids)
+    }
+
+    /// Returns the bytes of a region of the slot whose meaning isn't known yet for editing,
+    /// by one of the names returned by `Character::opaque_regions`, or `None` for any other
+    /// name. Regions keep their size.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// let mut chr = save_api.character_mut(0).unwrap();
+    /// chr.opaque_region_mut("player_game_data.unk0xc0").unwrap()[0] = 1;
+    /// let bytes = save_api.to_vec().unwrap();
+    /// let save_api = SaveApi::from_slice(&bytes).unwrap();
+    /// let character = save_api.character(0).unwrap();
+    /// let regions = character.opaque_regions();
+    /// let region = regions.iter().find(|(name, _)| *name == "player_game_data.unk0xc0");
+    /// assert_eq!(region.unwrap().1[0], 1);
+    /// ```
+    pub fn opaque_region_mut(&mut self, name: &str) -> Option<&mut [u8]> {
+        self.user_data_x
+            .opaque_regions_mut()
+            .into_iter()
+            .find(|(region, _)| *region == name)
+            .map(|(_, bytes)| bytes)
+    }
+
+    /// Sets the value of an event flag for the character.
+    pub fn set_event_flag(&mut self, event_id: u32, on: bool) -> Result<(), SaveApiError> {
+        EventFlagsApi::set(&mut self.user_data_x.event_flags, event_id, on)
+    }
+
+    /// Gives a cookbook to the character and sets the flag unlocking its recipes. Does
+    /// nothing to the inventory if the cookbook is already owned.
+    pub fn unlock_cookbook(&mut self, cookbook_id: u32) -> Result<(), SaveApiError> {
+        let flag =
+            cookbooks::cookbook_flag(cookbook_id).ok_or(SaveApiError::NotACookbook(cookbook_id))?;
+        let owned =
+            item::owned_items(self.user_data_x).contains(&(ItemCategory::Goods, cookbook_id));
+        if !owned && !item::add_key_item(self.user_data_x, cookbook_id) {
+            return Err(SaveApiError::KeyItemsFull(cookbook_id));
+        }
+        self.set_event_flag(flag, true)
+    }
+
+    // Adds `quantity` of a talisman or goods to the held inventory. Talismans already owned
+    // aren't added again.
+    #[cfg(feature = "params")]
+    pub(crate) fn give_item(
+        &mut self,
+        category: ItemCategory,
+        id: u32,
+        quantity: u32,
+        key_item: bool,
+    ) -> Result<(), SaveApiError> {
+        let handle = match category {
+            ItemCategory::Talisman => {
+                if item::owned_items(self.user_data_x).contains(&(category, id)) {
+                    return Ok(());
+                }
+                0xa0000000 | id
+            }
+            _ => 0xb0000000 | id,
+        };
+        if !item::add_held_item(self.user_data_x, handle, quantity, key_item) {
+            return Err(SaveApiError::InventoryFull(id));
+        }
+        Ok(())
+    }
+
+    // Sets the held quantity of a talisman or goods, adding it when it isn't held
+    #[cfg(feature = "regulation")]
+    pub(crate) fn set_item_quantity(
+        &mut self,
+        category: ItemCategory,
+        id: u32,
+        quantity: u32,
+        key_item: bool,
+    ) -> Result<(), SaveApiError> {
+        let (handle, quantity) = match category {
+            ItemCategory::Talisman => (0xa0000000 | id, 1),
+            _ => (0xb0000000 | id, quantity),
+        };
+        if !item::set_held_item(self.user_data_x, handle, quantity, key_item) {
+            return Err(SaveApiError::InventoryFull(id));
+        }
+        Ok(())
+    }
+}
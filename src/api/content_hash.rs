@@ -0,0 +1,160 @@
+use std::collections::BTreeMap;
+
+use super::{event_flags::EventFlagsApi, item::resolve_handle};
+use crate::{save::user_data_x::UserDataX, SaveApi, SaveApiError};
+
+impl SaveApi {
+    /// Returns an md5 digest of the state of the character at the specified index, rather
+    /// than of its bytes: name, attributes, runes, equipment, spells, the items held and
+    /// stored, the event flags that are on, unlocked regions and position.
+    ///
+    /// Item handles, inventory slots, acquisition indices and padding are left out, so two
+    /// saves of the same character get the same hash even after being reordered or written
+    /// by different tools. The ids are the ones stored in the save, before any
+    /// `ItemIdMapper`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// assert_eq!(save_api.content_hash(0).unwrap(), save_api.content_hash(1).unwrap());
+    /// save_api.set_level(1, 50).unwrap();
+    /// assert_ne!(save_api.content_hash(0).unwrap(), save_api.content_hash(1).unwrap());
+    /// ```
+    pub fn content_hash(&self, index: usize) -> Result<[u8; 16], SaveApiError> {
+        let character = self.character(index)?;
+        let user_data_x = self.user_data_x(index)?;
+
+        let mut content = Vec::new();
+        let mut push = |value: u32| content.extend_from_slice(&value.to_le_bytes());
+        let name = character.character_name();
+        push(name.len() as u32);
+        name.bytes().for_each(|byte| push(byte as u32));
+        push(character.level());
+        character
+            .stats()
+            .into_iter()
+            .for_each(|(_, value)| push(value));
+        push(character.runes());
+        push(character.runes_memory());
+        push(character.archetype() as u32);
+        push(character.gender() as u32);
+        push(character.deaths());
+        push(character.seconds_played());
+
+        let ids = &user_data_x.equipped_items_item_id;
+        [
+            ids.left_hand_armament1,
+            ids.left_hand_armament2,
+            ids.left_hand_armament3,
+            ids.right_hand_armament1,
+            ids.right_hand_armament2,
+            ids.right_hand_armament3,
+            ids.arrows1,
+            ids.arrows2,
+            ids.bolts1,
+            ids.bolts2,
+            ids.head,
+            ids.chest,
+            ids.arms,
+            ids.legs,
+            ids.talisman1,
+            ids.talisman2,
+            ids.talisman3,
+            ids.talisman4,
+        ]
+        .into_iter()
+        .chain(
+            user_data_x
+                .equipped_spells
+                .spellslot
+                .iter()
+                .map(|spell| spell.spell_id),
+        )
+        .chain([
+            user_data_x.equipped_physics.slot1,
+            user_data_x.equipped_physics.slot2,
+        ])
+        // Empty slots are stored as 0 or u32::MAX depending on who wrote them
+        .for_each(|id| push(if id == 0 { u32::MAX } else { id }));
+
+        let items = item_counts(user_data_x);
+        push(items.len() as u32);
+        for ((stored, category, id), quantity) in items {
+            [stored, category, id, quantity]
+                .into_iter()
+                .for_each(&mut push);
+        }
+
+        let mut flags = EventFlagsApi::set_flags(&user_data_x.event_flags);
+        flags.sort_unstable();
+        push(flags.len() as u32);
+        flags.into_iter().for_each(&mut push);
+
+        let mut regions = character.regions().clone();
+        regions.sort_unstable();
+        regions.dedup();
+        push(regions.len() as u32);
+        regions.into_iter().for_each(&mut push);
+
+        let position = self.position(index)?;
+        let (x, y, z) = position.coordinates;
+        [x, y, z]
+            .into_iter()
+            .for_each(|value| push(value.to_bits()));
+        push(u32::from_le_bytes(position.map_id));
+
+        Ok(md5::compute(&content).0)
+    }
+}
+
+// Quantity of every item, summed over the slots holding it, keyed by whether it is in the
+// storage box, the category bits of its handle and its id
+fn item_counts(user_data_x: &UserDataX) -> BTreeMap<(u32, u32, u32), u32> {
+    let mut counts = BTreeMap::new();
+    let inventories = [
+        &user_data_x.inventory_held,
+        &user_data_x.inventory_storage_box,
+    ];
+    for (stored, inventory) in inventories.into_iter().enumerate() {
+        for item in inventory.common_items.iter().chain(&inventory.key_items) {
+            if item.quantity == 0 {
+                continue;
+            }
+            if let Some((_, id)) = resolve_handle(user_data_x, item.gaitem_handle) {
+                let key = (stored as u32, item.gaitem_handle >> 28, id);
+                *counts.entry(key).or_insert(0) += item.quantity;
+            }
+        }
+    }
+    counts
+}
+
+#[test]
+fn inventory_order_does_not_change_the_hash() {
+    use crate::{item::ItemCategory, MockCharacter, MockSave};
+
+    let mock = MockSave::new(vec![MockCharacter {
+        level: 1,
+        items: vec![(ItemCategory::Goods, 1000), (ItemCategory::Goods, 1001)],
+        ..Default::default()
+    }]);
+    let mut save_api = SaveApi::from_mock(&mock).unwrap();
+    let hash = save_api.content_hash(0).unwrap();
+
+    let items = &mut save_api
+        .user_data_x_mut(0)
+        .unwrap()
+        .inventory_held
+        .common_items;
+    let first = items.iter().position(|item| item.quantity > 0).unwrap();
+    let second = first
+        + 1
+        + items[first + 1..]
+            .iter()
+            .position(|item| item.quantity > 0)
+            .unwrap();
+    items.swap(first, second);
+    items[first].aqcuistion_index += 100;
+    assert_eq!(save_api.content_hash(0).unwrap(), hash);
+}
@@ -0,0 +1,140 @@
+use super::item::ItemCategory;
+#[cfg(feature = "params")]
+use crate::ShopLineupParam_Recipe::ShopLineupParam_Recipe;
+use crate::{SaveApi, SaveApiError};
+
+/// A crafting recipe from the `ShopLineupParam_Recipe` param.
+#[cfg(feature = "params")]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Recipe {
+    /// Row id of the recipe.
+    pub id: i32,
+    /// Category of the crafted item.
+    pub category: ItemCategory,
+    /// Id of the crafted item.
+    pub item_id: u32,
+    /// Row of `EquipMtrlSetParam` listing the materials the recipe consumes.
+    pub material_set_id: i32,
+    /// Flag set by the cookbook teaching the recipe, 0 for recipes known from the start.
+    pub unlock_flag: u32,
+}
+
+// Cookbooks are goods numbered in the order of their unlock flags, ten flags apart.
+// Base game cookbooks start at 9300, Shadow of the Erdtree ones at 2009301.
+const COOKBOOKS: [(std::ops::RangeInclusive<u32>, u32, u32); 2] = [
+    (9300..=9450, 9300, 67000),
+    (2009301..=2009345, 2009300, 68500),
+];
+
+/// Returns the event flag set when the cookbook with the specified goods id is read, or
+/// `None` if the item isn't a cookbook.
+///
+/// # Example
+/// ```rust
+/// use er_save_lib::cookbooks::cookbook_flag;
+/// assert_eq!(cookbook_flag(9300), Some(67000));
+/// assert_eq!(cookbook_flag(100), None);
+/// ```
+pub fn cookbook_flag(cookbook_id: u32) -> Option<u32> {
+    COOKBOOKS
+        .iter()
+        .find(|(ids, _, _)| ids.contains(&cookbook_id))
+        .map(|(_, first_id, first_flag)| first_flag + (cookbook_id - first_id) * 10)
+}
+
+impl SaveApi {
+    /// Returns the goods ids of the cookbooks owned by the character at the specified
+    /// index, in the inventory or the storage box.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::item::ItemCategory;
+    /// use er_save_lib::{MockCharacter, MockSave, SaveApi};
+    /// let mock = MockSave::new(vec![MockCharacter {
+    ///     level: 1,
+    ///     items: vec![(ItemCategory::Goods, 9300), (ItemCategory::Goods, 1000)],
+    ///     ..Default::default()
+    /// }]);
+    /// let save_api = SaveApi::from_mock(&mock).unwrap();
+    /// assert_eq!(save_api.owned_cookbooks(0).unwrap(), vec![9300]);
+    /// ```
+    pub fn owned_cookbooks(&self, index: usize) -> Result<Vec<u32>, SaveApiError> {
+        let mut cookbooks: Vec<u32> = self
+            .character(index)?
+            .owned_items()
+            .into_iter()
+            .filter(|(category, id)| {
+                *category == ItemCategory::Goods && cookbook_flag(*id).is_some()
+            })
+            .map(|(_, id)| id)
+            .collect();
+        cookbooks.sort_unstable();
+        cookbooks.dedup();
+        Ok(cookbooks)
+    }
+
+    /// Returns the recipes the character at the specified index can craft, those known
+    /// from the start and those taught by the cookbooks it has read, using the save's
+    /// regulation.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use er_save_lib::SaveApi;
+    /// let save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+    /// let recipes = save_api.craftable_recipes(0).unwrap();
+    /// assert!(recipes.iter().any(|recipe| recipe.unlock_flag == 67000));
+    /// ```
+    #[cfg(feature = "params")]
+    pub fn craftable_recipes(&self, index: usize) -> Result<Vec<Recipe>, SaveApiError> {
+        let character = self.character(index)?;
+        let rows = self.get_param::<ShopLineupParam_Recipe>()?.rows;
+
+        let mut recipes = Vec::new();
+        for (id, row) in rows {
+            let category = match row.equipType {
+                0 => ItemCategory::Weapon,
+                1 => ItemCategory::Armor,
+                2 => ItemCategory::Talisman,
+                3 => ItemCategory::Goods,
+                4 => ItemCategory::AshOfWar,
+                _ => continue,
+            };
+            if row.equipId <= 0 {
+                continue;
+            }
+            let unlock_flag = row.eventFlag_forRelease.max(0) as u32;
+            // Flags missing from the save can't have been set
+            if unlock_flag != 0 && !character.event_flag(unlock_flag).unwrap_or(false) {
+                continue;
+            }
+            recipes.push(Recipe {
+                id,
+                category,
+                item_id: row.equipId as u32,
+                material_set_id: row.mtrlId,
+                unlock_flag,
+            });
+        }
+        recipes.sort_unstable_by_key(|recipe| recipe.id);
+        Ok(recipes)
+    }
+
+    /// Gives a cookbook to the character at the specified index and sets the flag
+    /// unlocking its recipes, so the inventory and the craftable recipes stay in sync.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{MockCharacter, MockSave, SaveApi};
+    /// let mock = MockSave::new(vec![MockCharacter {
+    ///     level: 1,
+    ///     ..Default::default()
+    /// }]);
+    /// let mut save_api = SaveApi::from_mock(&mock).unwrap();
+    /// save_api.unlock_cookbook(0, 9304).unwrap();
+    /// assert!(save_api.owned_cookbooks(0).unwrap().contains(&9304));
+    /// assert!(save_api.character(0).unwrap().event_flag(67040).unwrap());
+    /// ```
+    pub fn unlock_cookbook(&mut self, index: usize, cookbook_id: u32) -> Result<(), SaveApiError> {
+        self.character_mut(index)?.unlock_cookbook(cookbook_id)
+    }
+}
@@ -0,0 +1,152 @@
+use super::practice::Position;
+use crate::{SaveApi, SaveApiError};
+
+/// Counters the game keeps for a character without showing them in any menu.
+///
+/// Obtained through `SaveApi::counters`. The save has no count of the NPCs killed, only the
+/// event flag set when each of them dies, see `SaveApi::get_event_flag`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Counters {
+    /// Times the character died.
+    pub deaths: u32,
+    /// Runes dropped on the last death, left on the bloodstain until they are picked up or
+    /// the character dies again.
+    pub bloodstain_runes: u32,
+}
+
+/// The bloodstain left where a character last died, holding the runes it dropped.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Bloodstain {
+    pub position: Position,
+    pub runes: u32,
+}
+
+impl SaveApi {
+    /// Returns the hidden counters of the character at the specified index.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// let counters = save_api.counters(0).unwrap();
+    /// assert_eq!(counters.deaths, save_api.deaths(0).unwrap());
+    /// ```
+    pub fn counters(&self, index: usize) -> Result<Counters, SaveApiError> {
+        let character = self.character(index)?;
+        Ok(Counters {
+            deaths: character.deaths(),
+            bloodstain_runes: character.bloodstain_runes(),
+        })
+    }
+
+    /// Returns the number of times the character at the specified index died.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// let deaths = save_api.deaths(0).unwrap();
+    /// ```
+    pub fn deaths(&self, index: usize) -> Result<u32, SaveApiError> {
+        Ok(self.character(index)?.deaths())
+    }
+
+    /// Sets the number of times the character at the specified index died.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// save_api.set_deaths(0, 0).unwrap();
+    /// assert_eq!(save_api.counters(0).unwrap().deaths, 0);
+    /// ```
+    pub fn set_deaths(&mut self, index: usize, deaths: u32) -> Result<(), SaveApiError> {
+        self.character_mut(index)?.set_deaths(deaths)
+    }
+
+    /// Returns the runes left on the bloodstain of the character at the specified index.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// let runes = save_api.bloodstain_runes(0).unwrap();
+    /// ```
+    pub fn bloodstain_runes(&self, index: usize) -> Result<u32, SaveApiError> {
+        Ok(self.character(index)?.bloodstain_runes())
+    }
+
+    /// Sets the runes left on the bloodstain of the character at the specified index.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// save_api.set_bloodstain_runes(0, 0).unwrap();
+    /// assert_eq!(save_api.counters(0).unwrap().bloodstain_runes, 0);
+    /// ```
+    pub fn set_bloodstain_runes(&mut self, index: usize, runes: u32) -> Result<(), SaveApiError> {
+        self.character_mut(index)?.set_bloodstain_runes(runes)
+    }
+
+    /// Returns the bloodstain of the character at the specified index, or `None` once its
+    /// runes were picked up or lost.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// if let Some(bloodstain) = save_api.bloodstain(0).unwrap() {
+    ///     println!("{} runes at {:?}", bloodstain.runes, bloodstain.position.coordinates);
+    /// }
+    /// ```
+    pub fn bloodstain(&self, index: usize) -> Result<Option<Bloodstain>, SaveApiError> {
+        let blood_stain = &self.user_data_x(index)?.blood_stain;
+        Ok((blood_stain.runes > 0).then_some(Bloodstain {
+            position: Position {
+                coordinates: blood_stain.coordinates,
+                map_id: blood_stain.map_id,
+                angle: blood_stain.angle,
+            },
+            runes: blood_stain.runes as u32,
+        }))
+    }
+
+    /// Places the bloodstain of the character at the specified index, or removes it with
+    /// `None`. Runes are capped at `i32::MAX` like `SaveApi::set_bloodstain_runes`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::practice::Position;
+    /// use er_save_lib::{Bloodstain, SaveApi, SaveType};
+    /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// let bloodstain = Bloodstain {
+    ///     position: Position {
+    ///         coordinates: (10., 20., 30.),
+    ///         map_id: [0, 42, 36, 60],
+    ///         angle: (0., 1., 0., 0.),
+    ///     },
+    ///     runes: 5000,
+    /// };
+    /// save_api.set_bloodstain(0, Some(bloodstain)).unwrap();
+    /// assert_eq!(save_api.bloodstain(0).unwrap(), Some(bloodstain));
+    /// save_api.set_bloodstain(0, None).unwrap();
+    /// assert_eq!(save_api.bloodstain(0).unwrap(), None);
+    /// ```
+    pub fn set_bloodstain(
+        &mut self,
+        index: usize,
+        bloodstain: Option<Bloodstain>,
+    ) -> Result<(), SaveApiError> {
+        let blood_stain = &mut self.user_data_x_mut(index)?.blood_stain;
+        let Some(bloodstain) = bloodstain else {
+            blood_stain.runes = 0;
+            return Ok(());
+        };
+        blood_stain.coordinates = bloodstain.position.coordinates;
+        blood_stain.map_id = bloodstain.position.map_id;
+        blood_stain.angle = bloodstain.position.angle;
+        blood_stain.runes = bloodstain.runes.min(i32::MAX as u32) as i32;
+        Ok(())
+    }
+}
@@ -0,0 +1,198 @@
+use super::{calc::graph, item::ItemCategory};
+use crate::{
+    EquipParamAccessory::EquipParamAccessory, EquipParamProtector::EquipParamProtector,
+    ParamProvider, SaveApi, SaveApiError, SpEffect::SpEffect,
+};
+
+/// Defensive stats of a character, as shown on the status screen.
+///
+/// Negations are percentages of incoming damage absorbed by the equipped armor and
+/// talismans. Resistances are the points needed to build up a status effect.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct Defenses {
+    pub physical: f32,
+    pub strike: f32,
+    pub slash: f32,
+    pub pierce: f32,
+    pub magic: f32,
+    pub fire: f32,
+    pub lightning: f32,
+    pub holy: f32,
+    /// Resistance to poison and scarlet rot.
+    pub immunity: u32,
+    /// Resistance to hemorrhage and frostbite.
+    pub robustness: u32,
+    /// Resistance to sleep and madness.
+    pub focus: u32,
+    /// Resistance to death blight.
+    pub vitality: u32,
+}
+
+// Resistance granted by the stat total (level + 79), and the bonus granted by the attribute
+// tied to each resistance. These are the curves of `CalcCorrectGraph` rows 110 and 120.
+const RESISTANCE_BY_LEVEL: [(f32, f32); 5] = [
+    (1., 75.),
+    (150., 105.),
+    (190., 145.),
+    (240., 160.),
+    (792., 180.),
+];
+const RESISTANCE_BY_ATTRIBUTE: [(f32, f32); 5] =
+    [(0., 0.), (30., 0.), (40., 30.), (60., 40.), (99., 50.)];
+
+// Damage cut rates, in the order of the negation fields of `Defenses`
+type CutRates = [f32; 8];
+
+impl SaveApi {
+    /// Computes the damage negations and status resistances of the character at the
+    /// specified index from its level, attributes, armor and talismans, using the save's
+    /// regulation. Temporary buffs aren't taken into account.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use er_save_lib::SaveApi;
+    /// let save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+    /// let defenses = save_api.defenses(0).unwrap();
+    /// assert!(defenses.physical > 0. && defenses.physical < 100.);
+    /// assert!(defenses.immunity > 0);
+    /// ```
+    pub fn defenses(&self, index: usize) -> Result<Defenses, SaveApiError> {
+        self.defenses_with(index, self)
+    }
+
+    /// Computes the damage negations and status resistances like `SaveApi::defenses`,
+    /// reading the params from `params` instead of the save's regulation.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use er_save_lib::regulation_api::RegulationApi;
+    /// use er_save_lib::SaveApi;
+    /// let save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+    /// let regulation = RegulationApi::from_path("regulation.bin").unwrap();
+    /// let defenses = save_api.defenses_with(0, &regulation).unwrap();
+    /// ```
+    pub fn defenses_with(
+        &self,
+        index: usize,
+        params: &impl ParamProvider,
+    ) -> Result<Defenses, SaveApiError> {
+        let character = self.character(index)?;
+        let armor = params.get_param::<EquipParamProtector>()?.rows;
+        let talismans = params.get_param::<EquipParamAccessory>()?.rows;
+        let effects = params.get_param::<SpEffect>()?.rows;
+
+        let mut cut: CutRates = [1.; 8];
+        // Immunity, robustness, focus and vitality
+        let mut points = [0i32; 4];
+        let mut rates = [1f32; 4];
+        for (_, category, id) in character.equipped_items() {
+            match category {
+                ItemCategory::Armor => {
+                    let Some(row) = armor.get(&(id as i32)) else {
+                        continue;
+                    };
+                    let piece = [
+                        row.neutralDamageCutRate,
+                        row.blowDamageCutRate,
+                        row.slashDamageCutRate,
+                        row.thrustDamageCutRate,
+                        row.magicDamageCutRate,
+                        row.fireDamageCutRate,
+                        row.thunderDamageCutRate,
+                        row.darkDamageCutRate,
+                    ];
+                    cut.iter_mut()
+                        .zip(piece)
+                        .for_each(|(cut, rate)| *cut *= rate);
+                    let resist = [
+                        row.resistPoison,
+                        row.resistBlood,
+                        row.resistSleep,
+                        row.resistCurse,
+                    ];
+                    points
+                        .iter_mut()
+                        .zip(resist)
+                        .for_each(|(points, resist)| *points += i32::from(resist));
+                }
+                ItemCategory::Talisman => {
+                    let Some(effect) = talismans
+                        .get(&(id as i32))
+                        .and_then(|row| effects.get(&row.refId))
+                    else {
+                        continue;
+                    };
+                    // The physical rate applies to every physical damage type
+                    let physical = effect.neutralDamageCutRate;
+                    let talisman = [
+                        physical,
+                        physical * effect.blowDamageCutRate,
+                        physical * effect.slashDamageCutRate,
+                        physical * effect.thrustDamageCutRate,
+                        effect.magicDamageCutRate,
+                        effect.fireDamageCutRate,
+                        effect.thunderDamageCutRate,
+                        effect.darkDamageCutRate,
+                    ];
+                    cut.iter_mut()
+                        .zip(talisman)
+                        .for_each(|(cut, rate)| *cut *= rate);
+                    let change = [
+                        effect.changePoisonResistPoint,
+                        effect.changeBloodResistPoint,
+                        effect.changeSleepResistPoint,
+                        effect.changeCurseResistPoint,
+                    ];
+                    points
+                        .iter_mut()
+                        .zip(change)
+                        .for_each(|(points, change)| *points += change);
+                    let rate = [
+                        effect.registPoizonChangeRate,
+                        effect.registBloodChangeRate,
+                        effect.registSleepChangeRate,
+                        effect.registCurseChangeRate,
+                    ];
+                    rates
+                        .iter_mut()
+                        .zip(rate)
+                        .for_each(|(rates, rate)| *rates *= rate);
+                }
+                _ => {}
+            }
+        }
+
+        let base = graph(
+            &RESISTANCE_BY_LEVEL,
+            &[1.; 4],
+            (character.level() + 79) as f32,
+        );
+        let attributes = [
+            character.vigor(),
+            character.endurance(),
+            character.mind(),
+            character.arcane(),
+        ];
+        let resistances: [u32; 4] = std::array::from_fn(|i| {
+            let bonus = graph(&RESISTANCE_BY_ATTRIBUTE, &[1.; 4], attributes[i] as f32);
+            let total = (base + bonus).floor() + points[i] as f32;
+            (total * rates[i]).max(0.) as u32
+        });
+        let negation = cut.map(|rate| (1. - rate) * 100.);
+
+        Ok(Defenses {
+            physical: negation[0],
+            strike: negation[1],
+            slash: negation[2],
+            pierce: negation[3],
+            magic: negation[4],
+            fire: negation[5],
+            lightning: negation[6],
+            holy: negation[7],
+            immunity: resistances[0],
+            robustness: resistances[1],
+            focus: resistances[2],
+            vitality: resistances[3],
+        })
+    }
+}
@@ -1,35 +1,80 @@
-use std::{collections::HashMap, sync::OnceLock};
-
+#[cfg(feature = "names")]
+use super::bosses;
+use super::save_api::check_parsed;
 use crate::{Save, SaveApiError};
 
-// BST tree dump
-const EVENTFLAG_BST: &str = include_str!("../res/eventflag_bst.txt");
+// Offset of every block of flags, in units of `BLOCK_SIZE`, sorted by block. Generated by
+// build.rs from the BST tree dump in src/res/eventflag_bst.txt.
+include!(concat!(env!("OUT_DIR"), "/event_flag_blocks.rs"));
+
+/// Lookups of where event flags are stored.
+pub struct EventFlagsApi;
+
+/// Where an event flag is stored in the event flags of a character, see
+/// `EventFlagsApi::resolve`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct FlagLocation {
+    /// Offset of the byte holding the flag from the start of the event flags.
+    pub byte: usize,
+    /// Bit of the flag in that byte, 0 being the least significant one.
+    pub bit: u8,
+}
+
+/// An event flag whose value differs between two saves, see `SaveApi::event_flag_diff`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct FlagChange {
+    pub event_id: u32,
+    /// Value of the flag in the other save.
+    pub on: bool,
+}
 
-pub(crate) struct EventFlagsApi;
+impl FlagChange {
+    /// Returns the name of the boss whose defeat sets the flag, if it is one.
+    #[cfg(feature = "names")]
+    pub fn boss_name(&self) -> Option<&'static str> {
+        bosses::boss_name(self.event_id)
+    }
+}
 
 const FLAG_DIVISOR: u32 = 1000;
 const BLOCK_SIZE: u32 = 125;
 
 impl EventFlagsApi {
+    /// Returns where the flag with the specified id is stored, or `None` for ids outside
+    /// of the blocks the game uses. The blocks are looked up in a table sorted at build
+    /// time, so repeated lookups don't allocate or parse anything.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{EventFlagsApi, FlagLocation};
+    /// let location = EventFlagsApi::resolve(1005);
+    /// assert_eq!(location, Some(FlagLocation { byte: 125, bit: 2 }));
+    /// assert_eq!(EventFlagsApi::resolve(u32::MAX), None);
+    /// ```
+    pub fn resolve(event_id: u32) -> Option<FlagLocation> {
+        let block = event_id / FLAG_DIVISOR;
+        let index = event_id % FLAG_DIVISOR;
+        let position = EVENT_FLAG_BLOCKS
+            .binary_search_by_key(&block, |(block, _)| *block)
+            .ok()?;
+        let offset = EVENT_FLAG_BLOCKS[position].1 * BLOCK_SIZE;
+        Some(FlagLocation {
+            byte: (offset + index / 8) as usize,
+            bit: 7 - (index % 8) as u8,
+        })
+    }
+
     pub(crate) fn get_event_flag(
         raw: &Save,
         event_id: u32,
         character_index: usize,
     ) -> Result<bool, SaveApiError> {
-        let block = event_id / FLAG_DIVISOR;
-        let index = event_id - block * FLAG_DIVISOR;
-
-        if let Some(res) = Self::event_flag_map().get(&block) {
-            let offset = res * BLOCK_SIZE;
-            let byte_index = index / 8;
-            let mut bit_index = index - byte_index * 8;
-            bit_index = 7 - bit_index;
-            let eventflag_byte =
-                raw.user_data_x[character_index].event_flags[(offset + byte_index) as usize];
-            let eventflag_bit = ((eventflag_byte >> bit_index) & 1) == 1;
-            return Ok(eventflag_bit);
-        }
-        return Err(SaveApiError::EventIdNotFound(event_id));
+        let user_data_x = raw
+            .user_data_x
+            .get(character_index)
+            .ok_or(SaveApiError::CharacterIndexOutOfRange(character_index))?;
+        check_parsed(user_data_x, character_index)?;
+        Self::get(&user_data_x.event_flags, event_id)
     }
 
     pub(crate) fn set_event_flag(
@@ -38,44 +83,78 @@ impl EventFlagsApi {
         character_index: usize,
         on: bool,
     ) -> Result<(), SaveApiError> {
-        let block = event_id / FLAG_DIVISOR;
-        let index = event_id - block * FLAG_DIVISOR;
-
-        if let Some(res) = Self::event_flag_map().get(&block) {
-            let offset = res * BLOCK_SIZE;
-            let byte_index = index / 8;
-            let mut bit_index = index - byte_index * 8;
-            bit_index = 7 - bit_index;
-            let mut eventflag_byte =
-                raw.user_data_x[character_index].event_flags[(offset + byte_index) as usize];
-            if on {
-                eventflag_byte = eventflag_byte | (1 << bit_index);
-            } else {
-                eventflag_byte = eventflag_byte & !(1 << bit_index);
+        let user_data_x = raw
+            .user_data_x
+            .get_mut(character_index)
+            .ok_or(SaveApiError::CharacterIndexOutOfRange(character_index))?;
+        check_parsed(user_data_x, character_index)?;
+        Self::set(&mut user_data_x.event_flags, event_id, on)
+    }
+
+    // Reads a single flag from a character's event flags block
+    pub(crate) fn get(event_flags: &[u8], event_id: u32) -> Result<bool, SaveApiError> {
+        let FlagLocation { byte, bit } =
+            Self::resolve(event_id).ok_or(SaveApiError::EventIdNotFound(event_id))?;
+        Ok((event_flags[byte] >> bit) & 1 == 1)
+    }
+
+    // Writes a single flag into a character's event flags block
+    pub(crate) fn set(event_flags: &mut [u8], event_id: u32, on: bool) -> Result<(), SaveApiError> {
+        let FlagLocation { byte, bit } =
+            Self::resolve(event_id).ok_or(SaveApiError::EventIdNotFound(event_id))?;
+        if on {
+            event_flags[byte] |= 1 << bit;
+        } else {
+            event_flags[byte] &= !(1 << bit);
+        }
+        Ok(())
+    }
+
+    // Every flag that is on in a character's event flags block, in ascending order
+    pub(crate) fn set_flags(event_flags: &[u8]) -> Vec<u32> {
+        let mut flags = Vec::new();
+        for (block, res) in &EVENT_FLAG_BLOCKS {
+            let offset = (res * BLOCK_SIZE) as usize;
+            let Some(bytes) = event_flags.get(offset..offset + BLOCK_SIZE as usize) else {
+                continue;
+            };
+            for (byte_index, byte) in bytes.iter().enumerate() {
+                for bit_index in 0..8 {
+                    if (byte >> (7 - bit_index)) & 1 == 1 {
+                        flags.push(block * FLAG_DIVISOR + byte_index as u32 * 8 + bit_index);
+                    }
+                }
             }
-            raw.user_data_x[character_index].event_flags[(offset + byte_index) as usize] =
-                eventflag_byte;
-            return Ok(());
         }
-        return Err(SaveApiError::EventIdNotFound(event_id));
+        flags.sort_unstable();
+        flags.dedup();
+        flags
     }
 
-    // BST tree turned into a static hashmap
-    fn event_flag_map() -> &'static HashMap<u32, u32> {
-        static MAP: OnceLock<HashMap<u32, u32>> = OnceLock::new();
-        MAP.get_or_init(|| {
-            let mut map: HashMap<u32, u32> = HashMap::new();
-            for line in EVENTFLAG_BST.lines() {
-                let mut key_value = line.split(",");
-
-                if let (Some(key), Some(value)) = (key_value.next(), key_value.next()) {
-                    let key = key.parse::<u32>().unwrap();
-                    let value = value.parse::<u32>().unwrap();
-                    map.insert(key, value);
+    // Flags whose value differs between two event flags blocks, in ascending order, with
+    // their value in `after`
+    pub(crate) fn diff(before: &[u8], after: &[u8]) -> Vec<FlagChange> {
+        let mut changes = Vec::new();
+        for (block, res) in &EVENT_FLAG_BLOCKS {
+            let range = (res * BLOCK_SIZE) as usize..((res + 1) * BLOCK_SIZE) as usize;
+            let (Some(before), Some(after)) = (before.get(range.clone()), after.get(range)) else {
+                continue;
+            };
+            for (byte_index, (a, b)) in before.iter().zip(after).enumerate() {
+                for bit_index in 0..8 {
+                    let mask = 1 << (7 - bit_index);
+                    if (a ^ b) & mask != 0 {
+                        changes.push(FlagChange {
+                            event_id: block * FLAG_DIVISOR + byte_index as u32 * 8 + bit_index,
+                            on: b & mask != 0,
+                        });
+                    }
                 }
             }
-            map
-        })
+        }
+        changes.sort_unstable_by_key(|change| change.event_id);
+        changes.dedup();
+        changes
     }
 }
 
@@ -95,3 +174,16 @@ fn set_event_flag() {
     save.write_to_path("./test/null.sl2")
         .expect("Failed to write save file!");
 }
+
+#[cfg(feature = "regulation")]
+#[test]
+fn get_event_flag_out_of_range() {
+    let first_step_grace = 76101;
+    let save_api = crate::SaveApi::new_empty(crate::SaveType::PC).unwrap();
+    let save = save_api.raw();
+    let result = EventFlagsApi::get_event_flag(save, first_step_grace, save.user_data_x.len());
+    assert!(matches!(
+        result,
+        Err(SaveApiError::CharacterIndexOutOfRange(_))
+    ));
+}
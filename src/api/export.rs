@@ -0,0 +1,148 @@
+use std::io::Write;
+
+#[cfg(feature = "names")]
+use super::bosses;
+use super::{
+    arm_style::{self, ArmStyle},
+    character::equipment_slots,
+    event_flags::EventFlagsApi,
+    item,
+};
+use crate::{SaveApi, SaveApiError};
+
+/// Part of a character exported by `SaveApi::export_csv`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Section {
+    /// Every item of the inventory and the storage box, one row per slot in use.
+    Inventory,
+    /// Every event flag that is on, named when it is a boss defeat and the `names` feature
+    /// is enabled.
+    Flags,
+    /// Every equipment slot, marking how the active weapon of each hand is held.
+    Equipment,
+}
+
+impl SaveApi {
+    /// Writes a section of the character at the specified index to `writer` as CSV, with a
+    /// header row.
+    ///
+    /// Inventory rows hold the inventory (`held` or `storage_box`), the category and id
+    /// the item handle resolves to, translated by the save's `ItemIdMapper`, the quantity
+    /// and the acquisition index. The crate doesn't ship item names, tools that have them
+    /// can join on category and id. Flag rows hold the event id and, for boss defeats,
+    /// the boss name. Equipment rows hold the slot, the category and id of the item, and
+    /// for the active weapon of each hand `one_handed` or `two_handed`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::item::ItemCategory;
+    /// use er_save_lib::{export::Section, MockCharacter, MockSave, SaveApi};
+    /// use std::collections::HashSet;
+    /// let mock = MockSave::new(vec![MockCharacter {
+    ///     level: 1,
+    ///     event_flags: HashSet::from([10000850]),
+    ///     items: vec![(ItemCategory::Goods, 1000)],
+    ///     ..Default::default()
+    /// }]);
+    /// let save_api = SaveApi::from_mock(&mock).unwrap();
+    ///
+    /// let mut inventory = Vec::new();
+    /// save_api.export_csv(0, Section::Inventory, &mut inventory).unwrap();
+    /// let inventory = String::from_utf8(inventory).unwrap();
+    /// assert!(inventory.starts_with("inventory,category,id,quantity,acquisition_index\n"));
+    /// assert!(inventory.contains("\nheld,Goods,1000,1,"));
+    ///
+    /// let mut flags = Vec::new();
+    /// save_api.export_csv(0, Section::Flags, &mut flags).unwrap();
+    /// let flags = String::from_utf8(flags).unwrap();
+    /// assert!(flags.contains("\n10000850,\"Margit, the Fell Omen\"\n"));
+    /// ```
+    pub fn export_csv(
+        &self,
+        index: usize,
+        section: Section,
+        mut writer: impl Write,
+    ) -> Result<(), SaveApiError> {
+        let user_data_x = self.user_data_x(index)?;
+
+        match section {
+            Section::Inventory => {
+                writeln!(writer, "inventory,category,id,quantity,acquisition_index")?;
+                for (name, inventory) in [
+                    ("held", &user_data_x.inventory_held),
+                    ("storage_box", &user_data_x.inventory_storage_box),
+                ] {
+                    for slot in inventory.common_items.iter().chain(&inventory.key_items) {
+                        if slot.quantity == 0 {
+                            continue;
+                        }
+                        let Some((category, id)) =
+                            item::resolve_handle(user_data_x, slot.gaitem_handle)
+                        else {
+                            continue;
+                        };
+                        writeln!(
+                            writer,
+                            "{name},{category:?},{},{},{}",
+                            self.mapper().map_id(category, id),
+                            slot.quantity,
+                            slot.aqcuistion_index
+                        )?;
+                    }
+                }
+            }
+            Section::Flags => {
+                writeln!(writer, "event_id,name")?;
+                for event_id in EventFlagsApi::set_flags(&user_data_x.event_flags) {
+                    writeln!(writer, "{event_id},{}", flag_name(event_id))?;
+                }
+            }
+            Section::Equipment => {
+                writeln!(writer, "slot,category,id,held")?;
+                let active = &user_data_x.active_weapon_slots_and_arm_style;
+                let arm_style = ArmStyle::from_value(active.arm_style);
+                // Slots are listed left hand first, see `Character::equipped_items`
+                let left = active.left_hand_weapon_active_slot as usize;
+                let right = 3 + active.right_hand_weapon_active_slot as usize;
+                let slots = equipment_slots(&user_data_x.equipped_items_item_id, self.mapper());
+                for (i, (slot, category, id)) in slots.into_iter().enumerate() {
+                    let held = if i != left && i != right {
+                        ""
+                    } else {
+                        match (arm_style, i == left) {
+                            (Some(ArmStyle::TwoHandingLeft), true)
+                            | (Some(ArmStyle::TwoHandingRight), false) => "two_handed",
+                            (Some(ArmStyle::OneHanded), _) if arm_style::is_weapon(id) => {
+                                "one_handed"
+                            }
+                            _ => "",
+                        }
+                    };
+                    writeln!(writer, "{slot},{category:?},{id},{held}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+// Name of the boss whose defeat sets the flag, as a CSV field, or an empty one
+#[cfg(feature = "names")]
+fn flag_name(event_id: u32) -> String {
+    bosses::boss_name(event_id).map_or(String::new(), csv_field)
+}
+
+#[cfg(not(feature = "names"))]
+fn flag_name(_: u32) -> String {
+    String::new()
+}
+
+// Quotes a field holding a separator, a quote or a line break
+#[cfg(feature = "names")]
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
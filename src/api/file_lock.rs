@@ -0,0 +1,124 @@
+use std::{
+    fs::{self, File},
+    io::{self, Write},
+    path::Path,
+};
+
+#[cfg(feature = "lock")]
+use fs2::FileExt;
+
+use crate::SaveApiError;
+
+// Windows error codes returned when another process opened the file without sharing it,
+// or locked a range of it. The game does both while it writes the save. A lock held on
+// another platform is reported as `WouldBlock`.
+const ERROR_SHARING_VIOLATION: i32 = 32;
+const ERROR_LOCK_VIOLATION: i32 = 33;
+
+// Replaces the content of the file at `path` with `bytes`, creating it if needed. With the
+// `lock` feature the file is locked for the duration of the write, and a file locked by
+// another process fails with `SaveApiError::FileInUse` instead of being written over.
+//
+// Atomic writes go to a sibling file, `ER0000.sl2.tmp` for `ER0000.sl2`, which is synced
+// and renamed over `path`, so an interrupted write leaves either the old or the new save.
+// On Unix the lock on `path` is held until the rename is done. Windows can't rename over
+// a file that is open, so there the lock is only a check made right before the rename.
+// Other writes truncate `path` and write to it directly.
+pub(crate) fn write(path: &Path, bytes: &[u8], atomic: bool) -> Result<(), SaveApiError> {
+    if !atomic {
+        return write_in_place(path, bytes);
+    }
+
+    let mut temp_path = path.as_os_str().to_owned();
+    temp_path.push(".tmp");
+    let temp_path = Path::new(&temp_path);
+    let result = write_temp(temp_path, bytes).and_then(|()| {
+        // Nothing is renamed over a file another process is writing
+        let lock = path.exists().then(|| open(path)).transpose()?;
+        if cfg!(windows) {
+            drop(lock);
+            fs::rename(temp_path, path).map_err(|error| in_use(path, error))
+        } else {
+            let result = fs::rename(temp_path, path).map_err(|error| in_use(path, error));
+            drop(lock);
+            result
+        }
+    });
+    if result.is_err() {
+        let _ = fs::remove_file(temp_path);
+        return result;
+    }
+    #[cfg(unix)]
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        // Makes the rename itself durable
+        File::open(parent)?.sync_all()?;
+    }
+    Ok(())
+}
+
+// The bytes are written through the handle holding the lock: Windows locks are mandatory,
+// so a second handle couldn't write to the file.
+fn write_in_place(path: &Path, bytes: &[u8]) -> Result<(), SaveApiError> {
+    let mut file = open(path)?;
+    file.set_len(0).map_err(|error| in_use(path, error))?;
+    file.write_all(bytes).map_err(|error| in_use(path, error))?;
+    Ok(())
+}
+
+fn write_temp(path: &Path, bytes: &[u8]) -> Result<(), SaveApiError> {
+    let mut file = File::create(path)?;
+    file.write_all(bytes)?;
+    file.sync_all()?;
+    Ok(())
+}
+
+// Opens the file for writing, creating it if needed, and locks it with the `lock` feature
+fn open(path: &Path) -> Result<File, SaveApiError> {
+    let file = File::options()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
+        .map_err(|error| in_use(path, error))?;
+    #[cfg(feature = "lock")]
+    file.try_lock_exclusive()
+        .map_err(|error| in_use(path, error))?;
+    Ok(file)
+}
+
+fn in_use(path: &Path, error: io::Error) -> SaveApiError {
+    let locked = cfg!(windows)
+        && matches!(
+            error.raw_os_error(),
+            Some(ERROR_SHARING_VIOLATION | ERROR_LOCK_VIOLATION)
+        );
+    if locked || error.kind() == io::ErrorKind::WouldBlock {
+        SaveApiError::FileInUse(path.to_path_buf())
+    } else {
+        SaveApiError::IoError(error)
+    }
+}
+
+#[test]
+fn atomic_writes_leave_no_temporary_file() {
+    let dir = std::env::temp_dir().join(format!("er-save-lib-lock-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("ER0000.sl2");
+    let temp_path = dir.join("ER0000.sl2.tmp");
+
+    write(&path, b"first", true).unwrap();
+    write(&path, b"second", true).unwrap();
+    assert_eq!(fs::read(&path).unwrap(), b"second");
+    assert!(!temp_path.exists());
+
+    // A directory can't be written over, the temporary file is removed all the same
+    let blocked = dir.join("blocked");
+    fs::create_dir_all(&blocked).unwrap();
+    assert!(write(&blocked, b"third", true).is_err());
+    assert!(!dir.join("blocked.tmp").exists());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
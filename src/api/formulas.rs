@@ -0,0 +1,124 @@
+use super::calc::graph;
+use crate::{SaveApi, SaveApiError, Stat};
+
+// Max HP by vigor
+const HP: [(f32, f32); 5] = [
+    (1., 300.),
+    (25., 800.),
+    (40., 1450.),
+    (60., 1900.),
+    (99., 2100.),
+];
+const HP_EXPONENTS: [f32; 4] = [1.5, -1.1, -1.2, -1.2];
+
+// Max FP by mind
+const FP: [(f32, f32); 5] = [(1., 50.), (15., 95.), (35., 200.), (60., 350.), (99., 450.)];
+const FP_EXPONENTS: [f32; 4] = [1., 1., -1.2, 1.];
+
+// Max stamina by endurance
+const STAMINA: [(f32, f32); 5] = [
+    (1., 80.),
+    (15., 105.),
+    (30., 130.),
+    (50., 155.),
+    (99., 170.),
+];
+const STAMINA_EXPONENTS: [f32; 4] = [1., 1., 1., 1.];
+
+// Percentage of a weapon's scaling reached by a damage attribute, from the default
+// physical `CalcCorrectGraph` row
+const SCALING: [(f32, f32); 5] = [(1., 0.), (18., 25.), (60., 75.), (80., 90.), (150., 110.)];
+const SCALING_EXPONENTS: [f32; 4] = [1.2, -1.2, 1., 1.];
+
+// Highest value an attribute can be leveled to
+const MAX_STAT: u32 = 99;
+
+/// How much a stat value still gives, returned by `formulas::efficiency`.
+///
+/// Vigor is measured in max HP, mind in max FP, endurance in max stamina and the damage
+/// attributes in percent of a weapon's scaling, using the default physical curve. Weapons
+/// and spells with other curves have other soft caps.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Efficiency {
+    pub value: u32,
+    /// Number of soft caps the value has reached.
+    pub bracket: usize,
+    /// Soft cap ending the bracket of the value, or `None` past the last one.
+    pub next_soft_cap: Option<u32>,
+    /// What the next point adds, 0 at the maximum.
+    pub marginal_gain: f32,
+}
+
+/// Returns the soft caps of a stat, the values past which each point gives less.
+///
+/// # Example
+/// ```rust
+/// use er_save_lib::{formulas, Stat};
+/// assert_eq!(formulas::soft_caps(Stat::Vigor), vec![25, 40, 60]);
+/// ```
+pub fn soft_caps(stat: Stat) -> Vec<u32> {
+    let (points, _) = curve(stat);
+    points[1..]
+        .iter()
+        .map(|(input, _)| *input as u32)
+        .filter(|input| *input < MAX_STAT)
+        .collect()
+}
+
+/// Returns the soft cap bracket of a stat value and what the next point adds, so editors
+/// can warn about points spent past a soft cap.
+///
+/// # Example
+/// ```rust
+/// use er_save_lib::{formulas, Stat};
+/// let efficiency = formulas::efficiency(Stat::Vigor, 45);
+/// assert_eq!(efficiency.bracket, 2);
+/// assert_eq!(efficiency.next_soft_cap, Some(60));
+/// assert!(efficiency.marginal_gain < formulas::efficiency(Stat::Vigor, 30).marginal_gain);
+/// ```
+pub fn efficiency(stat: Stat, value: u32) -> Efficiency {
+    let (points, exponents) = curve(stat);
+    let soft_caps = soft_caps(stat);
+    let at = |value: u32| graph(points, exponents, value as f32);
+    Efficiency {
+        value,
+        bracket: soft_caps.iter().filter(|cap| **cap <= value).count(),
+        next_soft_cap: soft_caps.into_iter().find(|cap| *cap > value),
+        marginal_gain: if value < MAX_STAT {
+            at(value + 1) - at(value)
+        } else {
+            0.
+        },
+    }
+}
+
+impl SaveApi {
+    /// Returns the efficiency of every attribute of the character at the specified index,
+    /// in the order of `Stat::ALL`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType, Stat};
+    /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// save_api.set_vigor(0, 60).unwrap();
+    /// let report = save_api.stat_efficiency_report(0).unwrap();
+    /// assert_eq!(report[0].0, Stat::Vigor);
+    /// assert_eq!(report[0].1.next_soft_cap, None);
+    /// ```
+    pub fn stat_efficiency_report(
+        &self,
+        index: usize,
+    ) -> Result<[(Stat, Efficiency); 8], SaveApiError> {
+        let stats = self.character(index)?.stats();
+        Ok(stats.map(|(stat, value)| (stat, efficiency(stat, value))))
+    }
+}
+
+fn curve(stat: Stat) -> (&'static [(f32, f32)], &'static [f32]) {
+    match stat {
+        Stat::Vigor => (&HP, &HP_EXPONENTS),
+        Stat::Mind => (&FP, &FP_EXPONENTS),
+        Stat::Endurance => (&STAMINA, &STAMINA_EXPONENTS),
+        _ => (&SCALING, &SCALING_EXPONENTS),
+    }
+}
@@ -0,0 +1,118 @@
+use super::{
+    audit::GREAT_RUNES,
+    inventory::is_empty,
+    item::ItemCategory,
+    quick_items::{equipped_item, GOODS_ID_TYPE},
+};
+use crate::{SaveApi, SaveApiError};
+
+impl SaveApi {
+    /// Returns the goods id of the great rune equipped by the character at the specified
+    /// index, translated by the save's `ItemIdMapper`, or `None` if no great rune is
+    /// equipped.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// assert_eq!(save_api.equipped_great_rune(0).unwrap(), None);
+    /// ```
+    pub fn equipped_great_rune(&self, index: usize) -> Result<Option<u32>, SaveApiError> {
+        let handle = self
+            .user_data_x(index)?
+            .equipped_items
+            .great_rune
+            .gaitem_handle;
+        Ok((!is_empty(handle)).then(|| {
+            self.mapper()
+                .map_id(ItemCategory::Goods, handle & 0x0fffffff)
+        }))
+    }
+
+    /// Equips a held, restored great rune on the character at the specified index, or
+    /// unequips it with `None`. Rune Arc effects belong to the rune they were used on, so
+    /// changing the rune deactivates it.
+    ///
+    /// # Errors
+    /// - `SaveApiError::NotAGreatRune` if the item isn't a restored great rune.
+    /// - `SaveApiError::ItemNotOwned` if the great rune isn't held.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::item::ItemCategory;
+    /// use er_save_lib::{MockCharacter, MockSave, SaveApi};
+    /// let mock = MockSave::new(vec![MockCharacter {
+    ///     level: 1,
+    ///     // Godrick's Great Rune
+    ///     items: vec![(ItemCategory::Goods, 8148)],
+    ///     ..Default::default()
+    /// }]);
+    /// let mut save_api = SaveApi::from_mock(&mock).unwrap();
+    /// save_api.set_equipped_great_rune(0, Some(8148)).unwrap();
+    /// assert_eq!(save_api.equipped_great_rune(0).unwrap(), Some(8148));
+    /// // Radahn's Great Rune isn't held
+    /// assert!(save_api.set_equipped_great_rune(0, Some(8149)).is_err());
+    /// ```
+    pub fn set_equipped_great_rune(
+        &mut self,
+        index: usize,
+        goods_id: Option<u32>,
+    ) -> Result<(), SaveApiError> {
+        if let Some(goods_id) = goods_id {
+            if !GREAT_RUNES
+                .iter()
+                .any(|(_, restored, _)| *restored == goods_id)
+            {
+                return Err(SaveApiError::NotAGreatRune(goods_id));
+            }
+        }
+        let item = equipped_item(self.user_data_x(index)?, goods_id)?;
+
+        let user_data_x = self.user_data_x_mut(index)?;
+        if user_data_x.equipped_items.great_rune.gaitem_handle != item.gaitem_handle {
+            user_data_x.player_game_data.great_rune_on = false;
+        }
+        user_data_x.equipped_items.great_rune = item;
+        user_data_x.equipped_armaments_and_items.great_rune =
+            goods_id.map_or(u32::MAX, |id| GOODS_ID_TYPE | id);
+        Ok(())
+    }
+
+    /// Returns whether the Rune Arc effect of the great rune of the character at the
+    /// specified index is active.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// assert!(!save_api.great_rune_active(0).unwrap());
+    /// ```
+    pub fn great_rune_active(&self, index: usize) -> Result<bool, SaveApiError> {
+        Ok(self.user_data_x(index)?.player_game_data.great_rune_on)
+    }
+
+    /// Activates or deactivates the Rune Arc effect of the great rune of the character at
+    /// the specified index, without using a Rune Arc.
+    ///
+    /// # Errors
+    /// `SaveApiError::NoGreatRuneEquipped` when activating without a great rune equipped.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// assert!(save_api.set_great_rune_active(0, true).is_err());
+    /// save_api.set_great_rune_active(0, false).unwrap();
+    /// ```
+    pub fn set_great_rune_active(
+        &mut self,
+        index: usize,
+        active: bool,
+    ) -> Result<(), SaveApiError> {
+        if active && self.equipped_great_rune(index)?.is_none() {
+            return Err(SaveApiError::NoGreatRuneEquipped(index));
+        }
+        self.user_data_x_mut(index)?.player_game_data.great_rune_on = active;
+        Ok(())
+    }
+}
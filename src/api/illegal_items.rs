@@ -0,0 +1,213 @@
+use std::collections::HashSet;
+
+use super::{
+    calc::{lsb_first_flags, weapon_row_id},
+    inventory::{equipped_gaitem_handles, is_empty, InventoryKind},
+    item::{self, ItemCategory},
+};
+use crate::{
+    save::user_data_x::{Gaitem, UserDataX},
+    EquipParamAccessory::EquipParamAccessory,
+    EquipParamGem::EquipParamGem,
+    EquipParamGoods::EquipParamGoods,
+    EquipParamProtector::EquipParamProtector,
+    EquipParamWeapon::EquipParamWeapon,
+    SaveApi, SaveApiError,
+};
+
+/// Why `SaveApi::find_illegal_items` flagged an item.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum IllegalReason {
+    /// The item has no row in its param, so the game has no way to award it.
+    NotInRegulation,
+    /// The row of the item is disabled (`disableParam_NT`), which marks test and cut items.
+    Disabled,
+}
+
+/// An item owned by a character that the game can't award, found by
+/// `SaveApi::find_illegal_items`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct IllegalItem {
+    pub inventory: InventoryKind,
+    pub category: ItemCategory,
+    /// Id of the item, translated by the save's `ItemIdMapper`.
+    pub id: u32,
+    pub quantity: u32,
+    pub reason: IllegalReason,
+    /// The item is equipped, or is an ash of war mounted on a weapon. Such items are left
+    /// in place by `SaveApi::remove_illegal_items`.
+    pub in_use: bool,
+}
+
+// An illegal item along with the handle of the slot holding it
+struct Found {
+    handle: u32,
+    item: IllegalItem,
+}
+
+impl SaveApi {
+    /// Returns the items held or stored by the character at the specified index that
+    /// can't be obtained in the game: items missing from the save's regulation and items
+    /// whose row is disabled. Each inventory slot is reported on its own.
+    ///
+    /// Online, such items get a character flagged, see `SaveApi::remove_illegal_items`.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use er_save_lib::SaveApi;
+    /// let save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+    /// for item in save_api.find_illegal_items(0).unwrap() {
+    ///     println!("{:?} {} ({:?})", item.category, item.id, item.reason);
+    /// }
+    /// ```
+    pub fn find_illegal_items(&self, index: usize) -> Result<Vec<IllegalItem>, SaveApiError> {
+        Ok(self
+            .illegal_items(index)?
+            .into_iter()
+            .map(|found| found.item)
+            .collect())
+    }
+
+    /// Removes the items returned by `SaveApi::find_illegal_items` from the character at
+    /// the specified index and returns the ones removed. Weapons, armor and ashes of war
+    /// are dropped from the gaitem map as well.
+    ///
+    /// Items in use are kept, since removing them would leave the equipment pointing at
+    /// nothing. Unequip them in the game and call this again.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use er_save_lib::SaveApi;
+    /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+    /// let removed = save_api.remove_illegal_items(0).unwrap();
+    /// println!("Removed {} items", removed.len());
+    /// assert!(save_api
+    ///     .find_illegal_items(0)
+    ///     .unwrap()
+    ///     .iter()
+    ///     .all(|item| item.in_use));
+    /// ```
+    pub fn remove_illegal_items(&mut self, index: usize) -> Result<Vec<IllegalItem>, SaveApiError> {
+        let found = self.illegal_items(index)?;
+        if found.iter().all(|found| found.item.in_use) {
+            return Ok(Vec::new());
+        }
+        let user_data_x = self.user_data_x_mut(index)?;
+        let mut removed = Vec::new();
+        for Found { handle, item } in found {
+            if item.in_use || !item::take_item(user_data_x, handle, item.quantity) {
+                continue;
+            }
+            if !matches!(item.category, ItemCategory::Talisman | ItemCategory::Goods) {
+                free_gaitem(user_data_x, handle);
+            }
+            removed.push(item);
+        }
+        Ok(removed)
+    }
+
+    fn illegal_items(&self, index: usize) -> Result<Vec<Found>, SaveApiError> {
+        let user_data_x = self.user_data_x(index)?;
+        let weapons = self.get_param::<EquipParamWeapon>()?.rows;
+        let armor = self.get_param::<EquipParamProtector>()?.rows;
+        let talismans = self.get_param::<EquipParamAccessory>()?.rows;
+        let goods = self.get_param::<EquipParamGoods>()?.rows;
+        let gems = self.get_param::<EquipParamGem>()?.rows;
+
+        let equipped = &user_data_x.equipped_items_gaitem_handle;
+        let in_use: HashSet<u32> = equipped_gaitem_handles(user_data_x)
+            .into_iter()
+            .chain([
+                equipped.talisman1,
+                equipped.talisman2,
+                equipped.talisman3,
+                equipped.talisman4,
+            ])
+            .chain(
+                user_data_x
+                    .gaitem_map
+                    .iter()
+                    .filter_map(|gaitem| gaitem.gem_gaitem_handle)
+                    .map(|handle| handle as u32),
+            )
+            .filter(|handle| !is_empty(*handle))
+            .collect();
+
+        // Whether the row is disabled, or None without a row
+        let disabled = |category, id: u32| {
+            let flag = |nt, reserve| lsb_first_flags([nt], reserve)[0];
+            match category {
+                ItemCategory::Weapon => weapons
+                    .get(&weapon_row_id(id))
+                    .map(|row| flag(row.disableParam_NT, row.disableParamReserve1)),
+                ItemCategory::Armor => armor
+                    .get(&(id as i32))
+                    .map(|row| flag(row.disableParam_NT, row.disableParamReserve1)),
+                ItemCategory::Talisman => talismans
+                    .get(&(id as i32))
+                    .map(|row| flag(row.disableParam_NT, row.disableParamReserve1)),
+                ItemCategory::Goods => goods
+                    .get(&(id as i32))
+                    .map(|row| flag(row.disableParam_NT, row.disableParamReserve1)),
+                ItemCategory::AshOfWar => gems
+                    .get(&(id as i32))
+                    .map(|row| flag(row.disableParam_NT, row.disableParamReserve1)),
+            }
+        };
+
+        let mut found = Vec::new();
+        for (kind, inventory) in [
+            (InventoryKind::Held, &user_data_x.inventory_held),
+            (
+                InventoryKind::StorageBox,
+                &user_data_x.inventory_storage_box,
+            ),
+        ] {
+            for slot in inventory.common_items.iter().chain(&inventory.key_items) {
+                if slot.quantity == 0 {
+                    continue;
+                }
+                let handle = slot.gaitem_handle;
+                let Some((category, id)) = item::resolve_handle(user_data_x, handle) else {
+                    continue;
+                };
+                let reason = match disabled(category, id) {
+                    None => IllegalReason::NotInRegulation,
+                    Some(true) => IllegalReason::Disabled,
+                    Some(false) => continue,
+                };
+                found.push(Found {
+                    handle,
+                    item: IllegalItem {
+                        inventory: kind,
+                        category,
+                        id: self.mapper().map_id(category, id),
+                        quantity: slot.quantity,
+                        reason,
+                        in_use: in_use.contains(&handle),
+                    },
+                });
+            }
+        }
+        Ok(found)
+    }
+}
+
+// Frees the gaitem entry of a weapon, armor or ash of war. An ash mounted on a removed
+// weapon stays in the inventory on its own.
+fn free_gaitem(user_data_x: &mut UserDataX, handle: u32) {
+    if let Some(entry) = user_data_x
+        .gaitem_map
+        .iter_mut()
+        .find(|gaitem| gaitem.gaitem_handle == handle)
+    {
+        *entry = Gaitem {
+            gaitem_handle: 0,
+            item_id: 0,
+            unk0x10: None,
+            unk0x14: None,
+            gem_gaitem_handle: None,
+            unk0x1c: None,
+        };
+    }
+}
@@ -0,0 +1,147 @@
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+use serde::{de::Error, Deserialize, Deserializer};
+
+use super::{
+    item::ItemCategory,
+    manifest::{parse_category, ImportReport, ManifestEntry},
+};
+use crate::{SaveApi, SaveApiError, Stat};
+
+/// A character as exported to JSON by ERSaveManager and the C# editors sharing its format,
+/// applied by `SaveApi::import_er_save_manager`.
+///
+/// Fields are PascalCase and runes are called souls, as in the editors' earlier Souls
+/// games. Fields the crate doesn't model, such as the face data, are ignored.
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "PascalCase")]
+pub struct ErSaveManagerCharacter {
+    pub name: String,
+    pub level: u32,
+    pub vigor: u32,
+    pub mind: u32,
+    pub endurance: u32,
+    pub strength: u32,
+    pub dexterity: u32,
+    pub intelligence: u32,
+    pub faith: u32,
+    pub arcane: u32,
+    #[serde(alias = "Runes")]
+    pub souls: u32,
+    /// Id of the starting class, see `Archetype`.
+    #[serde(default, alias = "Class")]
+    pub archetype: Option<u8>,
+    /// Stored value of the body type, see `Gender`.
+    #[serde(default)]
+    pub gender: Option<u8>,
+    #[serde(default)]
+    pub inventory: Vec<ErSaveManagerItem>,
+}
+
+/// An item of an `ErSaveManagerCharacter`. Categories are named like the `ItemCategory`
+/// variants, in any case.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+#[serde(rename_all = "PascalCase")]
+pub struct ErSaveManagerItem {
+    #[serde(deserialize_with = "category")]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+    pub category: ItemCategory,
+    pub id: u32,
+    pub quantity: u32,
+}
+
+impl ErSaveManagerCharacter {
+    /// Parses an export.
+    ///
+    /// # Errors
+    /// `SaveApiError::InvalidManifest` with the line of the error if the JSON isn't a
+    /// character export.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::interop::ErSaveManagerCharacter;
+    /// let json = r#"{
+    ///     "Name": "Tarnished", "Level": 9, "Vigor": 15, "Mind": 10, "Endurance": 11,
+    ///     "Strength": 14, "Dexterity": 13, "Intelligence": 9, "Faith": 9, "Arcane": 7,
+    ///     "Souls": 1200, "Class": 1,
+    ///     "Inventory": [{ "Category": "goods", "Id": 10010, "Quantity": 5 }]
+    /// }"#;
+    /// let character = ErSaveManagerCharacter::from_json(json).unwrap();
+    /// assert_eq!(character.archetype, Some(1));
+    /// assert!(ErSaveManagerCharacter::from_json(r#"{ "Name": "Tarnished" }"#).is_err());
+    /// ```
+    pub fn from_json(json: &str) -> Result<Self, SaveApiError> {
+        serde_json::from_str(json)
+            .map_err(|error| SaveApiError::InvalidManifest(error.line(), error.to_string()))
+    }
+}
+
+impl SaveApi {
+    /// Applies an export to the character at the specified index: name, level, attributes,
+    /// runes, class and body type, then the inventory through `SaveApi::import_inventory`,
+    /// whose report is returned.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use er_save_lib::interop::ErSaveManagerCharacter;
+    /// use er_save_lib::SaveApi;
+    /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+    /// let json = r#"{
+    ///     "Name": "Tarnished", "Level": 9, "Vigor": 15, "Mind": 10, "Endurance": 11,
+    ///     "Strength": 14, "Dexterity": 13, "Intelligence": 9, "Faith": 9, "Arcane": 7,
+    ///     "Souls": 1200, "Class": 1,
+    ///     "Inventory": [{ "Category": "goods", "Id": 10010, "Quantity": 5 }]
+    /// }"#;
+    /// let character = ErSaveManagerCharacter::from_json(json).unwrap();
+    /// let report = save_api.import_er_save_manager(0, &character).unwrap();
+    /// assert!(report.rejected.is_empty());
+    /// assert_eq!(save_api.character_name(0).unwrap(), character.name);
+    /// ```
+    pub fn import_er_save_manager(
+        &mut self,
+        index: usize,
+        export: &ErSaveManagerCharacter,
+    ) -> Result<ImportReport, SaveApiError> {
+        let mut character = self.character_mut(index)?;
+        character.set_character_name(&export.name)?;
+        character.set_level(export.level)?;
+        let stats = [
+            export.vigor,
+            export.mind,
+            export.endurance,
+            export.strength,
+            export.dexterity,
+            export.intelligence,
+            export.faith,
+            export.arcane,
+        ];
+        for (stat, value) in Stat::ALL.into_iter().zip(stats) {
+            character.set_stat(stat, value)?;
+        }
+        character.set_runes(export.souls)?;
+        if let Some(archetype) = export.archetype {
+            character.set_archetype(archetype)?;
+        }
+        if let Some(gender) = export.gender {
+            character.set_gender(gender)?;
+        }
+
+        let entries: Vec<ManifestEntry> = export
+            .inventory
+            .iter()
+            .map(|item| ManifestEntry {
+                category: item.category,
+                id: item.id,
+                quantity: item.quantity,
+            })
+            .collect();
+        self.import_inventory(index, &entries)
+    }
+}
+
+fn category<'de, D: Deserializer<'de>>(deserializer: D) -> Result<ItemCategory, D::Error> {
+    let name = String::deserialize(deserializer)?;
+    parse_category(&name).ok_or_else(|| D::Error::custom(format!("invalid category {name:?}")))
+}
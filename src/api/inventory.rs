@@ -0,0 +1,592 @@
+use std::{
+    cmp::Reverse,
+    collections::{HashMap, HashSet},
+};
+
+use super::item::{self, ItemCategory};
+use crate::{
+    save::user_data_x::{EquippedItemsEquipIndex, Invenotry, InvenotryItem, UserDataX},
+    SaveApi, SaveApiError,
+};
+
+/// One of the two inventories of a character.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum InventoryKind {
+    Held,
+    StorageBox,
+}
+
+/// An inconsistency between the inventories of a character and the handles they point
+/// to, found by `SaveApi::check_inventory`. The game can crash or drop items on load when
+/// any of them is present.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum InventoryIssue {
+    /// The item count stored for a list doesn't match the number of slots in use.
+    CountMismatch {
+        inventory: InventoryKind,
+        key_items: bool,
+        count: u32,
+        used: u32,
+    },
+    /// A slot holds an item with a quantity of 0.
+    EmptyStack {
+        inventory: InventoryKind,
+        handle: u32,
+    },
+    /// A slot holds a handle without a valid item category.
+    UnknownCategory {
+        inventory: InventoryKind,
+        handle: u32,
+    },
+    /// A weapon, armor or ash of war handle isn't in the gaitem map.
+    MissingGaitem {
+        inventory: InventoryKind,
+        handle: u32,
+    },
+    /// An equipped weapon, armor or ash of war handle isn't in the gaitem map.
+    MissingEquippedGaitem { handle: u32 },
+    /// A handle appears more than once in the gaitem map.
+    DuplicateGaitem { handle: u32 },
+    /// A weapon, armor or ash of war handle is held by more than one slot of an inventory.
+    /// Each of them is a single item, unlike talismans and goods whose handle is their id.
+    /// The game itself leaves some handles both held and in the storage box, so those
+    /// aren't reported.
+    DuplicateHandle {
+        inventory: InventoryKind,
+        handle: u32,
+        count: usize,
+    },
+    /// Two slots of an inventory share an acquisition index, which orders the items in the
+    /// menus. Each inventory numbers its items with its own counter.
+    DuplicateAcquisitionIndex {
+        inventory: InventoryKind,
+        index: u32,
+    },
+}
+
+/// An inventory slot in use, listed by `SaveApi::inventory_items`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct InventoryItem {
+    pub inventory: InventoryKind,
+    pub key_item: bool,
+    pub category: ItemCategory,
+    /// Id of the item, translated by the save's `ItemIdMapper`.
+    pub id: u32,
+    pub quantity: u32,
+    /// Ordinal given to the item when it was picked up. The menus list items by it when
+    /// sorted by order of acquisition.
+    pub acquisition_index: u32,
+}
+
+/// Order `SaveApi::sort_inventory` puts the items of a character in.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum SortKey {
+    /// By category (weapons, armor, talismans, goods, ashes of war), then by id.
+    Id,
+    /// Largest stacks first, then by category and id.
+    Quantity,
+}
+
+// Checks that the inventories of a slot agree with its gaitem map. Run after every item
+// change in debug builds, and through `SaveApi::check_inventory` otherwise.
+pub(crate) struct InventoryIntegrity<'a> {
+    user_data_x: &'a UserDataX,
+}
+
+impl<'a> InventoryIntegrity<'a> {
+    pub(crate) fn new(user_data_x: &'a UserDataX) -> Self {
+        Self { user_data_x }
+    }
+
+    pub(crate) fn check(&self) -> Vec<InventoryIssue> {
+        let mut issues = Vec::new();
+
+        let mut gaitems = HashSet::new();
+        for gaitem in &self.user_data_x.gaitem_map {
+            let handle = gaitem.gaitem_handle;
+            if is_empty(handle) {
+                continue;
+            }
+            if !gaitems.insert(handle) {
+                issues.push(InventoryIssue::DuplicateGaitem { handle });
+            }
+        }
+
+        for (kind, inventory) in [
+            (InventoryKind::Held, &self.user_data_x.inventory_held),
+            (
+                InventoryKind::StorageBox,
+                &self.user_data_x.inventory_storage_box,
+            ),
+        ] {
+            self.check_counts(kind, inventory, &mut issues);
+
+            let mut handles: HashMap<u32, usize> = HashMap::new();
+            let mut acquisition_indices = HashSet::new();
+
+            for item in used_slots(&inventory.common_items).chain(used_slots(&inventory.key_items))
+            {
+                let handle = item.gaitem_handle;
+                if item.quantity == 0 {
+                    issues.push(InventoryIssue::EmptyStack {
+                        inventory: kind,
+                        handle,
+                    });
+                }
+                if !acquisition_indices.insert(item.aqcuistion_index) {
+                    issues.push(InventoryIssue::DuplicateAcquisitionIndex {
+                        inventory: kind,
+                        index: item.aqcuistion_index,
+                    });
+                }
+                match ItemCategory::from_handle(handle) {
+                    None => issues.push(InventoryIssue::UnknownCategory {
+                        inventory: kind,
+                        handle,
+                    }),
+                    Some(ItemCategory::Talisman | ItemCategory::Goods) => {}
+                    Some(_) => {
+                        if !gaitems.contains(&handle) {
+                            issues.push(InventoryIssue::MissingGaitem {
+                                inventory: kind,
+                                handle,
+                            });
+                        }
+                        *handles.entry(handle).or_insert(0) += 1;
+                    }
+                }
+            }
+
+            let mut duplicates = handles
+                .into_iter()
+                .filter(|(_, count)| *count > 1)
+                .collect::<Vec<_>>();
+            duplicates.sort_unstable();
+            issues.extend(duplicates.into_iter().map(|(handle, count)| {
+                InventoryIssue::DuplicateHandle {
+                    inventory: kind,
+                    handle,
+                    count,
+                }
+            }));
+        }
+
+        for handle in equipped_gaitem_handles(self.user_data_x) {
+            if !is_empty(handle) && !gaitems.contains(&handle) {
+                issues.push(InventoryIssue::MissingEquippedGaitem { handle });
+            }
+        }
+
+        issues
+    }
+
+    fn check_counts(
+        &self,
+        kind: InventoryKind,
+        inventory: &Invenotry,
+        issues: &mut Vec<InventoryIssue>,
+    ) {
+        for (key_items, count, items) in [
+            (false, inventory.common_item_count, &inventory.common_items),
+            (true, inventory.key_item_count, &inventory.key_items),
+        ] {
+            let used = used_slots(items).count() as u32;
+            if used != count {
+                issues.push(InventoryIssue::CountMismatch {
+                    inventory: kind,
+                    key_items,
+                    count,
+                    used,
+                });
+            }
+        }
+    }
+}
+
+// Handles of the equipped weapons, ammunition and armor, which all point into the gaitem map
+pub(crate) fn equipped_gaitem_handles(user_data_x: &UserDataX) -> [u32; 14] {
+    let equipped = &user_data_x.equipped_items_gaitem_handle;
+    [
+        equipped.left_hand_armament1,
+        equipped.right_hand_armament1,
+        equipped.left_hand_armament2,
+        equipped.right_hand_armament2,
+        equipped.left_hand_armament3,
+        equipped.right_hand_armament3,
+        equipped.arrows1,
+        equipped.bolts1,
+        equipped.arrows2,
+        equipped.bolts2,
+        equipped.head,
+        equipped.chest,
+        equipped.arms,
+        equipped.legs,
+    ]
+}
+
+// Equip index of the held item with this handle: its position in the held inventory,
+// counting the key items after every common item slot
+pub(crate) fn held_equip_index(user_data_x: &UserDataX, handle: u32) -> Option<u32> {
+    let inventory = &user_data_x.inventory_held;
+    inventory
+        .common_items
+        .iter()
+        .chain(&inventory.key_items)
+        .position(|item| item.gaitem_handle == handle && item.quantity > 0)
+        .map(|equip_index| equip_index as u32)
+}
+
+// Handle of a held item. Talismans and goods carry their id in the handle, weapons and
+// armor are looked up among the common items. Category bits of `id` are ignored.
+#[cfg(feature = "params")]
+pub(crate) fn held_handle(user_data_x: &UserDataX, category: ItemCategory, id: u32) -> Option<u32> {
+    let id = id & 0x0fffffff;
+    let handle = match category {
+        ItemCategory::Talisman => 0xa0000000 | id,
+        ItemCategory::Goods => 0xb0000000 | id,
+        _ => user_data_x
+            .inventory_held
+            .common_items
+            .iter()
+            .filter(|item| item.quantity > 0)
+            .map(|item| item.gaitem_handle)
+            .find(|handle| item::resolve_handle(user_data_x, *handle) == Some((category, id)))?,
+    };
+    held_equip_index(user_data_x, handle).map(|_| handle)
+}
+
+// Free slots hold either handle
+pub(crate) fn is_empty(handle: u32) -> bool {
+    handle == 0 || handle == u32::MAX
+}
+
+fn used_slots(items: &[InvenotryItem]) -> impl Iterator<Item = &InvenotryItem> {
+    items.iter().filter(|item| !is_empty(item.gaitem_handle))
+}
+
+// Merges the duplicate talisman and goods stacks of a list into the first one, frees empty
+// stacks and moves the slots in use to the front. Returns the number of slots freed and,
+// for every slot in use before, its old and new position.
+fn consolidate(items: &mut [InvenotryItem], count: &mut u32) -> (usize, Vec<(u32, u32)>) {
+    let mut kept: Vec<InvenotryItem> = Vec::new();
+    let mut moves = Vec::new();
+    let mut freed = 0;
+    for (position, item) in items.iter().enumerate() {
+        if is_empty(item.gaitem_handle) {
+            continue;
+        }
+        let stackable = matches!(
+            ItemCategory::from_handle(item.gaitem_handle),
+            Some(ItemCategory::Talisman | ItemCategory::Goods)
+        );
+        let first = kept
+            .iter()
+            .position(|kept| stackable && kept.gaitem_handle == item.gaitem_handle);
+        match first {
+            Some(first) => {
+                let stack = &mut kept[first];
+                stack.quantity = (stack.quantity + item.quantity).min(999);
+                stack.aqcuistion_index = stack.aqcuistion_index.min(item.aqcuistion_index);
+                moves.push((position as u32, first as u32));
+                freed += 1;
+            }
+            None if item.quantity == 0 => freed += 1,
+            None => {
+                moves.push((position as u32, kept.len() as u32));
+                kept.push(item.clone());
+            }
+        }
+    }
+
+    // Talismans are held once, whatever the stacks merged into them held
+    for item in &mut kept {
+        if ItemCategory::from_handle(item.gaitem_handle) == Some(ItemCategory::Talisman) {
+            item.quantity = 1;
+        }
+    }
+    *count = kept.len() as u32;
+    for (slot, item) in items.iter_mut().enumerate() {
+        *item = kept.get(slot).cloned().unwrap_or(InvenotryItem {
+            gaitem_handle: 0,
+            quantity: 0,
+            aqcuistion_index: 0,
+        });
+    }
+    (freed, moves)
+}
+
+// Item lists in the order of their sort locations: held common items and key items, then
+// stored common items and key items
+fn inventory_lists(user_data_x: &UserDataX) -> [&Vec<InvenotryItem>; 4] {
+    [
+        &user_data_x.inventory_held.common_items,
+        &user_data_x.inventory_held.key_items,
+        &user_data_x.inventory_storage_box.common_items,
+        &user_data_x.inventory_storage_box.key_items,
+    ]
+}
+
+fn equip_indices_mut(equip_index: &mut EquippedItemsEquipIndex) -> [&mut u32; 18] {
+    [
+        &mut equip_index.left_hand_armament1,
+        &mut equip_index.right_hand_armament1,
+        &mut equip_index.left_hand_armament2,
+        &mut equip_index.right_hand_armament2,
+        &mut equip_index.left_hand_armament3,
+        &mut equip_index.right_hand_armament3,
+        &mut equip_index.arrows1,
+        &mut equip_index.bolts1,
+        &mut equip_index.arrows2,
+        &mut equip_index.bolts2,
+        &mut equip_index.head,
+        &mut equip_index.chest,
+        &mut equip_index.arms,
+        &mut equip_index.legs,
+        &mut equip_index.talisman1,
+        &mut equip_index.talisman2,
+        &mut equip_index.talisman3,
+        &mut equip_index.talisman4,
+    ]
+}
+
+impl SaveApi {
+    /// Checks that the inventory and storage box of the character at the specified index
+    /// agree with the item handles of the slot. Items added through this crate are checked
+    /// after every change in debug builds, this is for release builds and for saves edited
+    /// elsewhere.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::item::ItemCategory;
+    /// use er_save_lib::{MockCharacter, MockSave, SaveApi};
+    /// let mock = MockSave::new(vec![MockCharacter {
+    ///     level: 1,
+    ///     items: vec![(ItemCategory::Goods, 1000), (ItemCategory::Talisman, 1000)],
+    ///     ..Default::default()
+    /// }]);
+    /// let save_api = SaveApi::from_mock(&mock).unwrap();
+    /// assert!(save_api.check_inventory(0).unwrap().is_empty());
+    /// ```
+    pub fn check_inventory(&self, index: usize) -> Result<Vec<InventoryIssue>, SaveApiError> {
+        Ok(self.character(index)?.check_inventory())
+    }
+
+    /// Returns the slots in use of the inventory and storage box of the character at the
+    /// specified index, in the order they're stored. Slots whose handle doesn't resolve to
+    /// an item are left out, see `SaveApi::check_inventory`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::item::ItemCategory;
+    /// use er_save_lib::{MockCharacter, MockSave, SaveApi};
+    /// let mock = MockSave::new(vec![MockCharacter {
+    ///     level: 1,
+    ///     items: vec![(ItemCategory::Goods, 1000)],
+    ///     ..Default::default()
+    /// }]);
+    /// let save_api = SaveApi::from_mock(&mock).unwrap();
+    /// let items = save_api.inventory_items(0).unwrap();
+    /// assert_eq!((items[0].category, items[0].id), (ItemCategory::Goods, 1000));
+    /// ```
+    pub fn inventory_items(&self, index: usize) -> Result<Vec<InventoryItem>, SaveApiError> {
+        let user_data_x = self.user_data_x(index)?;
+        let mut items = Vec::new();
+        for (kind, inventory) in [
+            (InventoryKind::Held, &user_data_x.inventory_held),
+            (
+                InventoryKind::StorageBox,
+                &user_data_x.inventory_storage_box,
+            ),
+        ] {
+            for (key_item, list) in [
+                (false, &inventory.common_items),
+                (true, &inventory.key_items),
+            ] {
+                for slot in used_slots(list) {
+                    let Some((category, id)) =
+                        item::resolve_handle(user_data_x, slot.gaitem_handle)
+                    else {
+                        continue;
+                    };
+                    items.push(InventoryItem {
+                        inventory: kind,
+                        key_item,
+                        category,
+                        id: self.mapper().map_id(category, id),
+                        quantity: slot.quantity,
+                        acquisition_index: slot.aqcuistion_index,
+                    });
+                }
+            }
+        }
+        Ok(items)
+    }
+
+    /// Reorders the items of the character at the specified index, as shown by the menus
+    /// sorted by order of acquisition, by handing out the acquisition indices already in
+    /// use in the order of `key`. Items picked up afterwards still come last. Ids are
+    /// compared as stored, before any `ItemIdMapper`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::inventory::SortKey;
+    /// use er_save_lib::item::ItemCategory;
+    /// use er_save_lib::{MockCharacter, MockSave, SaveApi};
+    /// let mock = MockSave::new(vec![MockCharacter {
+    ///     level: 1,
+    ///     items: vec![(ItemCategory::Goods, 1001), (ItemCategory::Goods, 1000)],
+    ///     ..Default::default()
+    /// }]);
+    /// let mut save_api = SaveApi::from_mock(&mock).unwrap();
+    /// save_api.sort_inventory(0, SortKey::Id).unwrap();
+    /// let items = save_api.inventory_items(0).unwrap();
+    /// assert!(items[1].acquisition_index < items[0].acquisition_index);
+    /// ```
+    pub fn sort_inventory(&mut self, index: usize, key: SortKey) -> Result<(), SaveApiError> {
+        let user_data_x = self.user_data_x_mut(index)?;
+
+        // Sort key and location (list, slot) of every slot in use
+        let mut slots = Vec::new();
+        for (list, items) in inventory_lists(user_data_x).into_iter().enumerate() {
+            for (slot, item) in items.iter().enumerate() {
+                if is_empty(item.gaitem_handle) {
+                    continue;
+                }
+                let id = item::resolve_handle(user_data_x, item.gaitem_handle)
+                    .map_or(u32::MAX, |(_, id)| id);
+                let by_id = (item.gaitem_handle >> 28, id);
+                let quantity = match key {
+                    SortKey::Id => Reverse(0),
+                    SortKey::Quantity => Reverse(item.quantity),
+                };
+                slots.push(((quantity, by_id), (list, slot), item.aqcuistion_index));
+            }
+        }
+
+        let mut acquisition_indices: Vec<u32> = slots.iter().map(|(_, _, index)| *index).collect();
+        acquisition_indices.sort_unstable();
+        slots.sort_by_key(|(key, _, index)| (*key, *index));
+        for ((_, (list, slot), _), acquisition_index) in slots.into_iter().zip(acquisition_indices)
+        {
+            let inventory = match list / 2 {
+                0 => &mut user_data_x.inventory_held,
+                _ => &mut user_data_x.inventory_storage_box,
+            };
+            let items = match list % 2 {
+                0 => &mut inventory.common_items,
+                _ => &mut inventory.key_items,
+            };
+            items[slot].aqcuistion_index = acquisition_index;
+        }
+        Ok(())
+    }
+
+    /// Repairs the inventory and storage box of the character at the specified index, as
+    /// left by editors that add items without looking for an existing stack. Duplicate
+    /// stacks of a talisman or goods are merged into the first one, up to the 999 the game
+    /// allows, empty stacks are freed, the slots in use are moved to the front of their
+    /// list and the item counts are recomputed. Equipped items, quick items and pouch items
+    /// keep pointing at their item.
+    ///
+    /// Returns the number of slots freed.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// assert_eq!(save_api.consolidate_inventory(0).unwrap(), 0);
+    /// ```
+    pub fn consolidate_inventory(&mut self, index: usize) -> Result<usize, SaveApiError> {
+        let user_data_x = self.user_data_x_mut(index)?;
+
+        // Equip indices are positions in the held inventory, key items coming after every
+        // common item slot
+        let mut equip_indices = HashMap::new();
+        let mut freed = 0;
+        let key_items_start = user_data_x.inventory_held.common_items.len() as u32;
+        for (held, inventory) in [
+            (true, &mut user_data_x.inventory_held),
+            (false, &mut user_data_x.inventory_storage_box),
+        ] {
+            for (offset, items, count) in [
+                (
+                    0,
+                    &mut inventory.common_items,
+                    &mut inventory.common_item_count,
+                ),
+                (
+                    key_items_start,
+                    &mut inventory.key_items,
+                    &mut inventory.key_item_count,
+                ),
+            ] {
+                let (list_freed, moves) = consolidate(items, count);
+                freed += list_freed;
+                if held {
+                    equip_indices.extend(
+                        moves
+                            .into_iter()
+                            .map(|(old, new)| (offset + old, offset + new)),
+                    );
+                }
+            }
+        }
+
+        let equipped = &mut user_data_x.equipped_items;
+        let equip_indices_of_items = equipped
+            .quick_items
+            .iter_mut()
+            .chain(&mut equipped.pouch_items)
+            .chain([&mut equipped.great_rune])
+            .filter(|item| !is_empty(item.gaitem_handle))
+            .map(|item| &mut item.equip_index);
+        for equip_index in equip_indices_mut(&mut user_data_x.equipped_items_equip_index)
+            .into_iter()
+            .chain(equip_indices_of_items)
+        {
+            if let Some(new) = equip_indices.get(&*equip_index) {
+                *equip_index = *new;
+            }
+        }
+        Ok(freed)
+    }
+}
+
+#[test]
+fn consolidate_inventory_merges_stacks_and_follows_quick_items() {
+    use crate::{MockCharacter, MockSave};
+
+    let mock = MockSave::new(vec![MockCharacter {
+        level: 1,
+        items: vec![(ItemCategory::Goods, 1000), (ItemCategory::Goods, 1001)],
+        ..Default::default()
+    }]);
+    let mut save_api = SaveApi::from_mock(&mock).unwrap();
+    save_api.set_quick_item(0, 0, Some(1001)).unwrap();
+
+    // Spread the stacks out and duplicate the first one, like a careless editor would
+    let user_data_x = save_api.user_data_x_mut(0).unwrap();
+    let items = &mut user_data_x.inventory_held.common_items;
+    let used: Vec<usize> = (0..items.len())
+        .filter(|slot| !is_empty(items[*slot].gaitem_handle))
+        .collect();
+    let last = items.len() - 1;
+    items.swap(used[1], last - 1);
+    items[last] = items[used[0]].clone();
+    items[last].quantity = 5;
+    user_data_x.inventory_held.common_item_count += 1;
+    let quick_item = &mut user_data_x.equipped_items.quick_items[0];
+    quick_item.equip_index = last as u32 - 1;
+
+    assert_eq!(save_api.consolidate_inventory(0).unwrap(), 1);
+    assert!(save_api.check_inventory(0).unwrap().is_empty());
+    assert_eq!(save_api.quick_items(0).unwrap().quick_items[0], Some(1001));
+    let user_data_x = save_api.user_data_x(0).unwrap();
+    let held = &user_data_x.inventory_held;
+    assert_eq!(held.common_item_count as usize, used.len());
+    assert_eq!(held.common_items[0].quantity, 6);
+    assert_eq!(
+        user_data_x.equipped_items.quick_items[0].equip_index,
+        held_equip_index(user_data_x, 0xb0000000 | 1001).unwrap()
+    );
+}
@@ -0,0 +1,289 @@
+#[cfg(debug_assertions)]
+use super::inventory::InventoryIntegrity;
+use crate::save::user_data_x::UserDataX;
+
+/// Category of an item, as encoded in the top nibble of its inventory handle.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ItemCategory {
+    Weapon,
+    Armor,
+    Talisman,
+    Goods,
+    AshOfWar,
+}
+
+/// Translates item ids read from the save before they are returned by item getters.
+///
+/// Randomizers and other regulation mods can remap item ids. Tools working with such
+/// games implement this to turn the ids in the save into the ones they know about,
+/// without forking the crate. The default, `IdentityMapper`, returns ids unchanged.
+///
+/// # Example
+/// ```rust
+/// use er_save_lib::item::{ItemCategory, ItemIdMapper};
+/// use er_save_lib::{MockCharacter, MockSave, SaveApi};
+/// use std::collections::HashMap;
+///
+/// struct RandomizerMapper(HashMap<u32, u32>);
+///
+/// impl ItemIdMapper for RandomizerMapper {
+///     fn map_id(&self, category: ItemCategory, id: u32) -> u32 {
+///         match category {
+///             ItemCategory::Talisman => self.0.get(&id).copied().unwrap_or(id),
+///             _ => id,
+///         }
+///     }
+/// }
+///
+/// let mock = MockSave::new(vec![MockCharacter {
+///     level: 1,
+///     items: vec![(ItemCategory::Talisman, 2060)],
+///     ..Default::default()
+/// }]);
+/// let mut save_api = SaveApi::from_mock(&mock).unwrap();
+/// save_api.set_item_id_mapper(RandomizerMapper(HashMap::from([(2060, 1000)])));
+/// let items = save_api.character(0).unwrap().owned_items();
+/// assert_eq!(items, vec![(ItemCategory::Talisman, 1000)]);
+/// ```
+pub trait ItemIdMapper: Send + Sync {
+    /// Returns the id to report for an item stored in the save with `id`.
+    fn map_id(&self, category: ItemCategory, id: u32) -> u32;
+}
+
+/// An `ItemIdMapper` that returns ids unchanged.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct IdentityMapper;
+
+impl ItemIdMapper for IdentityMapper {
+    fn map_id(&self, _: ItemCategory, id: u32) -> u32 {
+        id
+    }
+}
+
+// Goods of this `goodsType` are kept with the key items
+#[cfg(feature = "params")]
+pub(crate) const KEY_ITEM_GOODS_TYPE: u8 = 1;
+
+impl ItemCategory {
+    pub(crate) fn from_handle(handle: u32) -> Option<Self> {
+        match handle & 0xf0000000 {
+            0x80000000 => Some(ItemCategory::Weapon),
+            0x90000000 => Some(ItemCategory::Armor),
+            0xa0000000 => Some(ItemCategory::Talisman),
+            0xb0000000 => Some(ItemCategory::Goods),
+            0xc0000000 => Some(ItemCategory::AshOfWar),
+            _ => None,
+        }
+    }
+}
+
+// Resolves an inventory handle to its category and item id, without the category bits.
+// Talismans and goods carry their id in the handle, everything else is looked up in
+// the gaitem map.
+pub(crate) fn resolve_handle(user_data_x: &UserDataX, handle: u32) -> Option<(ItemCategory, u32)> {
+    let category = ItemCategory::from_handle(handle)?;
+    let id = match category {
+        ItemCategory::Talisman | ItemCategory::Goods => handle,
+        _ => {
+            user_data_x
+                .gaitem_map
+                .iter()
+                .find(|gaitem| gaitem.gaitem_handle == handle)?
+                .item_id
+        }
+    };
+    Some((category, id & 0x0fffffff))
+}
+
+// Every item held in the inventory or the storage box, resolved to category and id
+pub(crate) fn owned_items(user_data_x: &UserDataX) -> Vec<(ItemCategory, u32)> {
+    [
+        &user_data_x.inventory_held,
+        &user_data_x.inventory_storage_box,
+    ]
+    .into_iter()
+    .flat_map(|inventory| inventory.common_items.iter().chain(&inventory.key_items))
+    .filter(|item| item.quantity > 0)
+    .filter_map(|item| resolve_handle(user_data_x, item.gaitem_handle))
+    .collect()
+}
+
+// Adds a key item to the held inventory, in the first free slot. Returns false when the
+// key items are full.
+pub(crate) fn add_key_item(user_data_x: &mut UserDataX, id: u32) -> bool {
+    add_held_item(user_data_x, 0xb0000000 | id, 1, true)
+}
+
+// Sets the quantity of a talisman or goods in the held inventory, adding it in the first
+// free slot if it isn't held yet. Returns false when the list it belongs to is full.
+#[cfg(feature = "regulation")]
+pub(crate) fn set_held_item(
+    user_data_x: &mut UserDataX,
+    handle: u32,
+    quantity: u32,
+    key_item: bool,
+) -> bool {
+    let inventory = &mut user_data_x.inventory_held;
+    if let Some(held) = inventory
+        .common_items
+        .iter_mut()
+        .chain(&mut inventory.key_items)
+        .find(|item| item.gaitem_handle == handle && item.quantity > 0)
+    {
+        held.quantity = quantity.min(999);
+        return true;
+    }
+    add_held_item(user_data_x, handle, quantity, key_item)
+}
+
+// Adds `quantity` of a talisman or goods to the held inventory, stacking onto the slot
+// already holding it or else taking the first free one. Returns false when the key items,
+// or the common items, are full. The acquisition index only orders the inventory, so a
+// new item gets the next one after every item already owned.
+pub(crate) fn add_held_item(
+    user_data_x: &mut UserDataX,
+    handle: u32,
+    quantity: u32,
+    key_item: bool,
+) -> bool {
+    #[cfg(debug_assertions)]
+    let issues_before = InventoryIntegrity::new(user_data_x).check();
+    let added = add_held_item_unchecked(user_data_x, handle, quantity, key_item);
+    #[cfg(debug_assertions)]
+    {
+        // Only catches issues the change introduced, the save may have come in broken
+        let issues = InventoryIntegrity::new(user_data_x).check();
+        debug_assert!(
+            issues.iter().all(|issue| issues_before.contains(issue)),
+            "Adding item {handle:#x} broke the inventory: {issues:?}"
+        );
+    }
+    added
+}
+
+fn add_held_item_unchecked(
+    user_data_x: &mut UserDataX,
+    handle: u32,
+    quantity: u32,
+    key_item: bool,
+) -> bool {
+    let acquisition_index = [
+        &user_data_x.inventory_held,
+        &user_data_x.inventory_storage_box,
+    ]
+    .into_iter()
+    .flat_map(|inventory| inventory.common_items.iter().chain(&inventory.key_items))
+    .map(|item| item.aqcuistion_index)
+    .max()
+    .map_or(0, |index| index + 1);
+
+    let inventory = &mut user_data_x.inventory_held;
+    let items = if key_item {
+        &mut inventory.key_items
+    } else {
+        &mut inventory.common_items
+    };
+    if let Some(held) = items
+        .iter_mut()
+        .find(|item| item.gaitem_handle == handle && item.quantity > 0)
+    {
+        // The game caps every stack at 999
+        held.quantity = (held.quantity + quantity).min(999);
+        return true;
+    }
+    let Some(slot) = items
+        .iter_mut()
+        .find(|item| item.gaitem_handle == 0 || item.gaitem_handle == u32::MAX)
+    else {
+        return false;
+    };
+    slot.gaitem_handle = handle;
+    slot.quantity = quantity.min(999);
+    slot.aqcuistion_index = acquisition_index;
+    if key_item {
+        inventory.key_item_count += 1;
+    } else {
+        inventory.common_item_count += 1;
+    }
+    true
+}
+
+// Removes `quantity` of the item with the specified handle, from the held inventory first
+// and then from the storage box, freeing the slots that are emptied. Returns false, leaving
+// the inventories untouched, when fewer are owned.
+pub(crate) fn take_item(user_data_x: &mut UserDataX, handle: u32, quantity: u32) -> bool {
+    #[cfg(debug_assertions)]
+    let issues_before = InventoryIntegrity::new(user_data_x).check();
+    let taken = take_item_unchecked(user_data_x, handle, quantity);
+    #[cfg(debug_assertions)]
+    {
+        let issues = InventoryIntegrity::new(user_data_x).check();
+        debug_assert!(
+            issues.iter().all(|issue| issues_before.contains(issue)),
+            "Taking item {handle:#x} broke the inventory: {issues:?}"
+        );
+    }
+    taken
+}
+
+fn take_item_unchecked(user_data_x: &mut UserDataX, handle: u32, quantity: u32) -> bool {
+    let owned: u32 = [
+        &user_data_x.inventory_held,
+        &user_data_x.inventory_storage_box,
+    ]
+    .into_iter()
+    .flat_map(|inventory| inventory.common_items.iter().chain(&inventory.key_items))
+    .filter(|item| item.gaitem_handle == handle)
+    .map(|item| item.quantity)
+    .sum();
+    if owned < quantity {
+        return false;
+    }
+
+    let mut remaining = quantity;
+    for inventory in [
+        &mut user_data_x.inventory_held,
+        &mut user_data_x.inventory_storage_box,
+    ] {
+        for (items, count) in [
+            (
+                &mut inventory.common_items,
+                &mut inventory.common_item_count,
+            ),
+            (&mut inventory.key_items, &mut inventory.key_item_count),
+        ] {
+            for item in items.iter_mut().filter(|item| item.gaitem_handle == handle) {
+                if remaining == 0 {
+                    return true;
+                }
+                let taken = item.quantity.min(remaining);
+                item.quantity -= taken;
+                remaining -= taken;
+                if item.quantity == 0 {
+                    item.gaitem_handle = 0;
+                    item.aqcuistion_index = 0;
+                    // Edited saves can count fewer items than they hold
+                    *count = count.saturating_sub(1);
+                }
+            }
+        }
+    }
+    true
+}
+
+#[cfg(feature = "regulation")]
+#[test]
+fn taking_an_item_with_a_zero_count_doesnt_underflow() {
+    use crate::{MockCharacter, MockSave, SaveApi};
+
+    let mock = MockSave::new(vec![MockCharacter {
+        level: 1,
+        items: vec![(ItemCategory::Goods, 1000)],
+        ..Default::default()
+    }]);
+    let mut save_api = SaveApi::from_mock(&mock).unwrap();
+    let user_data_x = save_api.user_data_x_mut(0).unwrap();
+    user_data_x.inventory_held.common_item_count = 0;
+    assert!(take_item_unchecked(user_data_x, 0xb0000000 | 1000, 1));
+    assert_eq!(user_data_x.inventory_held.common_item_count, 0);
+}
@@ -0,0 +1,51 @@
+use schemars::{schema::RootSchema, schema_for};
+
+#[cfg(feature = "interop")]
+use super::interop::ErSaveManagerCharacter;
+use super::{build_template::BuildTemplate, practice::CheckpointBundle};
+use crate::ParseTrace;
+
+/// Returns the JSON Schema of every type the crate exchanges as JSON, along with the name
+/// of the type: `BuildTemplate`, `CheckpointBundle`, `ParseTrace` and, with the `interop`
+/// feature, `ErSaveManagerCharacter`.
+///
+/// Web tools can validate the JSON a user edited against these before handing it to the
+/// crate, and report the fields in error instead of a single deserialization error.
+///
+/// # Example
+/// ```rust
+/// use er_save_lib::json_schema::json_schemas;
+/// let schemas = json_schemas();
+/// let (_, build) = schemas
+///     .iter()
+///     .find(|(name, _)| *name == "BuildTemplate")
+///     .unwrap();
+/// let required = &build.schema.object.as_ref().unwrap().required;
+/// assert!(required.contains("stats"));
+/// ```
+pub fn json_schemas() -> Vec<(&'static str, RootSchema)> {
+    #[allow(unused_mut)]
+    let mut schemas = vec![
+        ("BuildTemplate", schema_for!(BuildTemplate)),
+        ("CheckpointBundle", schema_for!(CheckpointBundle)),
+        ("ParseTrace", schema_for!(ParseTrace)),
+    ];
+    #[cfg(feature = "interop")]
+    schemas.push((
+        "ErSaveManagerCharacter",
+        schema_for!(ErSaveManagerCharacter),
+    ));
+    schemas
+}
+
+#[cfg(feature = "interop")]
+#[test]
+fn interop_schema_uses_the_export_field_names() {
+    let schema = schema_for!(ErSaveManagerCharacter);
+    let object = schema.schema.object.unwrap();
+    assert!(object.properties.contains_key("Souls"));
+    assert!(object.required.contains("Name"));
+    // Fields with a default can be left out
+    assert!(!object.required.contains("Inventory"));
+    assert!(schema.definitions.contains_key("ErSaveManagerItem"));
+}
@@ -0,0 +1,216 @@
+use std::fmt::Display;
+
+use super::item::{self, ItemCategory};
+use crate::{SaveApi, SaveApiError};
+
+/// A key item that opens the way to a `Goal`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum KeyItem {
+    DectusMedallionLeft,
+    DectusMedallionRight,
+    RoldMedallion,
+    HaligtreeSecretMedallionLeft,
+    HaligtreeSecretMedallionRight,
+    AcademyGlintstoneKey,
+    CarianInvertedStatue,
+}
+
+/// A place a key item is needed to reach, see `SaveApi::missing_key_items_for`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Goal {
+    /// The lift from Liurnia to the Altus Plateau.
+    GrandLiftOfDectus,
+    /// The lift from the Forbidden Lands to the Mountaintops of the Giants.
+    GrandLiftOfRold,
+    ConsecratedSnowfield,
+    MiquellasHaligtree,
+    RayaLucariaAcademy,
+    /// The Carian Study Hall, on the way to the Divine Tower of Liurnia.
+    CarianStudyHall,
+}
+
+impl KeyItem {
+    /// Every key item of the graph.
+    pub const ALL: [KeyItem; 7] = [
+        KeyItem::DectusMedallionLeft,
+        KeyItem::DectusMedallionRight,
+        KeyItem::RoldMedallion,
+        KeyItem::HaligtreeSecretMedallionLeft,
+        KeyItem::HaligtreeSecretMedallionRight,
+        KeyItem::AcademyGlintstoneKey,
+        KeyItem::CarianInvertedStatue,
+    ];
+
+    /// Returns the goods id of the key item.
+    pub fn goods_id(&self) -> u32 {
+        match self {
+            KeyItem::DectusMedallionLeft => 8105,
+            KeyItem::DectusMedallionRight => 8106,
+            KeyItem::RoldMedallion => 8107,
+            KeyItem::HaligtreeSecretMedallionLeft => 8175,
+            KeyItem::HaligtreeSecretMedallionRight => 8176,
+            KeyItem::AcademyGlintstoneKey => 8109,
+            KeyItem::CarianInvertedStatue => 8111,
+        }
+    }
+
+    /// Returns the in-game name of the key item.
+    pub fn name(&self) -> &'static str {
+        match self {
+            KeyItem::DectusMedallionLeft => "Dectus Medallion (Left)",
+            KeyItem::DectusMedallionRight => "Dectus Medallion (Right)",
+            KeyItem::RoldMedallion => "Rold Medallion",
+            KeyItem::HaligtreeSecretMedallionLeft => "Haligtree Secret Medallion (Left)",
+            KeyItem::HaligtreeSecretMedallionRight => "Haligtree Secret Medallion (Right)",
+            KeyItem::AcademyGlintstoneKey => "Academy Glintstone Key",
+            KeyItem::CarianInvertedStatue => "Carian Inverted Statue",
+        }
+    }
+
+    /// Returns the goals the key item is directly needed for.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::key_items::{Goal, KeyItem};
+    /// assert_eq!(KeyItem::RoldMedallion.unlocks(), vec![Goal::GrandLiftOfRold]);
+    /// ```
+    pub fn unlocks(&self) -> Vec<Goal> {
+        Goal::ALL
+            .into_iter()
+            .filter(|goal| goal.key_items().contains(self))
+            .collect()
+    }
+}
+
+impl Goal {
+    /// Every goal of the graph.
+    pub const ALL: [Goal; 6] = [
+        Goal::GrandLiftOfDectus,
+        Goal::GrandLiftOfRold,
+        Goal::ConsecratedSnowfield,
+        Goal::MiquellasHaligtree,
+        Goal::RayaLucariaAcademy,
+        Goal::CarianStudyHall,
+    ];
+
+    /// Returns the key items needed to reach the goal once its prerequisites are reached.
+    pub fn key_items(&self) -> &'static [KeyItem] {
+        match self {
+            Goal::GrandLiftOfDectus => {
+                &[KeyItem::DectusMedallionLeft, KeyItem::DectusMedallionRight]
+            }
+            Goal::GrandLiftOfRold => &[KeyItem::RoldMedallion],
+            Goal::ConsecratedSnowfield => &[
+                KeyItem::HaligtreeSecretMedallionLeft,
+                KeyItem::HaligtreeSecretMedallionRight,
+            ],
+            Goal::MiquellasHaligtree => &[],
+            Goal::RayaLucariaAcademy => &[KeyItem::AcademyGlintstoneKey],
+            Goal::CarianStudyHall => &[KeyItem::CarianInvertedStatue],
+        }
+    }
+
+    /// Returns the goals that have to be reached first. The snowfield is reached through
+    /// the Grand Lift of Rold, and the Haligtree through the snowfield.
+    pub fn prerequisites(&self) -> &'static [Goal] {
+        match self {
+            Goal::ConsecratedSnowfield => &[Goal::GrandLiftOfRold],
+            Goal::MiquellasHaligtree => &[Goal::ConsecratedSnowfield],
+            _ => &[],
+        }
+    }
+
+    /// Returns the name of the place.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Goal::GrandLiftOfDectus => "Grand Lift of Dectus",
+            Goal::GrandLiftOfRold => "Grand Lift of Rold",
+            Goal::ConsecratedSnowfield => "Consecrated Snowfield",
+            Goal::MiquellasHaligtree => "Miquella's Haligtree",
+            Goal::RayaLucariaAcademy => "Raya Lucaria Academy",
+            Goal::CarianStudyHall => "Carian Study Hall",
+        }
+    }
+}
+
+impl Display for KeyItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl Display for Goal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl SaveApi {
+    /// Returns the key items of the graph owned by the character at the specified index,
+    /// in the order of `KeyItem::ALL`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::item::ItemCategory;
+    /// use er_save_lib::key_items::KeyItem;
+    /// use er_save_lib::{MockCharacter, MockSave, SaveApi};
+    /// let mock = MockSave::new(vec![MockCharacter {
+    ///     level: 1,
+    ///     items: vec![(ItemCategory::Goods, 8107)],
+    ///     ..Default::default()
+    /// }]);
+    /// let save_api = SaveApi::from_mock(&mock).unwrap();
+    /// assert_eq!(save_api.held_key_items(0).unwrap(), vec![KeyItem::RoldMedallion]);
+    /// ```
+    pub fn held_key_items(&self, index: usize) -> Result<Vec<KeyItem>, SaveApiError> {
+        let owned = item::owned_items(self.user_data_x(index)?);
+        Ok(KeyItem::ALL
+            .into_iter()
+            .filter(|key_item| owned.contains(&(ItemCategory::Goods, key_item.goods_id())))
+            .collect())
+    }
+
+    /// Returns the key items the character at the specified index still needs to reach
+    /// `goal`, its prerequisites included, in the order they're needed.
+    ///
+    /// Only key items are tracked. Ways around them, such as the Ruin-Strewn Precipice to
+    /// the Altus Plateau, and bosses barring the way aren't part of the graph.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::item::ItemCategory;
+    /// use er_save_lib::key_items::{Goal, KeyItem};
+    /// use er_save_lib::{MockCharacter, MockSave, SaveApi};
+    /// let mock = MockSave::new(vec![MockCharacter {
+    ///     level: 1,
+    ///     items: vec![(ItemCategory::Goods, 8107), (ItemCategory::Goods, 8175)],
+    ///     ..Default::default()
+    /// }]);
+    /// let save_api = SaveApi::from_mock(&mock).unwrap();
+    /// let missing = save_api
+    ///     .missing_key_items_for(0, Goal::MiquellasHaligtree)
+    ///     .unwrap();
+    /// assert_eq!(missing, vec![KeyItem::HaligtreeSecretMedallionRight]);
+    /// ```
+    pub fn missing_key_items_for(
+        &self,
+        index: usize,
+        goal: Goal,
+    ) -> Result<Vec<KeyItem>, SaveApiError> {
+        let held = self.held_key_items(index)?;
+        let mut missing = Vec::new();
+        collect_missing(goal, &held, &mut missing);
+        Ok(missing)
+    }
+}
+
+fn collect_missing(goal: Goal, held: &[KeyItem], missing: &mut Vec<KeyItem>) {
+    for prerequisite in goal.prerequisites() {
+        collect_missing(*prerequisite, held, missing);
+    }
+    for key_item in goal.key_items() {
+        if !held.contains(key_item) && !missing.contains(key_item) {
+            missing.push(*key_item);
+        }
+    }
+}
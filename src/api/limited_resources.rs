@@ -0,0 +1,139 @@
+use std::fmt::Display;
+
+use super::item;
+use crate::{SaveApi, SaveApiError};
+
+/// A consumable of which a playthrough only holds a fixed number, tracked by
+/// `SaveApi::limited_resource`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum LimitedResource {
+    /// Spent on rebirth, to reallocate attributes or change appearance.
+    LarvalTear,
+    /// Spent on more Flask of Crimson or Cerulean Tears charges.
+    GoldenSeed,
+    /// Spent on stronger Flask of Crimson or Cerulean Tears.
+    SacredTear,
+    /// Spent on absolution at the Church of Vows.
+    CelestialDew,
+    /// Restores FP when used.
+    StarlightShards,
+}
+
+impl LimitedResource {
+    /// Every limited resource.
+    pub const ALL: [LimitedResource; 5] = [
+        LimitedResource::LarvalTear,
+        LimitedResource::GoldenSeed,
+        LimitedResource::SacredTear,
+        LimitedResource::CelestialDew,
+        LimitedResource::StarlightShards,
+    ];
+
+    /// Returns the goods id of the resource.
+    pub fn goods_id(&self) -> u32 {
+        match self {
+            LimitedResource::LarvalTear => 8185,
+            LimitedResource::GoldenSeed => 10010,
+            LimitedResource::SacredTear => 10020,
+            LimitedResource::CelestialDew => 8193,
+            LimitedResource::StarlightShards => 8186,
+        }
+    }
+
+    /// Returns how many of the resource can be found in a playthrough of the base game.
+    /// Pickups sharing an acquisition flag, such as the ones of Leyndell and its ashen
+    /// version, count once.
+    pub fn max_obtainable(&self) -> u32 {
+        match self {
+            LimitedResource::LarvalTear => 18,
+            LimitedResource::GoldenSeed => 30,
+            LimitedResource::SacredTear => 12,
+            LimitedResource::CelestialDew => 6,
+            LimitedResource::StarlightShards => 3,
+        }
+    }
+
+    /// Returns the in-game name of the resource.
+    pub fn name(&self) -> &'static str {
+        match self {
+            LimitedResource::LarvalTear => "Larval Tear",
+            LimitedResource::GoldenSeed => "Golden Seed",
+            LimitedResource::SacredTear => "Sacred Tear",
+            LimitedResource::CelestialDew => "Celestial Dew",
+            LimitedResource::StarlightShards => "Starlight Shards",
+        }
+    }
+}
+
+impl Display for LimitedResource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl SaveApi {
+    /// Returns how many of a resource the character at the specified index holds, spent
+    /// ones excluded.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::limited_resources::LimitedResource;
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// assert_eq!(save_api.limited_resource(0, LimitedResource::GoldenSeed).unwrap(), 0);
+    /// ```
+    pub fn limited_resource(
+        &self,
+        index: usize,
+        resource: LimitedResource,
+    ) -> Result<u32, SaveApiError> {
+        let user_data_x = self.user_data_x(index)?;
+        let handle = 0xb0000000 | resource.goods_id();
+        Ok([
+            &user_data_x.inventory_held,
+            &user_data_x.inventory_storage_box,
+        ]
+        .into_iter()
+        .flat_map(|inventory| inventory.common_items.iter().chain(&inventory.key_items))
+        .filter(|item| item.gaitem_handle == handle)
+        .map(|item| item.quantity)
+        .sum())
+    }
+
+    /// Sets how many of a resource the character at the specified index holds, clamped to
+    /// `LimitedResource::max_obtainable`. Returns the quantity set.
+    ///
+    /// # Errors
+    /// `SaveApiError::InventoryFull` if the resource isn't held and the key items are full.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::limited_resources::LimitedResource;
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// let set = save_api
+    ///     .set_limited_resource(0, LimitedResource::LarvalTear, 99)
+    ///     .unwrap();
+    /// assert_eq!(set, 18);
+    /// assert_eq!(save_api.limited_resource(0, LimitedResource::LarvalTear).unwrap(), 18);
+    /// ```
+    pub fn set_limited_resource(
+        &mut self,
+        index: usize,
+        resource: LimitedResource,
+        quantity: u32,
+    ) -> Result<u32, SaveApiError> {
+        let quantity = quantity.min(resource.max_obtainable());
+        let held = self.limited_resource(index, resource)?;
+        let handle = 0xb0000000 | resource.goods_id();
+        let user_data_x = self.user_data_x_mut(index)?;
+        if quantity < held {
+            item::take_item(user_data_x, handle, held - quantity);
+        } else if quantity > held
+            && !item::add_held_item(user_data_x, handle, quantity - held, true)
+        {
+            return Err(SaveApiError::InventoryFull(resource.goods_id()));
+        }
+        Ok(quantity)
+    }
+}
@@ -0,0 +1,181 @@
+use super::item::ItemCategory;
+#[cfg(feature = "params")]
+use super::item::KEY_ITEM_GOODS_TYPE;
+use crate::SaveApiError;
+#[cfg(feature = "params")]
+use crate::{EquipParamAccessory::EquipParamAccessory, EquipParamGoods::EquipParamGoods, SaveApi};
+
+/// An item and the quantity a character should hold, see `SaveApi::import_inventory`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ManifestEntry {
+    pub category: ItemCategory,
+    pub id: u32,
+    pub quantity: u32,
+}
+
+/// Why an entry of a manifest wasn't applied.
+#[cfg(feature = "params")]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Rejection {
+    /// Only talismans and goods can be imported. Weapons, armor and ashes of war also need
+    /// an entry in the gaitem map, which the crate doesn't create.
+    UnsupportedCategory,
+    /// The id isn't in the regulation's params for its category.
+    UnknownItem,
+    /// The quantity is 0 or above the 999 the game allows.
+    InvalidQuantity,
+    /// The inventory has no free slot left for the item.
+    InventoryFull,
+}
+
+/// Outcome of `SaveApi::import_inventory`, with every entry either applied or rejected.
+#[cfg(feature = "params")]
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct ImportReport {
+    pub applied: Vec<ManifestEntry>,
+    pub rejected: Vec<(ManifestEntry, Rejection)>,
+}
+
+impl ManifestEntry {
+    /// Parses a CSV manifest.
+    ///
+    /// The first row names the columns. `category`, `id` and `quantity` are required and
+    /// other columns are ignored, so a `Section::Inventory` export of `SaveApi::export_csv`
+    /// can be read back. Categories are named like the `ItemCategory` variants, in any
+    /// case. Fields can't be quoted.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::item::ItemCategory;
+    /// use er_save_lib::manifest::ManifestEntry;
+    /// let entries = ManifestEntry::from_csv("id,category,quantity\n10010,goods,5\n").unwrap();
+    /// assert_eq!(
+    ///     entries,
+    ///     vec![ManifestEntry { category: ItemCategory::Goods, id: 10010, quantity: 5 }]
+    /// );
+    /// assert!(ManifestEntry::from_csv("category,id,quantity\nGoods,seed,5\n").is_err());
+    /// ```
+    pub fn from_csv(csv: &str) -> Result<Vec<ManifestEntry>, SaveApiError> {
+        let mut lines = csv
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line))
+            .filter(|(_, line)| !line.trim().is_empty());
+        let Some((header_number, header)) = lines.next() else {
+            return Ok(Vec::new());
+        };
+
+        let columns: Vec<&str> = header.split(',').map(str::trim).collect();
+        let column = |name: &str| {
+            columns
+                .iter()
+                .position(|column| column.eq_ignore_ascii_case(name))
+                .ok_or_else(|| {
+                    SaveApiError::InvalidManifest(header_number, format!("no {name} column"))
+                })
+        };
+        let (category, id, quantity) = (column("category")?, column("id")?, column("quantity")?);
+
+        lines
+            .map(|(number, line)| {
+                let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+                let field = |index: usize| fields.get(index).copied().unwrap_or_default();
+                let invalid = |name: &str, value: &str| {
+                    SaveApiError::InvalidManifest(number, format!("invalid {name} {value:?}"))
+                };
+                Ok(ManifestEntry {
+                    category: parse_category(field(category))
+                        .ok_or_else(|| invalid("category", field(category)))?,
+                    id: field(id).parse().map_err(|_| invalid("id", field(id)))?,
+                    quantity: field(quantity)
+                        .parse()
+                        .map_err(|_| invalid("quantity", field(quantity)))?,
+                })
+            })
+            .collect()
+    }
+}
+
+pub(crate) fn parse_category(name: &str) -> Option<ItemCategory> {
+    match name.to_ascii_lowercase().replace('_', "").as_str() {
+        "weapon" => Some(ItemCategory::Weapon),
+        "armor" => Some(ItemCategory::Armor),
+        "talisman" => Some(ItemCategory::Talisman),
+        "goods" => Some(ItemCategory::Goods),
+        "ashofwar" => Some(ItemCategory::AshOfWar),
+        _ => None,
+    }
+}
+
+#[cfg(feature = "params")]
+impl SaveApi {
+    /// Applies a manifest to the inventory of the character at the specified index. Items
+    /// already held get the quantity of their entry, the others are added. Talismans are
+    /// always held once.
+    ///
+    /// Entries are checked against the `EquipParamAccessory` and `EquipParamGoods` params
+    /// of the save's regulation. The ones that can't be applied are returned in the report
+    /// with the reason, the others are applied regardless.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use er_save_lib::item::ItemCategory;
+    /// use er_save_lib::manifest::{ManifestEntry, Rejection};
+    /// use er_save_lib::SaveApi;
+    /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+    /// let entries = ManifestEntry::from_csv(
+    ///     "category,id,quantity\nGoods,10010,5\nGoods,123456789,1\nWeapon,1000000,1\n",
+    /// )
+    /// .unwrap();
+    /// let report = save_api.import_inventory(0, &entries).unwrap();
+    /// assert_eq!(report.applied, vec![entries[0]]);
+    /// assert_eq!(report.rejected[0], (entries[1], Rejection::UnknownItem));
+    /// assert_eq!(report.rejected[1], (entries[2], Rejection::UnsupportedCategory));
+    /// let items = save_api.character(0).unwrap().owned_items();
+    /// assert!(items.contains(&(ItemCategory::Goods, 10010)));
+    /// ```
+    pub fn import_inventory(
+        &mut self,
+        index: usize,
+        entries: &[ManifestEntry],
+    ) -> Result<ImportReport, SaveApiError> {
+        let talismans = self.get_param::<EquipParamAccessory>()?.rows;
+        let goods = self.get_param::<EquipParamGoods>()?.rows;
+
+        let mut character = self.character_mut(index)?;
+        let mut report = ImportReport::default();
+        for entry in entries {
+            let row_id = entry.id as i32;
+            let known = match entry.category {
+                ItemCategory::Talisman => Some(talismans.contains_key(&row_id)),
+                ItemCategory::Goods => Some(goods.contains_key(&row_id)),
+                _ => None,
+            };
+            let rejection = match known {
+                None => Some(Rejection::UnsupportedCategory),
+                Some(false) => Some(Rejection::UnknownItem),
+                Some(true) if entry.quantity == 0 || entry.quantity > 999 => {
+                    Some(Rejection::InvalidQuantity)
+                }
+                Some(true) => None,
+            };
+            if let Some(rejection) = rejection {
+                report.rejected.push((*entry, rejection));
+                continue;
+            }
+
+            let key_item = goods
+                .get(&row_id)
+                .is_some_and(|row| row.goodsType == KEY_ITEM_GOODS_TYPE)
+                && entry.category == ItemCategory::Goods;
+            match character.set_item_quantity(entry.category, entry.id, entry.quantity, key_item) {
+                Ok(()) => report.applied.push(*entry),
+                Err(SaveApiError::InventoryFull(_)) => {
+                    report.rejected.push((*entry, Rejection::InventoryFull))
+                }
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(report)
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{SaveApi, SaveApiError};
+
+/// A part of the map uncovered by picking up its map fragment, identified by the event
+/// flag the fragment sets. Base game only.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum MapTile {
+    LimgraveWest,
+    WeepingPeninsula,
+    LimgraveEast,
+    LiurniaEast,
+    LiurniaNorth,
+    LiurniaWest,
+    AltusPlateau,
+    Leyndell,
+    MtGelmir,
+    Caelid,
+    Dragonbarrow,
+    MountaintopsWest,
+    MountaintopsEast,
+    ConsecratedSnowfield,
+    AinselRiver,
+    LakeOfRot,
+    MohgwynPalace,
+    SiofraRiver,
+    DeeprootDepths,
+}
+
+impl MapTile {
+    /// Every map tile, overworld first, in the order of their flags.
+    pub const ALL: [MapTile; 19] = [
+        MapTile::LimgraveWest,
+        MapTile::WeepingPeninsula,
+        MapTile::LimgraveEast,
+        MapTile::LiurniaEast,
+        MapTile::LiurniaNorth,
+        MapTile::LiurniaWest,
+        MapTile::AltusPlateau,
+        MapTile::Leyndell,
+        MapTile::MtGelmir,
+        MapTile::Caelid,
+        MapTile::Dragonbarrow,
+        MapTile::MountaintopsWest,
+        MapTile::MountaintopsEast,
+        MapTile::ConsecratedSnowfield,
+        MapTile::AinselRiver,
+        MapTile::LakeOfRot,
+        MapTile::MohgwynPalace,
+        MapTile::SiofraRiver,
+        MapTile::DeeprootDepths,
+    ];
+
+    /// Returns the event flag set when the tile is revealed.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::map_reveal::MapTile;
+    /// assert_eq!(MapTile::LimgraveWest.reveal_flag(), 62010);
+    /// ```
+    pub fn reveal_flag(&self) -> u32 {
+        match self {
+            MapTile::LimgraveWest => 62010,
+            MapTile::WeepingPeninsula => 62011,
+            MapTile::LimgraveEast => 62012,
+            MapTile::LiurniaEast => 62020,
+            MapTile::LiurniaNorth => 62021,
+            MapTile::LiurniaWest => 62022,
+            MapTile::AltusPlateau => 62030,
+            MapTile::Leyndell => 62031,
+            MapTile::MtGelmir => 62032,
+            MapTile::Caelid => 62040,
+            MapTile::Dragonbarrow => 62041,
+            MapTile::MountaintopsWest => 62050,
+            MapTile::MountaintopsEast => 62051,
+            MapTile::ConsecratedSnowfield => 62052,
+            MapTile::AinselRiver => 62060,
+            MapTile::LakeOfRot => 62061,
+            MapTile::MohgwynPalace => 62062,
+            MapTile::SiofraRiver => 62063,
+            MapTile::DeeprootDepths => 62064,
+        }
+    }
+
+    /// Returns whether the tile belongs to the underground map.
+    pub fn is_underground(&self) -> bool {
+        self.reveal_flag() >= 62060
+    }
+}
+
+impl SaveApi {
+    /// Returns the map tiles revealed for the character at the specified index, in the
+    /// order of `MapTile::ALL`.
+    ///
+    /// Unlike unlocked regions, which follow the graces discovered, tiles are only revealed
+    /// by map fragments.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// assert!(save_api.revealed_map_tiles(0).unwrap().is_empty());
+    /// ```
+    pub fn revealed_map_tiles(&self, index: usize) -> Result<Vec<MapTile>, SaveApiError> {
+        let mut revealed = Vec::new();
+        for tile in MapTile::ALL {
+            if self.get_event_flag(tile.reveal_flag(), index)? {
+                revealed.push(tile);
+            }
+        }
+        Ok(revealed)
+    }
+
+    /// Reveals or hides a map tile for the character at the specified index. The map
+    /// fragment itself is left in or out of the inventory.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::map_reveal::MapTile;
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// save_api.set_map_tile_revealed(0, MapTile::Caelid, true).unwrap();
+    /// assert_eq!(save_api.revealed_map_tiles(0).unwrap(), vec![MapTile::Caelid]);
+    /// ```
+    pub fn set_map_tile_revealed(
+        &mut self,
+        index: usize,
+        tile: MapTile,
+        revealed: bool,
+    ) -> Result<(), SaveApiError> {
+        self.set_event_flag(tile.reveal_flag(), index, revealed)
+    }
+
+    /// Reveals every tile of the overworld and underground maps for the character at the
+    /// specified index.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::map_reveal::MapTile;
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// save_api.reveal_all_map(0).unwrap();
+    /// assert_eq!(save_api.revealed_map_tiles(0).unwrap(), MapTile::ALL);
+    /// ```
+    pub fn reveal_all_map(&mut self, index: usize) -> Result<(), SaveApiError> {
+        for tile in MapTile::ALL {
+            self.set_map_tile_revealed(index, tile, true)?;
+        }
+        Ok(())
+    }
+}
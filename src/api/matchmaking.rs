@@ -0,0 +1,170 @@
+use std::ops::RangeInclusive;
+
+use crate::{SaveApi, SaveApiError};
+
+/// Whether two characters can play together, as returned by `SaveApi::coop_compatibility`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CoopCompatibility {
+    /// The guest's level is within the range the host can summon.
+    pub level_in_range: bool,
+    /// The guest's weapon upgrade level is within the range the host can summon.
+    pub weapon_level_in_range: bool,
+    /// Both characters set the same multiplayer password, which lifts the level and weapon
+    /// upgrade restrictions.
+    pub same_password: bool,
+}
+
+impl CoopCompatibility {
+    /// Returns true when the guest can be summoned by the host.
+    pub fn can_connect(&self) -> bool {
+        self.same_password || (self.level_in_range && self.weapon_level_in_range)
+    }
+}
+
+// Weapon upgrade levels a host can summon, indexed by the host's matchmaking weapon level,
+// both on the scale of regular weapons
+const WEAPON_LEVEL_RANGES: [(u8, u8); 26] = [
+    (0, 3),
+    (0, 4),
+    (0, 5),
+    (0, 6),
+    (1, 7),
+    (2, 8),
+    (2, 9),
+    (3, 10),
+    (4, 12),
+    (5, 13),
+    (5, 14),
+    (6, 15),
+    (7, 16),
+    (8, 17),
+    (9, 18),
+    (10, 19),
+    (11, 20),
+    (12, 21),
+    (13, 22),
+    (14, 23),
+    (15, 24),
+    (16, 25),
+    (17, 25),
+    (18, 25),
+    (19, 25),
+    (20, 25),
+];
+
+/// Levels and weapon upgrade levels of the characters a host can summon, as returned by
+/// `matchmaking_range`.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct RangeSet {
+    pub levels: RangeInclusive<u32>,
+    /// Weapon upgrade levels, on the scale of regular weapons (0 to 25).
+    pub weapon_levels: RangeInclusive<u8>,
+}
+
+impl RangeSet {
+    /// Returns true when a character of this level and matchmaking weapon level is within
+    /// both ranges.
+    pub fn contains(&self, level: u32, weapon_level: u8) -> bool {
+        self.levels.contains(&level) && self.weapon_levels.contains(&weapon_level)
+    }
+}
+
+/// Returns the ranges of characters a host of the specified level and highest weapon upgrade
+/// level can summon for co-op, without a multiplayer password. `max_weapon_upgrade` is on
+/// the scale of regular weapons, a somber weapon at +10 counts as 25.
+///
+/// Levels go from 10% below the host's to 10% plus 10 levels above it.
+///
+/// # Example
+/// ```rust
+/// use er_save_lib::matchmaking::matchmaking_range;
+/// let range = matchmaking_range(150, 25);
+/// assert_eq!(range.levels, 135..=175);
+/// assert_eq!(range.weapon_levels, 20..=25);
+/// assert!(range.contains(140, 24));
+/// ```
+pub fn matchmaking_range(level: u32, max_weapon_upgrade: u8) -> RangeSet {
+    let (low, high) = WEAPON_LEVEL_RANGES[usize::from(max_weapon_upgrade.min(25))];
+    RangeSet {
+        levels: level - level / 10..=level + level / 10 + 10,
+        weapon_levels: low..=high,
+    }
+}
+
+impl SaveApi {
+    /// Returns the ranges of characters the character at the specified index can summon for
+    /// co-op, see `matchmaking_range`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// let range = save_api.matchmaking_range(0).unwrap();
+    /// assert!(range.weapon_levels.contains(&0));
+    /// ```
+    pub fn matchmaking_range(&self, index: usize) -> Result<RangeSet, SaveApiError> {
+        let character = self.character(index)?;
+        Ok(matchmaking_range(
+            character.level(),
+            character.matchmaking_weapon_level(),
+        ))
+    }
+
+    /// Checks whether the character of `other` at `other_index` can join the world of the
+    /// character at `index` for co-op, from their levels, the highest weapon upgrade they
+    /// reached and their multiplayer passwords.
+    ///
+    /// The character at `index` is the host: the guest has to be within the level and
+    /// weapon upgrade ranges computed from the host's, the way the game matches summon
+    /// signs. Matching passwords lift both restrictions.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{MockCharacter, MockSave, SaveApi};
+    /// let host = SaveApi::from_mock(&MockSave::new(vec![MockCharacter {
+    ///     level: 100,
+    ///     ..Default::default()
+    /// }]))
+    /// .unwrap();
+    /// let guest = SaveApi::from_mock(&MockSave::new(vec![MockCharacter {
+    ///     level: 125,
+    ///     ..Default::default()
+    /// }]))
+    /// .unwrap();
+    /// let compatibility = host.coop_compatibility(0, &guest, 0).unwrap();
+    /// assert!(compatibility.weapon_level_in_range);
+    /// assert!(!compatibility.level_in_range);
+    /// assert!(!compatibility.can_connect());
+    /// ```
+    pub fn coop_compatibility(
+        &self,
+        index: usize,
+        other: &SaveApi,
+        other_index: usize,
+    ) -> Result<CoopCompatibility, SaveApiError> {
+        let range = self.matchmaking_range(index)?;
+        let guest = other.character(other_index)?;
+        let host_password = &self.user_data_x(index)?.player_game_data.password;
+        let guest_password = &other.user_data_x(other_index)?.player_game_data.password;
+
+        Ok(CoopCompatibility {
+            level_in_range: range.levels.contains(&guest.level()),
+            weapon_level_in_range: range
+                .weapon_levels
+                .contains(&guest.matchmaking_weapon_level()),
+            same_password: !host_password.is_empty() && host_password == guest_password,
+        })
+    }
+}
+
+#[test]
+fn level_range_matches_the_game() {
+    for (level, levels) in [
+        (1, 1..=11),
+        (50, 45..=65),
+        (150, 135..=175),
+        (713, 642..=794),
+    ] {
+        assert_eq!(matchmaking_range(level, 0).levels, levels);
+    }
+}
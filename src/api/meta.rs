@@ -0,0 +1,113 @@
+use std::{
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{SaveApi, SaveApiError};
+
+/// A log of the edits made to a save, kept in a JSON sidecar next to it by
+/// `SaveApi::write_to_path_with_meta`.
+///
+/// Values are stored as text so any field can be logged, whatever its type.
+///
+/// # Example
+/// ```rust
+/// use er_save_lib::meta::EditLog;
+/// let mut log = EditLog::default();
+/// log.record("0.vigor", 10, 40);
+/// assert_eq!(log.edits[0].old, "10");
+/// assert_eq!(log.edits[0].new, "40");
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+pub struct EditLog {
+    pub edits: Vec<Edit>,
+}
+
+/// A single edit of an `EditLog`.
+#[derive(Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub struct Edit {
+    /// What was edited, named by the tool making the edit.
+    pub field: String,
+    pub old: String,
+    pub new: String,
+    /// When the edit was made, in seconds since the Unix epoch.
+    pub timestamp: u64,
+}
+
+impl EditLog {
+    /// Appends an edit made now.
+    pub fn record(&mut self, field: impl Into<String>, old: impl ToString, new: impl ToString) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        self.edits.push(Edit {
+            field: field.into(),
+            old: old.to_string(),
+            new: new.to_string(),
+            timestamp,
+        });
+    }
+}
+
+impl SaveApi {
+    /// Writes the save data to the specified path, like `SaveApi::write_to_path`, and appends
+    /// the edits of `log` to the sidecar next to it (the path with `.meta.json` appended).
+    ///
+    /// Edits already in the sidecar are kept, so it holds every edit logged across sessions.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::meta::EditLog;
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let path = std::env::temp_dir().join("er-save-lib-meta.sl2");
+    /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// let mut log = EditLog::default();
+    /// log.record("0.vigor", save_api.vigor(0).unwrap(), 40);
+    /// save_api.set_vigor(0, 40).unwrap();
+    /// save_api.write_to_path_with_meta(&path, &log).unwrap();
+    /// let meta = SaveApi::read_meta(&path).unwrap();
+    /// assert!(meta.edits.ends_with(&log.edits));
+    /// ```
+    pub fn write_to_path_with_meta(
+        &self,
+        path: impl AsRef<Path>,
+        log: &EditLog,
+    ) -> Result<(), SaveApiError> {
+        let path = path.as_ref();
+        let mut meta = SaveApi::read_meta(path)?;
+        meta.edits.extend_from_slice(&log.edits);
+        self.write_to_path(path)?;
+        fs::write(meta_path(path), serde_json::to_vec_pretty(&meta)?)?;
+        Ok(())
+    }
+
+    /// Reads the edit log kept next to the save at the specified path by
+    /// `SaveApi::write_to_path_with_meta`. A save without a sidecar has an empty log.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use er_save_lib::SaveApi;
+    /// let log = SaveApi::read_meta("./test/ER0000.sl2").unwrap();
+    /// for edit in &log.edits {
+    ///     println!("{} {}: {} -> {}", edit.timestamp, edit.field, edit.old, edit.new);
+    /// }
+    /// ```
+    pub fn read_meta(path: impl AsRef<Path>) -> Result<EditLog, SaveApiError> {
+        match fs::read(meta_path(path.as_ref())) {
+            Ok(bytes) => Ok(serde_json::from_slice(&bytes)?),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(EditLog::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+// ER0000.sl2 -> ER0000.sl2.meta.json
+fn meta_path(path: &Path) -> PathBuf {
+    let mut meta_path = path.as_os_str().to_owned();
+    meta_path.push(".meta.json");
+    PathBuf::from(meta_path)
+}
@@ -0,0 +1,211 @@
+use std::collections::HashSet;
+
+use super::{
+    character::validate_character_name,
+    item::ItemCategory,
+    stat::Stat,
+    traits::{SaveRead, SaveWrite},
+};
+use crate::SaveApiError;
+#[cfg(feature = "regulation")]
+use crate::{SaveApi, SaveType};
+
+/// A character of a `MockSave`.
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct MockCharacter {
+    /// Left unset by `SaveApi::from_mock` when empty.
+    pub name: String,
+    pub level: u32,
+    /// Indexed like `Stat::ALL`.
+    pub stats: [u32; 8],
+    pub runes: u32,
+    pub event_flags: HashSet<u32>,
+    pub items: Vec<(ItemCategory, u32)>,
+}
+
+/// An in-memory implementation of `SaveRead` and `SaveWrite` for unit testing tools
+/// without a save file.
+///
+/// Every event id is accepted and names are validated like on a real save. Nothing is
+/// derived from anything else, setting a stat doesn't change the level.
+///
+/// # Example
+/// ```rust
+/// use er_save_lib::{MockCharacter, MockSave, SaveRead, SaveWrite, Stat};
+/// let mut save = MockSave::new(vec![MockCharacter {
+///     name: "Tarnished".to_string(),
+///     level: 9,
+///     ..Default::default()
+/// }]);
+/// save.set_stat(0, Stat::Vigor, 20).unwrap();
+/// save.set_event_flag(10000850, 0, true).unwrap();
+/// assert_eq!(save.get_stat(0, Stat::Vigor).unwrap(), 20);
+/// assert!(save.get_event_flag(10000850, 0).unwrap());
+/// assert!(save.level(1).is_err());
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct MockSave {
+    pub characters: Vec<MockCharacter>,
+}
+
+impl MockSave {
+    /// Creates a mock save holding the given characters, in slot order.
+    pub fn new(characters: Vec<MockCharacter>) -> Self {
+        MockSave { characters }
+    }
+
+    fn character(&self, index: usize) -> Result<&MockCharacter, SaveApiError> {
+        self.characters
+            .get(index)
+            .ok_or(SaveApiError::CharacterIndexOutOfRange(index))
+    }
+
+    fn character_mut(&mut self, index: usize) -> Result<&mut MockCharacter, SaveApiError> {
+        self.characters
+            .get_mut(index)
+            .ok_or(SaveApiError::CharacterIndexOutOfRange(index))
+    }
+}
+
+// Variants are declared in the order of `Stat::ALL`
+fn stat_index(stat: Stat) -> usize {
+    stat as usize
+}
+
+impl SaveRead for MockSave {
+    fn character_count(&self) -> usize {
+        self.characters.len()
+    }
+
+    fn character_name(&self, index: usize) -> Result<String, SaveApiError> {
+        Ok(self.character(index)?.name.clone())
+    }
+
+    fn level(&self, index: usize) -> Result<u32, SaveApiError> {
+        Ok(self.character(index)?.level)
+    }
+
+    fn get_stat(&self, index: usize, stat: Stat) -> Result<u32, SaveApiError> {
+        Ok(self.character(index)?.stats[stat_index(stat)])
+    }
+
+    fn runes(&self, index: usize) -> Result<u32, SaveApiError> {
+        Ok(self.character(index)?.runes)
+    }
+
+    fn get_event_flag(&self, event_id: u32, index: usize) -> Result<bool, SaveApiError> {
+        Ok(self.character(index)?.event_flags.contains(&event_id))
+    }
+
+    fn owned_items(&self, index: usize) -> Result<Vec<(ItemCategory, u32)>, SaveApiError> {
+        Ok(self.character(index)?.items.clone())
+    }
+}
+
+impl SaveWrite for MockSave {
+    fn set_character_name(&mut self, index: usize, name: &str) -> Result<(), SaveApiError> {
+        let character = self.character_mut(index)?;
+        validate_character_name(name, false)?;
+        character.name = name.to_string();
+        Ok(())
+    }
+
+    fn set_level(&mut self, index: usize, level: u32) -> Result<(), SaveApiError> {
+        self.character_mut(index)?.level = level;
+        Ok(())
+    }
+
+    fn set_stat(&mut self, index: usize, stat: Stat, value: u32) -> Result<(), SaveApiError> {
+        self.character_mut(index)?.stats[stat_index(stat)] = value;
+        Ok(())
+    }
+
+    fn set_runes(&mut self, index: usize, runes: u32) -> Result<(), SaveApiError> {
+        self.character_mut(index)?.runes = runes;
+        Ok(())
+    }
+
+    fn set_event_flag(
+        &mut self,
+        event_id: u32,
+        index: usize,
+        on: bool,
+    ) -> Result<(), SaveApiError> {
+        let event_flags = &mut self.character_mut(index)?.event_flags;
+        if on {
+            event_flags.insert(event_id);
+        } else {
+            event_flags.remove(&event_id);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "regulation")]
+impl SaveApi {
+    /// Creates a save holding the characters of a mock save, entirely in memory. Meant for
+    /// tests that need a `SaveApi` with characters but shouldn't depend on a save file.
+    ///
+    /// The save starts out as `SaveApi::new_empty(SaveType::PC)` and every character is
+    /// written to the slot of the same index, which is marked active. Only what
+    /// `MockCharacter` holds is set, the rest of the slot stays zeroed, so the game won't
+    /// load it. Items are held once.
+    ///
+    /// # Errors
+    /// - `SaveApiError::CharacterIndexOutOfRange` if there are more characters than slots.
+    /// - `SaveApiError::InvalidCharacterName` if a name isn't one the game accepts.
+    /// - `SaveApiError::EventIdNotFound` if an event flag doesn't exist in the game.
+    /// - `SaveApiError::UnsupportedItem` for weapons, armor and ashes of war, which also
+    ///   need an entry in the gaitem map.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::collections::HashSet;
+    ///
+    /// use er_save_lib::item::ItemCategory;
+    /// use er_save_lib::{MockCharacter, MockSave, SaveApi, SaveRead, Stat};
+    /// let mock = MockSave::new(vec![MockCharacter {
+    ///     name: "Tarnished".to_string(),
+    ///     level: 9,
+    ///     stats: [10; 8],
+    ///     event_flags: HashSet::from([10000850]),
+    ///     items: vec![(ItemCategory::Goods, 10010)],
+    ///     ..Default::default()
+    /// }]);
+    /// let save_api = SaveApi::from_mock(&mock).unwrap();
+    /// assert!(save_api.active_characters()[0]);
+    /// assert_eq!(save_api.character_name(0).unwrap(), "Tarnished");
+    /// assert_eq!(save_api.get_stat(0, Stat::Mind).unwrap(), 10);
+    /// assert!(save_api.get_event_flag(10000850, 0).unwrap());
+    /// assert_eq!(save_api.owned_items(0).unwrap(), mock.owned_items(0).unwrap());
+    /// ```
+    pub fn from_mock(mock: &MockSave) -> Result<Self, SaveApiError> {
+        let mut save_api = SaveApi::new_empty(SaveType::PC)?;
+        for (index, mock_character) in mock.characters.iter().enumerate() {
+            let mut character = save_api.character_mut(index)?;
+            // The empty default name isn't a valid one, the slot keeps its own then
+            if !mock_character.name.is_empty() {
+                character.set_character_name(&mock_character.name)?;
+            }
+            character.set_level(mock_character.level)?;
+            for (stat, value) in Stat::ALL.into_iter().zip(mock_character.stats) {
+                character.set_stat(stat, value)?;
+            }
+            character.set_runes(mock_character.runes)?;
+            for &(category, id) in &mock_character.items {
+                match category {
+                    ItemCategory::Talisman | ItemCategory::Goods => {
+                        character.set_item_quantity(category, id, 1, false)?
+                    }
+                    _ => return Err(SaveApiError::UnsupportedItem(category, id)),
+                }
+            }
+
+            for &event_id in &mock_character.event_flags {
+                save_api.set_event_flag(event_id, index, true)?;
+            }
+            save_api.user_data_10_mut().profile_summary.active_profiles[index] = true;
+        }
+        Ok(save_api)
+    }
+}
@@ -1,2 +1,77 @@
+#[cfg(feature = "names")]
+pub mod achievements;
+pub mod aggregate_stats;
+pub mod ammunition;
+pub mod archetype;
+pub mod arm_style;
+#[cfg(feature = "params")]
+pub mod armor_sets;
+pub mod ash_of_war;
+#[cfg(feature = "params")]
+pub mod attack_power;
+pub mod audit;
+pub(crate) mod bosses;
+pub mod build_template;
+#[cfg(feature = "params")]
+pub(crate) mod calc;
+pub mod character;
+pub(crate) mod content_hash;
+pub mod cookbooks;
+pub mod counters;
+#[cfg(feature = "params")]
+pub mod defenses;
+#[cfg(feature = "params")]
+pub mod equip_load;
 pub(crate) mod event_flags;
+pub mod export;
+#[cfg(feature = "std")]
+pub(crate) mod file_lock;
+#[cfg(feature = "params")]
+pub mod formulas;
+pub(crate) mod great_rune;
+#[cfg(feature = "params")]
+pub mod illegal_items;
+#[cfg(feature = "interop")]
+pub mod interop;
+pub mod inventory;
+#[cfg(feature = "schemars")]
+pub mod json_schema;
+pub mod item;
+pub mod key_items;
+pub mod limited_resources;
+pub mod manifest;
+pub mod map_reveal;
+pub mod matchmaking;
+#[cfg(feature = "meta")]
+pub mod meta;
+pub mod mock;
+#[cfg(feature = "params")]
+pub mod param_provider;
+#[cfg(feature = "regulation")]
+pub mod paramdef;
+pub mod practice;
+pub mod profile;
+pub mod quick_items;
+pub mod quick_stats;
+pub mod region;
+#[cfg(feature = "regulation")]
+pub mod regulation_api;
+pub(crate) mod replace_item;
+#[cfg(feature = "names")]
+pub mod report;
+#[cfg(feature = "params")]
+pub mod sanitize;
 pub mod save_api;
+pub mod save_reader;
+pub mod save_writer;
+pub mod scan;
+pub mod settings;
+pub mod sp_effects;
+pub mod starting_gift;
+pub mod stat;
+pub mod status_buildup;
+pub mod traits;
+pub mod transfer;
+#[cfg(feature = "notify")]
+pub mod watch;
+pub mod world_state;
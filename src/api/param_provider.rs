@@ -0,0 +1,44 @@
+use super::{
+    regulation_api::RegulationApi, save_api::user_data_11_api::user_data_api::Param,
+    save_reader::SaveReader,
+};
+use crate::{param_trait, SaveApi, SaveApiError};
+
+/// A source of params for the calculators.
+///
+/// The save's own regulation is used by `SaveApi::weapon_ar`, `SaveApi::defenses` and
+/// `SaveApi::equip_load`. Their `_with` variants take any provider instead, such as a
+/// `RegulationApi` read from a modded `regulation.bin` or a Smithbox project.
+///
+/// # Example
+/// ```rust,no_run
+/// use er_save_lib::regulation_api::RegulationApi;
+/// use er_save_lib::SaveApi;
+/// let save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+/// let modded = RegulationApi::from_project_dir("./my-mod").unwrap();
+/// let vanilla = save_api.weapon_ar(0, 3).unwrap();
+/// let with_mod = save_api.weapon_ar_with(0, 3, &modded).unwrap();
+/// println!("{} -> {}", vanilla.total(), with_mod.total());
+/// ```
+pub trait ParamProvider {
+    /// Returns parameters by type
+    fn get_param<P: param_trait::Param>(&self) -> Result<Param<P>, SaveApiError>;
+}
+
+impl ParamProvider for SaveApi {
+    fn get_param<P: param_trait::Param>(&self) -> Result<Param<P>, SaveApiError> {
+        SaveApi::get_param(self)
+    }
+}
+
+impl ParamProvider for SaveReader {
+    fn get_param<P: param_trait::Param>(&self) -> Result<Param<P>, SaveApiError> {
+        SaveReader::get_param(self)
+    }
+}
+
+impl ParamProvider for RegulationApi<'_> {
+    fn get_param<P: param_trait::Param>(&self) -> Result<Param<P>, SaveApiError> {
+        RegulationApi::get_param(self)
+    }
+}
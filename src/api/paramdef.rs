@@ -0,0 +1,367 @@
+use deku::ctx::Endian;
+use encoding_rs::{SHIFT_JIS, UTF_16BE, UTF_16LE};
+
+use crate::SaveApiError;
+
+/// Layout of the rows of a param, read from a paramdef of the Paramdex, the XML files
+/// Smithbox keeps under `Assets/PARAM/ER/Defs`.
+///
+/// Only what decoding rows needs is kept: the name and type of every field and the
+/// regulation versions it exists in.
+///
+/// # Example
+/// ```rust
+/// use er_save_lib::paramdef::{FieldType, ParamDef};
+/// let def = ParamDef::from_xml(
+///     r#"<PARAMDEF XmlVersion="3">
+///       <ParamType>BUDDY_STONE_PARAM_ST</ParamType>
+///       <Fields>
+///         <Field Def="u8 disableParam_NT:1 = 0" />
+///         <Field Def="dummy8 disableParamReserve1:7" />
+///         <Field Def="dummy8 disableParamReserve2[3]" />
+///         <Field Def="s32 talkChrEntityId" FirstRegulationVersion="10200000" />
+///       </Fields>
+///     </PARAMDEF>"#,
+/// )
+/// .unwrap();
+/// assert_eq!(def.fields.len(), 4);
+/// assert_eq!(def.fields[0].bits, Some(1));
+/// assert_eq!(def.fields[2].count, Some(3));
+/// assert_eq!(def.fields[3].field_type, FieldType::S32);
+/// assert_eq!(def.row_size(10210038), 8);
+/// assert_eq!(def.row_size(10100000), 4);
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParamDef {
+    /// Fields in the order they're stored.
+    pub fields: Vec<ParamDefField>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParamDefField {
+    pub name: String,
+    pub field_type: FieldType,
+    /// Number of elements of an array or of characters of a string.
+    pub count: Option<usize>,
+    /// Width of a bit field. Consecutive bit fields of the same type share a value of that
+    /// type, starting from its lowest bit.
+    pub bits: Option<u32>,
+    /// First regulation version the field exists in.
+    pub first_version: Option<u32>,
+    /// Regulation version the field was removed in.
+    pub removed_version: Option<u32>,
+}
+
+/// Type of a paramdef field, as named in the `Def` attribute.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum FieldType {
+    S8,
+    U8,
+    S16,
+    U16,
+    S32,
+    U32,
+    B32,
+    F32,
+    Angle32,
+    F64,
+    Dummy8,
+    /// Shift JIS string.
+    FixStr,
+    /// UTF-16 string.
+    FixStrW,
+}
+
+impl FieldType {
+    fn parse(name: &str) -> Option<FieldType> {
+        Some(match name {
+            "s8" => FieldType::S8,
+            "u8" => FieldType::U8,
+            "s16" => FieldType::S16,
+            "u16" => FieldType::U16,
+            "s32" => FieldType::S32,
+            "u32" => FieldType::U32,
+            "b32" => FieldType::B32,
+            "f32" => FieldType::F32,
+            "angle32" => FieldType::Angle32,
+            "f64" => FieldType::F64,
+            "dummy8" => FieldType::Dummy8,
+            "fixstr" => FieldType::FixStr,
+            "fixstrW" => FieldType::FixStrW,
+            _ => return None,
+        })
+    }
+
+    // Bit fields of u8 and dummy8 share the same bytes
+    fn bit_unit(&self) -> FieldType {
+        match self {
+            FieldType::Dummy8 => FieldType::U8,
+            field_type => *field_type,
+        }
+    }
+
+    /// Size of a single value or character, in bytes.
+    pub fn size(&self) -> usize {
+        match self {
+            FieldType::S8 | FieldType::U8 | FieldType::Dummy8 | FieldType::FixStr => 1,
+            FieldType::S16 | FieldType::U16 | FieldType::FixStrW => 2,
+            FieldType::S32 | FieldType::U32 | FieldType::B32 => 4,
+            FieldType::F32 | FieldType::Angle32 => 4,
+            FieldType::F64 => 8,
+        }
+    }
+}
+
+// Where a field is stored in a row: its offset and, for bit fields, the offset of its
+// lowest bit in the value at that offset
+struct Placement<'a> {
+    field: &'a ParamDefField,
+    offset: usize,
+    bit_offset: u32,
+}
+
+impl ParamDef {
+    /// Reads a paramdef from the XML of the Paramdex.
+    ///
+    /// # Errors
+    /// `SaveApiError::InvalidParamDef` if a field has no `Def` attribute, or one with an
+    /// unknown type or an invalid size, or if there are no fields at all.
+    pub fn from_xml(xml: &str) -> Result<ParamDef, SaveApiError> {
+        let mut fields = Vec::new();
+        for tag in xml.split("<Field ").skip(1) {
+            let tag = &tag[..tag.find('>').unwrap_or(tag.len())];
+            let def = attribute(tag, "Def")
+                .ok_or_else(|| invalid(format!("field without a Def: {}", tag.trim())))?;
+            let version = |name: &str| -> Result<Option<u32>, SaveApiError> {
+                match attribute(tag, name) {
+                    Some(version) => version
+                        .parse()
+                        .map(Some)
+                        .map_err(|_| invalid(format!("{name} of {def} isn't a number"))),
+                    None => Ok(None),
+                }
+            };
+            let mut field = parse_def(&def).ok_or_else(|| invalid(format!("Def {def}")))?;
+            field.first_version = version("FirstRegulationVersion")?;
+            field.removed_version = version("RemovedRegulationVersion")?;
+            fields.push(field);
+        }
+        if fields.is_empty() {
+            return Err(invalid("no fields".to_string()));
+        }
+        Ok(ParamDef { fields })
+    }
+
+    /// Returns the names of the fields that exist in the specified regulation version.
+    pub fn field_names(&self, version: u32) -> Vec<&str> {
+        self.fields
+            .iter()
+            .filter(|field| field.exists_in(version))
+            .map(|field| field.name.as_str())
+            .collect()
+    }
+
+    /// Returns the size of a row in the specified regulation version.
+    pub fn row_size(&self, version: u32) -> usize {
+        self.layout(version).1
+    }
+
+    // Formats the fields of a row the way `RegulationApi::export_param_csv` does
+    pub(crate) fn decode(
+        &self,
+        row: &[u8],
+        version: u32,
+        endian: Endian,
+    ) -> Result<Vec<String>, SaveApiError> {
+        let (placements, size) = self.layout(version);
+        if row.len() < size {
+            return Err(invalid(format!(
+                "rows are {} bytes, the definition needs {size}",
+                row.len()
+            )));
+        }
+
+        Ok(placements
+            .iter()
+            .map(|placement| format_field(placement, row, endian))
+            .collect())
+    }
+
+    // Placement of every field that exists in the version, and the size of a row
+    fn layout(&self, version: u32) -> (Vec<Placement<'_>>, usize) {
+        let mut placements = Vec::new();
+        let mut offset = 0;
+        // Type, offset and bits in use of the value shared by the current bit fields
+        let mut bit_unit: Option<(FieldType, usize, u32)> = None;
+        for field in self.fields.iter().filter(|field| field.exists_in(version)) {
+            let size = field.field_type.size();
+            let Some(bits) = field.bits else {
+                bit_unit = None;
+                placements.push(Placement {
+                    field,
+                    offset,
+                    bit_offset: 0,
+                });
+                offset += size * field.count.unwrap_or(1);
+                continue;
+            };
+            let (unit_offset, bit_offset) = match bit_unit {
+                Some((unit_type, unit_offset, used))
+                    if unit_type == field.field_type.bit_unit()
+                        && used + bits <= size as u32 * 8 =>
+                {
+                    (unit_offset, used)
+                }
+                _ => {
+                    offset += size;
+                    (offset - size, 0)
+                }
+            };
+            bit_unit = Some((field.field_type.bit_unit(), unit_offset, bit_offset + bits));
+            placements.push(Placement {
+                field,
+                offset: unit_offset,
+                bit_offset,
+            });
+        }
+        (placements, offset)
+    }
+}
+
+impl ParamDefField {
+    /// Returns true when the field exists in the specified regulation version.
+    pub fn exists_in(&self, version: u32) -> bool {
+        self.first_version.is_none_or(|first| version >= first)
+            && self.removed_version.is_none_or(|removed| version < removed)
+    }
+}
+
+fn invalid(reason: String) -> SaveApiError {
+    SaveApiError::InvalidParamDef(reason)
+}
+
+// Value of an attribute of an XML tag, with the predefined entities unescaped
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let pattern = format!("{name}=\"");
+    let start = tag
+        .match_indices(&pattern)
+        .find(|(index, _)| *index == 0 || tag[..*index].ends_with(char::is_whitespace))?
+        .0
+        + pattern.len();
+    let value = &tag[start..start + tag[start..].find('"')?];
+    Some(
+        value
+            .replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&quot;", "\"")
+            .replace("&apos;", "'")
+            .replace("&amp;", "&"),
+    )
+}
+
+// "u8 disableParam_NT:1 = 0", "dummy8 pad[3]", "fixstrW name[32]"
+fn parse_def(def: &str) -> Option<ParamDefField> {
+    let def = def.split_once('=').map_or(def, |(def, _)| def).trim();
+    let (field_type, name) = def.split_once(char::is_whitespace)?;
+    let field_type = FieldType::parse(field_type)?;
+    let name = name.trim();
+
+    let (name, bits, count) = if let Some((name, bits)) = name.split_once(':') {
+        let bits: u32 = bits.trim().parse().ok()?;
+        if bits == 0 || bits > field_type.size().min(4) as u32 * 8 {
+            return None;
+        }
+        (name, Some(bits), None)
+    } else if let Some((name, count)) = name.strip_suffix(']').and_then(|name| name.split_once('['))
+    {
+        (name, None, Some(count.trim().parse().ok()?))
+    } else {
+        (name, None, None)
+    };
+    Some(ParamDefField {
+        name: name.trim().to_string(),
+        field_type,
+        count,
+        bits,
+        first_version: None,
+        removed_version: None,
+    })
+}
+
+fn read_uint(bytes: &[u8], endian: Endian) -> u64 {
+    let fold = |value: u64, byte: &u8| value << 8 | u64::from(*byte);
+    match endian {
+        Endian::Little => bytes.iter().rev().fold(0, fold),
+        Endian::Big => bytes.iter().fold(0, fold),
+    }
+}
+
+fn format_field(placement: &Placement, row: &[u8], endian: Endian) -> String {
+    let Placement {
+        field,
+        offset,
+        bit_offset,
+    } = *placement;
+    let field_type = field.field_type;
+    let size = field_type.size();
+    if let Some(bits) = field.bits {
+        let value = read_uint(&row[offset..offset + size], endian) >> bit_offset;
+        return (value & ((1 << bits) - 1)).to_string();
+    }
+    let bytes = &row[offset..offset + size * field.count.unwrap_or(1)];
+    match (field_type, field.count) {
+        (FieldType::FixStr, _) => {
+            let len = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+            SHIFT_JIS.decode(&bytes[..len]).0.into_owned()
+        }
+        (FieldType::FixStrW, _) => {
+            let len = bytes
+                .chunks(2)
+                .position(|c| c == [0, 0])
+                .map_or(bytes.len(), |chars| chars * 2);
+            let encoding = match endian {
+                Endian::Little => UTF_16LE,
+                Endian::Big => UTF_16BE,
+            };
+            encoding.decode(&bytes[..len]).0.into_owned()
+        }
+        (_, Some(_)) => {
+            let values: Vec<String> = bytes
+                .chunks(size)
+                .map(|value| format_value(field_type, value, endian))
+                .collect();
+            format!("[{}]", values.join("|"))
+        }
+        (_, None) => format_value(field_type, bytes, endian),
+    }
+}
+
+fn format_value(field_type: FieldType, bytes: &[u8], endian: Endian) -> String {
+    let value = read_uint(bytes, endian);
+    match field_type {
+        FieldType::S8 => (value as i8).to_string(),
+        FieldType::S16 => (value as i16).to_string(),
+        FieldType::S32 => (value as i32).to_string(),
+        FieldType::F32 | FieldType::Angle32 => f32::from_bits(value as u32).to_string(),
+        FieldType::F64 => f64::from_bits(value).to_string(),
+        _ => value.to_string(),
+    }
+}
+
+#[test]
+fn bit_fields_share_a_value() {
+    let def = ParamDef::from_xml(
+        r#"<Field Def="u8 a:1" /><Field Def="u8 b:3" /><Field Def="u8 c:6" />
+        <Field Def="s16 d" /><Field Def="fixstr e[4]" /><Field Def="f32 f[2]" />"#,
+    )
+    .unwrap();
+    assert_eq!(def.row_size(0), 2 + 2 + 4 + 8);
+
+    let row = [
+        0b1011, 0b101, 0xfe, 0xff, b'a', b'b', 0, 0, 0, 0, 0x80, 0x3f, 0, 0, 0, 0x40,
+    ];
+    assert_eq!(
+        def.decode(&row, 0, Endian::Little).unwrap(),
+        ["1", "5", "5", "-2", "ab", "[1|2]"]
+    );
+}
@@ -0,0 +1,300 @@
+use std::ops::RangeInclusive;
+
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{bosses, event_flags::EventFlagsApi, item::ItemCategory};
+use crate::{SaveApi, SaveApiError};
+
+// Respawn points are stored as the entity id of the grace plus this
+const GRACE_ENTITY_OFFSET: u32 = 1000;
+
+/// A main boss, identified by the event flag set when it is defeated.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum BossId {
+    Margit,
+    Godrick,
+    RedWolfOfRadagon,
+    Rennala,
+    Radahn,
+    Rykard,
+    Astel,
+    RegalAncestorSpirit,
+    LichdragonFortissax,
+    Morgott,
+    FireGiant,
+    GodskinDuo,
+    Maliketh,
+    Placidusax,
+    Gideon,
+    HoarahLoux,
+    EldenBeast,
+    Malenia,
+    Mohg,
+}
+
+impl BossId {
+    /// Every boss in the order they're usually met.
+    pub const ALL: [BossId; 19] = [
+        BossId::Margit,
+        BossId::Godrick,
+        BossId::RedWolfOfRadagon,
+        BossId::Rennala,
+        BossId::Radahn,
+        BossId::Rykard,
+        BossId::Astel,
+        BossId::RegalAncestorSpirit,
+        BossId::LichdragonFortissax,
+        BossId::Morgott,
+        BossId::FireGiant,
+        BossId::GodskinDuo,
+        BossId::Maliketh,
+        BossId::Placidusax,
+        BossId::Gideon,
+        BossId::HoarahLoux,
+        BossId::EldenBeast,
+        BossId::Malenia,
+        BossId::Mohg,
+    ];
+
+    /// Returns the event flag set when the boss is defeated.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::practice::BossId;
+    /// assert_eq!(BossId::Margit.defeat_flag(), 10000850);
+    /// ```
+    pub fn defeat_flag(&self) -> u32 {
+        match self {
+            BossId::Margit => bosses::MARGIT,
+            BossId::Godrick => bosses::GODRICK,
+            BossId::RedWolfOfRadagon => bosses::RED_WOLF_OF_RADAGON,
+            BossId::Rennala => bosses::RENNALA,
+            BossId::Radahn => bosses::RADAHN,
+            BossId::Rykard => bosses::RYKARD,
+            BossId::Astel => bosses::ASTEL,
+            BossId::RegalAncestorSpirit => bosses::REGAL_ANCESTOR_SPIRIT,
+            BossId::LichdragonFortissax => bosses::LICHDRAGON_FORTISSAX,
+            BossId::Morgott => bosses::MORGOTT,
+            BossId::FireGiant => bosses::FIRE_GIANT,
+            BossId::GodskinDuo => bosses::GODSKIN_DUO,
+            BossId::Maliketh => bosses::MALIKETH,
+            BossId::Placidusax => bosses::PLACIDUSAX,
+            BossId::Gideon => bosses::GIDEON,
+            BossId::HoarahLoux => bosses::HOARAH_LOUX,
+            BossId::EldenBeast => bosses::ELDEN_BEAST,
+            BossId::Malenia => bosses::MALENIA,
+            BossId::Mohg => bosses::MOHG,
+        }
+    }
+}
+
+/// Where a character stands: the map it is in and its position and facing in that map.
+#[derive(Clone, Copy, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct Position {
+    pub coordinates: (f32, f32, f32),
+    /// Map id in the order it is stored, see `ProfileSummary::map_name`.
+    pub map_id: [u8; 4],
+    pub angle: (f32, f32, f32, f32),
+}
+
+/// A named snapshot of the parts of a character's world a speedrun practice file needs,
+/// created by `SaveApi::export_checkpoint` and applied by `SaveApi::apply_checkpoint`.
+///
+/// Only the flags in the selected ranges and the consumables are kept, so a bundle is a
+/// few kilobytes where a save is 28 MB. With the `serde` feature it can be serialized.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct CheckpointBundle {
+    pub name: String,
+    pub position: Position,
+    /// Ranges of event flags captured by the bundle.
+    pub flag_ranges: Vec<RangeInclusive<u32>>,
+    /// Flags of `flag_ranges` that are on. Every other flag of the ranges is off.
+    pub flags: Vec<u32>,
+    /// Goods ids and quantities of the consumables held, key items excluded.
+    pub consumables: Vec<(u32, u32)>,
+}
+
+impl SaveApi {
+    /// Returns the position of the character at the specified index.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// let position = save_api.position(0).unwrap();
+    /// assert_eq!(position.map_id, [0; 4]);
+    /// ```
+    pub fn position(&self, index: usize) -> Result<Position, SaveApiError> {
+        let player = &self.user_data_x(index)?.player_coordinates;
+        Ok(Position {
+            coordinates: player.coordinates,
+            map_id: player.map_id,
+            angle: player.angle,
+        })
+    }
+
+    /// Moves the character at the specified index. The game loads it at the new position
+    /// the next time the character is loaded.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::practice::Position;
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// let position = Position {
+    ///     coordinates: (10., 20., 30.),
+    ///     map_id: [0, 42, 36, 60],
+    ///     angle: (0., 1., 0., 0.),
+    /// };
+    /// save_api.set_position(0, position).unwrap();
+    /// assert_eq!(save_api.position(0).unwrap(), position);
+    /// ```
+    pub fn set_position(&mut self, index: usize, position: Position) -> Result<(), SaveApiError> {
+        let player = &mut self.user_data_x_mut(index)?.player_coordinates;
+        player.coordinates = position.coordinates;
+        player.map_id = position.map_id;
+        player.angle = position.angle;
+        Ok(())
+    }
+
+    /// Captures the position, the event flags in `flag_ranges` and the consumables of the
+    /// character at the specified index.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// save_api.set_event_flag(10000850, 0, true).unwrap();
+    /// let bundle = save_api
+    ///     .export_checkpoint(0, "Margit", &[10000000..=10009999])
+    ///     .unwrap();
+    /// assert_eq!(bundle.flags, vec![10000850]);
+    /// ```
+    pub fn export_checkpoint(
+        &self,
+        index: usize,
+        name: &str,
+        flag_ranges: &[RangeInclusive<u32>],
+    ) -> Result<CheckpointBundle, SaveApiError> {
+        let user_data_x = self.user_data_x(index)?;
+        let flags = EventFlagsApi::set_flags(&user_data_x.event_flags)
+            .into_iter()
+            .filter(|flag| flag_ranges.iter().any(|range| range.contains(flag)))
+            .collect();
+        let consumables = user_data_x
+            .inventory_held
+            .common_items
+            .iter()
+            .filter(|item| item.quantity > 0)
+            .filter(|item| {
+                ItemCategory::from_handle(item.gaitem_handle) == Some(ItemCategory::Goods)
+            })
+            .map(|item| (item.gaitem_handle & 0x0fffffff, item.quantity))
+            .collect();
+
+        Ok(CheckpointBundle {
+            name: name.to_string(),
+            position: self.position(index)?,
+            flag_ranges: flag_ranges.to_vec(),
+            flags,
+            consumables,
+        })
+    }
+
+    /// Applies a bundle to the character at the specified index: moves it, sets the flags
+    /// of the bundle's ranges to their captured value and sets the quantity of every
+    /// consumable of the bundle, adding the ones that aren't held. Other flags and items
+    /// are left as they are.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// let ranges = [10000000..=10009999];
+    /// let bundle = save_api.export_checkpoint(0, "Before Margit", &ranges).unwrap();
+    /// save_api.set_event_flag(10000850, 0, true).unwrap();
+    /// save_api.apply_checkpoint(0, &bundle).unwrap();
+    /// assert!(!save_api.get_event_flag(10000850, 0).unwrap());
+    /// ```
+    #[cfg(feature = "regulation")]
+    pub fn apply_checkpoint(
+        &mut self,
+        index: usize,
+        bundle: &CheckpointBundle,
+    ) -> Result<(), SaveApiError> {
+        self.set_position(index, bundle.position)?;
+
+        let event_flags = &mut self.user_data_x_mut(index)?.event_flags;
+        for flag in EventFlagsApi::set_flags(event_flags) {
+            if bundle.flag_ranges.iter().any(|range| range.contains(&flag)) {
+                EventFlagsApi::set(event_flags, flag, false)?;
+            }
+        }
+        for flag in &bundle.flags {
+            EventFlagsApi::set(event_flags, *flag, true)?;
+        }
+
+        let mut character = self.character_mut(index)?;
+        for (id, quantity) in &bundle.consumables {
+            character.set_item_quantity(ItemCategory::Goods, *id, *quantity, false)?;
+        }
+        Ok(())
+    }
+
+    /// Clears the defeat flag of a boss for the character at the specified index, so it is
+    /// alive again the next time its arena is loaded.
+    ///
+    /// Only the defeat flag is changed. The fog gate flags of the arena and the grace
+    /// closest to it aren't known to the crate, so fog gates stay as they are and the
+    /// character keeps its respawn point; use `SaveApi::set_respawn_grace` to respawn next
+    /// to the fight.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::practice::BossId;
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// save_api.set_event_flag(BossId::Margit.defeat_flag(), 0, true).unwrap();
+    /// save_api.clear_boss_defeat(0, BossId::Margit).unwrap();
+    /// assert!(!save_api.get_event_flag(BossId::Margit.defeat_flag(), 0).unwrap());
+    /// ```
+    pub fn clear_boss_defeat(&mut self, index: usize, boss: BossId) -> Result<(), SaveApiError> {
+        self.set_event_flag(boss.defeat_flag(), index, false)
+    }
+
+    /// Returns the entity id of the grace the character at the specified index respawns
+    /// at, or `None` if it never rested at one.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// assert_eq!(save_api.respawn_grace(0).unwrap(), None);
+    /// ```
+    pub fn respawn_grace(&self, index: usize) -> Result<Option<u32>, SaveApiError> {
+        let grace = self.user_data_x(index)?.last_rested_grace;
+        Ok(grace.checked_sub(GRACE_ENTITY_OFFSET))
+    }
+
+    /// Sets the grace the character at the specified index respawns at when it dies, by
+    /// the entity id of the grace in its map.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// save_api.set_respawn_grace(0, 10001950).unwrap();
+    /// assert_eq!(save_api.respawn_grace(0).unwrap(), Some(10001950));
+    /// ```
+    pub fn set_respawn_grace(&mut self, index: usize, grace: u32) -> Result<(), SaveApiError> {
+        self.user_data_x_mut(index)?.last_rested_grace = grace.saturating_add(GRACE_ENTITY_OFFSET);
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+use super::item::ItemCategory;
+
+/// Everything the load screen shows about a character, read from the profile summary the
+/// game keeps next to the character slots.
+///
+/// Obtained through `SaveApi::profile`. The summary is a copy of the character data taken
+/// when the game was saved, so it matches what the game displays even for slots it hasn't
+/// loaded.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ProfileSummary {
+    /// Whether the slot holds a character.
+    pub active: bool,
+    pub name: String,
+    pub level: u32,
+    pub seconds_played: u32,
+    pub runes_memory: u32,
+    /// Map the character was saved in, in the order it is stored, see
+    /// `ProfileSummary::map_name`.
+    pub map_id: [u8; 4],
+    pub gender: u8,
+    pub archetype: u8,
+    /// Item ids used to draw the character on the load screen, labelled like
+    /// `Character::equipped_items`. Empty slots hold `u32::MAX`.
+    pub equipment: [(&'static str, ItemCategory, u32); 18],
+    /// How the weapons are held on the load screen, see `ArmStyle::from_value`.
+    pub arm_style: u32,
+    /// Active slot, from 0 to 2, of the weapons drawn in the left and right hands.
+    pub active_weapon_slots: [u32; 2],
+}
+
+impl ProfileSummary {
+    /// Returns the name of the map the character was saved in, as used by the game files.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// let mut profile = save_api.profile(0).unwrap();
+    /// profile.map_id = [0, 44, 37, 60];
+    /// assert_eq!(profile.map_name(), "m60_37_44_00");
+    /// ```
+    pub fn map_name(&self) -> String {
+        let [d, c, b, a] = self.map_id;
+        format!("m{a:02}_{b:02}_{c:02}_{d:02}")
+    }
+}
@@ -0,0 +1,244 @@
+use super::{
+    inventory::{held_equip_index, is_empty},
+    item::{resolve_handle, ItemCategory},
+};
+use crate::{
+    save::user_data_x::{EquippedArmamentsAndItems, EquippedItem, UserDataX},
+    SaveApi, SaveApiError,
+};
+
+/// Number of slots of the quick item bar.
+pub const QUICK_ITEM_SLOTS: usize = 10;
+/// Number of slots of the pouch.
+pub const POUCH_SLOTS: usize = 6;
+
+// Category bits of a goods id in the equipment lists
+pub(crate) const GOODS_ID_TYPE: u32 = 0x40000000;
+
+/// The goods assigned to the quick item bar and the pouch of a character, as returned by
+/// `SaveApi::quick_items`. Empty slots hold `None`.
+///
+/// The first four pouch slots are the ones used with the directional buttons, in the
+/// order up, down, left, right.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct QuickItems {
+    /// Goods ids of the quick item bar, in the order the game cycles through them.
+    pub quick_items: [Option<u32>; QUICK_ITEM_SLOTS],
+    /// Quick item slot selected in the bar.
+    pub active_quick_item: Option<usize>,
+    /// Goods ids of the pouch.
+    pub pouch: [Option<u32>; POUCH_SLOTS],
+}
+
+impl SaveApi {
+    /// Returns the goods assigned to the quick item bar and the pouch of the character at
+    /// the specified index, with ids translated by the save's `ItemIdMapper`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// let quick_items = save_api.quick_items(0).unwrap();
+    /// assert!(quick_items.pouch.iter().all(Option::is_none));
+    /// ```
+    pub fn quick_items(&self, index: usize) -> Result<QuickItems, SaveApiError> {
+        let user_data_x = self.user_data_x(index)?;
+        let equipped = &user_data_x.equipped_items;
+        let id = |slot: &EquippedItem| {
+            if is_empty(slot.gaitem_handle) {
+                return None;
+            }
+            resolve_handle(user_data_x, slot.gaitem_handle)
+                .map(|(category, id)| self.mapper().map_id(category, id))
+        };
+
+        let mut quick_items = QuickItems {
+            quick_items: [None; QUICK_ITEM_SLOTS],
+            active_quick_item: Some(equipped.active_quick_item_index as usize)
+                .filter(|slot| *slot < QUICK_ITEM_SLOTS),
+            pouch: [None; POUCH_SLOTS],
+        };
+        for (slot, item) in quick_items
+            .quick_items
+            .iter_mut()
+            .zip(&equipped.quick_items)
+        {
+            *slot = id(item);
+        }
+        for (slot, item) in quick_items.pouch.iter_mut().zip(&equipped.pouch_items) {
+            *slot = id(item);
+        }
+        Ok(quick_items)
+    }
+
+    /// Assigns goods to a slot of the quick item bar of the character at the specified
+    /// index, or clears the slot with `None`. The goods must be held.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::item::ItemCategory;
+    /// use er_save_lib::{MockCharacter, MockSave, SaveApi};
+    /// let mock = MockSave::new(vec![MockCharacter {
+    ///     level: 1,
+    ///     items: vec![(ItemCategory::Goods, 1000)],
+    ///     ..Default::default()
+    /// }]);
+    /// let mut save_api = SaveApi::from_mock(&mock).unwrap();
+    /// save_api.set_quick_item(0, 0, Some(1000)).unwrap();
+    /// assert_eq!(save_api.quick_items(0).unwrap().quick_items[0], Some(1000));
+    /// assert!(save_api.set_quick_item(0, 1, Some(1001)).is_err());
+    /// ```
+    pub fn set_quick_item(
+        &mut self,
+        index: usize,
+        slot: usize,
+        goods_id: Option<u32>,
+    ) -> Result<(), SaveApiError> {
+        if slot >= QUICK_ITEM_SLOTS {
+            return Err(SaveApiError::QuickItemSlotOutOfRange(slot));
+        }
+        let item = equipped_item(self.user_data_x(index)?, goods_id)?;
+        let user_data_x = self.user_data_x_mut(index)?;
+        user_data_x.equipped_items.quick_items[slot] = item;
+        *quick_item_id_mut(&mut user_data_x.equipped_armaments_and_items, slot) =
+            goods_id.map_or(u32::MAX, |id| GOODS_ID_TYPE | id);
+        Ok(())
+    }
+
+    /// Assigns goods to a pouch slot of the character at the specified index, or clears
+    /// the slot with `None`. The goods must be held.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::item::ItemCategory;
+    /// use er_save_lib::{MockCharacter, MockSave, SaveApi};
+    /// let mock = MockSave::new(vec![MockCharacter {
+    ///     level: 1,
+    ///     items: vec![(ItemCategory::Goods, 1000)],
+    ///     ..Default::default()
+    /// }]);
+    /// let mut save_api = SaveApi::from_mock(&mock).unwrap();
+    /// save_api.set_pouch_item(0, 3, Some(1000)).unwrap();
+    /// assert_eq!(save_api.quick_items(0).unwrap().pouch[3], Some(1000));
+    /// ```
+    pub fn set_pouch_item(
+        &mut self,
+        index: usize,
+        slot: usize,
+        goods_id: Option<u32>,
+    ) -> Result<(), SaveApiError> {
+        if slot >= POUCH_SLOTS {
+            return Err(SaveApiError::PouchSlotOutOfRange(slot));
+        }
+        let item = equipped_item(self.user_data_x(index)?, goods_id)?;
+        let user_data_x = self.user_data_x_mut(index)?;
+        user_data_x.equipped_items.pouch_items[slot] = item;
+        *pouch_id_mut(&mut user_data_x.equipped_armaments_and_items, slot) =
+            goods_id.map_or(u32::MAX, |id| GOODS_ID_TYPE | id);
+        Ok(())
+    }
+
+    /// Selects a slot of the quick item bar of the character at the specified index, or
+    /// none of them.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// save_api.set_active_quick_item(0, Some(2)).unwrap();
+    /// assert_eq!(save_api.quick_items(0).unwrap().active_quick_item, Some(2));
+    /// ```
+    pub fn set_active_quick_item(
+        &mut self,
+        index: usize,
+        slot: Option<usize>,
+    ) -> Result<(), SaveApiError> {
+        let active = match slot {
+            Some(slot) if slot >= QUICK_ITEM_SLOTS => {
+                return Err(SaveApiError::QuickItemSlotOutOfRange(slot))
+            }
+            Some(slot) => slot as u32,
+            None => u32::MAX,
+        };
+        self.user_data_x_mut(index)?
+            .equipped_items
+            .active_quick_item_index = active;
+        Ok(())
+    }
+
+    /// Assigns every quick item and pouch slot of the character at the specified index,
+    /// for restoring a configuration returned by `SaveApi::quick_items`. Nothing is changed
+    /// if any of the goods isn't held.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// let quick_items = save_api.quick_items(0).unwrap();
+    /// save_api.set_quick_items(1, &quick_items).unwrap();
+    /// assert_eq!(save_api.quick_items(1).unwrap(), quick_items);
+    /// ```
+    pub fn set_quick_items(
+        &mut self,
+        index: usize,
+        quick_items: &QuickItems,
+    ) -> Result<(), SaveApiError> {
+        let user_data_x = self.user_data_x(index)?;
+        for goods_id in quick_items.quick_items.iter().chain(&quick_items.pouch) {
+            equipped_item(user_data_x, *goods_id)?;
+        }
+        for (slot, goods_id) in quick_items.quick_items.iter().enumerate() {
+            self.set_quick_item(index, slot, *goods_id)?;
+        }
+        for (slot, goods_id) in quick_items.pouch.iter().enumerate() {
+            self.set_pouch_item(index, slot, *goods_id)?;
+        }
+        self.set_active_quick_item(index, quick_items.active_quick_item)
+    }
+}
+
+// Quick item, pouch or great rune entry pointing at the held goods
+pub(crate) fn equipped_item(
+    user_data_x: &UserDataX,
+    goods_id: Option<u32>,
+) -> Result<EquippedItem, SaveApiError> {
+    let Some(goods_id) = goods_id else {
+        return Ok(EquippedItem {
+            gaitem_handle: 0,
+            equip_index: 0,
+        });
+    };
+    let handle = 0xb0000000 | goods_id;
+    held_equip_index(user_data_x, handle)
+        .map(|equip_index| EquippedItem {
+            gaitem_handle: handle,
+            equip_index,
+        })
+        .ok_or(SaveApiError::ItemNotOwned(ItemCategory::Goods, goods_id))
+}
+
+fn quick_item_id_mut(items: &mut EquippedArmamentsAndItems, slot: usize) -> &mut u32 {
+    match slot {
+        0 => &mut items.quickitem1,
+        1 => &mut items.quickitem2,
+        2 => &mut items.quickitem3,
+        3 => &mut items.quickitem4,
+        4 => &mut items.quickitem5,
+        5 => &mut items.quickitem6,
+        6 => &mut items.quickitem7,
+        7 => &mut items.quickitem8,
+        8 => &mut items.quickitem9,
+        _ => &mut items.quickitem10,
+    }
+}
+
+fn pouch_id_mut(items: &mut EquippedArmamentsAndItems, slot: usize) -> &mut u32 {
+    match slot {
+        0 => &mut items.pouch1,
+        1 => &mut items.pouch2,
+        2 => &mut items.pouch3,
+        3 => &mut items.pouch4,
+        4 => &mut items.pouch5,
+        _ => &mut items.pouch6,
+    }
+}
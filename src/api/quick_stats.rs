@@ -0,0 +1,101 @@
+#[cfg(feature = "std")]
+use std::{
+    fs::File,
+    io::{BufReader, Read, Seek, SeekFrom},
+    path::Path,
+};
+
+#[cfg(feature = "std")]
+use deku::{ctx::Endian, reader::Reader};
+
+#[cfg(feature = "std")]
+use crate::{
+    save::{user_data_10::UserData10, user_data_x::UserDataXStats},
+    GameProfile, SaveApi, SaveApiError,
+};
+
+/// What an overlay or tracker shows about a character, read by `SaveApi::quick_stats`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct QuickStats {
+    /// Index of the character slot.
+    pub index: usize,
+    pub name: String,
+    pub level: u32,
+    /// Runes held.
+    pub runes: u32,
+    pub seconds_played: u32,
+    pub deaths: u32,
+}
+
+#[cfg(feature = "std")]
+impl SaveApi {
+    /// Reads the name, level, runes, play time and death count of every active character
+    /// of the save file at `path`, without parsing the whole save.
+    ///
+    /// Only the profile summaries and the start of each active slot, up to its death
+    /// count, are read from the file. The regulation and the rest of the slots are
+    /// skipped, which makes this cheap enough to run every time the game writes the save.
+    /// Use `SaveApi::from_path` for anything else.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use er_save_lib::SaveApi;
+    /// let stats = SaveApi::quick_stats("./test/ER0000.sl2").unwrap();
+    /// let save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+    /// assert_eq!(stats.len(), save_api.active_characters().iter().filter(|a| **a).count());
+    /// for character in stats {
+    ///     assert_eq!(character.level, save_api.level(character.index).unwrap());
+    ///     assert_eq!(character.deaths, save_api.deaths(character.index).unwrap());
+    /// }
+    /// ```
+    pub fn quick_stats(path: impl AsRef<Path>) -> Result<Vec<QuickStats>, SaveApiError> {
+        let mut file = BufReader::new(File::open(path)?);
+        let game = GameProfile::ELDEN_RING;
+
+        // PlayStation saves have no BND4 container
+        let mut magic = [0; 4];
+        file.read_exact(&mut magic)?;
+        let is_ps = &magic != b"BND4";
+        let [header_size, slot_size, user_data_10_size, _] = game.sizes(is_ps);
+        let slots_start = magic.len() + header_size;
+
+        file.seek(SeekFrom::Start(
+            (slots_start + slot_size * game.slot_count) as u64,
+        ))?;
+        let user_data_10 = UserData10::read(
+            &mut Reader::new(&mut file),
+            Endian::Little,
+            0,
+            user_data_10_size,
+            is_ps,
+            game.slot_count,
+        )?;
+        let summary = &user_data_10.profile_summary;
+
+        let mut stats = Vec::new();
+        for (index, profile) in summary.profiles.iter().enumerate() {
+            if !summary.active_profiles.get(index).copied().unwrap_or(false) {
+                continue;
+            }
+            let start = slots_start + slot_size * index;
+            file.seek(SeekFrom::Start(start as u64))?;
+            let slot = UserDataXStats::read(
+                &mut Reader::new(&mut file),
+                Endian::Little,
+                index,
+                start,
+                slot_size,
+                is_ps,
+            )?;
+            stats.push(QuickStats {
+                index,
+                name: profile.character_name.clone(),
+                level: slot.player_game_data.level,
+                runes: slot.player_game_data.runes,
+                seconds_played: profile.seconds_played,
+                deaths: slot.total_deaths_count,
+            });
+        }
+        Ok(stats)
+    }
+}
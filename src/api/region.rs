@@ -0,0 +1,237 @@
+use std::fmt::Display;
+
+/// The main region of every major area, as stored in the unlocked regions of a character.
+///
+/// Areas are split into many smaller regions, only the first one of each area is named
+/// here. Region ids without a variant are still accepted wherever an id is, as a `u32`.
+///
+/// # Example
+/// ```rust
+/// use er_save_lib::{MockCharacter, MockSave, Region, SaveApi};
+/// let mock = MockSave::new(vec![MockCharacter {
+///     level: 1,
+///     ..Default::default()
+/// }]);
+/// let mut save_api = SaveApi::from_mock(&mock).unwrap();
+/// save_api.add_region(0, Region::Caelid).unwrap();
+/// assert!(save_api.regions(0).unwrap().contains(&Region::Caelid.into()));
+/// assert_eq!(Region::try_from(6400000).unwrap().to_string(), "Caelid");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Region {
+    StormveilCastle,
+    ChapelOfAnticipation,
+    LeyndellRoyalCapital,
+    LeyndellAshenCapital,
+    RoundtableHold,
+    CrumblingFarumAzula,
+    AcademyOfRayaLucaria,
+    MiquellasHaligtree,
+    VolcanoManor,
+    StrandedGraveyard,
+    BeluratTowerSettlement,
+    EnirIlim,
+    ShadowKeep,
+    SpecimenStorehouse,
+    StoneCoffinFissure,
+    Limgrave,
+    WeepingPeninsula,
+    Liurnia,
+    AltusPlateau,
+    Caelid,
+    MountaintopsOfTheGiants,
+}
+
+/// The map a region is in, returned by `Region::map_area`.
+///
+/// # Example
+/// ```rust
+/// use er_save_lib::region::MapArea;
+/// use er_save_lib::Region;
+/// let area = Region::RoundtableHold.map_area();
+/// assert_eq!(area, MapArea::Dungeon { area: 11, block: 10 });
+/// assert_eq!(area.to_string(), "m11_10");
+/// let area = Region::Limgrave.map_area();
+/// assert_eq!(area.to_string(), "m60_42_36..m60_45_39");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum MapArea {
+    /// A legacy dungeon or other self-contained map, `mAA_BB` in the game files.
+    Dungeon { area: u8, block: u8 },
+    /// Tiles of the open world, `m60_XX_ZZ` in the game files, as the `(x, z)` corners of
+    /// the smallest box holding the tiles the game's `PlayRegionParam` places the area's
+    /// regions in. The area itself reaches past the box.
+    Overworld { min: (u8, u8), max: (u8, u8) },
+}
+
+/// Error returned by `Region::try_from` for an id without a `Region` variant.
+#[derive(thiserror::Error, PartialEq, Eq, Debug)]
+#[error("Region {0} has no name")]
+pub struct UnknownRegion(pub u32);
+
+impl Region {
+    /// Every region in the order of their ids.
+    pub const ALL: [Region; 21] = [
+        Region::StormveilCastle,
+        Region::ChapelOfAnticipation,
+        Region::LeyndellRoyalCapital,
+        Region::LeyndellAshenCapital,
+        Region::RoundtableHold,
+        Region::CrumblingFarumAzula,
+        Region::AcademyOfRayaLucaria,
+        Region::MiquellasHaligtree,
+        Region::VolcanoManor,
+        Region::StrandedGraveyard,
+        Region::BeluratTowerSettlement,
+        Region::EnirIlim,
+        Region::ShadowKeep,
+        Region::SpecimenStorehouse,
+        Region::StoneCoffinFissure,
+        Region::Limgrave,
+        Region::WeepingPeninsula,
+        Region::Liurnia,
+        Region::AltusPlateau,
+        Region::Caelid,
+        Region::MountaintopsOfTheGiants,
+    ];
+
+    /// Returns the id of the region.
+    pub fn id(&self) -> u32 {
+        match self {
+            Region::StormveilCastle => 1000000,
+            Region::ChapelOfAnticipation => 1001000,
+            Region::LeyndellRoyalCapital => 1100000,
+            Region::LeyndellAshenCapital => 1105000,
+            Region::RoundtableHold => 1110000,
+            Region::CrumblingFarumAzula => 1300000,
+            Region::AcademyOfRayaLucaria => 1400000,
+            Region::MiquellasHaligtree => 1500000,
+            Region::VolcanoManor => 1600000,
+            Region::StrandedGraveyard => 1800000,
+            Region::BeluratTowerSettlement => 2000000,
+            Region::EnirIlim => 2001000,
+            Region::ShadowKeep => 2100000,
+            Region::SpecimenStorehouse => 2101000,
+            Region::StoneCoffinFissure => 2200000,
+            Region::Limgrave => 6100000,
+            Region::WeepingPeninsula => 6102000,
+            Region::Liurnia => 6200000,
+            Region::AltusPlateau => 6300000,
+            Region::Caelid => 6400000,
+            Region::MountaintopsOfTheGiants => 6500000,
+        }
+    }
+
+    /// Returns the in-game name of the region.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Region::StormveilCastle => "Stormveil Castle",
+            Region::ChapelOfAnticipation => "Chapel of Anticipation",
+            Region::LeyndellRoyalCapital => "Leyndell, Royal Capital",
+            Region::LeyndellAshenCapital => "Leyndell, Ashen Capital",
+            Region::RoundtableHold => "Roundtable Hold",
+            Region::CrumblingFarumAzula => "Crumbling Farum Azula",
+            Region::AcademyOfRayaLucaria => "Academy of Raya Lucaria",
+            Region::MiquellasHaligtree => "Miquella's Haligtree",
+            Region::VolcanoManor => "Volcano Manor",
+            Region::StrandedGraveyard => "Stranded Graveyard",
+            Region::BeluratTowerSettlement => "Belurat, Tower Settlement",
+            Region::EnirIlim => "Enir-Ilim",
+            Region::ShadowKeep => "Shadow Keep",
+            Region::SpecimenStorehouse => "Specimen Storehouse",
+            Region::StoneCoffinFissure => "Stone Coffin Fissure",
+            Region::Limgrave => "Limgrave",
+            Region::WeepingPeninsula => "Weeping Peninsula",
+            Region::Liurnia => "Liurnia of the Lakes",
+            Region::AltusPlateau => "Altus Plateau",
+            Region::Caelid => "Caelid",
+            Region::MountaintopsOfTheGiants => "Mountaintops of the Giants",
+        }
+    }
+
+    /// Returns the region containing a region id, as stored in the unlocked regions of a
+    /// character. Areas own the ids of their block of a thousand, Limgrave those from
+    /// 6100000 to 6100999 for instance.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::Region;
+    /// assert_eq!(Region::containing(6100090), Some(Region::Limgrave));
+    /// assert_eq!(Region::containing(1), None);
+    /// ```
+    pub fn containing(region_id: u32) -> Option<Region> {
+        Region::try_from(region_id / 1000 * 1000).ok()
+    }
+
+    /// Returns the name to show for a region id, the name of the region containing it.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{Region, SaveApi, SaveType};
+    /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// for region_id in save_api.regions(0).unwrap() {
+    ///     println!("{}", Region::display_name(*region_id).unwrap_or("Unknown region"));
+    /// }
+    /// assert_eq!(Region::display_name(6400010), Some("Caelid"));
+    /// ```
+    pub fn display_name(region_id: u32) -> Option<&'static str> {
+        Region::containing(region_id).map(|region| region.name())
+    }
+
+    /// Returns the map the region is in. The id of a dungeon region is its map id, 1105000
+    /// for `m11_05` for instance, while open world regions cover tiles of `m60`.
+    pub fn map_area(&self) -> MapArea {
+        let (min, max) = match self {
+            Region::Limgrave => ((42, 36), (45, 39)),
+            Region::WeepingPeninsula => ((42, 31), (44, 33)),
+            Region::Liurnia => ((34, 42), (38, 51)),
+            Region::AltusPlateau => ((37, 51), (41, 54)),
+            Region::Caelid => ((47, 36), (52, 39)),
+            Region::MountaintopsOfTheGiants => ((48, 51), (48, 51)),
+            _ => {
+                let id = self.id();
+                return MapArea::Dungeon {
+                    area: (id / 100000) as u8,
+                    block: (id / 1000 % 100) as u8,
+                };
+            }
+        };
+        MapArea::Overworld { min, max }
+    }
+}
+
+impl TryFrom<u32> for Region {
+    type Error = UnknownRegion;
+
+    fn try_from(id: u32) -> Result<Self, Self::Error> {
+        Region::ALL
+            .into_iter()
+            .find(|region| region.id() == id)
+            .ok_or(UnknownRegion(id))
+    }
+}
+
+impl From<Region> for u32 {
+    fn from(region: Region) -> Self {
+        region.id()
+    }
+}
+
+impl Display for MapArea {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MapArea::Dungeon { area, block } => write!(f, "m{area:02}_{block:02}"),
+            MapArea::Overworld { min, max } => write!(
+                f,
+                "m60_{:02}_{:02}..m60_{:02}_{:02}",
+                min.0, min.1, max.0, max.1
+            ),
+        }
+    }
+}
+
+impl Display for Region {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
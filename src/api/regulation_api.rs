@@ -0,0 +1,414 @@
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
+    io::Write,
+};
+#[cfg(feature = "std")]
+use std::{fs, path::Path};
+
+use super::paramdef::ParamDef;
+#[cfg(feature = "params")]
+use super::save_api::user_data_11_api::user_data_api::Param;
+use crate::{
+    regulation::{
+        params::param::{param_endian, raw_rows},
+        regulation::Regulation,
+    },
+    SaveApiError,
+};
+
+/// Read access to a regulation, either the one embedded in a save or a standalone
+/// `regulation.bin`.
+pub struct RegulationApi<'a> {
+    regulation: Cow<'a, Regulation>,
+}
+
+/// Differences between two regulations, as returned by `RegulationApi::diff`.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct RegulationDiff {
+    /// Params that were added, removed or have changed rows, sorted by name.
+    pub params: Vec<ParamDiff>,
+}
+
+impl RegulationDiff {
+    /// Returns true when both regulations hold the same params and rows.
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty()
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParamDiff {
+    pub name: String,
+    pub change: ParamChange,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ParamChange {
+    /// The param only exists in the other regulation.
+    Added,
+    /// The param only exists in this regulation.
+    Removed,
+    /// Rows that differ, sorted by id.
+    Rows(Vec<RowDiff>),
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct RowDiff {
+    pub id: i32,
+    pub change: RowChange,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum RowChange {
+    /// The row only exists in the other regulation.
+    Added,
+    /// The row only exists in this regulation.
+    Removed,
+    /// Runs of bytes that differ inside the row.
+    Fields(Vec<FieldDiff>),
+}
+
+/// A run of changed bytes inside a row.
+///
+/// `offset` is relative to the start of the row and matches the field offsets of the
+/// param's definition (see the structs in `param_structs::defs`).
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct FieldDiff {
+    pub offset: usize,
+    pub before: Vec<u8>,
+    pub after: Vec<u8>,
+}
+
+impl<'a> RegulationApi<'a> {
+    pub(crate) fn new(regulation: &'a Regulation) -> Self {
+        RegulationApi {
+            regulation: Cow::Borrowed(regulation),
+        }
+    }
+
+    /// Creates a `RegulationApi` instance from the bytes of an encrypted `regulation.bin`.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use er_save_lib::regulation_api::RegulationApi;
+    /// let bytes = std::fs::read("regulation.bin").unwrap();
+    /// let regulation = RegulationApi::from_slice(&bytes).unwrap();
+    /// ```
+    pub fn from_slice(bytes: &[u8]) -> Result<RegulationApi<'static>, SaveApiError> {
+        let regulation = Regulation::from_slice(&mut bytes.to_vec())?;
+        Ok(RegulationApi {
+            regulation: Cow::Owned(regulation),
+        })
+    }
+
+    /// Creates a `RegulationApi` instance from the path of an encrypted `regulation.bin`.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use er_save_lib::regulation_api::RegulationApi;
+    /// let regulation = RegulationApi::from_path("regulation.bin").unwrap();
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_path(path: impl AsRef<Path>) -> Result<RegulationApi<'static>, SaveApiError> {
+        let bytes = fs::read(path)?;
+        RegulationApi::from_slice(&bytes)
+    }
+
+    /// Creates a `RegulationApi` instance from a Smithbox project directory, reading the
+    /// `regulation.bin` Smithbox writes there when the params are saved.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use er_save_lib::regulation_api::RegulationApi;
+    /// let regulation = RegulationApi::from_project_dir("./my-mod").unwrap();
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_project_dir(dir: impl AsRef<Path>) -> Result<RegulationApi<'static>, SaveApiError> {
+        RegulationApi::from_path(dir.as_ref().join("regulation.bin"))
+    }
+
+    /// Returns the regulation version.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use er_save_lib::SaveApi;
+    /// let save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+    /// let version = save_api.regulation().version();
+    /// ```
+    pub fn version(&self) -> u32 {
+        self.regulation.content.data.header.version
+    }
+
+    /// Returns parameters by type
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use er_save_lib::SaveApi;
+    /// use er_save_lib::TalkParam::TalkParam;
+    /// let save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+    /// let param = save_api.regulation().get_param::<TalkParam>().unwrap();
+    /// ```
+    #[cfg(feature = "params")]
+    pub fn get_param<P: crate::param_trait::Param>(&self) -> Result<Param<P>, SaveApiError> {
+        let rows = self.regulation.get_param::<P>()?;
+        Ok(Param::<P> { rows })
+    }
+
+    /// Writes the rows of a param to `writer` as CSV, in the layout Smithbox uses: a header
+    /// row with `ID`, `Name` and the names of the fields, then one row per param row, in
+    /// the order they're stored.
+    ///
+    /// Fields are named as in the paramdefs and only the ones that exist in the version of
+    /// the regulation are written. Byte arrays are written as `[0|0|0]`. Row names aren't
+    /// read, the `Name` column is left empty.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use er_save_lib::BuddyStoneParam::BuddyStoneParam;
+    /// use er_save_lib::SaveApi;
+    /// let save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+    /// let mut csv = Vec::new();
+    /// save_api
+    ///     .regulation()
+    ///     .export_param_csv::<BuddyStoneParam>(&mut csv)
+    ///     .unwrap();
+    /// let csv = String::from_utf8(csv).unwrap();
+    /// assert!(csv.starts_with("ID,Name,disableParam_NT,"));
+    /// ```
+    #[cfg(feature = "params")]
+    pub fn export_param_csv<P: crate::param_trait::Param>(
+        &self,
+        mut writer: impl Write,
+    ) -> Result<(), SaveApiError> {
+        use crate::param_trait::ParamFields;
+
+        let version = self.version();
+        write_csv_row(
+            &mut writer,
+            ["ID", "Name"]
+                .into_iter()
+                .chain(P::ParamType::field_names(version)),
+        )?;
+        for (id, row) in self.regulation.param_rows::<P>()? {
+            let values = row.field_values(version);
+            let id = id.to_string();
+            write_csv_row(
+                &mut writer,
+                [id.as_str(), ""]
+                    .into_iter()
+                    .chain(values.iter().map(String::as_str)),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Writes the rows of the param named `param_name` to `writer` as CSV, like
+    /// `RegulationApi::export_param_csv`, decoding them with a paramdef of the Paramdex
+    /// instead of the param structs of the crate.
+    ///
+    /// This works without the `params` feature and for params the crate has no struct
+    /// for.
+    ///
+    /// # Errors
+    /// - `SaveApiError::ParamNotFound` if the regulation has no param of that name.
+    /// - `SaveApiError::InvalidParamDef` if the rows are smaller than the definition.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use er_save_lib::paramdef::ParamDef;
+    /// use er_save_lib::SaveApi;
+    /// let save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+    /// let xml = std::fs::read_to_string("Defs/BUDDY_STONE_PARAM_ST.xml").unwrap();
+    /// let def = ParamDef::from_xml(&xml).unwrap();
+    /// let file = std::fs::File::create("BuddyStoneParam.csv").unwrap();
+    /// save_api
+    ///     .regulation()
+    ///     .export_param_csv_with_def("BuddyStoneParam", &def, file)
+    ///     .unwrap();
+    /// ```
+    pub fn export_param_csv_with_def(
+        &self,
+        param_name: &str,
+        def: &ParamDef,
+        mut writer: impl Write,
+    ) -> Result<(), SaveApiError> {
+        let bytes = self
+            .param_files()
+            .get(param_name)
+            .ok_or_else(|| SaveApiError::ParamNotFound(param_name.to_string()))?;
+        let endian = param_endian(bytes)?;
+        let version = self.version();
+
+        write_csv_row(
+            &mut writer,
+            ["ID", "Name"].into_iter().chain(def.field_names(version)),
+        )?;
+        for (id, data) in raw_rows(bytes)? {
+            let values = def.decode(data, version, endian)?;
+            let id = id.to_string();
+            write_csv_row(
+                &mut writer,
+                [id.as_str(), ""]
+                    .into_iter()
+                    .chain(values.iter().map(String::as_str)),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Compares this regulation with `other` and returns the params, rows and fields that
+    /// differ. Changes are reported from this regulation to `other`.
+    ///
+    /// Diffing a save's regulation against the vanilla one of the same version tells whether
+    /// the save was played on a modded regulation.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use er_save_lib::regulation_api::ParamChange;
+    /// use er_save_lib::SaveApi;
+    /// let pc = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+    /// let ps = SaveApi::from_path("./test/PS_Save.txt").unwrap();
+    /// let diff = pc.regulation().diff(&ps.regulation()).unwrap();
+    /// for param in &diff.params {
+    ///     if let ParamChange::Rows(rows) = &param.change {
+    ///         println!("{}: {} rows changed", param.name, rows.len());
+    ///     }
+    /// }
+    /// assert!(pc.regulation().diff(&pc.regulation()).unwrap().is_empty());
+    /// ```
+    pub fn diff(&self, other: &RegulationApi) -> Result<RegulationDiff, SaveApiError> {
+        let before = self.param_files();
+        let after = other.param_files();
+
+        let mut params = Vec::new();
+        for (name, before_bytes) in before {
+            let change = match after.get(name) {
+                None => ParamChange::Removed,
+                Some(after_bytes) if after_bytes == before_bytes => continue,
+                Some(after_bytes) => {
+                    let rows = diff_rows(before_bytes, after_bytes)?;
+                    if rows.is_empty() {
+                        continue;
+                    }
+                    ParamChange::Rows(rows)
+                }
+            };
+            params.push(ParamDiff {
+                name: name.clone(),
+                change,
+            });
+        }
+        for name in after.keys().filter(|name| !before.contains_key(*name)) {
+            params.push(ParamDiff {
+                name: name.clone(),
+                change: ParamChange::Added,
+            });
+        }
+        params.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(RegulationDiff { params })
+    }
+
+    fn param_files(&self) -> &BTreeMap<String, Vec<u8>> {
+        &self.regulation.content.data.file_data.param_files
+    }
+}
+
+// Writes a CSV row, quoting fields that hold a separator, a quote or a line break
+fn write_csv_row<'a>(
+    writer: &mut impl Write,
+    fields: impl IntoIterator<Item = &'a str>,
+) -> Result<(), SaveApiError> {
+    let fields: Vec<Cow<str>> = fields
+        .into_iter()
+        .map(|field| {
+            if field.contains([',', '"', '\n', '\r']) {
+                Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+            } else {
+                Cow::Borrowed(field)
+            }
+        })
+        .collect();
+    writeln!(writer, "{}", fields.join(","))?;
+    Ok(())
+}
+
+// Rows are matched by id. Some params repeat ids, so repeated rows are matched in order.
+fn diff_rows(before: &[u8], after: &[u8]) -> Result<Vec<RowDiff>, SaveApiError> {
+    let before = keyed_rows(raw_rows(before)?);
+    let after = keyed_rows(raw_rows(after)?);
+
+    let mut rows = Vec::new();
+    for (key, before_data) in &before {
+        let change = match after.get(key) {
+            None => RowChange::Removed,
+            Some(after_data) if after_data == before_data => continue,
+            Some(after_data) => RowChange::Fields(diff_fields(before_data, after_data)),
+        };
+        rows.push(RowDiff { id: key.0, change });
+    }
+    for key in after.keys().filter(|key| !before.contains_key(*key)) {
+        rows.push(RowDiff {
+            id: key.0,
+            change: RowChange::Added,
+        });
+    }
+    rows.sort_by_key(|row| row.id);
+    Ok(rows)
+}
+
+fn keyed_rows(rows: Vec<(i32, &[u8])>) -> BTreeMap<(i32, usize), &[u8]> {
+    let mut occurrences = HashMap::new();
+    rows.into_iter()
+        .map(|(id, data)| {
+            let occurrence = occurrences.entry(id).or_insert(0);
+            *occurrence += 1;
+            ((id, *occurrence), data)
+        })
+        .collect()
+}
+
+// Groups differing bytes into contiguous runs. Bytes past the end of the shorter row count
+// as changed.
+fn diff_fields(before: &[u8], after: &[u8]) -> Vec<FieldDiff> {
+    let mut fields: Vec<FieldDiff> = Vec::new();
+    for offset in 0..before.len().max(after.len()) {
+        let (a, b) = (before.get(offset), after.get(offset));
+        if a == b {
+            continue;
+        }
+        match fields.last_mut() {
+            Some(field) if field.offset + field.before.len().max(field.after.len()) == offset => {
+                field.before.extend(a);
+                field.after.extend(b);
+            }
+            _ => fields.push(FieldDiff {
+                offset,
+                before: a.into_iter().copied().collect(),
+                after: b.into_iter().copied().collect(),
+            }),
+        }
+    }
+    fields
+}
+
+#[test]
+fn diff_fields_groups_runs() {
+    let fields = diff_fields(&[0, 1, 2, 3, 4], &[0, 9, 9, 3, 4, 5]);
+    assert_eq!(
+        fields,
+        vec![
+            FieldDiff {
+                offset: 1,
+                before: vec![1, 2],
+                after: vec![9, 9],
+            },
+            FieldDiff {
+                offset: 5,
+                before: vec![],
+                after: vec![5],
+            },
+        ]
+    );
+}
@@ -0,0 +1,196 @@
+use super::{
+    inventory::is_empty,
+    item::{self, ItemCategory},
+};
+use crate::{save::user_data_x::UserDataX, SaveApi, SaveApiError};
+
+// Fields of an equipment struct holding the slots of a category
+macro_rules! category_slots_mut {
+    ($equipped:expr, $category:expr) => {{
+        let equipped = &mut $equipped;
+        match $category {
+            ItemCategory::Weapon => vec![
+                &mut equipped.left_hand_armament1,
+                &mut equipped.right_hand_armament1,
+                &mut equipped.left_hand_armament2,
+                &mut equipped.right_hand_armament2,
+                &mut equipped.left_hand_armament3,
+                &mut equipped.right_hand_armament3,
+                &mut equipped.arrows1,
+                &mut equipped.bolts1,
+                &mut equipped.arrows2,
+                &mut equipped.bolts2,
+            ],
+            ItemCategory::Armor => vec![
+                &mut equipped.head,
+                &mut equipped.chest,
+                &mut equipped.arms,
+                &mut equipped.legs,
+            ],
+            ItemCategory::Talisman => vec![
+                &mut equipped.talisman1,
+                &mut equipped.talisman2,
+                &mut equipped.talisman3,
+                &mut equipped.talisman4,
+            ],
+            _ => Vec::new(),
+        }
+    }};
+}
+
+impl SaveApi {
+    /// Replaces an item of the character at the specified index by another one of the same
+    /// category, wherever it is referenced: held and stored inventory, equipment, quick
+    /// item bar, pouch, great rune, memorized spells and physick. Quantities, upgrade
+    /// bits and equip positions are kept. Returns the number of references rewritten.
+    ///
+    /// Ids are the ones stored in the save, before any `ItemIdMapper`. They aren't checked
+    /// against the regulation, and goods stay in the list they were in, common or key items.
+    ///
+    /// # Errors
+    /// Nothing is changed when one of these is returned:
+    /// - `SaveApiError::ItemNotOwned` if `old_id` isn't held or stored.
+    /// - `SaveApiError::ItemAlreadyOwned` if `new_id` is a talisman or goods already held or
+    ///   stored. Their handle is made of their id, so the two inventory entries would end
+    ///   up with the same handle. Weapons, armor and ashes of war keep a handle of their
+    ///   own and can be replaced by one already owned.
+    /// - `SaveApiError::InvalidItemId` if `new_id` overlaps the category bits of a handle.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::item::ItemCategory;
+    /// use er_save_lib::{MockCharacter, MockSave, SaveApi};
+    /// let mock = MockSave::new(vec![MockCharacter {
+    ///     level: 1,
+    ///     items: vec![(ItemCategory::Goods, 1000), (ItemCategory::Goods, 1050)],
+    ///     ..Default::default()
+    /// }]);
+    /// let mut save_api = SaveApi::from_mock(&mock).unwrap();
+    /// save_api.set_quick_item(0, 0, Some(1000)).unwrap();
+    /// save_api
+    ///     .replace_item_everywhere(0, ItemCategory::Goods, 1000, 1001)
+    ///     .unwrap();
+    /// assert_eq!(save_api.quick_items(0).unwrap().quick_items[0], Some(1001));
+    /// assert!(save_api
+    ///     .replace_item_everywhere(0, ItemCategory::Goods, 1001, 1050)
+    ///     .is_err());
+    /// ```
+    pub fn replace_item_everywhere(
+        &mut self,
+        index: usize,
+        category: ItemCategory,
+        old_id: u32,
+        new_id: u32,
+    ) -> Result<usize, SaveApiError> {
+        if new_id & 0xf0000000 != 0 {
+            return Err(SaveApiError::InvalidItemId(category, new_id));
+        }
+        let owned = item::owned_items(self.user_data_x(index)?);
+        if !owned.contains(&(category, old_id)) {
+            return Err(SaveApiError::ItemNotOwned(category, old_id));
+        }
+        if old_id == new_id {
+            return Ok(0);
+        }
+        let id_handle = matches!(category, ItemCategory::Talisman | ItemCategory::Goods);
+        if id_handle && owned.contains(&(category, new_id)) {
+            return Err(SaveApiError::ItemAlreadyOwned(category, new_id));
+        }
+        Ok(replace(
+            self.user_data_x_mut(index)?,
+            category,
+            old_id,
+            new_id,
+        ))
+    }
+}
+
+// Rewrites every reference to `old_id` of the category, keeping the type bits of each
+// field. Weapons, armor and ashes of war keep their gaitem handle, so only their ids
+// change; talismans and goods carry their id in the handle, which changes with it.
+fn replace(user_data_x: &mut UserDataX, category: ItemCategory, old_id: u32, new_id: u32) -> usize {
+    let mut replaced = 0;
+    let mut rewrite = |value: &mut u32| {
+        if !is_empty(*value) && *value & 0x0fffffff == old_id {
+            *value = (*value & 0xf0000000) | new_id;
+            replaced += 1;
+        }
+    };
+
+    if matches!(category, ItemCategory::Talisman | ItemCategory::Goods) {
+        for inventory in [
+            &mut user_data_x.inventory_held,
+            &mut user_data_x.inventory_storage_box,
+        ] {
+            for item in inventory
+                .common_items
+                .iter_mut()
+                .chain(&mut inventory.key_items)
+                .filter(|item| item.quantity > 0)
+                .filter(|item| ItemCategory::from_handle(item.gaitem_handle) == Some(category))
+            {
+                rewrite(&mut item.gaitem_handle);
+            }
+        }
+    }
+    for gaitem in user_data_x
+        .gaitem_map
+        .iter_mut()
+        .filter(|gaitem| ItemCategory::from_handle(gaitem.gaitem_handle) == Some(category))
+    {
+        rewrite(&mut gaitem.item_id);
+    }
+
+    for id in category_slots_mut!(user_data_x.equipped_items_item_id, category) {
+        rewrite(id);
+    }
+    for id in category_slots_mut!(user_data_x.equipped_armaments_and_items, category) {
+        rewrite(id);
+    }
+    if category == ItemCategory::Talisman {
+        for handle in category_slots_mut!(user_data_x.equipped_items_gaitem_handle, category) {
+            rewrite(handle);
+        }
+    }
+
+    if category == ItemCategory::Goods {
+        let equipped = &mut user_data_x.equipped_items;
+        for item in equipped
+            .quick_items
+            .iter_mut()
+            .chain(&mut equipped.pouch_items)
+            .chain([&mut equipped.great_rune])
+        {
+            rewrite(&mut item.gaitem_handle);
+        }
+        let ids = &mut user_data_x.equipped_armaments_and_items;
+        for id in [
+            &mut ids.quickitem1,
+            &mut ids.quickitem2,
+            &mut ids.quickitem3,
+            &mut ids.quickitem4,
+            &mut ids.quickitem5,
+            &mut ids.quickitem6,
+            &mut ids.quickitem7,
+            &mut ids.quickitem8,
+            &mut ids.quickitem9,
+            &mut ids.quickitem10,
+            &mut ids.pouch1,
+            &mut ids.pouch2,
+            &mut ids.pouch3,
+            &mut ids.pouch4,
+            &mut ids.pouch5,
+            &mut ids.pouch6,
+            &mut ids.great_rune,
+        ] {
+            rewrite(id);
+        }
+        for spell in &mut user_data_x.equipped_spells.spellslot {
+            rewrite(&mut spell.spell_id);
+        }
+        let physick = &mut user_data_x.equipped_physics;
+        rewrite(&mut physick.slot1);
+        rewrite(&mut physick.slot2);
+    }
+    replaced
+}
@@ -0,0 +1,182 @@
+use std::fmt::Write;
+
+use super::{bosses::BOSSES, character::Character};
+use crate::{SaveApi, SaveApiError};
+
+/// Output format of `SaveApi::character_report`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Format {
+    Markdown,
+    Html,
+}
+
+// A titled list of label/value rows, rendered as a table
+struct Section {
+    title: &'static str,
+    rows: Vec<(String, String)>,
+}
+
+impl SaveApi {
+    /// Renders a summary of the character at the specified index.
+    ///
+    /// The summary covers stats, equipped item ids, boss progression and the bosses
+    /// defeated so far, as a Markdown document or an HTML fragment ready to be embedded.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use er_save_lib::{report::Format, SaveApi};
+    /// let save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+    /// let markdown = save_api.character_report(0, Format::Markdown).unwrap();
+    /// let html = save_api.character_report(0, Format::Html).unwrap();
+    /// ```
+    pub fn character_report(&self, index: usize, format: Format) -> Result<String, SaveApiError> {
+        let character = self.character(index)?;
+        let sections = sections(&character)?;
+        Ok(match format {
+            Format::Markdown => markdown(character.character_name(), &sections),
+            Format::Html => html(character.character_name(), &sections),
+        })
+    }
+}
+
+fn sections(character: &Character) -> Result<Vec<Section>, SaveApiError> {
+    let seconds = character.seconds_played();
+    let general = vec![
+        ("Level".to_string(), character.level().to_string()),
+        ("Runes".to_string(), character.runes().to_string()),
+        (
+            "Rune memory".to_string(),
+            character.runes_memory().to_string(),
+        ),
+        (
+            "Play time".to_string(),
+            format!(
+                "{}:{:02}:{:02}",
+                seconds / 3600,
+                seconds / 60 % 60,
+                seconds % 60
+            ),
+        ),
+        ("Max HP".to_string(), character.max_hp().to_string()),
+        ("Max FP".to_string(), character.max_fp().to_string()),
+        ("Max stamina".to_string(), character.max_sp().to_string()),
+    ];
+
+    let stats = character
+        .stats()
+        .iter()
+        .map(|(stat, value)| (stat.to_string(), value.to_string()))
+        .collect();
+
+    let equipment = character
+        .equipped_items()
+        .iter()
+        .map(|(slot, _, id)| {
+            let id = if *id == u32::MAX {
+                "-".to_string()
+            } else {
+                id.to_string()
+            };
+            (slot.to_string(), id)
+        })
+        .collect();
+
+    let mut defeated = Vec::new();
+    for (name, event_id) in BOSSES {
+        if character.event_flag(event_id)? {
+            defeated.push((name.to_string(), "Defeated".to_string()));
+        }
+    }
+    let progression = vec![
+        (
+            "Bosses defeated".to_string(),
+            format!(
+                "{}/{} ({}%)",
+                defeated.len(),
+                BOSSES.len(),
+                defeated.len() * 100 / BOSSES.len()
+            ),
+        ),
+        (
+            "Regions unlocked".to_string(),
+            character.regions_count().to_string(),
+        ),
+    ];
+
+    Ok(vec![
+        Section {
+            title: "General",
+            rows: general,
+        },
+        Section {
+            title: "Stats",
+            rows: stats,
+        },
+        Section {
+            title: "Equipment",
+            rows: equipment,
+        },
+        Section {
+            title: "Progression",
+            rows: progression,
+        },
+        Section {
+            title: "Bosses",
+            rows: defeated,
+        },
+    ])
+}
+
+fn markdown(name: &str, sections: &[Section]) -> String {
+    let mut out = format!("# {name}\n");
+    for section in sections {
+        let _ = write!(out, "\n## {}\n\n", section.title);
+        if section.rows.is_empty() {
+            out.push_str("None\n");
+            continue;
+        }
+        out.push_str("| | |\n| --- | --- |\n");
+        for (label, value) in &section.rows {
+            let _ = writeln!(out, "| {} | {} |", label, value);
+        }
+    }
+    out
+}
+
+fn html(name: &str, sections: &[Section]) -> String {
+    let mut out = format!("<h1>{}</h1>\n", escape_html(name));
+    for section in sections {
+        let _ = writeln!(out, "<h2>{}</h2>", section.title);
+        if section.rows.is_empty() {
+            out.push_str("<p>None</p>\n");
+            continue;
+        }
+        out.push_str("<table>\n");
+        for (label, value) in &section.rows {
+            let _ = writeln!(
+                out,
+                "<tr><th>{}</th><td>{}</td></tr>",
+                escape_html(label),
+                escape_html(value)
+            );
+        }
+        out.push_str("</table>\n");
+    }
+    out
+}
+
+// Character names are player input and end up in third party pages
+fn escape_html(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
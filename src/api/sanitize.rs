@@ -0,0 +1,127 @@
+use super::{audit::Suspicion, illegal_items::IllegalItem, inventory::InventoryIssue};
+use crate::{SaveApi, SaveApiError};
+
+/// What `SaveApi::sanitize_for_online` is allowed to change. Everything it isn't allowed
+/// to fix is still reported.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Policy {
+    /// Remove the items returned by `SaveApi::find_illegal_items` that aren't in use.
+    pub remove_illegal_items: bool,
+    /// Raise rune memory to the runes needed to reach the character's level.
+    pub raise_runes_memory: bool,
+    /// Set the level to the one the attributes add up to. Off by default, since it changes
+    /// the level used for matchmaking.
+    pub match_level_to_stats: bool,
+}
+
+impl Default for Policy {
+    /// Removes illegal items and raises rune memory, neither of which takes anything the
+    /// game could have awarded.
+    fn default() -> Self {
+        Self {
+            remove_illegal_items: true,
+            raise_runes_memory: true,
+            match_level_to_stats: false,
+        }
+    }
+}
+
+/// A change made by `SaveApi::sanitize_for_online`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Fix {
+    RemovedItem(IllegalItem),
+    RaisedRunesMemory { from: u32, to: u32 },
+    SetLevel { from: u32, to: u32 },
+}
+
+/// Outcome of `SaveApi::sanitize_for_online`: the changes made and what is still wrong
+/// with the character afterwards.
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct SanitizeReport {
+    pub fixes: Vec<Fix>,
+    pub suspicions: Vec<Suspicion>,
+    /// Illegal items left in place, either in use or kept by the policy.
+    pub illegal_items: Vec<IllegalItem>,
+    pub inventory_issues: Vec<InventoryIssue>,
+}
+
+impl SanitizeReport {
+    /// Returns whether none of the checks found anything left to fix.
+    pub fn is_clean(&self) -> bool {
+        self.suspicions.is_empty()
+            && self.illegal_items.is_empty()
+            && self.inventory_issues.is_empty()
+    }
+}
+
+impl SaveApi {
+    /// Fixes what the policy allows among the states known to get a character flagged
+    /// online, and reports the rest. Runs `SaveApi::find_illegal_items`, `SaveApi::audit`
+    /// and `SaveApi::check_inventory` on the character at the specified index.
+    ///
+    /// A clean report doesn't guarantee a character won't be flagged, only that none of
+    /// the checks the crate knows about failed.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use er_save_lib::sanitize::{Fix, Policy};
+    /// use er_save_lib::SaveApi;
+    /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+    /// save_api.set_runes_memory(0, 0).unwrap();
+    /// let report = save_api.sanitize_for_online(0, Policy::default()).unwrap();
+    /// assert!(report
+    ///     .fixes
+    ///     .iter()
+    ///     .any(|fix| matches!(fix, Fix::RaisedRunesMemory { from: 0, .. })));
+    /// if !report.is_clean() {
+    ///     println!("Still suspicious: {:?}", report.suspicions);
+    /// }
+    /// ```
+    pub fn sanitize_for_online(
+        &mut self,
+        index: usize,
+        policy: Policy,
+    ) -> Result<SanitizeReport, SaveApiError> {
+        let mut fixes = Vec::new();
+
+        if policy.remove_illegal_items {
+            let removed = self.remove_illegal_items(index)?;
+            fixes.extend(removed.into_iter().map(Fix::RemovedItem));
+        }
+
+        // The runes needed depend on the level, so it is fixed first
+        if policy.match_level_to_stats {
+            for suspicion in self.audit(index)? {
+                if let Suspicion::StatsDontMatchLevel { level, stat_total } = suspicion {
+                    // Every class starts with 80 attribute points at level 1
+                    let to = stat_total.saturating_sub(79).max(1);
+                    self.set_level(index, to)?;
+                    fixes.push(Fix::SetLevel { from: level, to });
+                }
+            }
+        }
+        if policy.raise_runes_memory {
+            for suspicion in self.audit(index)? {
+                if let Suspicion::RuneMemoryBelowLevelCost {
+                    runes_memory,
+                    required,
+                } = suspicion
+                {
+                    let to = u32::try_from(required).unwrap_or(u32::MAX);
+                    self.set_runes_memory(index, to)?;
+                    fixes.push(Fix::RaisedRunesMemory {
+                        from: runes_memory,
+                        to,
+                    });
+                }
+            }
+        }
+
+        Ok(SanitizeReport {
+            fixes,
+            suspicions: self.audit(index)?,
+            illegal_items: self.find_illegal_items(index)?,
+            inventory_issues: self.check_inventory(index)?,
+        })
+    }
+}
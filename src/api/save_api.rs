@@ -4,15 +4,29 @@ pub mod user_data_11_api;
 pub mod user_data_api;
 pub mod user_data_x_api;
 
-use std::{
-    num::ParseIntError,
-    path::Path,
-};
+#[cfg(feature = "std")]
+use std::{fs, path::Path};
+use std::{num::ParseIntError, sync::Arc};
 
-use super::event_flags::EventFlagsApi;
+use super::{
+    ammunition::AmmoSlot,
+    archetype::UnknownValue,
+    arm_style::ArmStyle,
+    ash_of_war::AshOfWarError,
+    character::{Character, CharacterMut, CharacterNameError},
+    event_flags::{EventFlagsApi, FlagChange},
+    item::{IdentityMapper, ItemCategory, ItemIdMapper},
+    save_reader::SaveReader,
+};
+#[cfg(feature = "regulation")]
+use crate::save::game_profile::GameProfile;
 use crate::{
-    regulation::{regulation::RegulationParseError},
-    save::save::SaveParseError,
+    regulation::regulation::RegulationParseError,
+    save::{
+        save::{DirtySlots, ParseOptions, SaveParseError},
+        user_data_10::UserData10,
+        user_data_x::UserDataX,
+    },
     Save,
 };
 
@@ -22,14 +36,182 @@ pub enum SaveApiError {
     DekuError(#[from] deku::DekuError),
     #[error(transparent)]
     IoError(#[from] std::io::Error),
+    #[error("{} is in use by another process!", .0.display())]
+    FileInUse(std::path::PathBuf),
     #[error(transparent)]
     SaveParserError(#[from] SaveParseError),
     #[error(transparent)]
     ParseIntError(#[from] ParseIntError),
     #[error("EventId {} not found!", .0)]
     EventIdNotFound(u32),
+    #[error("Character index {} is out of range!", .0)]
+    CharacterIndexOutOfRange(usize),
+    #[error("Character slot {} is corrupt!", .0)]
+    CorruptSlot(usize),
+    #[error("Weapon slot {} is out of range!", .0)]
+    WeaponSlotOutOfRange(usize),
+    #[error("Quick item slot {} is out of range!", .0)]
+    QuickItemSlotOutOfRange(usize),
+    #[error("Pouch slot {} is out of range!", .0)]
+    PouchSlotOutOfRange(usize),
+    #[error("SpEffect slot {} is out of range!", .0)]
+    SpEffectSlotOutOfRange(usize),
+    #[error("Item {} can't be equipped in {:?}!", .1, .0)]
+    WrongAmmunition(AmmoSlot, u32),
+    #[error("{:?} doesn't match the equipped weapons!", .0)]
+    ArmStyleMismatch(ArmStyle),
+    #[error("Ash of war {} can't be applied: {}!", .0, .1)]
+    IllegalAshOfWar(u32, AshOfWarError),
+    #[error("Item {} is not a cookbook!", .0)]
+    NotACookbook(u32),
+    #[error("Item {} is not a restored great rune!", .0)]
+    NotAGreatRune(u32),
+    #[error("Character {} has no great rune equipped!", .0)]
+    NoGreatRuneEquipped(usize),
+    #[error("No free slot left for key item {}!", .0)]
+    KeyItemsFull(u32),
+    #[error("No free inventory slot left for item {}!", .0)]
+    InventoryFull(u32),
+    #[error("Character slot {} is {} bytes short for the edit!", .0, .1)]
+    SlotFull(usize, usize),
+    #[error("Profile data is {} bytes short for the edit!", .0)]
+    ProfileDataFull(usize),
+    #[error("{:?} {} can't be added to an inventory, only talismans and goods can!", .0, .1)]
+    UnsupportedItem(ItemCategory, u32),
+    #[error("{:?} {} isn't owned in the requested quantity!", .0, .1)]
+    ItemNotOwned(ItemCategory, u32),
+    #[error("{:?} {} is already owned!", .0, .1)]
+    ItemAlreadyOwned(ItemCategory, u32),
+    #[error("{:?} id {:#x} doesn't fit in an item handle!", .0, .1)]
+    InvalidItemId(ItemCategory, u32),
+    #[error("Item {} is equipped and can't be moved!", .0)]
+    ItemEquipped(u32),
+    #[error("Item {} can't be stacked any higher!", .0)]
+    StackFull(u32),
+    #[error("Manifest line {} is invalid: {}", .0, .1)]
+    InvalidManifest(usize, String),
+    #[error("{:02}:{:02}:{:02} is not a valid time of day!", .0, .1, .2)]
+    InvalidTimeOfDay(u32, u32, u32),
+    #[error("Saves can't be converted from {:?} to another platform!", .0)]
+    PlatformConversionUnsupported(SaveType),
+    #[error("Param {} not found!", .0)]
+    ParamNotFound(String),
+    #[error("Param definition is invalid: {}", .0)]
+    InvalidParamDef(String),
     #[error(transparent)]
     RegulationParseError(#[from] RegulationParseError),
+    #[error(transparent)]
+    InvalidCharacterName(#[from] CharacterNameError),
+    #[error(transparent)]
+    UnknownValue(#[from] UnknownValue),
+    #[cfg(feature = "notify")]
+    #[error(transparent)]
+    WatchError(#[from] notify::Error),
+    #[cfg(feature = "meta")]
+    #[error(transparent)]
+    MetaError(#[from] serde_json::Error),
+}
+
+/// Broad kind of a `SaveApiError`, returned by `SaveApiError::category` so tools can show
+/// a targeted message without matching every variant.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ErrorCategory {
+    /// Reading, writing or watching a file failed.
+    Io,
+    /// A section of the save can't be read, `section` names it.
+    Corrupt { section: &'static str },
+    /// The request is valid but the crate can't carry it out, `what` names the missing
+    /// support.
+    Unsupported { what: &'static str },
+    /// An argument was rejected, `field` names it.
+    Validation { field: &'static str },
+}
+
+impl SaveApiError {
+    /// Returns the kind of the error and which section, feature or argument it is about.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{ErrorCategory, SaveApi, SaveType};
+    /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// let error = save_api.level(42).unwrap_err();
+    /// assert_eq!(error.category(), ErrorCategory::Validation { field: "index" });
+    /// assert!(error.is_recoverable());
+    /// ```
+    pub fn category(&self) -> ErrorCategory {
+        use ErrorCategory::*;
+        match self {
+            SaveApiError::IoError(_) | SaveApiError::FileInUse(_) => Io,
+            #[cfg(feature = "notify")]
+            SaveApiError::WatchError(_) => Io,
+            SaveApiError::SaveParserError(error) => match error {
+                SaveParseError::IoError(_) => Io,
+                SaveParseError::SlotIndexOutOfRange(_) => Validation { field: "index" },
+                SaveParseError::EntryNotFound(_) => Validation { field: "entry" },
+                SaveParseError::LimitExceeded { .. } => Corrupt { section: "save" },
+                SaveParseError::DekuError(_) | SaveParseError::SlotSizeMismatch { .. } => Corrupt {
+                    section: "character slot",
+                },
+            },
+            SaveApiError::DekuError(_) => Corrupt { section: "save" },
+            SaveApiError::CorruptSlot(_) => Corrupt {
+                section: "character slot",
+            },
+            SaveApiError::RegulationParseError(_) => Corrupt {
+                section: "regulation",
+            },
+            #[cfg(feature = "meta")]
+            SaveApiError::MetaError(_) => Corrupt {
+                section: "meta sidecar",
+            },
+            SaveApiError::UnsupportedItem(..) => Unsupported {
+                what: "item category",
+            },
+            SaveApiError::ParamNotFound(_) => Unsupported { what: "param" },
+            SaveApiError::PlatformConversionUnsupported(_) => Unsupported {
+                what: "platform conversion",
+            },
+            SaveApiError::EventIdNotFound(_) => Validation { field: "event_id" },
+            SaveApiError::CharacterIndexOutOfRange(_) => Validation { field: "index" },
+            SaveApiError::WeaponSlotOutOfRange(_)
+            | SaveApiError::QuickItemSlotOutOfRange(_)
+            | SaveApiError::PouchSlotOutOfRange(_)
+            | SaveApiError::SpEffectSlotOutOfRange(_) => Validation { field: "slot" },
+            SaveApiError::WrongAmmunition(..)
+            | SaveApiError::NotACookbook(_)
+            | SaveApiError::NotAGreatRune(_)
+            | SaveApiError::ItemNotOwned(..)
+            | SaveApiError::ItemAlreadyOwned(..)
+            | SaveApiError::InvalidItemId(..)
+            | SaveApiError::ItemEquipped(_) => Validation { field: "item" },
+            SaveApiError::KeyItemsFull(_)
+            | SaveApiError::InventoryFull(_)
+            | SaveApiError::StackFull(_) => Validation { field: "quantity" },
+            SaveApiError::SlotFull(..) => Validation { field: "regions" },
+            SaveApiError::ProfileDataFull(_) => Validation { field: "settings" },
+            SaveApiError::ArmStyleMismatch(_) => Validation { field: "arm_style" },
+            SaveApiError::IllegalAshOfWar(..) => Validation {
+                field: "ash_of_war",
+            },
+            SaveApiError::NoGreatRuneEquipped(_) => Validation {
+                field: "great_rune",
+            },
+            SaveApiError::InvalidManifest(..) => Validation { field: "manifest" },
+            SaveApiError::InvalidTimeOfDay(..) => Validation { field: "time" },
+            SaveApiError::InvalidParamDef(_) => Validation { field: "paramdef" },
+            SaveApiError::InvalidCharacterName(_) => Validation { field: "name" },
+            SaveApiError::ParseIntError(_) | SaveApiError::UnknownValue(_) => {
+                Validation { field: "value" }
+            }
+        }
+    }
+
+    /// Returns whether the save is still usable after the error: every error but a
+    /// corrupt section comes from the input or the environment and leaves the save as it
+    /// was, so the operation can be retried with other input.
+    pub fn is_recoverable(&self) -> bool {
+        !matches!(self.category(), ErrorCategory::Corrupt { .. })
+    }
 }
 
 #[derive(PartialEq, Debug)]
@@ -38,49 +220,529 @@ pub enum SaveType {
     Playstation,
 }
 
+/// State of a character slot, see `SaveApi::slot_state`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum SlotState {
+    /// The slot holds a character.
+    Active,
+    /// The load screen doesn't list the slot, it was never used or its character was
+    /// deleted.
+    Empty,
+    /// The slot can't be read, or is listed on the load screen without holding a
+    /// character. The game crashing during character creation leaves slots like this.
+    Corrupt,
+}
+
+/// Integrity of a character slot as it was read, see `SaveApi::slot_integrity`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum SlotIntegrity {
+    /// The checksum matches and the slot was parsed.
+    Intact,
+    /// The slot was parsed but its stored checksum doesn't match its bytes, which the game
+    /// rejects. Writing the slot after an edit, or with `SaveWriter::recalc_checksums`,
+    /// stores a matching one.
+    ChecksumMismatch,
+    /// The slot can't be read, see `SlotState::Corrupt`.
+    Unparseable,
+    /// The slot was parsed and has no checksum to verify: PlayStation slots have none, and
+    /// saves that weren't read from bytes are written with matching ones.
+    Unchecked,
+}
+
+
+/// A copy of the parsed save taken with `SaveApi::snapshot`.
+///
+/// Restoring a snapshot with `SaveApi::restore` discards every edit made after it was taken.
+pub struct SaveSnapshot {
+    raw: Arc<Save>,
+    dirty: DirtySlots,
+}
 
 pub struct SaveApi {
-    raw: Save,
+    // Shared with the readers handed out by `SaveApi::reader`. Edits go through
+    // `Arc::make_mut`, which only copies the save while a reader still holds it.
+    raw: Arc<Save>,
+    // Bytes the save was parsed from. When present, sections that weren't edited
+    // are written back from here instead of being re-encoded.
+    original: Option<Arc<[u8]>>,
+    dirty: DirtySlots,
+    // Translates item ids returned by the character getters
+    mapper: Arc<dyn ItemIdMapper>,
 }
 
 impl SaveApi {
     /// Creates a new `SaveApi` instance from a `Save` object.
     ///
     /// # Example
-    /// ```rust
+    /// ```rust,no_run
     /// use er_save_lib::SaveApi;
     /// use er_save_lib::Save;
     /// let save = Save::from_path("./test/PS_Save.txt").unwrap();
     /// let save_api = SaveApi::new(save);
     /// ```
     pub fn new(save: Save) -> Self {
-        SaveApi { raw: save }
+        SaveApi {
+            raw: Arc::new(save),
+            original: None,
+            dirty: DirtySlots::default(),
+            mapper: Arc::new(IdentityMapper),
+        }
     }
 
     /// Creates a `SaveApi` instance from a slice of bytes.
     ///
     /// # Example
-    /// ```rust
+    /// ```rust,no_run
     /// use er_save_lib::SaveApi;
     /// let bytes = std::fs::read("./test/ER0000.sl2").unwrap();
     /// let save_api = SaveApi::from_slice(&bytes).unwrap();
     /// ```
     pub fn from_slice(bytes: &[u8]) -> Result<Self, SaveApiError> {
-        let raw = Save::from_slice(bytes)?;
-        Ok(SaveApi { raw })
+        Self::from_slice_with(bytes, &ParseOptions::default())
+    }
+
+    /// Creates a `SaveApi` instance from a slice of bytes, enforcing the limits in `options`.
+    ///
+    /// Use this instead of `from_slice` when loading files from an untrusted source.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use er_save_lib::{ParseOptions, SaveApi};
+    /// let bytes = std::fs::read("./test/ER0000.sl2").unwrap();
+    /// let save_api = SaveApi::from_slice_with(&bytes, &ParseOptions::default()).unwrap();
+    /// ```
+    pub fn from_slice_with(bytes: &[u8], options: &ParseOptions) -> Result<Self, SaveApiError> {
+        let raw = Save::from_slice_with(bytes, options)?;
+        Ok(SaveApi {
+            raw: Arc::new(raw),
+            original: Some(Arc::from(bytes)),
+            dirty: DirtySlots::default(),
+            mapper: Arc::new(IdentityMapper),
+        })
     }
 
     /// Creates a `SaveApi` instance from a file path.
     ///
     /// # Example
-    /// ```rust
+    /// ```rust,no_run
     /// use er_save_lib::SaveApi;
     /// let save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
     /// ```
+    #[cfg(feature = "std")]
     pub fn from_path(path: impl AsRef<Path>) -> Result<Self, SaveApiError> {
-        let raw = Save::from_path(path)?;
-        Ok(SaveApi { raw })
+        let bytes = fs::read(path)?;
+        Self::from_slice(&bytes)
+    }
+
+    /// Creates a save without any character, entirely in memory.
+    ///
+    /// Every slot is empty, as in a profile where no character was created yet. The
+    /// regulation holds no params, so `get_param` returns an error for every param. Meant
+    /// for tests that shouldn't depend on a save file.
+    ///
+    /// PlayStation saves are recognized by their regulation, so the bytes of an empty one
+    /// are read back as a PC save by `SaveApi::from_slice`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// assert_eq!(save_api.character_count(), 10);
+    /// assert!(save_api.active_characters().iter().all(|active| !active));
+    ///
+    /// let bytes = save_api.to_vec().unwrap();
+    /// let save_api = SaveApi::from_slice(&bytes).unwrap();
+    /// assert_eq!(save_api.platform(), SaveType::PC);
+    /// assert_eq!(save_api.to_vec().unwrap(), bytes);
+    ///
+    /// let save_api = SaveApi::new_empty(SaveType::Playstation).unwrap();
+    /// assert_eq!(save_api.platform(), SaveType::Playstation);
+    /// ```
+    #[cfg(feature = "regulation")]
+    pub fn new_empty(save_type: SaveType) -> Result<Self, SaveApiError> {
+        let is_ps = save_type == SaveType::Playstation;
+        Ok(Self::new(Save::empty(is_ps, GameProfile::ELDEN_RING)?))
+    }
+
+    /// Returns the number of character slots in the save file.
+    ///
+    /// Valid character indices are `0..character_count()`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// assert_eq!(save_api.character_count(), 10);
+    /// assert!(save_api.hp(save_api.character_count()).is_err());
+    /// ```
+    pub fn character_count(&self) -> usize {
+        self.raw.user_data_x.len()
+    }
+
+    pub(crate) fn user_data_x(&self, index: usize) -> Result<&UserDataX, SaveApiError> {
+        let user_data_x = self
+            .raw
+            .user_data_x
+            .get(index)
+            .ok_or(SaveApiError::CharacterIndexOutOfRange(index))?;
+        check_parsed(user_data_x, index)?;
+        Ok(user_data_x)
+    }
+
+    // Returns the slot of a character for editing and marks it as edited
+    pub(crate) fn user_data_x_mut(&mut self, index: usize) -> Result<&mut UserDataX, SaveApiError> {
+        self.user_data_x(index)?;
+        self.mark_character_dirty(index)?;
+        Ok(&mut Arc::make_mut(&mut self.raw).user_data_x[index])
+    }
+
+    pub(crate) fn user_data_10(&self) -> &UserData10 {
+        &self.raw.user_data_10
+    }
+
+    // Returns the profile summary section for editing and marks it as edited
+    pub(crate) fn user_data_10_mut(&mut self) -> &mut UserData10 {
+        self.dirty.mark_user_data_10();
+        &mut Arc::make_mut(&mut self.raw).user_data_10
+    }
+
+    pub(crate) fn mapper(&self) -> &dyn ItemIdMapper {
+        self.mapper.as_ref()
+    }
+
+    /// Returns a read-only view of the character at the specified index.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// let chr = save_api.character(0).unwrap();
+    /// let vigor = chr.vigor();
+    /// ```
+    pub fn character(&self, index: usize) -> Result<Character<'_>, SaveApiError> {
+        Character::from_save(&self.raw, index, self.mapper.as_ref())
+    }
+
+    /// Returns a mutable view of the character at the specified index.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// let mut chr = save_api.character_mut(0).unwrap();
+    /// chr.set_level(100).unwrap();
+    /// ```
+    pub fn character_mut(&mut self, index: usize) -> Result<CharacterMut<'_>, SaveApiError> {
+        self.user_data_x(index)?;
+        self.mark_character_dirty(index)?;
+        let raw = Arc::make_mut(&mut self.raw);
+        let user_data_x = raw
+            .user_data_x
+            .get_mut(index)
+            .ok_or(SaveApiError::CharacterIndexOutOfRange(index))?;
+        let profile = raw
+            .user_data_10
+            .profile_summary
+            .profiles
+            .get_mut(index)
+            .ok_or(SaveApiError::CharacterIndexOutOfRange(index))?;
+        Ok(CharacterMut::new(
+            index,
+            user_data_x,
+            profile,
+            self.mapper.as_ref(),
+        ))
+    }
+
+    /// Returns a read-only view of every active character with its slot index, skipping
+    /// empty and corrupt slots, see `SaveApi::slot_state`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{MockCharacter, MockSave, SaveApi};
+    /// let characters = vec![MockCharacter {
+    ///     level: 12,
+    ///     ..Default::default()
+    /// }];
+    /// let save_api = SaveApi::from_mock(&MockSave::new(characters)).unwrap();
+    /// for (index, chr) in save_api.characters() {
+    ///     println!("{index}: {} is level {}", chr.character_name(), chr.level());
+    /// }
+    /// assert_eq!(save_api.characters().count(), 1);
+    /// ```
+    pub fn characters(&self) -> impl Iterator<Item = (usize, Character<'_>)> + '_ {
+        (0..self.character_count())
+            .filter(|index| matches!(self.slot_state(*index), Ok(SlotState::Active)))
+            .filter_map(|index| Some((index, self.character(index).ok()?)))
     }
+
+    /// Returns a mutable view of every active character with its slot index, skipping
+    /// empty and corrupt slots. Every active slot is written back with the save, whether
+    /// its character was edited or not.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{MockCharacter, MockSave, SaveApi};
+    /// let chr = MockCharacter {
+    ///     level: 1,
+    ///     runes: 5000,
+    ///     ..Default::default()
+    /// };
+    /// let characters = vec![chr.clone(), chr];
+    /// let mut save_api = SaveApi::from_mock(&MockSave::new(characters)).unwrap();
+    /// for (_, mut chr) in save_api.characters_mut() {
+    ///     chr.set_runes(0).unwrap();
+    /// }
+    /// assert!(save_api.characters().all(|(_, chr)| chr.runes() == 0));
+    /// ```
+    pub fn characters_mut(&mut self) -> impl Iterator<Item = (usize, CharacterMut<'_>)> + '_ {
+        let active: Vec<usize> = self.characters().map(|(index, _)| index).collect();
+        for index in &active {
+            self.dirty.mark_user_data_x(*index);
+        }
+        if !active.is_empty() {
+            self.dirty.mark_user_data_10();
+        }
+
+        let mapper = self.mapper.as_ref();
+        let raw = Arc::make_mut(&mut self.raw);
+        let profiles = &mut raw.user_data_10.profile_summary.profiles;
+        raw.user_data_x
+            .iter_mut()
+            .zip(profiles.iter_mut())
+            .enumerate()
+            .filter(move |(index, _)| active.contains(index))
+            .map(move |(index, (user_data_x, profile))| {
+                (
+                    index,
+                    CharacterMut::new(index, user_data_x, profile, mapper),
+                )
+            })
+    }
+
+    /// Returns a read-only view sharing the parsed save, for concurrent queries.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// let reader = save_api.reader();
+    /// let vigor = reader.character(0).unwrap().vigor();
+    /// save_api.set_vigor(0, vigor + 1).unwrap();
+    /// assert_eq!(reader.character(0).unwrap().vigor(), vigor);
+    /// ```
+    pub fn reader(&self) -> SaveReader {
+        SaveReader::new(Arc::clone(&self.raw), Arc::clone(&self.mapper))
+    }
+
+    /// Returns the parsed save. Only the items listed under the stability section of `Save`
+    /// are covered by semver.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// let slot = save_api.raw().decrypt_slot_bytes(0).unwrap();
+    /// ```
+    pub fn raw(&self) -> &Save {
+        &self.raw
+    }
+
+    /// Returns the parsed save for editing. Edits made through it can't be tracked, so
+    /// every section but the header is re-encoded by the next write.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// let slot = save_api.raw().decrypt_slot_bytes(1).unwrap();
+    /// save_api.raw_mut().encrypt_slot_bytes(0, &slot).unwrap();
+    /// ```
+    pub fn raw_mut(&mut self) -> &mut Save {
+        for index in 0..self.character_count() {
+            self.dirty.mark_user_data_x(index);
+        }
+        self.dirty.mark_user_data_10();
+        self.dirty.mark_user_data_11();
+        Arc::make_mut(&mut self.raw)
+    }
+
+    /// Sets the `ItemIdMapper` used to translate the item ids returned by the character
+    /// getters. Readers created afterwards use the new mapper as well.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::item::IdentityMapper;
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// save_api.set_item_id_mapper(IdentityMapper);
+    /// let items = save_api.character(0).unwrap().owned_items();
+    /// ```
+    pub fn set_item_id_mapper(&mut self, mapper: impl ItemIdMapper + 'static) {
+        self.mapper = Arc::new(mapper);
+    }
+
+    /// Swaps the characters at the specified indices, along with their profile summaries
+    /// and active flags, so they switch places on the load screen.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{MockCharacter, MockSave, SaveApi};
+    /// let characters = ["Melina", "Ranni"].map(|name| MockCharacter {
+    ///     name: name.to_string(),
+    ///     ..Default::default()
+    /// });
+    /// let mut save_api = SaveApi::from_mock(&MockSave::new(characters.to_vec())).unwrap();
+    /// save_api.swap_characters(0, 1).unwrap();
+    /// assert_eq!(save_api.character_name(0).unwrap(), "Ranni");
+    /// assert_eq!(save_api.character_name(1).unwrap(), "Melina");
+    /// ```
+    pub fn swap_characters(&mut self, a: usize, b: usize) -> Result<(), SaveApiError> {
+        self.mark_character_dirty(a.max(b))?;
+        self.mark_character_dirty(a.min(b))?;
+        let raw = Arc::make_mut(&mut self.raw);
+        let summary = &mut raw.user_data_10.profile_summary;
+        raw.user_data_x.swap(a, b);
+        summary.profiles.swap(a, b);
+        summary.active_profiles.swap(a, b);
+        Ok(())
+    }
+
+    /// Moves the character at `from` to `to`, shifting the characters in between by one
+    /// slot, along with their profile summaries and active flags.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{MockCharacter, MockSave, SaveApi};
+    /// let characters = ["Melina", "Ranni", "Blaidd"].map(|name| MockCharacter {
+    ///     name: name.to_string(),
+    ///     ..Default::default()
+    /// });
+    /// let mut save_api = SaveApi::from_mock(&MockSave::new(characters.to_vec())).unwrap();
+    /// save_api.move_character(0, 2).unwrap();
+    /// assert_eq!(save_api.character_name(0).unwrap(), "Ranni");
+    /// assert_eq!(save_api.character_name(1).unwrap(), "Blaidd");
+    /// assert_eq!(save_api.character_name(2).unwrap(), "Melina");
+    /// ```
+    pub fn move_character(&mut self, from: usize, to: usize) -> Result<(), SaveApiError> {
+        let range = from.min(to)..=from.max(to);
+        // Highest index first, so an out of range index fails before anything is marked
+        for index in range.clone().rev() {
+            self.mark_character_dirty(index)?;
+        }
+        let raw = Arc::make_mut(&mut self.raw);
+        let summary = &mut raw.user_data_10.profile_summary;
+        if from < to {
+            raw.user_data_x[range.clone()].rotate_left(1);
+            summary.profiles[range.clone()].rotate_left(1);
+            summary.active_profiles[range].rotate_left(1);
+        } else {
+            raw.user_data_x[range.clone()].rotate_right(1);
+            summary.profiles[range.clone()].rotate_right(1);
+            summary.active_profiles[range].rotate_right(1);
+        }
+        Ok(())
+    }
+
+    /// Returns the state of the character slot at the specified index. Getters and setters
+    /// return `SaveApiError::CorruptSlot` for slots that can't be read, which are written
+    /// back untouched until `SaveApi::delete_character` clears them.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType, SlotState};
+    /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// assert_eq!(save_api.slot_state(0).unwrap(), SlotState::Empty);
+    /// ```
+    pub fn slot_state(&self, index: usize) -> Result<SlotState, SaveApiError> {
+        let user_data_x = self
+            .raw
+            .user_data_x
+            .get(index)
+            .ok_or(SaveApiError::CharacterIndexOutOfRange(index))?;
+        let active = self
+            .active_characters()
+            .get(index)
+            .copied()
+            .unwrap_or(false);
+        Ok(match (user_data_x.corrupt.is_some(), active) {
+            (true, _) => SlotState::Corrupt,
+            (false, false) => SlotState::Empty,
+            // Characters start at level 1, a level of 0 is left by a creation that didn't
+            // finish
+            (false, true) if user_data_x.player_game_data.level == 0 => SlotState::Corrupt,
+            (false, true) => SlotState::Active,
+        })
+    }
+
+    /// Returns the integrity of every character slot as it was read, one entry per slot, so
+    /// tools can warn about a corrupt slot while the others are edited as usual.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType, SlotIntegrity};
+    /// let bytes = SaveApi::new_empty(SaveType::PC).unwrap().to_vec().unwrap();
+    /// let save_api = SaveApi::from_slice(&bytes).unwrap();
+    /// let integrity = save_api.slot_integrity();
+    /// assert!(integrity.iter().all(|slot| *slot == SlotIntegrity::Intact));
+    /// ```
+    pub fn slot_integrity(&self) -> Vec<SlotIntegrity> {
+        let checksums = match &self.original {
+            Some(original) => self.raw.slot_checksums(original),
+            None => vec![None; self.character_count()],
+        };
+        self.raw
+            .user_data_x
+            .iter()
+            .zip(checksums)
+            .map(|(user_data_x, checksum)| match (&user_data_x.corrupt, checksum) {
+                (Some(_), _) => SlotIntegrity::Unparseable,
+                (None, Some(true)) => SlotIntegrity::Intact,
+                (None, Some(false)) => SlotIntegrity::ChecksumMismatch,
+                (None, None) => SlotIntegrity::Unchecked,
+            })
+            .collect()
+    }
+
+    /// Deletes the character at the specified index, zeroing its slot and profile summary
+    /// the way they are before a character is created. Also clears corrupt slots.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{MockCharacter, MockSave, SaveApi, SlotState};
+    /// let characters = vec![MockCharacter {
+    ///     level: 1,
+    ///     ..Default::default()
+    /// }];
+    /// let mut save_api = SaveApi::from_mock(&MockSave::new(characters)).unwrap();
+    /// assert_eq!(save_api.slot_state(0).unwrap(), SlotState::Active);
+    /// save_api.delete_character(0).unwrap();
+    /// assert_eq!(save_api.slot_state(0).unwrap(), SlotState::Empty);
+    /// assert_eq!(save_api.level(0).unwrap(), 0);
+    /// ```
+    pub fn delete_character(&mut self, index: usize) -> Result<(), SaveApiError> {
+        self.mark_character_dirty(index)?;
+        Arc::make_mut(&mut self.raw).clear_slot(index)?;
+        Ok(())
+    }
+
+    // Marks the slot and the profile summary of a character as edited
+    fn mark_character_dirty(&mut self, index: usize) -> Result<(), SaveApiError> {
+        if index >= self.character_count() {
+            return Err(SaveApiError::CharacterIndexOutOfRange(index));
+        }
+        self.dirty.mark_user_data_x(index);
+        self.dirty.mark_user_data_10();
+        Ok(())
+    }
+}
+
+// Refuses slots that couldn't be parsed, whose fields don't hold a character
+pub(crate) fn check_parsed(user_data_x: &UserDataX, index: usize) -> Result<(), SaveApiError> {
+    if user_data_x.corrupt.is_some() {
+        return Err(SaveApiError::CorruptSlot(index));
+    }
+    Ok(())
 }
 
 impl SaveApi {
@@ -88,9 +750,9 @@ impl SaveApi {
     ///
     /// # Example
     /// ```rust
-    /// use er_save_lib::SaveApi;
+    /// use er_save_lib::{SaveApi, SaveType};
     /// use er_save_lib::TalkParam::TalkParam;
-    /// let save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+    /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
     /// // IDs can be found in src\res\eventflag_bst.txt as the second element of each tuple
     /// let event_flag = save_api.get_event_flag(6223, 0).unwrap();
     /// ```
@@ -106,8 +768,8 @@ impl SaveApi {
     ///
     /// # Example
     /// ```rust
-    /// use er_save_lib::SaveApi;
-    /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
     /// save_api.set_event_flag(123, 0, true).unwrap();
     /// ```
     pub fn set_event_flag(
@@ -116,6 +778,37 @@ impl SaveApi {
         character_index: usize,
         on: bool,
     ) -> Result<(), SaveApiError> {
-        EventFlagsApi::set_event_flag(&mut self.raw, event_id, character_index, on)
+        EventFlagsApi::set_event_flag(Arc::make_mut(&mut self.raw), event_id, character_index, on)?;
+        self.dirty.mark_user_data_x(character_index);
+        Ok(())
+    }
+
+    /// Returns the event flags whose value differs between the character at the specified
+    /// index and the character at the same index of `other`, in ascending order, with their
+    /// value in `other`.
+    ///
+    /// Comparing two copies of a save taken before and after an event (a cutscene, an NPC
+    /// dialogue, a pickup) tells which flags it sets.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{FlagChange, SaveApi, SaveType};
+    /// let before = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// let mut after = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// after.set_event_flag(10000850, 0, true).unwrap();
+    /// assert_eq!(
+    ///     before.event_flag_diff(0, &after).unwrap(),
+    ///     vec![FlagChange { event_id: 10000850, on: true }]
+    /// );
+    /// ```
+    pub fn event_flag_diff(
+        &self,
+        character_index: usize,
+        other: &SaveApi,
+    ) -> Result<Vec<FlagChange>, SaveApiError> {
+        Ok(EventFlagsApi::diff(
+            &self.user_data_x(character_index)?.event_flags,
+            &other.user_data_x(character_index)?.event_flags,
+        ))
     }
 }
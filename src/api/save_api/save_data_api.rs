@@ -1,34 +1,90 @@
 mod save_data_api {
+    use crate::api::save_api::SaveSnapshot;
     use crate::SaveApi;
     use crate::SaveApiError;
     use crate::SaveType;
+    use std::sync::Arc;
+    #[cfg(feature = "std")]
+    use crate::api::file_lock;
+    #[cfg(feature = "std")]
     use std::path::Path;
 
 
     impl SaveApi {
         /// Converts the save data to a vector of bytes.
         ///
+        /// When the save was read with `from_slice` or `from_path`, only the character slots
+        /// and sections that were edited are re-encoded, the rest is copied from the original bytes.
+        ///
         /// # Example
         /// ```rust
-        /// use er_save_lib::SaveApi;
-        /// let save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+        /// use er_save_lib::{SaveApi, SaveType};
+        /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
         /// let bytes = save_api.to_vec().unwrap();
         /// ```
         pub fn to_vec(&self) -> Result<Vec<u8>, SaveApiError> {
-            let bytes = self.raw.write_to_vec()?;
+            let bytes = match &self.original {
+                Some(original) => self.raw.write_to_vec_reusing(original, &self.dirty)?,
+                None => self.raw.write_to_vec()?,
+            };
             Ok(bytes)
         }
 
-        /// Writes the save data to the specified path.
+        /// Writes the save data to the specified path. The save is written to a temporary
+        /// file next to it that is then renamed over it, so an interrupted write never leaves
+        /// a partial save. See `SaveWriter` for backups, writing in place and the other write
+        /// options.
+        ///
+        /// # Errors
+        /// `SaveApiError::FileInUse` if the game is writing the file, or with the `lock`
+        /// feature if another process holds a lock on it. The file is left as it was.
         ///
         /// # Example
-        /// ```rust
+        /// ```rust,no_run
         /// use er_save_lib::SaveApi;
         /// let save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
         /// save_api.write_to_path("./test/null.sl2").unwrap();
         /// ```
+        #[cfg(feature = "std")]
         pub fn write_to_path(&self, path: impl AsRef<Path>) -> Result<(), SaveApiError> {
-            Ok(self.raw.write_to_path(path)?)
+            file_lock::write(path.as_ref(), &self.to_vec()?, true)
+        }
+
+        /// Takes a snapshot of the current state of the save.
+        ///
+        /// The snapshot shares the parsed save instead of serializing it. The next edit copies
+        /// the save once, so it is cheap enough to call before every edit when implementing undo.
+        ///
+        /// # Example
+        /// ```rust
+        /// use er_save_lib::{SaveApi, SaveType};
+        /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+        /// let snapshot = save_api.snapshot();
+        /// save_api.set_vigor(0, 99).unwrap();
+        /// save_api.restore(snapshot);
+        /// ```
+        pub fn snapshot(&self) -> SaveSnapshot {
+            SaveSnapshot {
+                raw: Arc::clone(&self.raw),
+                dirty: self.dirty.clone(),
+            }
+        }
+
+        /// Restores the save to the state it was in when the snapshot was taken.
+        ///
+        /// # Example
+        /// ```rust
+        /// use er_save_lib::{SaveApi, SaveType};
+        /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+        /// let vigor = save_api.vigor(0).unwrap();
+        /// let snapshot = save_api.snapshot();
+        /// save_api.set_vigor(0, vigor + 1).unwrap();
+        /// save_api.restore(snapshot);
+        /// assert_eq!(save_api.vigor(0).unwrap(), vigor);
+        /// ```
+        pub fn restore(&mut self, snapshot: SaveSnapshot) {
+            self.raw = snapshot.raw;
+            self.dirty = snapshot.dirty;
         }
 
         /// Returns the platform type of the save file.
@@ -36,12 +92,12 @@ mod save_data_api {
         /// # Example
         /// ```rust
         /// use er_save_lib::{SaveApi, SaveType};
-        /// let save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+        /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
         /// let platform = save_api.platform();
         /// assert_eq!(platform, SaveType::PC);
         /// ```
         pub fn platform(&self) -> SaveType {
-            if self.raw.header.len() == 0x6c {
+            if self.raw.is_ps_layout() {
                 SaveType::Playstation
             } else {
                 SaveType::PC
@@ -52,8 +108,8 @@ mod save_data_api {
         ///
         /// # Example
         /// ```rust
-        /// use er_save_lib::SaveApi;
-        /// let save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+        /// use er_save_lib::{SaveApi, SaveType};
+        /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
         /// let steam_id = save_api.steam_id();
         /// ```
         pub fn steam_id(&self) -> u64 {
@@ -64,12 +120,13 @@ mod save_data_api {
         ///
         /// # Example
         /// ```rust
-        /// use er_save_lib::SaveApi;
-        /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+        /// use er_save_lib::{SaveApi, SaveType};
+        /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
         /// save_api.set_steam_id(1234567890).unwrap();
         /// ```
         pub fn set_steam_id(&mut self, steam_id: u64) -> Result<(), SaveApiError> {
-            self.raw.user_data_10.steam_id = steam_id;
+            Arc::make_mut(&mut self.raw).user_data_10.steam_id = steam_id;
+            self.dirty.mark_user_data_10();
             Ok(())
         }
     }
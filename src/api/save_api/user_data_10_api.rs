@@ -1,12 +1,15 @@
 pub mod user_data_10_api {
-    use crate::SaveApi;
+    use crate::{
+        api::{character::equipment_slots, profile::ProfileSummary},
+        SaveApi, SaveApiError,
+    };
     impl SaveApi {
         /// Returns the index of the character with the given name.
         ///
         /// # Example
         /// ```rust
-        /// use er_save_lib::SaveApi;
-        /// let save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+        /// use er_save_lib::{SaveApi, SaveType};
+        /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
         /// let index = save_api.character_index_from_name("CharacterName");
         /// ```
         pub fn character_index_from_name(&self, name: &str) -> Option<usize> {
@@ -18,16 +21,54 @@ pub mod user_data_10_api {
                 .position(|profile| profile.character_name.contains(name))
         }
 
-        /// Returns an array indicating which characters are active.
+        /// Returns which characters are active, one entry per character slot.
         ///
         /// # Example
         /// ```rust
-        /// use er_save_lib::SaveApi;
-        /// let save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+        /// use er_save_lib::{SaveApi, SaveType};
+        /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
         /// let active_characters = save_api.active_characters();
+        /// assert_eq!(active_characters.len(), save_api.character_count());
         /// ```
-        pub fn active_characters(&self) -> [bool; 10] {
-            self.raw.user_data_10.profile_summary.active_profiles
+        pub fn active_characters(&self) -> &[bool] {
+            &self.raw.user_data_10.profile_summary.active_profiles
+        }
+
+        /// Returns what the load screen shows for the character at the specified index.
+        ///
+        /// # Example
+        /// ```rust
+        /// use er_save_lib::{SaveApi, SaveType};
+        /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+        /// let profile = save_api.profile(0).unwrap();
+        /// assert_eq!(profile.level, save_api.character(0).unwrap().level());
+        /// ```
+        pub fn profile(&self, index: usize) -> Result<ProfileSummary, SaveApiError> {
+            let summary = &self.raw.user_data_10.profile_summary;
+            let profile = summary
+                .profiles
+                .get(index)
+                .ok_or(SaveApiError::CharacterIndexOutOfRange(index))?;
+            let active = &profile.equipment.active_weapon_slots_and_arm_style;
+            Ok(ProfileSummary {
+                active: summary.active_profiles.get(index).copied().unwrap_or(false),
+                name: profile.character_name.clone(),
+                level: profile.level,
+                seconds_played: profile.seconds_played,
+                runes_memory: profile.runes_memory,
+                map_id: profile.map_id,
+                gender: profile.gender,
+                archetype: profile.archetype,
+                equipment: equipment_slots(
+                    &profile.equipment.equipped_items_item_id,
+                    self.mapper.as_ref(),
+                ),
+                arm_style: active.arm_style,
+                active_weapon_slots: [
+                    active.left_hand_weapon_active_slot,
+                    active.right_hand_weapon_active_slot,
+                ],
+            })
         }
     }
 }
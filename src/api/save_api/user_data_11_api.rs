@@ -1,8 +1,11 @@
 pub mod user_data_api {
-    use crate::SaveApiError;
-    use std::{
-        collections::{BTreeMap, HashMap},
-    };
+    #[cfg(feature = "regulation")]
+    use crate::{api::regulation_api::RegulationApi, SaveApiError};
+    #[cfg(feature = "regulation")]
+    use std::collections::BTreeMap;
+    #[cfg(feature = "params")]
+    use std::collections::HashMap;
+    #[cfg(feature = "params")]
     pub struct Param<P: crate::param_trait::Param> {
         pub rows: HashMap<i32, P::ParamType>,
     }
@@ -10,25 +13,40 @@ pub mod user_data_api {
         /// Returns parameters by type
         ///
         /// # Example
-        /// ```rust
+        /// ```rust,no_run
         /// use er_save_lib::SaveApi;
         /// use er_save_lib::TalkParam::TalkParam;
         /// let save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
         /// let param = save_api.get_param::<TalkParam>().unwrap();
         /// ```
+        #[cfg(feature = "params")]
         pub fn get_param<P: crate::param_trait::Param>(&self) -> Result<Param<P>, SaveApiError> {
             let rows = self.raw.user_data_11.regulation.get_param::<P>()?;
             Ok(Param::<P> { rows })
         }
 
-        /// Returns a map of parameter bytes.
+        /// Returns the regulation embedded in the save.
         ///
         /// # Example
         /// ```rust
+        /// use er_save_lib::{SaveApi, SaveType};
+        /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+        /// let version = save_api.regulation().version();
+        /// ```
+        #[cfg(feature = "regulation")]
+        pub fn regulation(&self) -> RegulationApi<'_> {
+            RegulationApi::new(&self.raw.user_data_11.regulation)
+        }
+
+        /// Returns a map of parameter bytes.
+        ///
+        /// # Example
+        /// ```rust,no_run
         /// use er_save_lib::SaveApi;
         /// let save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
         /// let param_bytes_map = save_api.get_param_bytes_map().unwrap();
         /// ```
+        #[cfg(feature = "regulation")]
         pub fn get_param_bytes_map(&self) -> Result<&BTreeMap<String, Vec<u8>>, SaveApiError> {
             Ok(&self
                 .raw
@@ -1,41 +1,44 @@
 pub mod user_data_api {
-    use crate::SaveApiError;
+    use crate::{NamePolicy, SaveApiError};
     impl crate::SaveApi {
-        /// Sets the archetype of the character at the specified index.
+        /// Sets the archetype of the character at the specified index, as an `Archetype` or
+        /// its id. Ids without an `Archetype` variant are rejected.
         ///
         /// # Example
         /// ```rust
-        /// use er_save_lib::SaveApi;
-        /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+        /// use er_save_lib::{Archetype, SaveApi, SaveType};
+        /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
         /// let (index, archetype_id) = (0, 1);
-        /// save_api.set_archetype(index, archetype_id);
+        /// save_api.set_archetype(index, archetype_id).unwrap();
+        /// save_api.set_archetype(index, Archetype::Astrologer).unwrap();
+        /// assert!(save_api.set_archetype(index, 42).is_err());
         /// ```
-        pub fn set_archetype(&mut self, index: usize, archetype: u8) -> Result<(), SaveApiError> {
-            self.raw.user_data_x[index].player_game_data.archetype = archetype;
-            self.raw.user_data_10.profile_summary.profiles[index].archetype = archetype;
-            Ok(())
+        pub fn set_archetype(
+            &mut self,
+            index: usize,
+            archetype: impl Into<u8>,
+        ) -> Result<(), SaveApiError> {
+            self.character_mut(index)?.set_archetype(archetype)
         }
 
         /// Sets the level of the character at the specified index.
         ///
         /// # Example
         /// ```rust
-        /// use er_save_lib::SaveApi;
-        /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+        /// use er_save_lib::{SaveApi, SaveType};
+        /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
         /// save_api.set_level(0, 1);
         /// ```
         pub fn set_level(&mut self, index: usize, level: u32) -> Result<(), SaveApiError> {
-            self.raw.user_data_x[index].player_game_data.level = level;
-            self.raw.user_data_10.profile_summary.profiles[index].level = level;
-            Ok(())
+            self.character_mut(index)?.set_level(level)
         }
 
         /// Sets the rune memory of the character at the specified index.
         ///
         /// # Example
         /// ```rust
-        /// use er_save_lib::SaveApi;
-        /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+        /// use er_save_lib::{SaveApi, SaveType};
+        /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
         /// save_api.set_runes_memory(0, 1_000);
         /// ```
         pub fn set_runes_memory(
@@ -43,43 +46,109 @@ pub mod user_data_api {
             index: usize,
             runes_memory: u32,
         ) -> Result<(), SaveApiError> {
-            self.raw.user_data_x[index].player_game_data.runes_memory = runes_memory;
-            self.raw.user_data_10.profile_summary.profiles[index].runes_memory = runes_memory;
-            Ok(())
+            self.character_mut(index)?.set_runes_memory(runes_memory)
         }
 
-        /// Sets the name of the character at the specified index.
+        /// Sets the name of the character at the specified index, see
+        /// `CharacterMut::set_character_name` for the names the game accepts.
         ///
         /// # Example
         /// ```rust
-        /// use er_save_lib::SaveApi;
-        /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+        /// use er_save_lib::{CharacterNameError, SaveApi, SaveApiError, SaveType};
+        /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
         /// save_api.set_character_name(0, "NewName").unwrap();
+        /// let err = save_api.set_character_name(0, "A name far too long").unwrap_err();
+        /// assert!(matches!(
+        ///     err,
+        ///     SaveApiError::InvalidCharacterName(CharacterNameError::TooLong { .. })
+        /// ));
         /// ```
         pub fn set_character_name(
             &mut self,
             index: usize,
             new_name: &str,
         ) -> Result<(), SaveApiError> {
-            self.raw.user_data_x[index].player_game_data.character_name = new_name.to_string();
-            self.raw.user_data_10.profile_summary.profiles[index].character_name =
-                new_name.to_string();
-            Ok(())
+            self.character_mut(index)?.set_character_name(new_name)
+        }
+
+        /// Sets the name of the character at the specified index, allowing characters the
+        /// in-game keyboard can't type, see `CharacterMut::set_character_name_unrestricted`.
+        ///
+        /// # Example
+        /// ```rust
+        /// use er_save_lib::{SaveApi, SaveType};
+        /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+        /// assert!(save_api.set_character_name(0, "Tarnished 🗡").is_err());
+        /// save_api.set_character_name_unrestricted(0, "Tarnished 🗡").unwrap();
+        /// ```
+        pub fn set_character_name_unrestricted(
+            &mut self,
+            index: usize,
+            new_name: &str,
+        ) -> Result<(), SaveApiError> {
+            self.character_mut(index)?
+                .set_character_name_unrestricted(new_name)
+        }
+
+        /// Sets the name of the character at the specified index after checking it against
+        /// `policy`, see `NamePolicy`.
+        ///
+        /// # Example
+        /// ```rust
+        /// use er_save_lib::{NamePolicy, SaveApi, SaveType};
+        /// let mut save_api = SaveApi::new_empty(SaveType::Playstation).unwrap();
+        /// let policy = NamePolicy {
+        ///     allow_extended_glyphs: true,
+        ///     ..NamePolicy::for_platform(save_api.platform())
+        /// };
+        /// save_api.set_character_name_with(0, "Tarnished 🗡", &policy).unwrap();
+        /// ```
+        pub fn set_character_name_with(
+            &mut self,
+            index: usize,
+            new_name: &str,
+            policy: &NamePolicy,
+        ) -> Result<(), SaveApiError> {
+            self.character_mut(index)?
+                .set_character_name_with(new_name, policy)
         }
 
-        /// Sets the gender of the character at the specified index.
+        /// Sets the gender of the character at the specified index, as a `Gender` or its
+        /// value. Values without a `Gender` variant are rejected.
         ///
         /// # Example
         /// ```rust
-        /// use er_save_lib::SaveApi;
-        /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+        /// use er_save_lib::{Gender, SaveApi, SaveType};
+        /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
         /// let (index, gender_id) = (0, 1);
-        /// save_api.set_gender(index, gender_id);
+        /// save_api.set_gender(index, gender_id).unwrap();
+        /// save_api.set_gender(index, Gender::Female).unwrap();
+        /// ```
+        pub fn set_gender(
+            &mut self,
+            index: usize,
+            gender: impl Into<u8>,
+        ) -> Result<(), SaveApiError> {
+            self.character_mut(index)?.set_gender(gender)
+        }
+
+        /// Regenerates the load screen preview of the character at the specified index from
+        /// its equipment. Editors changing the equipment call this so the load screen
+        /// doesn't show the old one until the game saves again.
+        ///
+        /// # Example
+        /// ```rust
+        /// use er_save_lib::{SaveApi, SaveType};
+        /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+        /// save_api.refresh_slot_preview(0).unwrap();
+        /// let profile = save_api.profile(0).unwrap();
+        /// assert_eq!(
+        ///     profile.equipment,
+        ///     save_api.character(0).unwrap().equipped_items()
+        /// );
         /// ```
-        pub fn set_gender(&mut self, index: usize, gender: u8) -> Result<(), SaveApiError> {
-            self.raw.user_data_x[index].player_game_data.gender = gender;
-            self.raw.user_data_10.profile_summary.profiles[index].gender = gender;
-            Ok(())
+        pub fn refresh_slot_preview(&mut self, index: usize) -> Result<(), SaveApiError> {
+            self.character_mut(index)?.refresh_slot_preview()
         }
     }
 }
@@ -1,371 +1,478 @@
 pub mod user_data_api {
     pub mod update {
+        #[cfg(feature = "params")]
+        use crate::PlayRegionParam::PlayRegionParam;
         use crate::SaveApi;
         use crate::SaveApiError;
         impl SaveApi {
-            /// Adds a region to the character at the specified index.
+            /// Adds a region to the character at the specified index, either a `Region` or
+            /// a raw region id.
+            ///
+            /// The slot keeps its size, the longer list takes up the zero padding at its end.
+            /// `SaveApiError::SlotFull` is returned when there isn't enough of it.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
-            /// save_api.add_region(0, 1_000);
-            /// ```
-            pub fn add_region(&mut self, index: usize, region_id: u32) -> Result<(), SaveApiError> {
-                let user_data_x = &mut self.raw.user_data_x[index];
-                if user_data_x
-                    .unlocked_regions
-                    .ids
-                    .iter()
-                    .position(|id| *id == region_id)
-                    .is_none()
-                {
-                    user_data_x.unlocked_regions.ids.push(region_id);
-                    user_data_x.unlocked_regions.count += 1;
-                    let rest_len = user_data_x.rest.len();
-                    user_data_x.rest.truncate(rest_len - 4);
-                }
-                Ok(())
+            /// use er_save_lib::{MockCharacter, MockSave, Region, SaveApi};
+            /// let mock = MockSave::new(vec![MockCharacter {
+            ///     level: 1,
+            ///     ..Default::default()
+            /// }]);
+            /// let mut save_api = SaveApi::from_mock(&mock).unwrap();
+            /// save_api.add_region(0, Region::Limgrave).unwrap();
+            /// save_api.add_region(0, 6100001_u32).unwrap();
+            /// assert_eq!(save_api.regions(0).unwrap(), &vec![6100000, 6100001]);
+            /// ```
+            pub fn add_region(
+                &mut self,
+                index: usize,
+                region_id: impl Into<u32>,
+            ) -> Result<(), SaveApiError> {
+                self.character_mut(index)?.add_region(region_id)
             }
 
             /// Removes a region from the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
-            /// save_api.remove_region(0, 1_000);
+            /// use er_save_lib::{MockCharacter, MockSave, Region, SaveApi};
+            /// let mock = MockSave::new(vec![MockCharacter {
+            ///     level: 1,
+            ///     ..Default::default()
+            /// }]);
+            /// let mut save_api = SaveApi::from_mock(&mock).unwrap();
+            /// save_api.set_regions(0, &[Region::Limgrave, Region::Caelid]).unwrap();
+            /// save_api.remove_region(0, Region::Limgrave).unwrap();
+            /// assert_eq!(save_api.regions(0).unwrap(), &vec![6400000]);
+            /// assert_eq!(save_api.regions_count(0).unwrap(), 1);
             /// ```
             pub fn remove_region(
                 &mut self,
                 index: usize,
-                region_id: u32,
+                region_id: impl Into<u32>,
             ) -> Result<(), SaveApiError> {
-                let user_data_x = &mut self.raw.user_data_x[index];
-                if let Some(region_index) = user_data_x
-                    .unlocked_regions
-                    .ids
-                    .iter()
-                    .position(|id| *id == region_id)
-                {
-                    user_data_x.unlocked_regions.ids.remove(region_index);
-                    user_data_x.unlocked_regions.count -= 1;
-                    user_data_x.rest.extend(vec![0; 4]);
-                }
-                Ok(())
+                self.character_mut(index)?.remove_region(region_id)
+            }
+
+            /// Replaces the unlocked regions of the character at the specified index in a
+            /// single operation. Duplicates are ignored.
+            ///
+            /// Like `add_region`, a longer list is refused with `SaveApiError::SlotFull` when
+            /// the slot has no room left for it.
+            ///
+            /// # Example
+            /// ```rust
+            /// use er_save_lib::{MockCharacter, MockSave, Region, SaveApi};
+            /// let mock = MockSave::new(vec![MockCharacter {
+            ///     level: 1,
+            ///     ..Default::default()
+            /// }]);
+            /// let mut save_api = SaveApi::from_mock(&mock).unwrap();
+            /// save_api.set_regions(0, &[Region::Limgrave, Region::Caelid]).unwrap();
+            /// assert_eq!(save_api.regions(0).unwrap(), &vec![6100000, 6400000]);
+            /// assert_eq!(save_api.regions_count(0).unwrap(), 2);
+            /// ```
+            pub fn set_regions<R: Into<u32> + Copy>(
+                &mut self,
+                index: usize,
+                regions: &[R],
+            ) -> Result<(), SaveApiError> {
+                self.character_mut(index)?.set_regions(regions)
+            }
+
+            /// Unlocks every region of the save's regulation for the character at the
+            /// specified index.
+            ///
+            /// # Example
+            /// ```rust,no_run
+            /// use er_save_lib::{Region, SaveApi};
+            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+            /// save_api.unlock_all_regions(0).unwrap();
+            /// assert!(save_api.regions(0).unwrap().contains(&Region::Caelid.into()));
+            /// ```
+            #[cfg(feature = "params")]
+            pub fn unlock_all_regions(&mut self, index: usize) -> Result<(), SaveApiError> {
+                let mut regions: Vec<u32> = self
+                    .get_param::<PlayRegionParam>()?
+                    .rows
+                    .into_keys()
+                    // Rows below the first legacy dungeon are test regions
+                    .filter(|id| *id >= 1_000_000)
+                    .map(|id| id as u32)
+                    .collect();
+                regions.sort_unstable();
+                self.character_mut(index)?.set_regions(&regions)
             }
         }
     }
 
     pub mod get {
+        use crate::Archetype;
+        use crate::Gender;
         use crate::SaveApi;
-        
+        use crate::SaveApiError;
+        use crate::Stat;
+
         impl SaveApi {
             /// Returns the hp for the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
-            /// let hp = save_api.hp(0);
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+            /// let hp = save_api.hp(0).unwrap();
             /// ```
-            pub fn hp(&self, index: usize) -> u32 {
-                self.raw.user_data_x[index].player_game_data.hp
+            pub fn hp(&self, index: usize) -> Result<u32, SaveApiError> {
+                Ok(self.user_data_x(index)?.player_game_data.hp)
             }
 
             /// Returns the equipped gestures for the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
-            /// let equipped_gestures = save_api.equipped_gestures(0);
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+            /// let equipped_gestures = save_api.equipped_gestures(0).unwrap();
             /// ```
-            pub fn equipped_gestures(&self, index: usize) -> &Vec<u32> {
-                &self.raw.user_data_x[index]
-                    .equipped_gestures
-                    .equipped_gesture
+            pub fn equipped_gestures(&self, index: usize) -> Result<&Vec<u32>, SaveApiError> {
+                Ok(&self.user_data_x(index)?.equipped_gestures.equipped_gesture)
             }
             /// Gets the max hp of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
             /// let index = 0;
-            /// save_api.max_hp(index);
+            /// save_api.max_hp(index).unwrap();
             /// ```
-            pub fn max_hp(&self, index: usize) -> u32 {
-                self.raw.user_data_x[index].player_game_data.max_hp
+            pub fn max_hp(&self, index: usize) -> Result<u32, SaveApiError> {
+                Ok(self.user_data_x(index)?.player_game_data.max_hp)
             }
             /// Gets the base max hp of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
             /// let index = 0;
-            /// save_api.base_max_hp(index);
+            /// save_api.base_max_hp(index).unwrap();
             /// ```
-            pub fn base_max_hp(&self, index: usize) -> u32 {
-                self.raw.user_data_x[index].player_game_data.base_max_hp
+            pub fn base_max_hp(&self, index: usize) -> Result<u32, SaveApiError> {
+                Ok(self.user_data_x(index)?.player_game_data.base_max_hp)
             }
             /// Gets the fp of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
-            /// let fp = save_api.fp(0);
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+            /// let fp = save_api.fp(0).unwrap();
             /// ```
-            pub fn fp(&mut self, index: usize) -> u32 {
-                self.raw.user_data_x[index].player_game_data.fp
+            pub fn fp(&mut self, index: usize) -> Result<u32, SaveApiError> {
+                Ok(self.user_data_x(index)?.player_game_data.fp)
             }
             /// Gets the max fp of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
-            /// save_api.max_fp(0);
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+            /// save_api.max_fp(0).unwrap();
             /// ```
-            pub fn max_fp(&self, index: usize) -> u32 {
-                self.raw.user_data_x[index].player_game_data.max_fp
+            pub fn max_fp(&self, index: usize) -> Result<u32, SaveApiError> {
+                Ok(self.user_data_x(index)?.player_game_data.max_fp)
             }
             /// Gets the base max fp of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
-            /// save_api.base_max_fp(0);
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+            /// save_api.base_max_fp(0).unwrap();
             /// ```
-            pub fn base_max_fp(&self, index: usize) -> u32 {
-                self.raw.user_data_x[index].player_game_data.base_max_fp
+            pub fn base_max_fp(&self, index: usize) -> Result<u32, SaveApiError> {
+                Ok(self.user_data_x(index)?.player_game_data.base_max_fp)
             }
             /// Gets the sp of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
-            /// save_api.sp(0);
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+            /// save_api.sp(0).unwrap();
             /// ```
-            pub fn sp(&mut self, index: usize) -> u32 {
-                self.raw.user_data_x[index].player_game_data.sp
+            pub fn sp(&mut self, index: usize) -> Result<u32, SaveApiError> {
+                Ok(self.user_data_x(index)?.player_game_data.sp)
             }
             /// Gets the max sp of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
-            /// save_api.max_sp(0);
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+            /// save_api.max_sp(0).unwrap();
             /// ```
-            pub fn max_sp(&self, index: usize) -> u32 {
-                self.raw.user_data_x[index].player_game_data.max_sp
+            pub fn max_sp(&self, index: usize) -> Result<u32, SaveApiError> {
+                Ok(self.user_data_x(index)?.player_game_data.max_sp)
             }
             /// Gets the base max sp of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
-            /// save_api.base_max_sp(0);
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+            /// save_api.base_max_sp(0).unwrap();
             /// ```
-            pub fn base_max_sp(&self, index: usize) -> u32 {
-                self.raw.user_data_x[index].player_game_data.base_max_sp
+            pub fn base_max_sp(&self, index: usize) -> Result<u32, SaveApiError> {
+                Ok(self.user_data_x(index)?.player_game_data.base_max_sp)
             }
             /// Gets the level of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
-            /// save_api.level(0);
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+            /// save_api.level(0).unwrap();
             /// ```
-            pub fn level(&self, index: usize) -> u32 {
-                self.raw.user_data_x[index].player_game_data.level
+            pub fn level(&self, index: usize) -> Result<u32, SaveApiError> {
+                Ok(self.user_data_x(index)?.player_game_data.level)
             }
 
             /// Gets the vigor of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
-            /// save_api.vigor(0);
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+            /// save_api.vigor(0).unwrap();
             /// ```
-            pub fn vigor(&self, index: usize) -> u32 {
-                self.raw.user_data_x[index].player_game_data.vigor
+            pub fn vigor(&self, index: usize) -> Result<u32, SaveApiError> {
+                Ok(self.user_data_x(index)?.player_game_data.vigor)
             }
 
             /// Gets the mind of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
-            /// save_api.mind(0);
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+            /// save_api.mind(0).unwrap();
             /// ```
-            pub fn mind(&self, index: usize) -> u32 {
-                self.raw.user_data_x[index].player_game_data.mind
+            pub fn mind(&self, index: usize) -> Result<u32, SaveApiError> {
+                Ok(self.user_data_x(index)?.player_game_data.mind)
             }
 
             /// Gets the endurance of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
-            /// save_api.endurance(0);
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+            /// save_api.endurance(0).unwrap();
             /// ```
-            pub fn endurance(&self, index: usize) -> u32 {
-                self.raw.user_data_x[index].player_game_data.endurance
+            pub fn endurance(&self, index: usize) -> Result<u32, SaveApiError> {
+                Ok(self.user_data_x(index)?.player_game_data.endurance)
             }
             /// Gets the strength of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
-            /// save_api.strength(0);
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+            /// save_api.strength(0).unwrap();
             /// ```
-            pub fn strength(&self, index: usize) -> u32 {
-                self.raw.user_data_x[index].player_game_data.strength
+            pub fn strength(&self, index: usize) -> Result<u32, SaveApiError> {
+                Ok(self.user_data_x(index)?.player_game_data.strength)
             }
             /// Gets the dexterity of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
-            /// save_api.dexterity(0);
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+            /// save_api.dexterity(0).unwrap();
             /// ```
-            pub fn dexterity(&self, index: usize) -> u32 {
-                self.raw.user_data_x[index].player_game_data.dexterity
+            pub fn dexterity(&self, index: usize) -> Result<u32, SaveApiError> {
+                Ok(self.user_data_x(index)?.player_game_data.dexterity)
             }
 
             /// Gets the intelligence of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
-            /// save_api.intelligence(0);
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+            /// save_api.intelligence(0).unwrap();
             /// ```
-            pub fn intelligence(&self, index: usize) -> u32 {
-                self.raw.user_data_x[index].player_game_data.intelligence
+            pub fn intelligence(&self, index: usize) -> Result<u32, SaveApiError> {
+                Ok(self.user_data_x(index)?.player_game_data.intelligence)
             }
 
             /// Gets the faith of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
-            /// save_api.faith(0);
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+            /// save_api.faith(0).unwrap();
             /// ```
-            pub fn faith(&self, index: usize) -> u32 {
-                self.raw.user_data_x[index].player_game_data.faith
+            pub fn faith(&self, index: usize) -> Result<u32, SaveApiError> {
+                Ok(self.user_data_x(index)?.player_game_data.faith)
             }
 
             /// Gets the arcane of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
-            /// save_api.arcane(0);
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+            /// save_api.arcane(0).unwrap();
+            /// ```
+            pub fn arcane(&self, index: usize) -> Result<u32, SaveApiError> {
+                Ok(self.user_data_x(index)?.player_game_data.arcane)
+            }
+
+            /// Gets the given stat of the character at the specified index.
+            ///
+            /// # Example
+            /// ```rust
+            /// use er_save_lib::{SaveApi, SaveType, Stat};
+            /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+            /// let vigor = save_api.get_stat(0, Stat::Vigor).unwrap();
+            /// assert_eq!(vigor, save_api.vigor(0).unwrap());
+            /// ```
+            pub fn get_stat(&self, index: usize, stat: Stat) -> Result<u32, SaveApiError> {
+                Ok(self.character(index)?.stat(stat))
+            }
+
+            /// Gets every stat of the character at the specified index along with its value.
+            ///
+            /// # Example
+            /// ```rust
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+            /// for (stat, value) in save_api.stats(0).unwrap() {
+            ///     println!("{stat}: {value}");
+            /// }
             /// ```
-            pub fn arcane(&self, index: usize) -> u32 {
-                self.raw.user_data_x[index].player_game_data.arcane
+            pub fn stats(&self, index: usize) -> Result<[(Stat, u32); 8], SaveApiError> {
+                Ok(self.character(index)?.stats())
             }
 
             /// Gets the runes of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
-            /// save_api.runes(0);
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+            /// save_api.runes(0).unwrap();
             /// ```
-            pub fn runes(&self, index: usize) -> u32 {
-                self.raw.user_data_x[index].player_game_data.runes
+            pub fn runes(&self, index: usize) -> Result<u32, SaveApiError> {
+                Ok(self.user_data_x(index)?.player_game_data.runes)
             }
 
             /// Gets the rune memory of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
-            /// save_api.runes_memory(0);
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+            /// save_api.runes_memory(0).unwrap();
             /// ```
-            pub fn runes_memory(&self, index: usize) -> u32 {
-                self.raw.user_data_x[index].player_game_data.runes_memory
+            pub fn runes_memory(&self, index: usize) -> Result<u32, SaveApiError> {
+                Ok(self.user_data_x(index)?.player_game_data.runes_memory)
             }
 
             /// Returns the id of the archetype for the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
-            /// let archetype = save_api.archetype(0);
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+            /// let archetype = save_api.archetype(0).unwrap();
             /// ```
-            pub fn archetype(&self, index: usize) -> u8 {
-                self.raw.user_data_x[index].player_game_data.archetype
+            pub fn archetype(&self, index: usize) -> Result<u8, SaveApiError> {
+                Ok(self.user_data_x(index)?.player_game_data.archetype)
             }
 
             /// Returns the gender of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
-            /// let gender = save_api.gender(0);
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+            /// let gender = save_api.gender(0).unwrap();
+            /// ```
+            pub fn gender(&self, index: usize) -> Result<u8, SaveApiError> {
+                Ok(self.user_data_x(index)?.player_game_data.gender)
+            }
+
+            /// Returns the archetype of the character at the specified index. Ids without an
+            /// `Archetype` variant return `SaveApiError::UnknownValue`, `SaveApi::archetype`
+            /// returns them as they are.
+            ///
+            /// # Example
+            /// ```rust
+            /// use er_save_lib::{Archetype, SaveApi, SaveType};
+            /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+            /// assert_eq!(save_api.archetype_enum(0).unwrap(), Archetype::Vagabond);
             /// ```
-            pub fn gender(&self, index: usize) -> u8 {
-                self.raw.user_data_x[index].player_game_data.gender
+            pub fn archetype_enum(&self, index: usize) -> Result<Archetype, SaveApiError> {
+                Ok(self.character(index)?.archetype_enum()?)
+            }
+
+            /// Returns the gender of the character at the specified index. Values without a
+            /// `Gender` variant return `SaveApiError::UnknownValue`, `SaveApi::gender` returns
+            /// them as they are.
+            ///
+            /// # Example
+            /// ```rust
+            /// use er_save_lib::{Gender, SaveApi, SaveType};
+            /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+            /// assert_eq!(save_api.gender_enum(0).unwrap(), Gender::Female);
+            /// ```
+            pub fn gender_enum(&self, index: usize) -> Result<Gender, SaveApiError> {
+                Ok(self.character(index)?.gender_enum()?)
             }
 
             /// Returns the name of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
-            /// let name = save_api.character_name(0);
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+            /// let name = save_api.character_name(0).unwrap();
             /// ```
-            pub fn character_name(&self, index: usize) -> String {
-                self.raw.user_data_x[index]
+            pub fn character_name(&self, index: usize) -> Result<String, SaveApiError> {
+                Ok(self
+                    .user_data_x(index)?
                     .player_game_data
                     .character_name
-                    .to_string()
+                    .to_string())
             }
             /// Gets the regions of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
-            /// save_api.regions(0);
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+            /// save_api.regions(0).unwrap();
             /// ```
-            pub fn regions(&self, index: usize) -> &Vec<u32> {
-                &self.raw.user_data_x[index].unlocked_regions.ids
+            pub fn regions(&self, index: usize) -> Result<&Vec<u32>, SaveApiError> {
+                Ok(&self.user_data_x(index)?.unlocked_regions.ids)
             }
 
             /// Gets the regions count of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
-            /// save_api.regions_count(0);
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+            /// save_api.regions_count(0).unwrap();
             /// ```
-            pub fn regions_count(&self, index: usize) -> u32 {
-                self.raw.user_data_x[index].unlocked_regions.count
+            pub fn regions_count(&self, index: usize) -> Result<u32, SaveApiError> {
+                Ok(self.user_data_x(index)?.unlocked_regions.count)
             }
         }
     }
@@ -373,26 +480,26 @@ pub mod user_data_api {
     pub mod set {
         use crate::SaveApi;
         use crate::SaveApiError;
+        use crate::Stat;
         impl SaveApi {
             /// Sets the hp of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
             /// let (index, hp) = (0, 1_000);
             /// save_api.set_hp(index, hp);
             /// ```
             pub fn set_hp(&mut self, index: usize, hp: u32) -> Result<(), SaveApiError> {
-                self.raw.user_data_x[index].player_game_data.hp = hp;
-                Ok(())
+                self.character_mut(index)?.set_hp(hp)
             }
             /// Sets the equipped gestures for the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
             /// save_api.set_equipped_gestures(0, vec![1u32,2u32,3u32]).unwrap();
             /// ```
             pub fn set_equipped_gestures(
@@ -400,30 +507,27 @@ pub mod user_data_api {
                 index: usize,
                 new_gestures: Vec<u32>,
             ) -> Result<(), SaveApiError> {
-                self.raw.user_data_x[index]
-                    .equipped_gestures
-                    .equipped_gesture = new_gestures;
-                Ok(())
+                self.character_mut(index)?
+                    .set_equipped_gestures(new_gestures)
             }
             /// Sets the max hp of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
             /// let (index, max_hp) = (0, 1_000);
             /// save_api.set_max_hp(index, max_hp);
             /// ```
             pub fn set_max_hp(&mut self, index: usize, max_hp: u32) -> Result<(), SaveApiError> {
-                self.raw.user_data_x[index].player_game_data.max_hp = max_hp;
-                Ok(())
+                self.character_mut(index)?.set_max_hp(max_hp)
             }
             /// Sets the base max hp of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
             /// let (index, base_max_hp) = (0, 1_000);
             /// save_api.set_base_max_hp(index, base_max_hp);
             /// ```
@@ -432,39 +536,36 @@ pub mod user_data_api {
                 index: usize,
                 base_max_hp: u32,
             ) -> Result<(), SaveApiError> {
-                self.raw.user_data_x[index].player_game_data.base_max_hp = base_max_hp;
-                Ok(())
+                self.character_mut(index)?.set_base_max_hp(base_max_hp)
             }
             /// Sets the fp of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
             /// save_api.set_fp(0, 1);
             /// ```
             pub fn set_fp(&mut self, index: usize, fp: u32) -> Result<(), SaveApiError> {
-                self.raw.user_data_x[index].player_game_data.fp = fp;
-                Ok(())
+                self.character_mut(index)?.set_fp(fp)
             }
             /// Sets the max fp of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
             /// save_api.set_max_fp(0, 1);
             /// ```
             pub fn set_max_fp(&mut self, index: usize, max_fp: u32) -> Result<(), SaveApiError> {
-                self.raw.user_data_x[index].player_game_data.max_fp = max_fp;
-                Ok(())
+                self.character_mut(index)?.set_max_fp(max_fp)
             }
             /// Sets the base max fp of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
             /// save_api.set_base_max_fp(0, 1);
             /// ```
             pub fn set_base_max_fp(
@@ -472,39 +573,36 @@ pub mod user_data_api {
                 index: usize,
                 base_max_fp: u32,
             ) -> Result<(), SaveApiError> {
-                self.raw.user_data_x[index].player_game_data.base_max_fp = base_max_fp;
-                Ok(())
+                self.character_mut(index)?.set_base_max_fp(base_max_fp)
             }
             /// Sets the sp of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
             /// save_api.set_sp(0, 1);
             /// ```
             pub fn set_sp(&mut self, index: usize, sp: u32) -> Result<(), SaveApiError> {
-                self.raw.user_data_x[index].player_game_data.sp = sp;
-                Ok(())
+                self.character_mut(index)?.set_sp(sp)
             }
             /// Sets the max sp of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
             /// save_api.set_max_sp(0, 1);
             /// ```
             pub fn set_max_sp(&mut self, index: usize, max_sp: u32) -> Result<(), SaveApiError> {
-                self.raw.user_data_x[index].player_game_data.max_sp = max_sp;
-                Ok(())
+                self.character_mut(index)?.set_max_sp(max_sp)
             }
             /// Sets the base max sp of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
             /// save_api.set_base_max_sp(0, 1);
             /// ```
             pub fn set_base_max_sp(
@@ -512,41 +610,38 @@ pub mod user_data_api {
                 index: usize,
                 base_max_sp: u32,
             ) -> Result<(), SaveApiError> {
-                self.raw.user_data_x[index].player_game_data.base_max_sp = base_max_sp;
-                Ok(())
+                self.character_mut(index)?.set_base_max_sp(base_max_sp)
             }
 
             /// Sets the vigor of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
             /// save_api.set_vigor(0, 1);
             /// ```
             pub fn set_vigor(&mut self, index: usize, vigor: u32) -> Result<(), SaveApiError> {
-                self.raw.user_data_x[index].player_game_data.vigor = vigor;
-                Ok(())
+                self.character_mut(index)?.set_vigor(vigor)
             }
 
             /// Sets the mind of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
             /// save_api.set_mind(0, 1);
             /// ```
             pub fn set_mind(&mut self, index: usize, mind: u32) -> Result<(), SaveApiError> {
-                self.raw.user_data_x[index].player_game_data.mind = mind;
-                Ok(())
+                self.character_mut(index)?.set_mind(mind)
             }
             /// Sets the endurance of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
             /// save_api.set_endurance(0, 1);
             /// ```
             pub fn set_endurance(
@@ -554,16 +649,15 @@ pub mod user_data_api {
                 index: usize,
                 endurance: u32,
             ) -> Result<(), SaveApiError> {
-                self.raw.user_data_x[index].player_game_data.endurance = endurance;
-                Ok(())
+                self.character_mut(index)?.set_endurance(endurance)
             }
 
             /// Sets the strength of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
             /// save_api.set_strength(0, 1);
             /// ```
             pub fn set_strength(
@@ -571,16 +665,15 @@ pub mod user_data_api {
                 index: usize,
                 strength: u32,
             ) -> Result<(), SaveApiError> {
-                self.raw.user_data_x[index].player_game_data.strength = strength;
-                Ok(())
+                self.character_mut(index)?.set_strength(strength)
             }
 
             /// Sets the dexterity of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
             /// save_api.set_dexterity(0, 1);
             /// ```
             pub fn set_dexterity(
@@ -588,16 +681,15 @@ pub mod user_data_api {
                 index: usize,
                 dexterity: u32,
             ) -> Result<(), SaveApiError> {
-                self.raw.user_data_x[index].player_game_data.dexterity = dexterity;
-                Ok(())
+                self.character_mut(index)?.set_dexterity(dexterity)
             }
 
             /// Sets the intelligence of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
             /// save_api.set_intelligence(0, 1);
             /// ```
             pub fn set_intelligence(
@@ -605,47 +697,61 @@ pub mod user_data_api {
                 index: usize,
                 intelligence: u32,
             ) -> Result<(), SaveApiError> {
-                self.raw.user_data_x[index].player_game_data.intelligence = intelligence;
-                Ok(())
+                self.character_mut(index)?.set_intelligence(intelligence)
             }
 
             /// Sets the faith of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
             /// save_api.set_faith(0, 1);
             /// ```
             pub fn set_faith(&mut self, index: usize, faith: u32) -> Result<(), SaveApiError> {
-                self.raw.user_data_x[index].player_game_data.faith = faith;
-                Ok(())
+                self.character_mut(index)?.set_faith(faith)
             }
 
             /// Gets the arcane of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
             /// save_api.set_arcane(0, 1);
             /// ```
             pub fn set_arcane(&mut self, index: usize, arcane: u32) -> Result<(), SaveApiError> {
-                self.raw.user_data_x[index].player_game_data.arcane = arcane;
-                Ok(())
+                self.character_mut(index)?.set_arcane(arcane)
+            }
+
+            /// Sets the given stat of the character at the specified index.
+            ///
+            /// # Example
+            /// ```rust
+            /// use er_save_lib::{SaveApi, SaveType, Stat};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+            /// save_api.set_stat(0, Stat::Mind, 20).unwrap();
+            /// assert_eq!(save_api.mind(0).unwrap(), 20);
+            /// ```
+            pub fn set_stat(
+                &mut self,
+                index: usize,
+                stat: Stat,
+                value: u32,
+            ) -> Result<(), SaveApiError> {
+                self.character_mut(index)?.set_stat(stat, value)
             }
 
             /// Sets the runes of the character at the specified index.
             ///
             /// # Example
             /// ```rust
-            /// use er_save_lib::SaveApi;
-            /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+            /// use er_save_lib::{SaveApi, SaveType};
+            /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
             /// save_api.set_runes(0, 1_000);
             /// ```
             pub fn set_runes(&mut self, index: usize, runes: u32) -> Result<(), SaveApiError> {
-                self.raw.user_data_x[index].player_game_data.runes = runes;
-                Ok(())
+                self.character_mut(index)?.set_runes(runes)
             }
         }
     }
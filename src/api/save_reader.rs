@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+#[cfg(feature = "params")]
+use super::save_api::user_data_11_api::user_data_api::Param;
+use super::{character::Character, event_flags::EventFlagsApi, item::ItemIdMapper};
+use crate::{Save, SaveApi, SaveApiError};
+
+/// An immutable, cheaply cloneable view of a parsed save.
+///
+/// Obtained through `SaveApi::reader`. Clones share the same parsed save, so a server can
+/// hand one to every request thread instead of cloning the whole save. Edits made through
+/// the `SaveApi` afterwards are not visible to existing readers.
+///
+/// # Example
+/// ```rust
+/// use er_save_lib::{SaveApi, SaveType};
+/// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+/// let reader = save_api.reader();
+/// let handles: Vec<_> = (0..reader.character_count())
+///     .map(|index| {
+///         let reader = reader.clone();
+///         std::thread::spawn(move || reader.character(index).unwrap().level())
+///     })
+///     .collect();
+/// for handle in handles {
+///     println!("{}", handle.join().unwrap());
+/// }
+/// ```
+#[derive(Clone)]
+pub struct SaveReader {
+    raw: Arc<Save>,
+    mapper: Arc<dyn ItemIdMapper>,
+}
+
+// Readers and the editor they come from are meant to be shared across threads.
+const fn assert_send_sync<T: Send + Sync>() {}
+const _: () = assert_send_sync::<SaveApi>();
+const _: () = assert_send_sync::<SaveReader>();
+
+impl SaveReader {
+    pub(crate) fn new(raw: Arc<Save>, mapper: Arc<dyn ItemIdMapper>) -> Self {
+        SaveReader { raw, mapper }
+    }
+
+    /// Returns the number of character slots in the save file.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let reader = SaveApi::new_empty(SaveType::PC).unwrap().reader();
+    /// assert_eq!(reader.character_count(), 10);
+    /// ```
+    pub fn character_count(&self) -> usize {
+        self.raw.user_data_x.len()
+    }
+
+    /// Returns a read-only view of the character at the specified index.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let reader = SaveApi::new_empty(SaveType::PC).unwrap().reader();
+    /// let vigor = reader.character(0).unwrap().vigor();
+    /// ```
+    pub fn character(&self, index: usize) -> Result<Character<'_>, SaveApiError> {
+        Character::from_save(&self.raw, index, self.mapper.as_ref())
+    }
+
+    /// Returns event flags by id and character index
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let reader = SaveApi::new_empty(SaveType::PC).unwrap().reader();
+    /// let event_flag = reader.get_event_flag(6223, 0).unwrap();
+    /// ```
+    pub fn get_event_flag(
+        &self,
+        event_id: u32,
+        character_index: usize,
+    ) -> Result<bool, SaveApiError> {
+        EventFlagsApi::get_event_flag(&self.raw, event_id, character_index)
+    }
+
+    /// Returns parameters by type
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use er_save_lib::SaveApi;
+    /// use er_save_lib::TalkParam::TalkParam;
+    /// let reader = SaveApi::from_path("./test/ER0000.sl2").unwrap().reader();
+    /// let param = reader.get_param::<TalkParam>().unwrap();
+    /// ```
+    #[cfg(feature = "params")]
+    pub fn get_param<P: crate::param_trait::Param>(&self) -> Result<Param<P>, SaveApiError> {
+        let rows = self.raw.user_data_11.regulation.get_param::<P>()?;
+        Ok(Param::<P> { rows })
+    }
+}
@@ -0,0 +1,121 @@
+#[cfg(feature = "std")]
+use std::{fs, path::Path};
+
+#[cfg(feature = "std")]
+use super::file_lock;
+use crate::{SaveApi, SaveApiError, SaveType};
+
+/// Write-time options of a save, set builder style and applied by `SaveWriter::write` or
+/// `SaveWriter::to_vec`.
+///
+/// The default options write the same bytes as `SaveApi::write_to_path`: sections that
+/// weren't edited are copied from the bytes the save was read from, and nothing else is
+/// touched.
+///
+/// # Example
+/// ```rust
+/// use er_save_lib::{SaveApi, SaveType, SaveWriter};
+/// let path = std::env::temp_dir().join("er-save-lib-writer.sl2");
+/// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+/// SaveWriter::new(&save_api)
+///     .backup(true)
+///     .recalc_checksums(true)
+///     .platform(SaveType::PC)
+///     .write(&path)
+///     .unwrap();
+/// ```
+pub struct SaveWriter<'a> {
+    save_api: &'a SaveApi,
+    backup: bool,
+    recalc_checksums: bool,
+    platform: Option<SaveType>,
+    atomic: bool,
+}
+
+impl<'a> SaveWriter<'a> {
+    /// Creates a writer for a save with the default options.
+    pub fn new(save_api: &'a SaveApi) -> Self {
+        SaveWriter {
+            save_api,
+            backup: false,
+            recalc_checksums: false,
+            platform: None,
+            atomic: true,
+        }
+    }
+
+    /// Copies the file being replaced to the same path with `.bak` appended before writing,
+    /// `ER0000.sl2.bak` for `ER0000.sl2`. Nothing is copied when the file doesn't exist.
+    pub fn backup(mut self, backup: bool) -> Self {
+        self.backup = backup;
+        self
+    }
+
+    /// Re-encodes every section, computing each checksum again, instead of copying the
+    /// sections that weren't edited. Repairs saves whose untouched sections have a wrong
+    /// checksum, at the cost of no longer keeping them byte for byte.
+    pub fn recalc_checksums(mut self, recalc_checksums: bool) -> Self {
+        self.recalc_checksums = recalc_checksums;
+        self
+    }
+
+    /// Platform the save is written for. Converting between platforms isn't supported, so
+    /// writing fails with `SaveApiError::PlatformConversionUnsupported` unless it is the
+    /// platform of the save, see `SaveApi::platform`. Saves are written for their own
+    /// platform when it isn't set.
+    pub fn platform(mut self, platform: SaveType) -> Self {
+        self.platform = Some(platform);
+        self
+    }
+
+    /// Writes to a temporary file next to the save, synced to disk and renamed over it,
+    /// so an interrupted write leaves the previous save intact. On by default. Turned off,
+    /// the save is truncated and written in place, which keeps the file's permissions and
+    /// links but can leave a partial save behind.
+    pub fn atomic(mut self, atomic: bool) -> Self {
+        self.atomic = atomic;
+        self
+    }
+
+    /// Returns the bytes of the save with the options applied.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType, SaveWriter};
+    /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// let bytes = SaveWriter::new(&save_api).to_vec().unwrap();
+    /// assert_eq!(bytes, save_api.to_vec().unwrap());
+    /// let result = SaveWriter::new(&save_api)
+    ///     .platform(SaveType::Playstation)
+    ///     .to_vec();
+    /// assert!(result.is_err());
+    /// ```
+    pub fn to_vec(&self) -> Result<Vec<u8>, SaveApiError> {
+        if let Some(platform) = &self.platform {
+            if *platform != self.save_api.platform() {
+                return Err(SaveApiError::PlatformConversionUnsupported(
+                    self.save_api.platform(),
+                ));
+            }
+        }
+        if self.recalc_checksums {
+            return Ok(self.save_api.raw().write_to_vec()?);
+        }
+        self.save_api.to_vec()
+    }
+
+    /// Writes the save to the specified path with the options applied. The save is
+    /// encoded before anything is backed up or written, so an error leaves the file as it
+    /// was.
+    #[cfg(feature = "std")]
+    pub fn write(&self, path: impl AsRef<Path>) -> Result<(), SaveApiError> {
+        let path = path.as_ref();
+        let bytes = self.to_vec()?;
+        if self.backup && path.exists() {
+            let mut backup_path = path.as_os_str().to_owned();
+            backup_path.push(".bak");
+            fs::copy(path, backup_path)?;
+        }
+        file_lock::write(path, &bytes, self.atomic)
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{SaveApi, SaveApiError};
+
+/// A value to look for with `SaveApi::scan_unknown`. Numbers are matched by their little
+/// endian bytes, the way the save stores them.
+#[derive(Clone, PartialEq, Debug)]
+pub enum Needle {
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    F32(f32),
+    Bytes(Vec<u8>),
+}
+
+impl Needle {
+    fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            Needle::U8(value) => vec![*value],
+            Needle::U16(value) => value.to_le_bytes().to_vec(),
+            Needle::U32(value) => value.to_le_bytes().to_vec(),
+            Needle::F32(value) => value.to_le_bytes().to_vec(),
+            Needle::Bytes(bytes) => bytes.clone(),
+        }
+    }
+}
+
+/// Where `SaveApi::scan_unknown` found a value.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ScanMatch {
+    /// Name of the region, as returned by `Character::opaque_regions`.
+    pub region: &'static str,
+    /// Offset of the value from the start of the region.
+    pub offset: usize,
+}
+
+impl SaveApi {
+    /// Searches the regions of the character at the specified index whose meaning isn't
+    /// known for a value, and returns every offset it was found at, in the order of the
+    /// regions. Values aren't expected to be aligned, so every offset is tried.
+    ///
+    /// Scanning for a value the game shows, like the current runes, and scanning again
+    /// after it changed narrows down where an unknown field is stored.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::scan::{Needle, ScanMatch};
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// let mut chr = save_api.character_mut(0).unwrap();
+    /// let region = chr.opaque_region_mut("player_game_data.unk0x17c").unwrap();
+    /// region[5..9].copy_from_slice(&0x12345678u32.to_le_bytes());
+    /// let matches = save_api.scan_unknown(0, Needle::U32(0x12345678)).unwrap();
+    /// assert_eq!(
+    ///     matches,
+    ///     vec![ScanMatch {
+    ///         region: "player_game_data.unk0x17c",
+    ///         offset: 5,
+    ///     }]
+    /// );
+    /// ```
+    pub fn scan_unknown(
+        &self,
+        index: usize,
+        needle: Needle,
+    ) -> Result<Vec<ScanMatch>, SaveApiError> {
+        let needle = needle.to_bytes();
+        if needle.is_empty() {
+            return Ok(Vec::new());
+        }
+        let character = self.character(index)?;
+        let mut matches = Vec::new();
+        for (region, bytes) in character.opaque_regions() {
+            for (offset, window) in bytes.windows(needle.len()).enumerate() {
+                if window == needle {
+                    matches.push(ScanMatch { region, offset });
+                }
+            }
+        }
+        Ok(matches)
+    }
+}
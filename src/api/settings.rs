@@ -0,0 +1,219 @@
+use crate::{save::user_data_10, SaveApi, SaveApiError};
+
+/// Game options shared by every character of a save, as set in the system menu.
+///
+/// Values are stored the way the menu keeps them: sliders hold their position and
+/// switches and choices the index of the selected entry, starting at 0.
+///
+/// The key bindings and the menu state are stored next to these, in blocks whose format
+/// isn't known. They are only carried over as a whole by `SaveApi::copy_settings_from`.
+///
+/// # Example
+/// ```rust
+/// use er_save_lib::{SaveApi, SaveType};
+/// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+/// let mut settings = save_api.settings();
+/// settings.brightness = 7;
+/// settings.music_volume = 0;
+/// save_api.set_settings(settings).unwrap();
+/// assert_eq!(save_api.settings(), settings);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Settings {
+    pub camera_speed: u8,
+    pub controller_vibration: u8,
+    pub brightness: u8,
+    pub master_volume: u8,
+    pub music_volume: u8,
+    pub sound_effects_volume: u8,
+    pub voice_volume: u8,
+    pub display_blood: u8,
+    pub subtitles: u8,
+    pub hud: u8,
+    pub camera_x_axis: u8,
+    pub camera_y_axis: u8,
+    pub toggle_auto_lockon: u8,
+    pub camera_auto_wall_recovery: u8,
+    pub camera_auto_rotation: u8,
+    pub reset_camera_y_axis: u8,
+    pub cinematic_effects: u8,
+    pub perform_matchmaking: u8,
+    pub manual_attack_aim: u8,
+    pub autotarget: u8,
+    pub launch_settings: u8,
+    pub send_summon_sign: u8,
+    pub hdr: u8,
+    pub hdr_adjust_brightness: u8,
+    pub hdr_maximum_brightness: u8,
+    pub hdr_adjust_saturation: u8,
+    pub raytracing: u8,
+    pub mark_new_items: u8,
+    pub show_recent_tabs: u8,
+    pub show_tutorials: u8,
+}
+
+impl From<&user_data_10::Settings> for Settings {
+    fn from(settings: &user_data_10::Settings) -> Self {
+        Settings {
+            camera_speed: settings.camera_speed,
+            controller_vibration: settings.controller_vibration,
+            brightness: settings.brightness,
+            master_volume: settings.master_volume,
+            music_volume: settings.music_volume,
+            sound_effects_volume: settings.sound_effects_volume,
+            voice_volume: settings.voice_volume,
+            display_blood: settings.display_blood,
+            subtitles: settings.subtitles,
+            hud: settings.hud,
+            camera_x_axis: settings.camera_x_axis,
+            camera_y_axis: settings.camera_y_axis,
+            toggle_auto_lockon: settings.toggle_auto_lockon,
+            camera_auto_wall_recovery: settings.camera_auto_wall_recovery,
+            camera_auto_rotation: settings.camera_auto_rotation,
+            reset_camera_y_axis: settings.reset_camera_y_axis,
+            cinematic_effects: settings.cinematic_effects,
+            perform_matchmaking: settings.perform_matchmaking,
+            manual_attack_aim: settings.manual_attack_aim,
+            autotarget: settings.autotarget,
+            launch_settings: settings.launchsettings,
+            send_summon_sign: settings.send_summon_sign,
+            hdr: settings.hdr,
+            hdr_adjust_brightness: settings.hdr_adjust_brightness,
+            hdr_maximum_brightness: settings.hdr_maximum_brightness,
+            hdr_adjust_saturation: settings.hdr_adjust_saturation,
+            raytracing: settings.is_raytracing_on,
+            mark_new_items: settings.mark_new_items,
+            show_recent_tabs: settings.show_recent_tabs,
+            show_tutorials: settings.show_tutorials,
+        }
+    }
+}
+
+impl Settings {
+    // Writes the options into the save's block, leaving its unknown bytes untouched
+    fn apply(&self, settings: &mut user_data_10::Settings) {
+        settings.camera_speed = self.camera_speed;
+        settings.controller_vibration = self.controller_vibration;
+        settings.brightness = self.brightness;
+        settings.master_volume = self.master_volume;
+        settings.music_volume = self.music_volume;
+        settings.sound_effects_volume = self.sound_effects_volume;
+        settings.voice_volume = self.voice_volume;
+        settings.display_blood = self.display_blood;
+        settings.subtitles = self.subtitles;
+        settings.hud = self.hud;
+        settings.camera_x_axis = self.camera_x_axis;
+        settings.camera_y_axis = self.camera_y_axis;
+        settings.toggle_auto_lockon = self.toggle_auto_lockon;
+        settings.camera_auto_wall_recovery = self.camera_auto_wall_recovery;
+        settings.camera_auto_rotation = self.camera_auto_rotation;
+        settings.reset_camera_y_axis = self.reset_camera_y_axis;
+        settings.cinematic_effects = self.cinematic_effects;
+        settings.perform_matchmaking = self.perform_matchmaking;
+        settings.manual_attack_aim = self.manual_attack_aim;
+        settings.autotarget = self.autotarget;
+        settings.launchsettings = self.launch_settings;
+        settings.send_summon_sign = self.send_summon_sign;
+        settings.hdr = self.hdr;
+        settings.hdr_adjust_brightness = self.hdr_adjust_brightness;
+        settings.hdr_maximum_brightness = self.hdr_maximum_brightness;
+        settings.hdr_adjust_saturation = self.hdr_adjust_saturation;
+        settings.is_raytracing_on = self.raytracing;
+        settings.mark_new_items = self.mark_new_items;
+        settings.show_recent_tabs = self.show_recent_tabs;
+        settings.show_tutorials = self.show_tutorials;
+    }
+}
+
+impl SaveApi {
+    /// Returns the game options of the save.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// let settings = save_api.settings();
+    /// println!("Brightness {}", settings.brightness);
+    /// ```
+    pub fn settings(&self) -> Settings {
+        Settings::from(&self.user_data_10().settings)
+    }
+
+    /// Sets the game options of the save.
+    pub fn set_settings(&mut self, settings: Settings) -> Result<(), SaveApiError> {
+        settings.apply(&mut self.user_data_10_mut().settings);
+        Ok(())
+    }
+
+    /// Copies every setting of `other` into this save: the game options, the menu state,
+    /// the key bindings and, between PC saves, the graphics options. Characters are left
+    /// alone. Meant to carry settings over to a save from another machine.
+    ///
+    /// Key bindings and menu state are copied as is, their format isn't known. They carry
+    /// their own size, and a longer copy takes up the zero padding at the end of the
+    /// profile data. `SaveApiError::ProfileDataFull` is returned when there isn't enough
+    /// of it, nothing is copied then.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let other = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// let mut settings = save_api.settings();
+    /// settings.hud = 1 - settings.hud.min(1);
+    /// save_api.set_settings(settings).unwrap();
+    /// save_api.copy_settings_from(&other).unwrap();
+    /// assert_eq!(save_api.settings(), other.settings());
+    /// let bytes = save_api.to_vec().unwrap();
+    /// assert_eq!(SaveApi::from_slice(&bytes).unwrap().settings(), other.settings());
+    /// ```
+    pub fn copy_settings_from(&mut self, other: &SaveApi) -> Result<(), SaveApiError> {
+        let same_platform = self.platform() == other.platform();
+        let source = other.user_data_10();
+        let target = self.user_data_10_mut();
+        target
+            .set_settings_blocks(
+                source.menu_system_save_load.clone(),
+                source.key_config_save_load.clone(),
+            )
+            .map_err(SaveApiError::ProfileDataFull)?;
+        target.settings = source.settings.clone();
+        // PlayStation saves don't have the block
+        if same_platform {
+            target.pc_option_data = source.pc_option_data.clone();
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "regulation")]
+#[test]
+fn copied_settings_keep_the_profile_size() {
+    use crate::SaveType;
+
+    let mut other = SaveApi::new_empty(SaveType::PC).unwrap();
+    let key_config = &mut other.user_data_10_mut().key_config_save_load;
+    key_config.data.extend([1; 0x40]);
+    key_config.size = key_config.data.len() as u32;
+
+    let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    let size = save_api.to_vec().unwrap().len();
+    save_api.copy_settings_from(&other).unwrap();
+    let bytes = save_api.to_vec().unwrap();
+    assert_eq!(bytes.len(), size);
+    let save_api = SaveApi::from_slice(&bytes).unwrap();
+    assert_eq!(
+        save_api.user_data_10().key_config_save_load,
+        other.user_data_10().key_config_save_load
+    );
+
+    // The end of the profile data isn't padding, the longer bindings don't fit
+    let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    *save_api.user_data_10_mut().rest.last_mut().unwrap() = 1;
+    let bytes = save_api.to_vec().unwrap();
+    assert!(matches!(
+        save_api.copy_settings_from(&other),
+        Err(SaveApiError::ProfileDataFull(0x40))
+    ));
+    assert_eq!(save_api.to_vec().unwrap(), bytes);
+}
@@ -0,0 +1,96 @@
+use crate::{SaveApi, SaveApiError};
+
+/// Number of special effects a character slot can store.
+pub const SP_EFFECT_SLOTS: usize = 13;
+
+// Id written to slots that hold no effect
+const EMPTY_SP_EFFECT_ID: i32 = -1;
+
+/// A special effect active on a character when it was saved, such as a buff or a status
+/// ailment. Ids are row ids of `SpEffectParam`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct ActiveSpEffect {
+    pub id: i32,
+    /// Time left before the effect wears off, in seconds.
+    pub remaining_time: f32,
+}
+
+impl SaveApi {
+    /// Returns the special effects stored for the character at the specified index, in the
+    /// order of their slots. Slots without an effect, with an id of 0 or less, hold `None`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// assert!(save_api.sp_effects(0).unwrap().iter().all(Option::is_none));
+    /// ```
+    pub fn sp_effects(
+        &self,
+        index: usize,
+    ) -> Result<[Option<ActiveSpEffect>; SP_EFFECT_SLOTS], SaveApiError> {
+        let mut sp_effects = [None; SP_EFFECT_SLOTS];
+        for (slot, sp_effect) in sp_effects
+            .iter_mut()
+            .zip(&self.user_data_x(index)?.sp_effects)
+        {
+            if sp_effect.sp_effect_id > 0 {
+                *slot = Some(ActiveSpEffect {
+                    id: sp_effect.sp_effect_id,
+                    remaining_time: sp_effect.remaining_time,
+                });
+            }
+        }
+        Ok(sp_effects)
+    }
+
+    /// Stores a special effect in a slot of the character at the specified index, or
+    /// empties the slot with `None`. The game applies it the next time the character is
+    /// loaded.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::sp_effects::ActiveSpEffect;
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// let sp_effect = ActiveSpEffect {
+    ///     id: 1000,
+    ///     remaining_time: 30.,
+    /// };
+    /// save_api.set_sp_effect(0, 0, Some(sp_effect)).unwrap();
+    /// assert_eq!(save_api.sp_effects(0).unwrap()[0], Some(sp_effect));
+    /// assert!(save_api.set_sp_effect(0, 13, None).is_err());
+    /// ```
+    pub fn set_sp_effect(
+        &mut self,
+        index: usize,
+        slot: usize,
+        sp_effect: Option<ActiveSpEffect>,
+    ) -> Result<(), SaveApiError> {
+        let stored = self
+            .user_data_x_mut(index)?
+            .sp_effects
+            .get_mut(slot)
+            .ok_or(SaveApiError::SpEffectSlotOutOfRange(slot))?;
+        stored.sp_effect_id = sp_effect.map_or(EMPTY_SP_EFFECT_ID, |sp_effect| sp_effect.id);
+        stored.remaining_time = sp_effect.map_or(0., |sp_effect| sp_effect.remaining_time);
+        Ok(())
+    }
+
+    /// Removes every special effect stored for the character at the specified index, for
+    /// getting rid of a debuff that stays after loading.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// save_api.clear_sp_effects(0).unwrap();
+    /// assert!(save_api.sp_effects(0).unwrap().iter().all(Option::is_none));
+    /// ```
+    pub fn clear_sp_effects(&mut self, index: usize) -> Result<(), SaveApiError> {
+        for slot in 0..SP_EFFECT_SLOTS {
+            self.set_sp_effect(index, slot, None)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,193 @@
+use std::fmt::Display;
+
+#[cfg(feature = "params")]
+use super::item::{ItemCategory, KEY_ITEM_GOODS_TYPE};
+#[cfg(feature = "params")]
+use crate::{CharaInitParam::CharaInitParam, EquipParamGoods::EquipParamGoods};
+use crate::{SaveApi, SaveApiError};
+
+/// The keepsake picked when the character was created.
+///
+/// # Example
+/// ```rust
+/// use er_save_lib::StartingGift;
+/// assert_eq!(StartingGift::from_id(6), Some(StartingGift::StoneswordKey));
+/// assert_eq!(StartingGift::StoneswordKey.to_string(), "Stonesword Key");
+/// assert_eq!(StartingGift::from_id(10), None);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum StartingGift {
+    Nothing,
+    CrimsonAmberMedallion,
+    LandsBetweenRune,
+    GoldenSeed,
+    FangedImpAshes,
+    CrackedPot,
+    StoneswordKey,
+    BewitchingBranch,
+    BoiledPrawn,
+    ShabririsWoe,
+}
+
+// Row of `CharaInitParam` holding the items of the first gift, the others follow in order
+#[cfg(feature = "params")]
+const FIRST_GIFT_ROW: i32 = 2400;
+
+impl StartingGift {
+    /// Every gift in the order the game lists them.
+    pub const ALL: [StartingGift; 10] = [
+        StartingGift::Nothing,
+        StartingGift::CrimsonAmberMedallion,
+        StartingGift::LandsBetweenRune,
+        StartingGift::GoldenSeed,
+        StartingGift::FangedImpAshes,
+        StartingGift::CrackedPot,
+        StartingGift::StoneswordKey,
+        StartingGift::BewitchingBranch,
+        StartingGift::BoiledPrawn,
+        StartingGift::ShabririsWoe,
+    ];
+
+    /// Returns the gift stored in the save as `id`, or `None` for an unknown id.
+    pub fn from_id(id: u8) -> Option<Self> {
+        Self::ALL.get(id as usize).copied()
+    }
+
+    /// Returns the id the save stores for the gift.
+    pub fn id(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Returns the in-game name of the gift.
+    pub fn name(&self) -> &'static str {
+        match self {
+            StartingGift::Nothing => "None",
+            StartingGift::CrimsonAmberMedallion => "Crimson Amber Medallion",
+            StartingGift::LandsBetweenRune => "Lands Between Rune",
+            StartingGift::GoldenSeed => "Golden Seed",
+            StartingGift::FangedImpAshes => "Fanged Imp Ashes",
+            StartingGift::CrackedPot => "Cracked Pot",
+            StartingGift::StoneswordKey => "Stonesword Key",
+            StartingGift::BewitchingBranch => "Bewitching Branch",
+            StartingGift::BoiledPrawn => "Boiled Prawn",
+            StartingGift::ShabririsWoe => "Shabriri's Woe",
+        }
+    }
+}
+
+impl Display for StartingGift {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl SaveApi {
+    /// Returns the gift picked when the character at the specified index was created, or
+    /// `None` if the save holds an unknown gift id.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{MockCharacter, MockSave, SaveApi, StartingGift};
+    /// let mock = MockSave::new(vec![MockCharacter {
+    ///     level: 1,
+    ///     ..Default::default()
+    /// }]);
+    /// let mut save_api = SaveApi::from_mock(&mock).unwrap();
+    /// assert_eq!(save_api.starting_gift(0).unwrap(), Some(StartingGift::Nothing));
+    /// save_api
+    ///     .character_mut(0)
+    ///     .unwrap()
+    ///     .set_starting_gift(StartingGift::StoneswordKey)
+    ///     .unwrap();
+    /// assert_eq!(save_api.starting_gift(0).unwrap(), Some(StartingGift::StoneswordKey));
+    /// ```
+    pub fn starting_gift(&self, index: usize) -> Result<Option<StartingGift>, SaveApiError> {
+        Ok(self.character(index)?.starting_gift())
+    }
+
+    /// Returns the items handed out with a gift at character creation, as category, id and
+    /// quantity, read from the `CharaInitParam` of the save's regulation.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use er_save_lib::item::ItemCategory;
+    /// use er_save_lib::{SaveApi, StartingGift};
+    /// let save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+    /// let items = save_api.starting_gift_items(StartingGift::StoneswordKey).unwrap();
+    /// assert_eq!(items, vec![(ItemCategory::Goods, 8000, 2)]);
+    /// ```
+    #[cfg(feature = "params")]
+    pub fn starting_gift_items(
+        &self,
+        gift: StartingGift,
+    ) -> Result<Vec<(ItemCategory, u32, u32)>, SaveApiError> {
+        let rows = self.get_param::<CharaInitParam>()?.rows;
+        // Regulations without the row hand out nothing
+        let Some(row) = rows.get(&(FIRST_GIFT_ROW + gift.id() as i32)) else {
+            return Ok(Vec::new());
+        };
+
+        let talismans = [
+            row.equip_Accessory01,
+            row.equip_Accessory02,
+            row.equip_Accessory03,
+            row.equip_Accessory04,
+        ]
+        .map(|id| (ItemCategory::Talisman, id, 1));
+        let goods = [
+            (row.item_01, row.itemNum_01),
+            (row.item_02, row.itemNum_02),
+            (row.item_03, row.itemNum_03),
+            (row.item_04, row.itemNum_04),
+            (row.item_05, row.itemNum_05),
+            (row.item_06, row.itemNum_06),
+            (row.item_07, row.itemNum_07),
+            (row.item_08, row.itemNum_08),
+            (row.item_09, row.itemNum_09),
+            (row.item_10, row.itemNum_10),
+        ]
+        .map(|(id, quantity)| (ItemCategory::Goods, id, quantity.max(1) as u32));
+
+        Ok(talismans
+            .into_iter()
+            .chain(goods)
+            .filter(|(_, id, _)| *id > 0)
+            .map(|(category, id, quantity)| (category, id as u32, quantity))
+            .collect())
+    }
+
+    /// Sets the gift of the character at the specified index and gives it the gift's
+    /// items, the same way character creation does. Useful to fix a character created with
+    /// the wrong gift. Items of the previous gift are left in the inventory, and talismans
+    /// already owned aren't given again.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use er_save_lib::item::ItemCategory;
+    /// use er_save_lib::{SaveApi, StartingGift};
+    /// let mut save_api = SaveApi::from_path("./test/ER0000.sl2").unwrap();
+    /// save_api.set_starting_gift(0, StartingGift::GoldenSeed).unwrap();
+    /// assert_eq!(save_api.starting_gift(0).unwrap(), Some(StartingGift::GoldenSeed));
+    /// let items = save_api.character(0).unwrap().owned_items();
+    /// assert!(items.contains(&(ItemCategory::Goods, 10010)));
+    /// ```
+    #[cfg(feature = "params")]
+    pub fn set_starting_gift(
+        &mut self,
+        index: usize,
+        gift: StartingGift,
+    ) -> Result<(), SaveApiError> {
+        let items = self.starting_gift_items(gift)?;
+        let goods = self.get_param::<EquipParamGoods>()?.rows;
+
+        let mut character = self.character_mut(index)?;
+        for (category, id, quantity) in items {
+            let key_item = category == ItemCategory::Goods
+                && goods
+                    .get(&(id as i32))
+                    .is_some_and(|row| row.goodsType == KEY_ITEM_GOODS_TYPE);
+            character.give_item(category, id, quantity, key_item)?;
+        }
+        character.set_starting_gift(gift)
+    }
+}
@@ -0,0 +1,60 @@
+use std::fmt::Display;
+
+/// The eight attributes a character can level up.
+///
+/// Useful for editors that want to bind every attribute to the same control instead of
+/// calling a differently named getter/setter for each one.
+///
+/// # Example
+/// ```rust
+/// use er_save_lib::{SaveApi, SaveType, Stat};
+/// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+/// for stat in Stat::ALL {
+///     println!("{stat}: {}", save_api.get_stat(0, stat).unwrap());
+/// }
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Stat {
+    Vigor,
+    Mind,
+    Endurance,
+    Strength,
+    Dexterity,
+    Intelligence,
+    Faith,
+    Arcane,
+}
+
+impl Stat {
+    /// Every stat in the order the game displays them.
+    pub const ALL: [Stat; 8] = [
+        Stat::Vigor,
+        Stat::Mind,
+        Stat::Endurance,
+        Stat::Strength,
+        Stat::Dexterity,
+        Stat::Intelligence,
+        Stat::Faith,
+        Stat::Arcane,
+    ];
+
+    /// Returns the in-game name of the stat.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Stat::Vigor => "Vigor",
+            Stat::Mind => "Mind",
+            Stat::Endurance => "Endurance",
+            Stat::Strength => "Strength",
+            Stat::Dexterity => "Dexterity",
+            Stat::Intelligence => "Intelligence",
+            Stat::Faith => "Faith",
+            Stat::Arcane => "Arcane",
+        }
+    }
+}
+
+impl Display for Stat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
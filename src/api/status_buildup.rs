@@ -0,0 +1,131 @@
+use std::fmt::Display;
+
+use crate::{save::user_data_x::PlayerGameData, SaveApi, SaveApiError};
+
+/// A status ailment that builds up on a character before it procs.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum StatusAilment {
+    Poison,
+    ScarletRot,
+    Hemorrhage,
+    Deathblight,
+    Frostbite,
+    Sleep,
+    Madness,
+}
+
+impl StatusAilment {
+    /// Every status ailment in the order they're stored.
+    pub const ALL: [StatusAilment; 7] = [
+        StatusAilment::Poison,
+        StatusAilment::ScarletRot,
+        StatusAilment::Hemorrhage,
+        StatusAilment::Deathblight,
+        StatusAilment::Frostbite,
+        StatusAilment::Sleep,
+        StatusAilment::Madness,
+    ];
+
+    /// Returns the in-game name of the status ailment.
+    pub fn name(&self) -> &'static str {
+        match self {
+            StatusAilment::Poison => "Poison",
+            StatusAilment::ScarletRot => "Scarlet Rot",
+            StatusAilment::Hemorrhage => "Hemorrhage",
+            StatusAilment::Deathblight => "Deathblight",
+            StatusAilment::Frostbite => "Frostbite",
+            StatusAilment::Sleep => "Sleep",
+            StatusAilment::Madness => "Madness",
+        }
+    }
+}
+
+impl Display for StatusAilment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl SaveApi {
+    /// Returns how much of a status ailment had built up on the character at the specified
+    /// index when it was saved.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::status_buildup::StatusAilment;
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// for ailment in StatusAilment::ALL {
+    ///     println!("{ailment}: {}", save_api.status_buildup(0, ailment).unwrap());
+    /// }
+    /// ```
+    pub fn status_buildup(
+        &self,
+        index: usize,
+        ailment: StatusAilment,
+    ) -> Result<u32, SaveApiError> {
+        let player_game_data = &self.user_data_x(index)?.player_game_data;
+        Ok(match ailment {
+            StatusAilment::Poison => player_game_data.poison_buildup,
+            StatusAilment::ScarletRot => player_game_data.rot_buildup,
+            StatusAilment::Hemorrhage => player_game_data.bleed_buildup,
+            StatusAilment::Deathblight => player_game_data.death_buildup,
+            StatusAilment::Frostbite => player_game_data.frost_buildup,
+            StatusAilment::Sleep => player_game_data.sleep_buildup,
+            StatusAilment::Madness => player_game_data.madness_buildup,
+        })
+    }
+
+    /// Sets how much of a status ailment has built up on the character at the specified
+    /// index. The game picks it up from there when the character is loaded.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::status_buildup::StatusAilment;
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// save_api.set_status_buildup(0, StatusAilment::Frostbite, 80).unwrap();
+    /// assert_eq!(save_api.status_buildup(0, StatusAilment::Frostbite).unwrap(), 80);
+    /// ```
+    pub fn set_status_buildup(
+        &mut self,
+        index: usize,
+        ailment: StatusAilment,
+        buildup: u32,
+    ) -> Result<(), SaveApiError> {
+        let player_game_data = &mut self.user_data_x_mut(index)?.player_game_data;
+        *buildup_mut(player_game_data, ailment) = buildup;
+        Ok(())
+    }
+
+    /// Clears the buildup of every status ailment of the character at the specified index.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::status_buildup::StatusAilment;
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// save_api.set_status_buildup(0, StatusAilment::Poison, 50).unwrap();
+    /// save_api.clear_status_buildups(0).unwrap();
+    /// assert_eq!(save_api.status_buildup(0, StatusAilment::Poison).unwrap(), 0);
+    /// ```
+    pub fn clear_status_buildups(&mut self, index: usize) -> Result<(), SaveApiError> {
+        let player_game_data = &mut self.user_data_x_mut(index)?.player_game_data;
+        for ailment in StatusAilment::ALL {
+            *buildup_mut(player_game_data, ailment) = 0;
+        }
+        Ok(())
+    }
+}
+
+fn buildup_mut(player_game_data: &mut PlayerGameData, ailment: StatusAilment) -> &mut u32 {
+    match ailment {
+        StatusAilment::Poison => &mut player_game_data.poison_buildup,
+        StatusAilment::ScarletRot => &mut player_game_data.rot_buildup,
+        StatusAilment::Hemorrhage => &mut player_game_data.bleed_buildup,
+        StatusAilment::Deathblight => &mut player_game_data.death_buildup,
+        StatusAilment::Frostbite => &mut player_game_data.frost_buildup,
+        StatusAilment::Sleep => &mut player_game_data.sleep_buildup,
+        StatusAilment::Madness => &mut player_game_data.madness_buildup,
+    }
+}
@@ -0,0 +1,163 @@
+use super::{item::ItemCategory, save_reader::SaveReader, stat::Stat};
+use crate::{SaveApi, SaveApiError};
+
+/// Read access to the characters of a save.
+///
+/// Implemented by `SaveApi`, `SaveReader` and `MockSave`, so tools can take any of them and
+/// be unit tested without a save file. Characters are addressed by slot index, like on
+/// `SaveApi`.
+///
+/// # Example
+/// ```rust
+/// use er_save_lib::{SaveApi, SaveRead, SaveType};
+/// fn total_level(save: &impl SaveRead) -> u32 {
+///     (0..save.character_count())
+///         .filter_map(|index| save.level(index).ok())
+///         .sum()
+/// }
+/// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+/// assert_eq!(total_level(&save_api), total_level(&save_api.reader()));
+/// ```
+pub trait SaveRead {
+    /// Returns the number of character slots.
+    fn character_count(&self) -> usize;
+
+    /// Returns the name of the character at the specified index.
+    fn character_name(&self, index: usize) -> Result<String, SaveApiError>;
+
+    /// Returns the level of the character at the specified index.
+    fn level(&self, index: usize) -> Result<u32, SaveApiError>;
+
+    /// Returns the given stat of the character at the specified index.
+    fn get_stat(&self, index: usize, stat: Stat) -> Result<u32, SaveApiError>;
+
+    /// Returns the runes held by the character at the specified index.
+    fn runes(&self, index: usize) -> Result<u32, SaveApiError>;
+
+    /// Returns event flags by id and character index.
+    fn get_event_flag(&self, event_id: u32, index: usize) -> Result<bool, SaveApiError>;
+
+    /// Returns the category and id of every item the character at the specified index owns.
+    fn owned_items(&self, index: usize) -> Result<Vec<(ItemCategory, u32)>, SaveApiError>;
+}
+
+/// Write access to the characters of a save, on top of `SaveRead`.
+///
+/// # Example
+/// ```rust
+/// use er_save_lib::{SaveApi, SaveType, SaveWrite, Stat};
+/// fn respec(save: &mut impl SaveWrite, index: usize) -> Result<(), er_save_lib::SaveApiError> {
+///     for stat in Stat::ALL {
+///         save.set_stat(index, stat, 10)?;
+///     }
+///     save.set_level(index, 1)
+/// }
+/// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+/// respec(&mut save_api, 0).unwrap();
+/// assert_eq!(save_api.vigor(0).unwrap(), 10);
+/// ```
+pub trait SaveWrite: SaveRead {
+    /// Sets the name of the character at the specified index, see
+    /// `CharacterMut::set_character_name` for the names the game accepts.
+    fn set_character_name(&mut self, index: usize, name: &str) -> Result<(), SaveApiError>;
+
+    /// Sets the level of the character at the specified index.
+    fn set_level(&mut self, index: usize, level: u32) -> Result<(), SaveApiError>;
+
+    /// Sets the given stat of the character at the specified index.
+    fn set_stat(&mut self, index: usize, stat: Stat, value: u32) -> Result<(), SaveApiError>;
+
+    /// Sets the runes held by the character at the specified index.
+    fn set_runes(&mut self, index: usize, runes: u32) -> Result<(), SaveApiError>;
+
+    /// Sets the value of the specified event flag for the given character index.
+    fn set_event_flag(&mut self, event_id: u32, index: usize, on: bool)
+        -> Result<(), SaveApiError>;
+}
+
+impl SaveRead for SaveApi {
+    fn character_count(&self) -> usize {
+        SaveApi::character_count(self)
+    }
+
+    fn character_name(&self, index: usize) -> Result<String, SaveApiError> {
+        SaveApi::character_name(self, index)
+    }
+
+    fn level(&self, index: usize) -> Result<u32, SaveApiError> {
+        SaveApi::level(self, index)
+    }
+
+    fn get_stat(&self, index: usize, stat: Stat) -> Result<u32, SaveApiError> {
+        SaveApi::get_stat(self, index, stat)
+    }
+
+    fn runes(&self, index: usize) -> Result<u32, SaveApiError> {
+        SaveApi::runes(self, index)
+    }
+
+    fn get_event_flag(&self, event_id: u32, index: usize) -> Result<bool, SaveApiError> {
+        SaveApi::get_event_flag(self, event_id, index)
+    }
+
+    fn owned_items(&self, index: usize) -> Result<Vec<(ItemCategory, u32)>, SaveApiError> {
+        Ok(self.character(index)?.owned_items())
+    }
+}
+
+impl SaveWrite for SaveApi {
+    fn set_character_name(&mut self, index: usize, name: &str) -> Result<(), SaveApiError> {
+        SaveApi::set_character_name(self, index, name)
+    }
+
+    fn set_level(&mut self, index: usize, level: u32) -> Result<(), SaveApiError> {
+        SaveApi::set_level(self, index, level)
+    }
+
+    fn set_stat(&mut self, index: usize, stat: Stat, value: u32) -> Result<(), SaveApiError> {
+        SaveApi::set_stat(self, index, stat, value)
+    }
+
+    fn set_runes(&mut self, index: usize, runes: u32) -> Result<(), SaveApiError> {
+        SaveApi::set_runes(self, index, runes)
+    }
+
+    fn set_event_flag(
+        &mut self,
+        event_id: u32,
+        index: usize,
+        on: bool,
+    ) -> Result<(), SaveApiError> {
+        SaveApi::set_event_flag(self, event_id, index, on)
+    }
+}
+
+impl SaveRead for SaveReader {
+    fn character_count(&self) -> usize {
+        SaveReader::character_count(self)
+    }
+
+    fn character_name(&self, index: usize) -> Result<String, SaveApiError> {
+        Ok(self.character(index)?.character_name().to_string())
+    }
+
+    fn level(&self, index: usize) -> Result<u32, SaveApiError> {
+        Ok(self.character(index)?.level())
+    }
+
+    fn get_stat(&self, index: usize, stat: Stat) -> Result<u32, SaveApiError> {
+        Ok(self.character(index)?.stat(stat))
+    }
+
+    fn runes(&self, index: usize) -> Result<u32, SaveApiError> {
+        Ok(self.character(index)?.runes())
+    }
+
+    fn get_event_flag(&self, event_id: u32, index: usize) -> Result<bool, SaveApiError> {
+        SaveReader::get_event_flag(self, event_id, index)
+    }
+
+    fn owned_items(&self, index: usize) -> Result<Vec<(ItemCategory, u32)>, SaveApiError> {
+        Ok(self.character(index)?.owned_items())
+    }
+}
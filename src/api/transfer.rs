@@ -0,0 +1,257 @@
+use std::collections::HashSet;
+
+use super::{
+    inventory::{equipped_gaitem_handles, is_empty},
+    item::{self, ItemCategory},
+};
+use crate::{
+    save::user_data_x::{Gaitem, InvenotryItem, UserDataX},
+    SaveApi, SaveApiError,
+};
+
+/// Whether `SaveApi::transfer_items` takes the items from the source character.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum TransferMode {
+    /// The items are removed from the source character.
+    Move,
+    /// The source character keeps its items.
+    Copy,
+}
+
+/// Items to transfer with `SaveApi::transfer_items`.
+///
+/// `id` is the item id without the category bits, as returned by `Character::owned_items`,
+/// so weapons carry their upgrade level and affinity. For talismans and goods `quantity` is
+/// taken from the stacks holding the item. For weapons, armor and ashes of war it is the
+/// number of inventory slots, each holding a single item except for ammunition, whose
+/// stacks are transferred whole.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ItemSelector {
+    pub category: ItemCategory,
+    pub id: u32,
+    pub quantity: u32,
+    pub mode: TransferMode,
+}
+
+impl SaveApi {
+    /// Transfers items from the character at `src_index` to the character of `other` at
+    /// `dst_index`. Items are taken from the inventory first and then from the storage box,
+    /// and always arrive in the inventory.
+    ///
+    /// Weapons, armor and ashes of war get a new handle in the destination's gaitem map,
+    /// and the ash of war attached to a weapon is transferred along with it. Either every
+    /// selector is applied or, on error, neither save is changed.
+    ///
+    /// # Errors
+    /// - `SaveApiError::ItemNotOwned` if the source holds fewer than the selected quantity.
+    /// - `SaveApiError::ItemEquipped` when moving a weapon or armor the source has equipped.
+    /// - `SaveApiError::StackFull` if the destination's stack of a talisman or goods would
+    ///   go past what the game allows, 1 for talismans and 999 for goods.
+    /// - `SaveApiError::InventoryFull` if the destination's inventory or gaitem map is full.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::item::ItemCategory;
+    /// use er_save_lib::transfer::{ItemSelector, TransferMode};
+    /// use er_save_lib::{MockCharacter, MockSave, SaveApi, SaveRead, SaveType};
+    /// let mut mule = SaveApi::from_mock(&MockSave::new(vec![MockCharacter {
+    ///     items: vec![(ItemCategory::Talisman, 1000), (ItemCategory::Goods, 10010)],
+    ///     ..Default::default()
+    /// }]))
+    /// .unwrap();
+    /// let mut main = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// let items = [
+    ///     ItemSelector {
+    ///         category: ItemCategory::Talisman,
+    ///         id: 1000,
+    ///         quantity: 1,
+    ///         mode: TransferMode::Move,
+    ///     },
+    ///     ItemSelector {
+    ///         category: ItemCategory::Goods,
+    ///         id: 10010,
+    ///         quantity: 1,
+    ///         mode: TransferMode::Copy,
+    ///     },
+    /// ];
+    /// mule.transfer_items(&mut main, 0, 0, &items).unwrap();
+    /// assert_eq!(mule.owned_items(0).unwrap(), vec![(ItemCategory::Goods, 10010)]);
+    /// let owned = main.owned_items(0).unwrap();
+    /// assert!(owned.contains(&(ItemCategory::Talisman, 1000)));
+    /// assert!(owned.contains(&(ItemCategory::Goods, 10010)));
+    /// ```
+    pub fn transfer_items(
+        &mut self,
+        other: &mut SaveApi,
+        src_index: usize,
+        dst_index: usize,
+        items: &[ItemSelector],
+    ) -> Result<(), SaveApiError> {
+        // Both slots are edited on copies, so an error halfway leaves the saves untouched
+        let mut source = self.user_data_x(src_index)?.clone();
+        let mut target = other.user_data_x(dst_index)?.clone();
+        for selector in items {
+            transfer(&mut source, &mut target, selector)?;
+        }
+
+        *other.user_data_x_mut(dst_index)? = target;
+        if items
+            .iter()
+            .any(|selector| selector.mode == TransferMode::Move)
+        {
+            *self.user_data_x_mut(src_index)? = source;
+        }
+        Ok(())
+    }
+}
+
+fn transfer(
+    source: &mut UserDataX,
+    target: &mut UserDataX,
+    selector: &ItemSelector,
+) -> Result<(), SaveApiError> {
+    let ItemSelector {
+        category,
+        id,
+        quantity,
+        mode,
+    } = *selector;
+    match category {
+        ItemCategory::Talisman | ItemCategory::Goods => {
+            let (handle, max) = match category {
+                ItemCategory::Talisman => (0xa0000000 | id, 1),
+                _ => (0xb0000000 | id, 999),
+            };
+            let slots = || owned_slots(source).filter(|(_, item)| item.gaitem_handle == handle);
+            let owned: u32 = slots().map(|(_, item)| item.quantity).sum();
+            if owned < quantity {
+                return Err(SaveApiError::ItemNotOwned(category, id));
+            }
+            if quantity == 0 {
+                return Ok(());
+            }
+            let key_item = slots().any(|(key_item, _)| key_item);
+
+            let held: u32 = owned_slots(target)
+                .filter(|(_, item)| item.gaitem_handle == handle)
+                .map(|(_, item)| item.quantity)
+                .sum();
+            if held + quantity > max {
+                return Err(SaveApiError::StackFull(id));
+            }
+            if !item::add_held_item(target, handle, quantity, key_item) {
+                return Err(SaveApiError::InventoryFull(id));
+            }
+            if mode == TransferMode::Move {
+                item::take_item(source, handle, quantity);
+            }
+        }
+        ItemCategory::Weapon | ItemCategory::Armor | ItemCategory::AshOfWar => {
+            let slots: Vec<(u32, u32)> = owned_slots(source)
+                .map(|(_, item)| (item.gaitem_handle, item.quantity))
+                .filter(|(handle, _)| item::resolve_handle(source, *handle) == Some((category, id)))
+                .take(quantity as usize)
+                .collect();
+            if slots.len() < quantity as usize {
+                return Err(SaveApiError::ItemNotOwned(category, id));
+            }
+            let equipped = equipped_gaitem_handles(source);
+            if mode == TransferMode::Move && slots.iter().any(|(h, _)| equipped.contains(h)) {
+                return Err(SaveApiError::ItemEquipped(id));
+            }
+
+            for (handle, stack) in slots {
+                let new_handle =
+                    copy_gaitem(source, target, handle).ok_or(SaveApiError::InventoryFull(id))?;
+                if !item::add_held_item(target, new_handle, stack, false) {
+                    return Err(SaveApiError::InventoryFull(id));
+                }
+                if mode == TransferMode::Move {
+                    item::take_item(source, handle, stack);
+                    remove_gaitem(source, handle);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+// Slots in use of the inventory and then the storage box, with whether they hold a key item
+fn owned_slots(user_data_x: &UserDataX) -> impl Iterator<Item = (bool, &InvenotryItem)> {
+    [
+        &user_data_x.inventory_held,
+        &user_data_x.inventory_storage_box,
+    ]
+    .into_iter()
+    .flat_map(|inventory| {
+        let common = inventory.common_items.iter().map(|item| (false, item));
+        common.chain(inventory.key_items.iter().map(|item| (true, item)))
+    })
+    .filter(|(_, item)| !is_empty(item.gaitem_handle) && item.quantity > 0)
+}
+
+// Copies the gaitem of `handle`, and the ash of war attached to it, into free entries of
+// the target's map. New handles keep the upper half of the source one and take the
+// lowest counter that no gaitem of the target uses. Returns None when the map is full.
+fn copy_gaitem(source: &UserDataX, target: &mut UserDataX, handle: u32) -> Option<u32> {
+    let mut gaitem = find_gaitem(source, handle)?.clone();
+    if let Some(gem_handle) = gaitem.gem_gaitem_handle {
+        if let Some(gem) = find_gaitem(source, gem_handle as u32) {
+            let new_gem_handle = insert_gaitem(target, gem.clone())?;
+            gaitem.gem_gaitem_handle = Some(new_gem_handle as i32);
+        }
+    }
+    insert_gaitem(target, gaitem)
+}
+
+fn insert_gaitem(target: &mut UserDataX, mut gaitem: Gaitem) -> Option<u32> {
+    let used: HashSet<u32> = target
+        .gaitem_map
+        .iter()
+        .filter(|gaitem| !is_empty(gaitem.gaitem_handle))
+        .map(|gaitem| gaitem.gaitem_handle & 0xffff)
+        .collect();
+    let counter = (1..=0xffff).find(|counter| !used.contains(counter))?;
+    let entry = target
+        .gaitem_map
+        .iter_mut()
+        .find(|gaitem| is_empty(gaitem.gaitem_handle))?;
+    gaitem.gaitem_handle = (gaitem.gaitem_handle & 0xffff0000) | counter;
+    *entry = gaitem;
+    Some(entry.gaitem_handle)
+}
+
+// Frees the gaitem of `handle` and of the ash of war attached to it
+fn remove_gaitem(user_data_x: &mut UserDataX, handle: u32) {
+    let gem_handle = find_gaitem(user_data_x, handle).and_then(|gaitem| gaitem.gem_gaitem_handle);
+    for handle in [Some(handle), gem_handle.map(|gem| gem as u32)]
+        .into_iter()
+        .flatten()
+        .filter(|handle| !is_empty(*handle))
+    {
+        if let Some(entry) = user_data_x
+            .gaitem_map
+            .iter_mut()
+            .find(|gaitem| gaitem.gaitem_handle == handle)
+        {
+            *entry = Gaitem {
+                gaitem_handle: 0,
+                item_id: 0,
+                unk0x10: None,
+                unk0x14: None,
+                gem_gaitem_handle: None,
+                unk0x1c: None,
+            };
+        }
+    }
+}
+
+fn find_gaitem(user_data_x: &UserDataX, handle: u32) -> Option<&Gaitem> {
+    if is_empty(handle) {
+        return None;
+    }
+    user_data_x
+        .gaitem_map
+        .iter()
+        .find(|gaitem| gaitem.gaitem_handle == handle)
+}
@@ -0,0 +1,115 @@
+use std::{
+    ffi::OsString,
+    path::{Path, PathBuf},
+    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+
+use crate::{SaveApi, SaveApiError};
+
+/// Re-parses a save file whenever it is written and hands the new `SaveApi` to a callback.
+///
+/// The game writes its save in several steps, so every burst of writes is waited out
+/// before the file is read. A file that still fails to parse is skipped, the next write
+/// is picked up as usual. The parent directory is watched rather than the file, which
+/// keeps working when the file is replaced instead of written in place.
+///
+/// Watching stops when the `SaveWatcher` is dropped.
+///
+/// # Example
+/// ```rust,no_run
+/// use er_save_lib::watch::SaveWatcher;
+/// let watcher = SaveWatcher::new("./test/ER0000.sl2", |save_api| {
+///     println!("Level {}", save_api.level(0).unwrap());
+/// })
+/// .unwrap();
+/// std::thread::park();
+/// ```
+pub struct SaveWatcher {
+    _watcher: RecommendedWatcher,
+}
+
+impl SaveWatcher {
+    /// Time without writes after which the game is considered done writing the save.
+    pub const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(500);
+
+    /// Watches the save file at `path`, waiting `DEFAULT_DEBOUNCE` after the last write
+    /// before parsing it.
+    pub fn new<F>(path: impl AsRef<Path>, callback: F) -> Result<Self, SaveApiError>
+    where
+        F: FnMut(SaveApi) + Send + 'static,
+    {
+        Self::with_debounce(path, Self::DEFAULT_DEBOUNCE, callback)
+    }
+
+    /// Watches the save file at `path`, waiting `debounce` after the last write before
+    /// parsing it.
+    pub fn with_debounce<F>(
+        path: impl AsRef<Path>,
+        debounce: Duration,
+        callback: F,
+    ) -> Result<Self, SaveApiError>
+    where
+        F: FnMut(SaveApi) + Send + 'static,
+    {
+        let path = path.as_ref().to_path_buf();
+        let file_name = path
+            .file_name()
+            .ok_or_else(|| std::io::Error::from(std::io::ErrorKind::InvalidInput))?
+            .to_os_string();
+        let directory = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+
+        let (sender, events) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(move |event| {
+            // The receiving thread only stops once the watcher is dropped
+            let _ = sender.send(event);
+        })?;
+        watcher.watch(&directory, RecursiveMode::NonRecursive)?;
+
+        thread::spawn(move || reload(path, file_name, events, debounce, callback));
+
+        Ok(Self { _watcher: watcher })
+    }
+}
+
+// Runs until the watcher, and with it the sending end of `events`, is dropped
+fn reload<F: FnMut(SaveApi)>(
+    path: PathBuf,
+    file_name: OsString,
+    events: Receiver<notify::Result<Event>>,
+    debounce: Duration,
+    mut callback: F,
+) {
+    while let Ok(event) = events.recv() {
+        if !writes_to(&event, &file_name) {
+            continue;
+        }
+        loop {
+            match events.recv_timeout(debounce) {
+                Ok(_) => continue,
+                Err(RecvTimeoutError::Timeout) => break,
+                Err(RecvTimeoutError::Disconnected) => return,
+            }
+        }
+        if let Ok(save_api) = SaveApi::from_path(&path) {
+            callback(save_api);
+        }
+    }
+}
+
+fn writes_to(event: &notify::Result<Event>, file_name: &OsString) -> bool {
+    let Ok(event) = event else {
+        return false;
+    };
+    (event.kind.is_create() || event.kind.is_modify())
+        && event
+            .paths
+            .iter()
+            .any(|path| path.file_name() == Some(file_name.as_os_str()))
+}
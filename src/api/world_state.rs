@@ -0,0 +1,96 @@
+use crate::{SaveApi, SaveApiError};
+
+/// In-game time of a character's world.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TimeOfDay {
+    pub hour: u32,
+    pub minute: u32,
+    pub second: u32,
+}
+
+/// Weather of the area a character was saved in.
+///
+/// The ids are those used by the `WeatherParam` and `WeatherLotParam` params.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Weather {
+    /// Area the weather applies to.
+    pub area_id: u16,
+    pub weather_type: u16,
+    /// Weather timer, in the unit the game stores it.
+    pub timer: u32,
+}
+
+impl TimeOfDay {
+    /// Returns whether the time fits on a 24 hour clock.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::world_state::TimeOfDay;
+    /// assert!(TimeOfDay { hour: 23, minute: 59, second: 59 }.is_valid());
+    /// assert!(!TimeOfDay { hour: 24, minute: 0, second: 0 }.is_valid());
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        self.hour < 24 && self.minute < 60 && self.second < 60
+    }
+}
+
+impl SaveApi {
+    /// Returns the in-game time of the character at the specified index.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// let time = save_api.time_of_day(0).unwrap();
+    /// println!("{:02}:{:02}", time.hour, time.minute);
+    /// ```
+    pub fn time_of_day(&self, index: usize) -> Result<TimeOfDay, SaveApiError> {
+        Ok(self.character(index)?.time_of_day())
+    }
+
+    /// Sets the in-game time of the character at the specified index. The game picks it
+    /// up the next time the character is loaded.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::world_state::TimeOfDay;
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// let night = TimeOfDay { hour: 22, minute: 0, second: 0 };
+    /// save_api.set_time_of_day(0, night).unwrap();
+    /// assert_eq!(save_api.time_of_day(0).unwrap(), night);
+    /// let invalid = TimeOfDay { hour: 25, minute: 0, second: 0 };
+    /// assert!(save_api.set_time_of_day(0, invalid).is_err());
+    /// ```
+    pub fn set_time_of_day(&mut self, index: usize, time: TimeOfDay) -> Result<(), SaveApiError> {
+        self.character_mut(index)?.set_time_of_day(time)
+    }
+
+    /// Returns the weather of the area the character at the specified index was saved in.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// let weather = save_api.weather(0).unwrap();
+    /// ```
+    pub fn weather(&self, index: usize) -> Result<Weather, SaveApiError> {
+        Ok(self.character(index)?.weather())
+    }
+
+    /// Sets the weather of the area the character at the specified index was saved in.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    /// let mut weather = save_api.weather(0).unwrap();
+    /// weather.weather_type = 0;
+    /// weather.timer = 0;
+    /// save_api.set_weather(0, weather).unwrap();
+    /// assert_eq!(save_api.weather(0).unwrap(), weather);
+    /// ```
+    pub fn set_weather(&mut self, index: usize, weather: Weather) -> Result<(), SaveApiError> {
+        self.character_mut(index)?.set_weather(weather)
+    }
+}
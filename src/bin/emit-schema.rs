@@ -0,0 +1,17 @@
+//! Prints the save layout for hex tools.
+//!
+//! `cargo run --features schema --bin emit-schema -- kaitai > er_save.ksy`
+//! `cargo run --features schema --bin emit-schema -- 010 > er_save.bt`
+
+use er_save_lib::schema;
+
+fn main() {
+    match std::env::args().nth(1).as_deref() {
+        Some("kaitai") | None => print!("{}", schema::kaitai()),
+        Some("010") => print!("{}", schema::template_010()),
+        Some(format) => {
+            eprintln!("Unknown format {format}, expected kaitai or 010");
+            std::process::exit(2);
+        }
+    }
+}
@@ -0,0 +1,105 @@
+//! Command line tools for saves.
+//!
+//! `cargo run --bin er-save -- flagdiff old.sl2 new.sl2 --slot 0` prints the event flags
+//! and the bytes of the unknown regions that differ between two copies of a save, taken
+//! before and after an event.
+
+use std::{ops::Range, process::exit};
+
+use er_save_lib::SaveApi;
+
+const USAGE: &str = "Usage: er-save flagdiff <old save> <new save> [--slot <index>]";
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("flagdiff") => flagdiff(&args[1..]),
+        Some(command) => fail(&format!("Unknown command {command}")),
+        None => fail("Missing command"),
+    }
+}
+
+fn flagdiff(args: &[String]) {
+    let mut paths = Vec::new();
+    let mut slot = 0;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg == "--slot" {
+            slot = match args.next().map(|index| index.parse()) {
+                Some(Ok(index)) => index,
+                _ => fail("--slot expects a character index"),
+            };
+        } else {
+            paths.push(arg);
+        }
+    }
+    let [old, new] = paths[..] else {
+        fail("flagdiff expects two saves");
+    };
+    let old = open(old);
+    let new = open(new);
+
+    let flags = old
+        .event_flag_diff(slot, &new)
+        .unwrap_or_else(|err| fail(&err.to_string()));
+    println!("{} event flags changed", flags.len());
+    for flag in &flags {
+        let sign = if flag.on { '+' } else { '-' };
+        #[cfg(feature = "names")]
+        if let Some(name) = flag.boss_name() {
+            println!("{sign} {} ({name} defeated)", flag.event_id);
+            continue;
+        }
+        println!("{sign} {}", flag.event_id);
+    }
+
+    let (Ok(before), Ok(after)) = (old.character(slot), new.character(slot)) else {
+        fail(&format!("No character at slot {slot}"));
+    };
+    let regions = before
+        .opaque_regions()
+        .into_iter()
+        .zip(after.opaque_regions());
+    let mut ranges = Vec::new();
+    for ((region, before), (_, after)) in regions {
+        for range in changed_ranges(before, after) {
+            ranges.push((region, range.start, &before[range.clone()], &after[range]));
+        }
+    }
+    println!("{} byte ranges of unknown regions changed", ranges.len());
+    for (region, offset, before, after) in ranges {
+        println!("{region}+{offset:#x}: {} -> {}", hex(before), hex(after));
+    }
+}
+
+fn open(path: &str) -> SaveApi {
+    SaveApi::from_path(path).unwrap_or_else(|err| fail(&format!("Failed to read {path}: {err}")))
+}
+
+// Runs of bytes that differ
+fn changed_ranges(before: &[u8], after: &[u8]) -> Vec<Range<usize>> {
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    for (offset, (a, b)) in before.iter().zip(after).enumerate() {
+        if a == b {
+            continue;
+        }
+        match ranges.last_mut() {
+            Some(range) if range.end == offset => range.end += 1,
+            _ => ranges.push(offset..offset + 1),
+        }
+    }
+    ranges
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("{message}\n{USAGE}");
+    exit(2);
+}
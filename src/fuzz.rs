@@ -0,0 +1,102 @@
+//! Entry points for the cargo-fuzz targets in `fuzz/`.
+//!
+//! These are not part of the public API. They only exist so the fuzz crate can reach the
+//! parsers that aren't exported. Each function feeds arbitrary bytes to a parser and throws
+//! the result away: a malformed input must come back as an error, never as a panic.
+
+#[cfg(feature = "regulation")]
+use std::io::Cursor;
+
+#[cfg(feature = "regulation")]
+use deku::{reader::Reader, DekuError, DekuReader};
+
+#[cfg(feature = "regulation")]
+use crate::regulation::{
+    bnd4::bnd4::BND4, dcx_zstd::dcx_zstd::DCXZSTD, params::params::Params, regulation::Regulation,
+};
+#[cfg(feature = "regulation")]
+use crate::save::save::ParseOptions;
+use crate::save::save::Save;
+
+/// Parses `data` as a whole save file.
+pub fn save_from_slice(data: &[u8]) {
+    let _ = Save::from_slice(data);
+}
+
+/// Decrypts and parses `data` as a regulation blob, as found inside a save.
+#[cfg(feature = "regulation")]
+pub fn regulation_from_slice(data: &[u8]) {
+    let _ = Regulation::from_slice(&mut data.to_vec());
+}
+
+/// Decompresses and parses `data` as an already decrypted regulation.
+///
+/// Random bytes almost never survive AES decryption with a valid DCX header, so this skips
+/// straight to the decompressor and the BND4/param readers behind it.
+#[cfg(feature = "regulation")]
+pub fn dcx_zstd_from_slice(data: &[u8]) {
+    let _ = read_dcx_zstd(data, ParseOptions::default().max_alloc);
+}
+
+#[cfg(feature = "regulation")]
+fn read_dcx_zstd(data: &[u8], max_alloc: usize) -> Result<DCXZSTD<BND4<Params>>, DekuError> {
+    let mut cursor = Cursor::new(data);
+    let mut reader = Reader::new(&mut cursor);
+    DCXZSTD::from_reader_with_ctx(&mut reader, max_alloc)
+}
+
+#[cfg(feature = "regulation")]
+#[test]
+fn truncated_save_does_not_panic() {
+    let bytes = crate::SaveApi::new_empty(crate::SaveType::PC)
+        .unwrap()
+        .to_vec()
+        .unwrap();
+    for len in [
+        0,
+        3,
+        0x2fc,
+        0x300 + 0x1000,
+        0x300 + 0x280010,
+        bytes.len() - 1,
+    ] {
+        assert!(Save::from_slice(&bytes[..len]).is_err());
+    }
+}
+
+#[cfg(feature = "regulation")]
+#[test]
+fn bogus_dcx_input_is_rejected() {
+    // A DCX header up to and including compressed_size, with the rest left zeroed.
+    let header = |compressed_size: i32| {
+        let mut bytes = vec![0u8; 0x4c];
+        bytes[..4].copy_from_slice(b"DCX\0");
+        for (offset, value) in [(0x4, 0x11000), (0x8, 0x18), (0xc, 0x24), (0x10, 0x44)] {
+            bytes[offset..offset + 4].copy_from_slice(&i32::to_be_bytes(value));
+        }
+        bytes[0x14..0x18].copy_from_slice(&i32::to_be_bytes(0x4c));
+        bytes[0x18..0x1c].copy_from_slice(b"DCS\0");
+        bytes[0x20..0x24].copy_from_slice(&compressed_size.to_be_bytes());
+        bytes[0x24..0x28].copy_from_slice(b"DCP\0");
+        bytes[0x28..0x2c].copy_from_slice(b"ZSTD");
+        bytes[0x2c..0x30].copy_from_slice(&i32::to_be_bytes(0x20));
+        bytes[0x40..0x44].copy_from_slice(&i32::to_be_bytes(0x00010100));
+        bytes[0x44..0x48].copy_from_slice(b"DCA\0");
+        bytes[0x48..0x4c].copy_from_slice(&i32::to_be_bytes(8));
+        bytes
+    };
+    for compressed_size in [-1, i32::MIN, i32::MAX] {
+        let bytes = header(compressed_size);
+        assert!(read_dcx_zstd(&bytes, ParseOptions::default().max_alloc).is_err());
+    }
+
+    // Decompressing past max_alloc stops at the limit instead of inflating the whole payload.
+    let payload = zstd::encode_all(&[0u8; 0x10000][..], 0).unwrap();
+    let mut bytes = header(payload.len() as i32);
+    bytes.extend(payload);
+    assert!(matches!(
+        read_dcx_zstd(&bytes, 0x1000),
+        Err(DekuError::Io(std::io::ErrorKind::OutOfMemory))
+    ));
+    assert!(Regulation::from_slice(&mut [0; 8]).is_err());
+}
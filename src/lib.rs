@@ -1,9 +1,98 @@
 mod api;
+#[doc(hidden)]
+pub mod fuzz;
 mod regulation;
 mod save;
+#[cfg(feature = "schema")]
+pub mod schema;
+mod trace;
+#[cfg(feature = "names")]
+pub use api::achievements;
+pub use api::aggregate_stats::AggregateStats;
+pub use api::ammunition;
+pub use api::archetype::{Archetype, Gender, UnknownValue};
+pub use api::arm_style;
+#[cfg(feature = "params")]
+pub use api::armor_sets::ArmorSet;
+pub use api::ash_of_war;
+#[cfg(feature = "params")]
+pub use api::attack_power;
+pub use api::audit;
+pub use api::build_template;
+pub use api::character::{
+    Character, CharacterMut, CharacterNameError, NamePolicy, MAX_NAME_LENGTH_PC,
+    MAX_NAME_LENGTH_PLAYSTATION,
+};
+pub use api::cookbooks;
+pub use api::counters::{Bloodstain, Counters};
+pub use api::event_flags::{EventFlagsApi, FlagChange, FlagLocation};
+#[cfg(feature = "params")]
+pub use api::defenses;
+#[cfg(feature = "params")]
+pub use api::equip_load;
+pub use api::export;
+#[cfg(feature = "params")]
+pub use api::formulas;
+#[cfg(feature = "params")]
+pub use api::illegal_items;
+#[cfg(feature = "interop")]
+pub use api::interop;
+pub use api::inventory;
+#[cfg(feature = "schemars")]
+pub use api::json_schema;
+pub use api::item;
+pub use api::key_items;
+pub use api::limited_resources;
+pub use api::manifest;
+pub use api::map_reveal;
+pub use api::matchmaking;
+#[cfg(feature = "meta")]
+pub use api::meta;
+pub use api::mock::{MockCharacter, MockSave};
+#[cfg(feature = "params")]
+pub use api::param_provider::ParamProvider;
+#[cfg(feature = "regulation")]
+pub use api::paramdef;
+pub use api::practice;
+pub use api::profile::ProfileSummary;
+pub use api::quick_items;
+pub use api::quick_stats::QuickStats;
+pub use api::region;
+pub use api::region::{Region, UnknownRegion};
+#[cfg(feature = "regulation")]
+pub use api::regulation_api;
+#[cfg(feature = "names")]
+pub use api::report;
+#[cfg(feature = "params")]
+pub use api::sanitize;
+pub use api::save_api::ErrorCategory;
 pub use api::save_api::SaveApi;
 pub use api::save_api::SaveApiError;
+pub use api::save_api::SaveSnapshot;
 pub use api::save_api::SaveType;
+pub use api::save_api::SlotIntegrity;
+pub use api::save_api::SlotState;
+pub use api::save_reader::SaveReader;
+pub use api::save_writer::SaveWriter;
+pub use api::scan;
+pub use api::settings::Settings;
+pub use api::sp_effects;
+pub use api::starting_gift::StartingGift;
+pub use api::stat::Stat;
+pub use api::status_buildup;
+pub use api::traits::{SaveRead, SaveWrite};
+pub use api::transfer;
+#[cfg(feature = "notify")]
+pub use api::watch;
+pub use api::world_state;
+pub use regulation::bnd4::archive as bnd4;
+#[cfg(feature = "params")]
 pub use regulation::params::param_structs::*;
+pub use save::game_profile::GameProfile;
+pub use save::parse_trace::{ParseTrace, TracedSection};
+pub use save::save::ParseOptions;
 pub use save::save::Save;
+pub use save::save::SaveEntry;
+pub use save::save::SaveParseError;
+pub use save::save::SaveSection;
 pub use api::save_api::save_data_api;
@@ -0,0 +1,346 @@
+use std::io::Cursor;
+
+use deku::{reader::Reader, writer::Writer, DekuError, DekuReader, DekuWriter};
+use encoding_rs::SHIFT_JIS;
+
+pub use super::bnd4::BND4ParseError;
+use super::{
+    file_header::BND4FileHeader,
+    flags::{FileFlags, Format},
+    header::BND4Header,
+};
+
+/// A BND4 archive: the container format of Elden Ring saves (`.sl2`), the regulation and
+/// most other FromSoftware binders.
+///
+/// Entries are kept as they are stored. Compressed or encrypted entries (Dark Souls III
+/// saves encrypt every entry) have to be decoded by the caller. Only little endian
+/// archives with a numeric version, which covers every binder read by this crate, are
+/// supported.
+///
+/// # Example
+/// ```rust
+/// use er_save_lib::bnd4::Bnd4;
+/// use er_save_lib::{SaveApi, SaveType};
+/// let bytes = SaveApi::new_empty(SaveType::PC).unwrap().to_vec().unwrap();
+/// let archive = Bnd4::from_slice(&bytes).unwrap();
+/// assert_eq!(archive.entries.len(), 12);
+/// assert_eq!(archive.entries[10].name, "USER_DATA010");
+/// assert_eq!(archive.to_vec().unwrap(), bytes);
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct Bnd4 {
+    pub(crate) header: BND4Header,
+    pub entries: Vec<Bnd4Entry>,
+}
+
+/// A single file in a `Bnd4` archive.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Bnd4Entry {
+    /// Only stored when the archive format has ids.
+    pub id: i32,
+    /// Only stored when the archive format has names.
+    pub name: String,
+    /// Raw file flags. Bit 0 marks a compressed entry.
+    pub flags: u8,
+    /// Size of the data once decompressed. Only stored when the archive format has
+    /// compression.
+    pub uncompressed_size: i64,
+    pub data: Vec<u8>,
+}
+
+impl Bnd4Entry {
+    /// Creates an uncompressed entry.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::bnd4::Bnd4Entry;
+    /// let entry = Bnd4Entry::new(0, "USER_DATA012", vec![0; 0x10]);
+    /// ```
+    pub fn new(id: i32, name: &str, data: Vec<u8>) -> Self {
+        Bnd4Entry {
+            id,
+            name: name.to_string(),
+            flags: FileFlags::Flag1 as u8,
+            uncompressed_size: data.len() as i64,
+            data,
+        }
+    }
+
+    /// Returns true when the entry data is compressed.
+    pub fn is_compressed(&self) -> bool {
+        self.flags & FileFlags::Compressed as u8 != 0
+    }
+}
+
+impl Bnd4 {
+    /// Creates an empty archive.
+    ///
+    /// `format` holds the BND4 format flags: big endian (0x1), ids (0x2), names (0x4 or
+    /// 0x8), long offsets (0x10) and compression (0x20). Saves use names only, with
+    /// `unicode` names.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::bnd4::{Bnd4, Bnd4Entry};
+    /// let mut archive = Bnd4::new(1, true, 0x4);
+    /// archive.entries.push(Bnd4Entry::new(0, "USER_DATA000", vec![1, 2, 3]));
+    /// let bytes = archive.to_vec().unwrap();
+    /// assert_eq!(Bnd4::from_slice(&bytes).unwrap().entries, archive.entries);
+    /// ```
+    pub fn new(version: u32, unicode: bool, format: u8) -> Self {
+        Bnd4 {
+            header: BND4Header::new(version, unicode, format, 0),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Parses an archive from a slice of bytes.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::bnd4::Bnd4;
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let bytes = SaveApi::new_empty(SaveType::PC).unwrap().to_vec().unwrap();
+    /// for entry in Bnd4::from_slice(&bytes).unwrap().entries {
+    ///     println!("{}: {} bytes", entry.name, entry.data.len());
+    /// }
+    /// ```
+    pub fn from_slice(bytes: &[u8]) -> Result<Self, BND4ParseError> {
+        let mut cursor = Cursor::new(bytes);
+        let mut reader = Reader::new(&mut cursor);
+        let header = BND4Header::from_reader_with_ctx(&mut reader, ())?;
+        let file_headers = (0..header.file_count)
+            .map(|_| {
+                BND4FileHeader::from_reader_with_ctx(
+                    &mut reader,
+                    (header.format, header.bit_big_endian),
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let has_names = header.format & (Format::Names1 as u8 | Format::Names2 as u8) != 0;
+        let has_compression = header.format & Format::Compression as u8 != 0;
+        let entries = file_headers
+            .iter()
+            .enumerate()
+            .map(|(index, file_header)| {
+                let name = if has_names {
+                    read_name(bytes, file_header.name_offset, header.unicode)
+                        .ok_or(BND4ParseError::EntryOutOfBounds(index))?
+                } else {
+                    String::new()
+                };
+                let data = usize::try_from(file_header.data_offset)
+                    .ok()
+                    .zip(usize::try_from(file_header.compressed_size).ok())
+                    .and_then(|(offset, size)| bytes.get(offset..offset.checked_add(size)?))
+                    .ok_or(BND4ParseError::EntryOutOfBounds(index))?;
+                Ok(Bnd4Entry {
+                    id: file_header.id,
+                    name,
+                    flags: file_header.file_flags,
+                    uncompressed_size: if has_compression {
+                        file_header.uncompressed_size
+                    } else {
+                        data.len() as i64
+                    },
+                    data: data.to_vec(),
+                })
+            })
+            .collect::<Result<Vec<_>, BND4ParseError>>()?;
+
+        Ok(Bnd4 { header, entries })
+    }
+
+    /// Writes the archive to a byte vector.
+    ///
+    /// Headers, names and the hash table (for archives that have one) are laid out again,
+    /// so entries can be added, removed or resized freely.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::bnd4::Bnd4;
+    /// use er_save_lib::{SaveApi, SaveType};
+    /// let bytes = SaveApi::new_empty(SaveType::PC).unwrap().to_vec().unwrap();
+    /// let mut archive = Bnd4::from_slice(&bytes).unwrap();
+    /// archive.entries.truncate(1);
+    /// let bytes = archive.to_vec().unwrap();
+    /// ```
+    pub fn to_vec(&self) -> Result<Vec<u8>, DekuError> {
+        let format = self.header.format;
+        let has_names = format & (Format::Names1 as u8 | Format::Names2 as u8) != 0;
+        let file_header_size = BND4FileHeader::size(format);
+        let file_count = i32::try_from(self.entries.len())
+            .map_err(|_| DekuError::InvalidParam("Too many BND4 entries".into()))?;
+
+        // Names follow the file headers
+        let names_offset = 0x40 + file_header_size * i64::from(file_count);
+        let mut names = Vec::new();
+        let mut name_offsets = Vec::with_capacity(self.entries.len());
+        for entry in &self.entries {
+            name_offsets.push(names_offset + names.len() as i64);
+            if has_names {
+                names.extend(encode_name(&entry.name, self.header.unicode));
+            }
+        }
+
+        let mut headers_end = names_offset + names.len() as i64;
+        let hash_table = if self.header.extended == 4 {
+            let hashtable_offset = align(headers_end, 8);
+            let hash_table = hash_table(&self.entries, hashtable_offset);
+            headers_end = hashtable_offset + hash_table.len() as i64;
+            Some((hashtable_offset, hash_table))
+        } else {
+            None
+        };
+        let headers_end = align(headers_end, 0x10);
+
+        // Data follows the headers, every entry aligned to 0x10
+        let mut data_offsets = Vec::with_capacity(self.entries.len());
+        let mut end = headers_end;
+        for entry in &self.entries {
+            if !entry.data.is_empty() {
+                end = align(end, 0x10);
+            }
+            data_offsets.push(end);
+            end += entry.data.len() as i64;
+        }
+
+        let mut header = self.header.clone();
+        header.file_count = file_count;
+        header.file_header_size = file_header_size;
+        header.headers_end = headers_end as u64;
+        header.hashtable_offset = hash_table.as_ref().map_or(0, |(offset, _)| *offset as u64);
+
+        let mut out = Vec::with_capacity(end as usize);
+        let mut cursor = Cursor::new(&mut out);
+        let mut writer = Writer::new(&mut cursor);
+        header.to_writer(&mut writer, ())?;
+        for ((entry, data_offset), name_offset) in
+            self.entries.iter().zip(&data_offsets).zip(name_offsets)
+        {
+            let name_offset = i32::try_from(name_offset)
+                .map_err(|_| DekuError::InvalidParam("BND4 names too long".into()))?;
+            BND4FileHeader::new(
+                entry.flags,
+                entry.data.len() as i64,
+                entry.uncompressed_size,
+                *data_offset,
+                entry.id,
+                name_offset,
+            )
+            .to_writer(&mut writer, (format, header.bit_big_endian))?;
+        }
+        writer.finalize()?;
+
+        out.extend(names);
+        if let Some((hashtable_offset, hash_table)) = hash_table {
+            out.resize(hashtable_offset as usize, 0);
+            out.extend(hash_table);
+        }
+        for (entry, data_offset) in self.entries.iter().zip(data_offsets) {
+            out.resize(data_offset as usize, 0);
+            out.extend(&entry.data);
+        }
+        Ok(out)
+    }
+
+    /// Returns the archive version.
+    pub fn version(&self) -> u32 {
+        self.header.version
+    }
+
+    /// Returns the format flags, see `Bnd4::new`.
+    pub fn format(&self) -> u8 {
+        self.header.format
+    }
+
+    /// Returns true when entry names are stored as UTF-16 instead of Shift JIS.
+    pub fn unicode(&self) -> bool {
+        self.header.unicode
+    }
+}
+
+fn align(offset: i64, alignment: i64) -> i64 {
+    (offset + alignment - 1) / alignment * alignment
+}
+
+fn read_name(bytes: &[u8], offset: i32, unicode: bool) -> Option<String> {
+    let bytes = bytes.get(usize::try_from(offset).ok()?..)?;
+    if unicode {
+        let chars = bytes
+            .chunks_exact(2)
+            .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+            .take_while(|char| *char != 0)
+            .collect::<Vec<_>>();
+        Some(String::from_utf16_lossy(&chars))
+    } else {
+        let end = bytes.iter().position(|byte| *byte == 0)?;
+        Some(SHIFT_JIS.decode(&bytes[..end]).0.to_string())
+    }
+}
+
+fn encode_name(name: &str, unicode: bool) -> Vec<u8> {
+    if unicode {
+        name.encode_utf16()
+            .chain([0])
+            .flat_map(|char| char.to_le_bytes())
+            .collect()
+    } else {
+        let mut bytes = SHIFT_JIS.encode(name).0.to_vec();
+        bytes.push(0);
+        bytes
+    }
+}
+
+// Hash table of extended binders. Paths are hashed case insensitively, split into a prime
+// number of buckets and sorted by hash inside each bucket.
+fn hash_table(entries: &[Bnd4Entry], offset: i64) -> Vec<u8> {
+    let bucket_count = (entries.len() as u32 / 7..)
+        .find(|count| is_prime(*count))
+        .unwrap_or(1);
+
+    let mut buckets = vec![Vec::new(); bucket_count as usize];
+    for (index, entry) in entries.iter().enumerate() {
+        let hash = path_hash(&entry.name);
+        buckets[(hash % bucket_count) as usize].push((hash, index as u32));
+    }
+
+    let hashes_offset = offset + 0x10 + 8 * i64::from(bucket_count);
+    let mut out = Vec::new();
+    out.extend(hashes_offset.to_le_bytes());
+    out.extend(bucket_count.to_le_bytes());
+    out.extend([0x10, 8, 8, 0]);
+    let mut start = 0u32;
+    for bucket in &mut buckets {
+        bucket.sort();
+        out.extend((bucket.len() as u32).to_le_bytes());
+        out.extend(start.to_le_bytes());
+        start += bucket.len() as u32;
+    }
+    for (hash, index) in buckets.into_iter().flatten() {
+        out.extend(hash.to_le_bytes());
+        out.extend(index.to_le_bytes());
+    }
+    out
+}
+
+fn path_hash(path: &str) -> u32 {
+    let path = path.trim().replace('\\', "/").to_lowercase();
+    let path = if path.starts_with('/') {
+        path
+    } else {
+        format!("/{path}")
+    };
+    path.chars().fold(0u32, |hash, char| {
+        hash.wrapping_mul(37).wrapping_add(char as u32)
+    })
+}
+
+fn is_prime(number: u32) -> bool {
+    number >= 2
+        && (2..)
+            .take_while(|i| i * i <= number)
+            .all(|i| !number.is_multiple_of(i))
+}
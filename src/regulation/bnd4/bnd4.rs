@@ -10,6 +10,8 @@ pub enum BND4ParseError {
     DekuError(#[from] DekuError),
     #[error(transparent)]
     IoError(#[from] std::io::Error),
+    #[error("Entry {} is out of bounds!", .0)]
+    EntryOutOfBounds(usize),
 }
 
 // Context params for BND4Hheader
@@ -22,7 +24,7 @@ type Ctx<'a> = (&'a BND4Header, &'a Vec<BND4FileHeader>, &'a Vec<String>);
 /// It includes functions for reading, parsing, and validating BND4 files, ensuring that
 /// implementations handle the respective file formats correctly.
 ///
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 pub(crate) struct BND4<T: for<'a> DekuWriter<Ctx<'a>> + for<'a> DekuReader<'a, Ctx<'a>>> {
     pub(crate) header: BND4Header,
     #[deku(
@@ -44,6 +46,19 @@ pub(crate) struct BND4<T: for<'a> DekuWriter<Ctx<'a>> + for<'a> DekuReader<'a, C
 }
 
 impl<T: for<'a> DekuWriter<Ctx<'a>> + for<'a> DekuReader<'a, Ctx<'a>>> BND4<T> {
+    // A binder without files, `header` is expected to have a file count of 0
+    #[cfg(feature = "regulation")]
+    pub(crate) fn empty(header: BND4Header, file_data: T) -> Self {
+        BND4 {
+            header,
+            file_headers: Vec::new(),
+            file_names: Vec::new(),
+            zero: 0,
+            buckets: Buckets::empty(),
+            file_data,
+        }
+    }
+
     fn read_file_names<R: std::io::Read>(
         reader: &mut deku::reader::Reader<R>,
         unicode: bool,
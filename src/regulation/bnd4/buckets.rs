@@ -1,9 +1,12 @@
 use deku::prelude::*;
 
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+use super::header::BND4Header;
+
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(ctx = "file_count: i32")]
 pub(crate) struct Buckets {
     pub(crate) hash_offset: u64,
+    #[deku(assert = "(0..=BND4Header::MAX_FILE_COUNT).contains(bucket_count)")]
     pub(crate) bucket_count: i32,
     #[deku(assert_eq = "0x10")]
     pub(crate) bucket_header_size: u8,
@@ -18,3 +21,20 @@ pub(crate) struct Buckets {
     #[deku(count = "file_count")]
     pub(crate) hashes: Vec<(u32, u32)>,
 }
+
+impl Buckets {
+    // Hash table of a binder without files
+    #[cfg(feature = "regulation")]
+    pub(crate) fn empty() -> Self {
+        Buckets {
+            hash_offset: 0,
+            bucket_count: 0,
+            bucket_header_size: 0x10,
+            bucket_size: 8,
+            hash_size: 8,
+            unk0xf: 0,
+            buckets: Vec::new(),
+            hashes: Vec::new(),
+        }
+    }
+}
@@ -2,11 +2,12 @@ use super::flags::Format;
 use deku::prelude::*;
 use deku::DekuError;
 
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(ctx = "format: u8, bit_big_endian: bool")]
 pub(crate) struct BND4FileHeader {
     #[deku(
-        map = "|b: u8| -> Result<_, DekuError> {if bit_big_endian {Ok(b)} else {Ok(b.reverse_bits())}}"
+        map = "|b: u8| -> Result<_, DekuError> {if bit_big_endian {Ok(b)} else {Ok(b.reverse_bits())}}",
+        writer = "if bit_big_endian {file_flags.to_writer(deku::writer, ())} else {file_flags.reverse_bits().to_writer(deku::writer, ())}"
     )]
     pub(crate) file_flags: u8,
     #[deku(assert_eq = "0")]
@@ -33,10 +34,47 @@ pub(crate) struct BND4FileHeader {
     )]
     pub(crate) name_offset: i32,
     #[deku(skip, cond = "format != Format::Names1 as u8", assert_eq = "0")]
-    unk0x28: i32,
+    unk0x28: i64,
 }
 
 impl BND4FileHeader {
+    pub(crate) fn new(
+        file_flags: u8,
+        compressed_size: i64,
+        uncompressed_size: i64,
+        data_offset: i64,
+        id: i32,
+        name_offset: i32,
+    ) -> Self {
+        BND4FileHeader {
+            file_flags,
+            unk0x1: 0,
+            unk0x2: 0,
+            unk0x3: 0,
+            unk0x4: -1,
+            compressed_size,
+            uncompressed_size,
+            data_offset,
+            id,
+            name_offset,
+            unk0x28: 0,
+        }
+    }
+
+    // Size of a file header for the given format
+    pub(crate) fn size(format: u8) -> i64 {
+        let has = |flag: Format| format & flag as u8 != 0;
+        0x10 + if has(Format::Compression) { 8 } else { 0 }
+            + if has(Format::LongOffsets) { 8 } else { 4 }
+            + if has(Format::IDs) { 4 } else { 0 }
+            + if has(Format::Names1) || has(Format::Names2) {
+                4
+            } else {
+                0
+            }
+            + if format == Format::Names1 as u8 { 8 } else { 0 }
+    }
+
     fn read_data_offset<R: std::io::Read>(
         reader: &mut deku::reader::Reader<R>,
         format: u8,
@@ -3,7 +3,7 @@ use std::borrow::Cow;
 use deku::prelude::*;
 use deku::{ctx::Endian, DekuError};
 
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(magic = b"BND4")]
 pub(crate) struct BND4Header {
     #[deku(assert_eq = "0")]
@@ -21,10 +21,14 @@ pub(crate) struct BND4Header {
         writer = "BND4Header::write_endian(deku::writer, endian)"
     )]
     pub(crate) endian: Endian,
-    #[deku(map = "|b: bool| -> Result<_, DekuError> {Ok(!b)}")]
+    #[deku(
+        map = "|b: bool| -> Result<_, DekuError> {Ok(!b)}",
+        writer = "(!*bit_big_endian).to_writer(deku::writer, ())"
+    )]
     pub(crate) bit_big_endian: bool,
     #[deku(assert_eq = "0")]
     unk0x9: u8,
+    #[deku(assert = "(0..=BND4Header::MAX_FILE_COUNT).contains(file_count)")]
     pub(crate) file_count: i32,
     #[deku(assert_eq = "0x40")]
     pub(crate) header_size: i64,
@@ -36,7 +40,10 @@ pub(crate) struct BND4Header {
     pub(crate) file_header_size: i64,
     pub(crate) headers_end: u64,
     pub(crate) unicode: bool,
-    #[deku(reader = "BND4Header::read_format(deku::reader, bit_big_endian)")]
+    #[deku(
+        reader = "BND4Header::read_format(deku::reader, bit_big_endian)",
+        writer = "BND4Header::write_format(deku::writer, *bit_big_endian, *format)"
+    )]
     pub(crate) format: u8,
     #[deku(assert = "*extended == 0 || *extended == 1 || *extended == 4 || *extended == 0x80")]
     pub(crate) extended: u8,
@@ -49,6 +56,35 @@ pub(crate) struct BND4Header {
 }
 
 impl BND4Header {
+    // Sanity limit for the file and bucket counts, far above what any known binder uses.
+    // Keeps a corrupt header from allocating gigabytes before the read fails.
+    pub(crate) const MAX_FILE_COUNT: i32 = 0x10000;
+
+    // Header of an empty little endian binder, counts and offsets are filled in on write
+    pub(crate) fn new(version: u32, unicode: bool, format: u8, extended: u8) -> Self {
+        BND4Header {
+            unk0x4: 0,
+            unk0x5: 0,
+            unk0x6: 0,
+            unk0x7: 0,
+            unk0x8: 0,
+            endian: Endian::Little,
+            bit_big_endian: false,
+            unk0x9: 0,
+            file_count: 0,
+            header_size: 0x40,
+            version,
+            file_header_size: 0,
+            headers_end: 0,
+            unicode,
+            format,
+            extended,
+            unk0x33: 0,
+            unk0x34: 0,
+            hashtable_offset: 0,
+        }
+    }
+
     fn read_format<R: std::io::Read>(
         reader: &mut deku::reader::Reader<R>,
         bit_big_endian: &bool,
@@ -61,6 +97,19 @@ impl BND4Header {
             Ok(raw_format.reverse_bits())
         }
     }
+
+    fn write_format<W: std::io::Write>(
+        writer: &mut Writer<W>,
+        bit_big_endian: bool,
+        format: u8,
+    ) -> Result<(), DekuError> {
+        let reverse = bit_big_endian || (format & 1) != 0 && (format & 0b1000_0000) == 0;
+        if reverse {
+            format.to_writer(writer, ())
+        } else {
+            format.reverse_bits().to_writer(writer, ())
+        }
+    }
     fn read_endian<R: std::io::Read>(
         reader: &mut deku::reader::Reader<R>,
     ) -> Result<Endian, DekuError> {
@@ -77,8 +126,8 @@ impl BND4Header {
         endian: &Endian,
     ) -> Result<(), DekuError> {
         match endian {
-            Endian::Little => 0.to_writer(writer, ()),
-            Endian::Big => 1.to_writer(writer, ()),
+            Endian::Little => 0u8.to_writer(writer, ()),
+            Endian::Big => 1u8.to_writer(writer, ()),
         }
     }
 
@@ -114,8 +163,8 @@ impl BND4Header {
         writer: &mut Writer<W>,
         version: &u32,
     ) -> Result<(), DekuError> {
-        // Convert u32 into string bytes
-        let bytes = version.to_string().into_bytes();
+        // Convert u32 into zero padded string bytes
+        let bytes = format!("{version:08}").into_bytes();
 
         // Write string bytes
         bytes.to_writer(writer, ())?;
@@ -1,3 +1,4 @@
+pub mod archive;
 pub(crate) mod bnd4;
 pub(crate) mod buckets;
 pub(crate) mod file_header;
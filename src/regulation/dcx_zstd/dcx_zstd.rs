@@ -1,12 +1,12 @@
 use std::borrow::Cow;
-use std::io::Cursor;
+use std::io::{Cursor, Read};
 
 use deku::ctx::Endian;
 use deku::prelude::*;
 use deku::{DekuRead, DekuWrite};
 
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
-#[deku(magic = b"DCX\0", endian = "Endian::Big")]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
+#[deku(magic = b"DCX\0", endian = "Endian::Big", ctx = "max_alloc: usize")]
 pub(crate) struct DCXZSTD<T: DekuWriter + for<'a> DekuReader<'a>> {
     #[deku(assert_eq = "0x11000")]
     unk0x4: i32,
@@ -21,6 +21,7 @@ pub(crate) struct DCXZSTD<T: DekuWriter + for<'a> DekuReader<'a>> {
     #[deku(assert = "unk0x18 == b\"DCS\\0\"")]
     unk0x18: [u8; 4],
     decompressed_size: i32,
+    #[deku(assert = "(*compressed_size as usize) <= max_alloc")]
     compressed_size: i32,
     #[deku(assert = "unk0x24 == b\"DCP\\0\"")]
     unk0x24: [u8; 4],
@@ -54,7 +55,7 @@ pub(crate) struct DCXZSTD<T: DekuWriter + for<'a> DekuReader<'a>> {
     #[deku(assert_eq = "8")]
     unk0x48: i32,
     #[deku(
-        reader = "DCXZSTD::decompress(deku::reader, *compressed_size)",
+        reader = "DCXZSTD::decompress(deku::reader, *compressed_size, max_alloc)",
         writer = "DCXZSTD::compress(data, *compressed_size)"
     )]
     pub(crate) data: T,
@@ -64,13 +65,22 @@ impl<T: DekuWriter + for<'a> DekuReader<'a>> DCXZSTD<T> {
     pub(crate) fn decompress<R: std::io::Read>(
         reader: &mut deku::reader::Reader<R>,
         size: i32,
+        max_alloc: usize,
     ) -> Result<T, DekuError> {
-        let size = size as usize;
+        let size = usize::try_from(size).map_err(|_| {
+            DekuError::Parse(Cow::from(format!("Invalid DCX compressed size: {size}")))
+        })?;
         let mut bytes = vec![0; size];
-        let _ = reader.read_bytes(size, &mut bytes);
+        reader.read_bytes(size, &mut bytes)?;
 
-        // Try to decompress DCX ZSTD
-        let result = zstd::decode_all(bytes.as_slice());
+        // Try to decompress DCX ZSTD, reading at most one byte past the limit to tell
+        // whether it was hit.
+        let mut decompressed = Vec::new();
+        let result = zstd::Decoder::new(bytes.as_slice()).and_then(|decoder| {
+            decoder
+                .take((max_alloc as u64).saturating_add(1))
+                .read_to_end(&mut decompressed)
+        });
 
         // Throw error if decompression failed
         if let Err(err) = result {
@@ -79,7 +89,11 @@ impl<T: DekuWriter + for<'a> DekuReader<'a>> DCXZSTD<T> {
             ))));
         };
 
-        let bytes = result.unwrap();
+        if decompressed.len() > max_alloc {
+            return Err(DekuError::Io(std::io::ErrorKind::OutOfMemory));
+        }
+
+        let bytes = decompressed;
 
         let mut cursor = Cursor::new(&bytes);
         let mut reader = Reader::new(&mut cursor);
@@ -93,3 +107,56 @@ impl<T: DekuWriter + for<'a> DekuReader<'a>> DCXZSTD<T> {
         todo!()
     }
 }
+
+// Wraps `bytes` in a DCX container, compressed with zstd at `level`. The header holds the
+// same constants `DCXZSTD` checks for on read.
+pub(crate) fn encode(bytes: &[u8], level: i32) -> Result<Vec<u8>, DekuError> {
+    let compressed = zstd::encode_all(bytes, level).map_err(|err| DekuError::Io(err.kind()))?;
+    let size = |len: usize| {
+        i32::try_from(len).map_err(|_| DekuError::InvalidParam(Cow::from("DCX data too large")))
+    };
+
+    let mut out = Vec::with_capacity(0x4c + compressed.len());
+    out.extend(b"DCX\0");
+    for value in [0x11000, 0x18, 0x24, 0x44, 0x4c] {
+        out.extend(i32::to_be_bytes(value));
+    }
+    out.extend(b"DCS\0");
+    out.extend(size(bytes.len())?.to_be_bytes());
+    out.extend(size(compressed.len())?.to_be_bytes());
+    out.extend(b"DCP\0");
+    out.extend(b"ZSTD");
+    out.extend(i32::to_be_bytes(0x20));
+    out.extend([level as u8, 0, 0, 0]);
+    out.extend([0; 12]);
+    out.extend(i32::to_be_bytes(0x00010100));
+    out.extend(b"DCA\0");
+    out.extend(i32::to_be_bytes(8));
+    out.extend(compressed);
+    Ok(out)
+}
+
+#[test]
+fn unlimited_decompression_doesnt_overflow() {
+    use crate::regulation::{
+        bnd4::{bnd4::BND4, header::BND4Header},
+        params::params::Params,
+    };
+
+    let binder = BND4::empty(
+        BND4Header::new(0, true, 0x2e, 4),
+        Params {
+            param_files: Default::default(),
+        },
+    );
+    let mut bytes = Vec::new();
+    binder
+        .to_writer(&mut Writer::new(Cursor::new(&mut bytes)), ())
+        .unwrap();
+    let encoded = encode(&bytes, 0x15).unwrap();
+
+    let mut cursor = Cursor::new(&encoded);
+    let mut reader = Reader::new(&mut cursor);
+    let dcx = DCXZSTD::<BND4<Params>>::from_reader_with_ctx(&mut reader, usize::MAX).unwrap();
+    assert_eq!(dcx.data, binder);
+}
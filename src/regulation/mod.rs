@@ -1,4 +1,6 @@
 pub(crate) mod bnd4;
+#[cfg(feature = "regulation")]
 pub(crate) mod dcx_zstd;
+#[cfg(feature = "regulation")]
 pub(crate) mod params;
 pub(crate) mod regulation;
@@ -1,6 +1,7 @@
 pub(crate) mod flags;
 pub(crate) mod header;
 pub(crate) mod param;
+#[cfg(feature = "params")]
 pub mod param_structs;
 pub(crate) mod params;
 pub(crate) mod row_header;
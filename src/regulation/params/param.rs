@@ -1,11 +1,16 @@
-use deku::prelude::*;
+use std::borrow::Cow;
+
+use deku::{ctx::Endian, prelude::*};
+#[cfg(feature = "params")]
 use deku::{DekuRead, DekuWrite};
 
+#[cfg(feature = "params")]
 use crate::param_trait::Param;
 
-use super::{header::PARAMHeader, row_header::ParamRowHeader};
+use super::{header::PARAMHeader, params::Offset, row_header::ParamRowHeader};
 
 // PARAM
+#[cfg(feature = "params")]
 #[derive(PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(ctx = "version: u32")]
 pub(crate) struct PARAM<P: Param> {
@@ -18,3 +23,57 @@ pub(crate) struct PARAM<P: Param> {
     #[deku(ctx = "header.endian, version", count = "header.row_count")]
     pub(crate) row_data: Vec<P::ParamType>,
 }
+
+// Byte order of the values of a PARAM file
+pub(crate) fn param_endian(bytes: &[u8]) -> Result<Endian, DekuError> {
+    let mut cursor = std::io::Cursor::new(bytes);
+    let mut reader = Reader::new(&mut cursor);
+    Ok(PARAMHeader::from_reader_with_ctx(&mut reader, ())?.endian)
+}
+
+// Rows of a PARAM file as (id, data) pairs, without decoding the data. Row data is laid out
+// with a fixed stride, so the size of a row is the distance between two consecutive rows or,
+// for a single row, the distance to the string table.
+pub(crate) fn raw_rows(bytes: &[u8]) -> Result<Vec<(i32, &[u8])>, DekuError> {
+    let mut cursor = std::io::Cursor::new(bytes);
+    let mut reader = Reader::new(&mut cursor);
+    let header = PARAMHeader::from_reader_with_ctx(&mut reader, ())?;
+    let row_headers = (0..header.row_count)
+        .map(|_| {
+            ParamRowHeader::from_reader_with_ctx(
+                &mut reader,
+                (header.endian, header.format0x2d, header.format0x2e),
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let data_offsets = row_headers
+        .iter()
+        .map(|row_header| match row_header.data_offset {
+            Offset::Long(offset) => usize::try_from(offset).ok(),
+            Offset::Int(offset) => usize::try_from(offset).ok(),
+            Offset::None => None,
+        })
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(|| DekuError::Parse(Cow::from("Invalid param row data offset")))?;
+
+    let row_size = match data_offsets.as_slice() {
+        [] => Some(0),
+        [first, second, ..] => second.checked_sub(*first),
+        [first] => (header.string_offset as usize).checked_sub(*first),
+    };
+    let row_size =
+        row_size.ok_or_else(|| DekuError::Parse(Cow::from("Invalid param row data offset")))?;
+
+    row_headers
+        .iter()
+        .zip(data_offsets)
+        .map(|(row_header, offset)| {
+            offset
+                .checked_add(row_size)
+                .and_then(|end| bytes.get(offset..end))
+                .map(|data| (row_header.id, data))
+                .ok_or_else(|| DekuError::Parse(Cow::from("Param row data out of bounds")))
+        })
+        .collect()
+}
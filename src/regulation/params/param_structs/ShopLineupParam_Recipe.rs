@@ -0,0 +1,9 @@
+use super::{
+	defs::SHOP_LINEUP_PARAM::SHOP_LINEUP_PARAM,
+	param_trait::Param
+};
+pub struct ShopLineupParam_Recipe;
+impl Param for ShopLineupParam_Recipe {
+	type ParamType = SHOP_LINEUP_PARAM;
+	const PARAM_NAME: &'static str = "ShopLineupParam_Recipe";
+}
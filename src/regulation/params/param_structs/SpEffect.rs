@@ -5,5 +5,5 @@ use super::{
 pub struct SpEffect;
 impl Param for SpEffect {
 	type ParamType = SP_EFFECT_PARAM_ST;
-	const PARAM_NAME: &'static str = "SpEffect";
+	const PARAM_NAME: &'static str = "SpEffectParam";
 }
@@ -2,6 +2,10 @@
 pub mod param_trait;
 #[allow(unused,non_snake_case, non_camel_case_types)]
 pub mod defs;
+// `ParamFields` of the defs, generated by build.rs
+mod param_fields {
+    include!(concat!(env!("OUT_DIR"), "/param_fields.rs"));
+}
 #[allow(unused,non_snake_case, non_camel_case_types)]
 pub mod ActionButtonParam;
 #[allow(unused,non_snake_case, non_camel_case_types)]
@@ -319,6 +323,8 @@ pub mod SfxBlockResShareParam;
 #[allow(unused,non_snake_case, non_camel_case_types)]
 pub mod ShopLineupParam;
 #[allow(unused,non_snake_case, non_camel_case_types)]
+pub mod ShopLineupParam_Recipe;
+#[allow(unused,non_snake_case, non_camel_case_types)]
 pub mod SignPuddleParam;
 #[allow(unused,non_snake_case, non_camel_case_types)]
 pub mod SignPuddleSubCategoryParam;
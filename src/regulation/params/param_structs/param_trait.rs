@@ -1,6 +1,100 @@
 use deku::ctx::Endian;
 use deku::{DekuReader, DekuWriter};
 pub trait Param {
-	type ParamType: DekuWriter<(Endian, u32)> + for<'a> DekuReader<'a, (Endian, u32)>;
+	type ParamType: DekuWriter<(Endian, u32)> + for<'a> DekuReader<'a, (Endian, u32)> + ParamFields;
 	const PARAM_NAME: &'static str;
 }
+
+// Fields of a param row, named as in the paramdefs, for the CSV export. Fields that don't
+// exist in the regulation version are left out.
+pub trait ParamFields {
+	fn field_names(version: u32) -> Vec<&'static str>;
+	fn field_values(&self, version: u32) -> Vec<String>;
+}
+
+// A field formatted for the CSV export. Byte arrays are written as `[0|0|0]`, the way
+// Smithbox writes padding fields.
+pub trait CsvValue {
+	fn csv_value(&self) -> String;
+}
+
+macro_rules! impl_csv_value {
+	($($t:ty),*) => {
+		$(impl CsvValue for $t {
+			fn csv_value(&self) -> String {
+				self.to_string()
+			}
+		})*
+	};
+}
+impl_csv_value!(u8, i8, i16, i32, f32);
+
+impl CsvValue for [u8] {
+	fn csv_value(&self) -> String {
+		let bytes: Vec<String> = self.iter().map(u8::to_string).collect();
+		format!("[{}]", bytes.join("|"))
+	}
+}
+
+impl<const N: usize> CsvValue for [u8; N] {
+	fn csv_value(&self) -> String {
+		self[..].csv_value()
+	}
+}
+
+impl CsvValue for Vec<u8> {
+	fn csv_value(&self) -> String {
+		self[..].csv_value()
+	}
+}
+
+// Collects the values of `ParamFields::field_values`. Param bit fields are stored least
+// significant bit first, but deku 0.17 reads them most significant bit first, so bit fields
+// are buffered until they complete a byte and then read again in the right order.
+#[derive(Default)]
+pub struct CsvFields {
+	values: Vec<String>,
+	// Widths of the buffered bit fields and their bits, as read by deku
+	widths: Vec<u32>,
+	bits: Vec<bool>,
+}
+
+impl CsvFields {
+	pub fn push<T: CsvValue + ?Sized>(&mut self, value: &T) {
+		self.values.push(value.csv_value());
+	}
+
+	pub fn push_bits(&mut self, value: impl Into<i64>, width: u32) {
+		let value = value.into();
+		self.widths.push(width);
+		self.bits.extend((0..width).rev().map(|i| (value >> i) & 1 == 1));
+		if !self.bits.len().is_multiple_of(8) {
+			return;
+		}
+		// Bits in storage order: least significant first in every byte
+		let bits: Vec<bool> = self.bits.chunks(8).flat_map(|byte| byte.iter().rev()).copied().collect();
+		let mut start = 0;
+		for width in self.widths.drain(..) {
+			let field = &bits[start..start + width as usize];
+			let value = field.iter().rev().fold(0u64, |value, bit| value << 1 | u64::from(*bit));
+			self.values.push(value.to_string());
+			start += width as usize;
+		}
+		self.bits.clear();
+	}
+
+	pub fn into_values(self) -> Vec<String> {
+		self.values
+	}
+}
+
+#[test]
+fn bit_fields_are_read_least_significant_bit_first() {
+	// Stored byte 0b0000_0011: a 1-bit field set to 1 and a 7-bit field set to 1, which
+	// deku reads as 0 and 3
+	let mut fields = CsvFields::default();
+	fields.push_bits(0u8, 1);
+	fields.push_bits(3u8, 7);
+	fields.push(&[1u8, 2]);
+	assert_eq!(fields.into_values(), ["1", "1", "[1|2]"]);
+}
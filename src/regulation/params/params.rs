@@ -21,7 +21,7 @@ pub(crate) enum Offset {
 // Context params for Params
 type Ctx<'a> = (&'a BND4Header, &'a Vec<BND4FileHeader>, &'a Vec<String>);
 
-#[derive(PartialEq, Debug)]
+#[derive(Clone, PartialEq, Debug)]
 pub(crate) struct Params {
     pub(crate) param_files: BTreeMap<String, Vec<u8>>,
 }
@@ -38,8 +38,8 @@ impl<'a> DekuReader<'a, Ctx<'a>> for Params {
         let mut param_files = BTreeMap::new();
         for (file_header, file_name) in file_headers.iter().zip(file_names) {
             let bytes_read = reader.bits_read / 8;
-            let data_offset = file_header.data_offset as usize;
-            let compressed_size = file_header.compressed_size as usize;
+            let data_offset = Params::to_usize(file_header.data_offset)?;
+            let compressed_size = Params::to_usize(file_header.compressed_size)?;
 
             let file_name_with_extension = file_name.split("\\").last();
 
@@ -60,16 +60,55 @@ impl<'a> DekuReader<'a, Ctx<'a>> for Params {
             };
 
             if bytes_read < data_offset {
-                reader.skip_bits((data_offset - bytes_read) * 8)?;
+                Params::skip_bytes(reader, data_offset - bytes_read)?;
             }
-            let mut file_data = vec![0; compressed_size];
-            reader.read_bytes(compressed_size, &mut file_data)?;
+            let file_data = Params::read_file_data(reader, compressed_size)?;
             param_files.insert(param_name.to_string(), file_data);
         }
 
         Ok(Self { param_files })
     }
 }
+impl Params {
+    fn to_usize(value: i64) -> Result<usize, DekuError> {
+        usize::try_from(value).map_err(|_| {
+            DekuError::Parse(Cow::from(format!(
+                "Invalid param file offset or size: {value}"
+            )))
+        })
+    }
+
+    // Offsets and sizes come straight from the file headers, so both helpers below work in
+    // chunks: a bogus value runs into the end of the data instead of allocating the whole
+    // claimed size up front.
+    const CHUNK_SIZE: usize = 0x10000;
+
+    fn skip_bytes<R: std::io::Read>(reader: &mut Reader<R>, size: usize) -> Result<(), DekuError> {
+        let mut buffer = vec![0; size.min(Params::CHUNK_SIZE)];
+        let mut remaining = size;
+        while remaining > 0 {
+            let chunk = remaining.min(Params::CHUNK_SIZE);
+            reader.read_bytes(chunk, &mut buffer[..chunk])?;
+            remaining -= chunk;
+        }
+        Ok(())
+    }
+
+    fn read_file_data<R: std::io::Read>(
+        reader: &mut Reader<R>,
+        size: usize,
+    ) -> Result<Vec<u8>, DekuError> {
+        let mut file_data = Vec::new();
+        while file_data.len() < size {
+            let start = file_data.len();
+            let chunk = (size - start).min(Params::CHUNK_SIZE);
+            file_data.resize(start + chunk, 0);
+            reader.read_bytes(chunk, &mut file_data[start..])?;
+        }
+        Ok(file_data)
+    }
+}
+
 impl<'a> DekuWriter<Ctx<'a>> for Params {
     fn to_writer<W: std::io::Write>(
         &self,
@@ -1,17 +1,25 @@
-use super::params::{param::PARAM, params::Params};
+#[cfg(feature = "params")]
+use super::params::param::PARAM;
+#[cfg(feature = "regulation")]
+use super::params::params::Params;
+#[cfg(feature = "params")]
+use crate::param_trait::Param;
+use crate::regulation::bnd4::bnd4::BND4ParseError;
+#[cfg(feature = "regulation")]
 use crate::{
-    param_trait::Param,
     regulation::{
-        bnd4::bnd4::{BND4ParseError, BND4},
-        dcx_zstd::dcx_zstd::DCXZSTD,
+        bnd4::{bnd4::BND4, header::BND4Header},
+        dcx_zstd::dcx_zstd::{self, DCXZSTD},
     },
+    save::{game_profile::GameProfile, save::ParseOptions},
+    trace::Step,
 };
-use aes::cipher::{block_padding::NoPadding, BlockDecryptMut, KeyIvInit};
-use deku::{
-    ctx::Endian, reader::Reader, writer::Writer, DekuContainerRead, DekuError, DekuReader,
-    DekuWriter,
-};
-use std::{borrow::Cow, collections::HashMap, fs, io::Cursor, path::Path, sync::OnceLock};
+#[cfg(feature = "regulation")]
+use aes::cipher::{block_padding::NoPadding, BlockDecryptMut, BlockEncryptMut, KeyIvInit};
+use deku::{ctx::Endian, reader::Reader, writer::Writer, DekuError, DekuReader, DekuWriter};
+use std::{borrow::Cow, collections::HashMap, sync::OnceLock};
+#[cfg(feature = "regulation")]
+use std::{collections::BTreeMap, io::Cursor};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -28,21 +36,24 @@ pub enum RegulationParseError {
     ParamNotFound(&'static str),
 }
 
-// Context params for Regulation
-type Ctx = (Endian, RegulationIdentifier);
+// Context params for Regulation: the largest buffer the decompressed regulation may
+// take and the decryption key.
+type Ctx = (Endian, RegulationIdentifier, usize, [u8; 32]);
 
 // Regulation size can be determined from the regulation version. This
 // needs to be done when reading regulation from the save file. However when
 // reading regulation from a file the size is already known.
 pub(crate) enum RegulationIdentifier {
     Version(u32),
+    #[cfg(feature = "regulation")]
     Size(usize),
 }
 
 #[repr(C)]
-#[derive(PartialEq, Debug)]
+#[derive(Clone, PartialEq, Debug)]
 pub struct Regulation {
     raw: Vec<u8>,
+    #[cfg(feature = "regulation")]
     pub(crate) content: DCXZSTD<BND4<Params>>,
 }
 
@@ -56,7 +67,7 @@ impl<'a> DekuReader<'a, Ctx> for Regulation {
         Self: Sized,
     {
         // Grab regulation_identifier from the context params
-        let (_, regulation_identifier) = ctx;
+        let (_, regulation_identifier, max_alloc, key) = ctx;
 
         // If the identifer is size then just store it, if it's version then
         // look up the size from the version to size map.
@@ -71,6 +82,7 @@ impl<'a> DekuReader<'a, Ctx> for Regulation {
                     ))));
                 }
             }
+            #[cfg(feature = "regulation")]
             RegulationIdentifier::Size(size) => size,
         };
 
@@ -80,13 +92,55 @@ impl<'a> DekuReader<'a, Ctx> for Regulation {
         // Read the regulation bytes.
         let _ = reader.read_bytes(size, &mut bytes)?;
 
+        Self::parse(bytes, max_alloc, &key)
+    }
+}
+
+// DekuWriter
+impl DekuWriter<Ctx> for Regulation {
+    fn to_writer<W: std::io::Write>(
+        &self,
+        writer: &mut Writer<W>,
+        _: Ctx,
+    ) -> Result<(), DekuError> {
+        // Due to the zstd compression configuration not being known, it's not
+        // possible to compress the regulation back to how it originally was in the save
+        // file. So we write back the same regulation bytes we read.
+        self.raw.to_writer(writer, ())?;
+        Ok(())
+    }
+}
+
+impl Regulation {
+    #[cfg(feature = "regulation")]
+    pub fn from_slice(bytes: &mut [u8]) -> Result<Self, RegulationParseError> {
+        let size = bytes.len();
+        let mut cursor = Cursor::new(bytes);
+        let mut reader = Reader::new(&mut cursor);
+        Ok(Regulation::from_reader_with_ctx(
+            &mut reader,
+            (
+                Endian::Little,
+                RegulationIdentifier::Size(size),
+                ParseOptions::default().max_alloc,
+                GameProfile::ELDEN_RING.regulation_key,
+            ),
+        )?)
+    }
+
+    // Decrypts and parses the regulation bytes read from a save or a regulation.bin
+    #[cfg(feature = "regulation")]
+    fn parse(bytes: Vec<u8>, max_alloc: usize, key: &[u8; 32]) -> Result<Self, DekuError> {
         // Store a copy of the bytes to use when write. This is due
         // to the zstd compression configuartion being unknown. Because of that
         // writing edited regulation to the save file is not possible.
         let raw = bytes.clone();
+        let size = bytes.len();
 
         // Try to decrypt the regulation bytes
-        let result = Self::decrypt(&bytes);
+        let step = Step::enter("Regulation", "decrypt", None, 0, size);
+        let result = Self::decrypt(&bytes, key);
+        step.finish(&result);
 
         // Throw error if decryption failed
         if let Err(err) = result {
@@ -99,17 +153,23 @@ impl<'a> DekuReader<'a, Ctx> for Regulation {
         let mut bytes = result.unwrap();
 
         // Try to parse the regulation
-        let result = DCXZSTD::<BND4<Params>>::from_bytes((&mut bytes, 0));
-
-        // Throw error if regulation parsing failed
-        if let Err(err) = result {
-            return Err(DekuError::Parse(Cow::from(format!(
-                "Failed to parse dcx! {err})"
-            ))));
-        }
+        let step = Step::enter("Regulation", "decompress", None, 0, bytes.len());
+        let mut cursor = Cursor::new(&mut bytes);
+        let mut reader = Reader::new(&mut cursor);
+        let result = DCXZSTD::<BND4<Params>>::from_reader_with_ctx(&mut reader, max_alloc);
+        step.finish(&result);
 
-        // Unwrap regulation result
-        let (_, dcx_zstd) = result.unwrap();
+        // Throw error if regulation parsing failed. A tripped allocation limit is passed
+        // through as is so the caller can tell it apart from a malformed file.
+        let dcx_zstd = match result {
+            Ok(dcx_zstd) => dcx_zstd,
+            Err(err @ DekuError::Io(_)) => return Err(err),
+            Err(err) => {
+                return Err(DekuError::Parse(Cow::from(format!(
+                    "Failed to parse dcx! {err})"
+                ))))
+            }
+        };
 
         // Store a raw copy of the bytes along with the parsed regulation
         Ok(Regulation {
@@ -117,50 +177,23 @@ impl<'a> DekuReader<'a, Ctx> for Regulation {
             content: dcx_zstd,
         })
     }
-}
-
-// DekuWriter
-impl DekuWriter<Ctx> for Regulation {
-    fn to_writer<W: std::io::Write>(
-        &self,
-        writer: &mut Writer<W>,
-        _: Ctx,
-    ) -> Result<(), DekuError> {
-        // Due to the zstd compression configuration not being known, it's not
-        // possible to compress the regulation back to how it originally was in the save
-        // file. So we write back the same regulation bytes we read.
-        self.raw.to_writer(writer, ())?;
-        Ok(())
-    }
-}
-
-impl Regulation {
-    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, RegulationParseError> {
-        let mut bytes = fs::read(path)?;
-        Self::from_slice(&mut bytes)
-    }
 
-    pub fn from_slice(bytes: &mut [u8]) -> Result<Self, RegulationParseError> {
-        let size = bytes.len();
-        let mut cursor = Cursor::new(bytes);
-        let mut reader = Reader::new(&mut cursor);
-        Ok(Regulation::from_reader_with_ctx(
-            &mut reader,
-            (Endian::Little, RegulationIdentifier::Size(size)),
-        )?)
+    // Without the `regulation` feature the bytes are only carried through to the write
+    #[cfg(not(feature = "regulation"))]
+    fn parse(raw: Vec<u8>, _: usize, _: &[u8; 32]) -> Result<Self, DekuError> {
+        Ok(Regulation { raw })
     }
 
-    fn decrypt(bytes: &[u8]) -> Result<Vec<u8>, RegulationParseError> {
+    #[cfg(feature = "regulation")]
+    fn decrypt(bytes: &[u8], key: &[u8; 32]) -> Result<Vec<u8>, RegulationParseError> {
         type Aes256CbcDec = cbc::Decryptor<aes::Aes256>;
-        let key = [
-            0x99, 0xBF, 0xFC, 0x36, 0x6A, 0x6B, 0xC8, 0xC6, 0xF5, 0x82, 0x7D, 0x09, 0x36, 0x02,
-            0xD6, 0x76, 0xC4, 0x28, 0x92, 0xA0, 0x1C, 0x20, 0x7F, 0xB0, 0x24, 0xD3, 0xAF, 0x4E,
-            0x49, 0x3F, 0xEF, 0x99,
-        ];
+        if bytes.len() < 16 {
+            return Err(RegulationParseError::DecryptionError);
+        }
         let iv = &bytes[0..16];
         let mut buf = bytes[16..bytes.len()].to_vec();
         if let Ok(pt) =
-            Aes256CbcDec::new(&key.into(), iv.into()).decrypt_padded_mut::<NoPadding>(&mut buf)
+            Aes256CbcDec::new(key.into(), iv.into()).decrypt_padded_mut::<NoPadding>(&mut buf)
         {
             Ok(pt.to_owned())
         } else {
@@ -168,16 +201,62 @@ impl Regulation {
         }
     }
 
+    // Encrypted regulation without any param, as stored in saves of the given version. The
+    // compressed binder is padded with zeros to the size regulations of that version take.
+    #[cfg(feature = "regulation")]
+    pub(crate) fn encrypt_empty(version: u32, key: &[u8; 32]) -> Result<Vec<u8>, DekuError> {
+        type Aes256CbcEnc = cbc::Encryptor<aes::Aes256>;
+        let Some(size) = Self::ver_size_map().get(&version).copied() else {
+            return Err(DekuError::InvalidParam(Cow::from(format!(
+                "Unknown regulation version: {version}"
+            ))));
+        };
+
+        // Same format as the binder shipped with the game
+        let binder = BND4::empty(
+            BND4Header::new(version, true, 0x2e, 4),
+            Params {
+                param_files: BTreeMap::new(),
+            },
+        );
+        let mut bytes = Vec::new();
+        binder.to_writer(&mut Writer::new(Cursor::new(&mut bytes)), ())?;
+
+        let mut bytes = dcx_zstd::encode(&bytes, 0x15)?;
+        if bytes.len() + 16 > size {
+            return Err(DekuError::InvalidParam(Cow::from(
+                "Regulation doesn't fit in its version's size",
+            )));
+        }
+        bytes.resize(size - 16, 0);
+
+        let iv = [0u8; 16];
+        let len = bytes.len();
+        Aes256CbcEnc::new(key.into(), (&iv).into())
+            .encrypt_padded_mut::<NoPadding>(&mut bytes, len)
+            .map_err(|_| DekuError::InvalidParam(Cow::from("Failed to encrypt regulation")))?;
+        Ok([iv.as_slice(), &bytes].concat())
+    }
+
+    #[cfg(feature = "params")]
     pub(crate) fn get_param<P: Param>(
         &self,
     ) -> Result<HashMap<i32, P::ParamType>, RegulationParseError> {
+        Ok(self.param_rows::<P>()?.into_iter().collect())
+    }
+
+    // Rows of a param as (id, data) pairs, in the order they're stored
+    #[cfg(feature = "params")]
+    pub(crate) fn param_rows<P: Param>(
+        &self,
+    ) -> Result<Vec<(i32, P::ParamType)>, RegulationParseError> {
         let version = self.content.data.header.version;
         if let Some(param_bytes) = self.content.data.file_data.param_files.get(P::PARAM_NAME) {
             let mut cursor = Cursor::new(&param_bytes);
             let mut reader = Reader::new(&mut cursor);
             let l_param = PARAM::<P>::from_reader_with_ctx(&mut reader, version)?;
 
-            let rows: HashMap<i32, P::ParamType> = l_param
+            let rows = l_param
                 .row_headers
                 .iter()
                 .zip(l_param.row_data)
@@ -0,0 +1,70 @@
+use super::layout::{Layout, Pc, Playstation};
+
+/// Game specific constants of the BND4 save format.
+///
+/// Dark Souls III and Armored Core VI saves use the same container, checksums and
+/// regulation encryption as Elden Ring, with different keys and sizes. A fork targeting one
+/// of them can describe it here and reuse the container and crypto code. The character
+/// layer (`UserDataX` and friends) stays specific to Elden Ring. Save entries are read as
+/// plain data, games that encrypt them, like Dark Souls III, need them decrypted first.
+///
+/// # Example
+/// ```rust
+/// use er_save_lib::{GameProfile, ParseOptions, Save, SaveApi, SaveType};
+/// let bytes = SaveApi::new_empty(SaveType::PC).unwrap().to_vec().unwrap();
+/// let options = ParseOptions {
+///     profile: GameProfile::ELDEN_RING,
+///     ..Default::default()
+/// };
+/// let save = Save::from_slice_with(&bytes, &options).unwrap();
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct GameProfile {
+    pub name: &'static str,
+    /// Number of character slots.
+    pub slot_count: usize,
+    /// Sizes of the header, a single character slot, the profile summary section and the
+    /// regulation section of a PC save. Every section but the header starts with its md5
+    /// checksum.
+    pub pc_sizes: [usize; 4],
+    /// Same as `pc_sizes` for a PlayStation save, which has no checksums.
+    pub ps_sizes: [usize; 4],
+    /// AES-256-CBC key of the regulation.
+    pub regulation_key: [u8; 32],
+}
+
+impl GameProfile {
+    pub const ELDEN_RING: GameProfile = GameProfile {
+        name: "ELDEN RING",
+        slot_count: 10,
+        pc_sizes: [0x2fc, 0x280010, 0x60010, 0x240020],
+        ps_sizes: [0x6c, 0x280000, 0x60000, 0x240010],
+        regulation_key: [
+            0x99, 0xBF, 0xFC, 0x36, 0x6A, 0x6B, 0xC8, 0xC6, 0xF5, 0x82, 0x7D, 0x09, 0x36, 0x02,
+            0xD6, 0x76, 0xC4, 0x28, 0x92, 0xA0, 0x1C, 0x20, 0x7F, 0xB0, 0x24, 0xD3, 0xAF, 0x4E,
+            0x49, 0x3F, 0xEF, 0x99,
+        ],
+    };
+
+    // Section sizes of the platform, for the deku contexts which only carry `is_ps`. See
+    // `Layout` for the code that knows the platform at compile time.
+    pub(crate) fn sizes(&self, is_ps: bool) -> [usize; 4] {
+        if is_ps {
+            Playstation::sizes(self)
+        } else {
+            Pc::sizes(self)
+        }
+    }
+
+    // Size of a whole save of the platform, magic included
+    pub(crate) fn file_size(&self, is_ps: bool) -> usize {
+        let [header, slot, user_data_10, user_data_11] = self.sizes(is_ps);
+        4 + header + slot * self.slot_count + user_data_10 + user_data_11
+    }
+}
+
+impl Default for GameProfile {
+    fn default() -> Self {
+        GameProfile::ELDEN_RING
+    }
+}
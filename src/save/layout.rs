@@ -0,0 +1,43 @@
+use super::game_profile::GameProfile;
+
+// Section layout of the save of one platform. The platforms are marker types, so code
+// generic over a layout gets the checksum size and platform at compile time and can't pair
+// the sizes of one platform with the sections of the other. The sizes themselves stay in
+// `GameProfile`, which keeps them configurable for games other than Elden Ring.
+pub(crate) trait Layout {
+    // Passed to the section readers and writers, which take the platform as a deku context
+    const IS_PS: bool;
+    // Size of the md5 checksum starting every section but the header
+    const CHECKSUM_SIZE: usize;
+
+    // Section sizes: header, single UserDataX slot, UserData10, UserData11
+    fn sizes(profile: &GameProfile) -> [usize; 4];
+
+    // Size of a character slot without its checksum, as returned by
+    // `Save::decrypt_slot_bytes`
+    fn slot_data_size(profile: &GameProfile) -> usize {
+        Self::sizes(profile)[1] - Self::CHECKSUM_SIZE
+    }
+}
+
+pub(crate) struct Pc;
+
+pub(crate) struct Playstation;
+
+impl Layout for Pc {
+    const IS_PS: bool = false;
+    const CHECKSUM_SIZE: usize = 0x10;
+
+    fn sizes(profile: &GameProfile) -> [usize; 4] {
+        profile.pc_sizes
+    }
+}
+
+impl Layout for Playstation {
+    const IS_PS: bool = true;
+    const CHECKSUM_SIZE: usize = 0;
+
+    fn sizes(profile: &GameProfile) -> [usize; 4] {
+        profile.ps_sizes
+    }
+}
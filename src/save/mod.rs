@@ -1,3 +1,7 @@
+pub(crate) mod game_profile;
+pub(crate) mod layout;
+pub(crate) mod opaque;
+pub mod parse_trace;
 pub mod save;
 pub(crate) mod user_data_10;
 pub(crate) mod user_data_11;
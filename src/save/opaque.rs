@@ -0,0 +1,77 @@
+use deku::{ctx::Endian, reader::Reader, writer::Writer, DekuError, DekuReader, DekuWriter};
+
+// Bytes of a region whose meaning isn't known. They are read and written back untouched,
+// whatever the endianness, and can only be reached as raw bytes.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub(crate) struct Opaque<const N: usize>([u8; N]);
+
+impl<const N: usize> Opaque<N> {
+    pub(crate) fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub(crate) fn as_bytes_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl<'a, const N: usize> DekuReader<'a, Endian> for Opaque<N> {
+    fn from_reader_with_ctx<R: std::io::Read>(
+        reader: &mut Reader<R>,
+        _: Endian,
+    ) -> Result<Self, DekuError>
+    where
+        Self: Sized,
+    {
+        Ok(Opaque(<[u8; N]>::from_reader_with_ctx(reader, ())?))
+    }
+}
+
+impl<const N: usize> DekuWriter<Endian> for Opaque<N> {
+    fn to_writer<W: std::io::Write>(
+        &self,
+        writer: &mut Writer<W>,
+        _: Endian,
+    ) -> Result<(), DekuError> {
+        self.0.to_writer(writer, ())
+    }
+}
+
+#[cfg(feature = "regulation")]
+#[test]
+fn opaque_regions_round_trip() {
+    use crate::{SaveApi, SaveType};
+
+    let mut save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    let mut chr = save_api.character_mut(0).unwrap();
+    let names: Vec<&str> = chr
+        .as_character()
+        .opaque_regions()
+        .iter()
+        .map(|(name, _)| *name)
+        .collect();
+    for (i, name) in names.iter().enumerate() {
+        for (j, byte) in chr.opaque_region_mut(name).unwrap().iter_mut().enumerate() {
+            *byte = (i * 31 + j) as u8 | 1;
+        }
+    }
+    let expected: Vec<(&str, Vec<u8>)> = chr
+        .as_character()
+        .opaque_regions()
+        .into_iter()
+        .map(|(name, bytes)| (name, bytes.to_vec()))
+        .collect();
+
+    let read = SaveApi::from_slice(&save_api.to_vec().unwrap()).unwrap();
+    let regions: Vec<(&str, Vec<u8>)> = read
+        .character(0)
+        .unwrap()
+        .opaque_regions()
+        .into_iter()
+        .map(|(name, bytes)| (name, bytes.to_vec()))
+        .collect();
+    assert_eq!(regions, expected);
+
+    let mut chr = save_api.character_mut(0).unwrap();
+    assert!(chr.opaque_region_mut("player_game_data.level").is_none());
+}
@@ -0,0 +1,92 @@
+#[cfg(feature = "schemars")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use super::{game_profile::GameProfile, save::Save, save::SaveParseError};
+
+/// Structure of a save as the parser saw it, recorded by `Save::from_slice_traced`.
+///
+/// Only offsets, sizes, version numbers and errors are recorded, nothing a player wrote
+/// into the save, so the trace can be attached to a bug report instead of the save. With
+/// the `serde` feature it can be serialized, to JSON for instance.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct ParseTrace {
+    pub file_size: usize,
+    /// Whether the save was recognized as a PlayStation save.
+    pub is_ps: bool,
+    /// Sections in the order they were entered. A section holding others, such as `Save`,
+    /// comes before them.
+    pub sections: Vec<TracedSection>,
+    /// File version stored in `UserData10`, read from the bytes even when parsing failed.
+    /// `None` when the file is too short to hold it.
+    pub save_version: Option<u32>,
+    /// Version of the regulation stored in `UserData11`, read like `save_version`.
+    pub regulation_version: Option<u32>,
+    /// Game version each character slot was last saved with, for the slots that were
+    /// parsed. Empty when parsing failed.
+    pub slot_versions: Vec<Option<u32>>,
+    /// Error parsing stopped with.
+    pub error: Option<String>,
+}
+
+/// A step of the parser, see `ParseTrace::sections`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+#[cfg_attr(feature = "schemars", derive(JsonSchema))]
+pub struct TracedSection {
+    /// Section or stage, such as `UserDataX` or `Regulation`.
+    pub name: &'static str,
+    /// What was done to it, such as `read` or `decompress`.
+    pub operation: &'static str,
+    /// Character slot of a `UserDataX`.
+    pub index: Option<usize>,
+    /// Offset in the file, or in the regulation for the regulation stages.
+    pub offset: usize,
+    pub size: usize,
+    /// Error the step failed with.
+    pub error: Option<String>,
+}
+
+impl ParseTrace {
+    pub(crate) fn new(
+        bytes: &[u8],
+        sections: Vec<TracedSection>,
+        result: &Result<Save, SaveParseError>,
+    ) -> Self {
+        let is_ps = Save::is_ps(bytes);
+        let profile = GameProfile::ELDEN_RING;
+        let sizes = profile.sizes(is_ps);
+        let checksum_size = if is_ps { 0 } else { 0x10 };
+        let user_data_10 = 4 + sizes[0] + sizes[1] * profile.slot_count;
+        let user_data_11 = user_data_10 + sizes[2];
+        let u32_at = |offset: usize| {
+            let bytes = bytes.get(offset..offset + 4)?;
+            Some(u32::from_le_bytes(bytes.try_into().ok()?))
+        };
+
+        ParseTrace {
+            file_size: bytes.len(),
+            is_ps,
+            sections,
+            save_version: u32_at(user_data_10 + checksum_size),
+            // Past the magic and an unknown field
+            regulation_version: u32_at(user_data_11 + checksum_size + 8),
+            slot_versions: match result {
+                Ok(save) => save
+                    .user_data_x
+                    .iter()
+                    .map(|slot| {
+                        slot.corrupt
+                            .is_none()
+                            .then_some(slot.base_version.base_version)
+                    })
+                    .collect(),
+                Err(_) => Vec::new(),
+            },
+            error: result.as_ref().err().map(ToString::to_string),
+        }
+    }
+}
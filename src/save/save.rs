@@ -2,15 +2,27 @@ use core::result::Result;
 use deku::ctx::Endian;
 use deku::prelude::*;
 use deku::{DekuRead, DekuWrite};
-use std::fs::File;
+use std::borrow::Cow;
+#[cfg(feature = "std")]
+use std::fs::{self, File};
+use std::io::{self, Cursor};
+#[cfg(feature = "std")]
 use std::path::Path;
-use std::{
-    fs::{self},
-    io::{self, Cursor},
-};
 use thiserror::Error;
 
-use super::{user_data_10::UserData10, user_data_11::UserData11, user_data_x::UserDataX};
+#[cfg(feature = "regulation")]
+use super::util::Util;
+use super::{
+    game_profile::GameProfile,
+    layout::{Layout, Pc, Playstation},
+    parse_trace::ParseTrace,
+    user_data_10::UserData10,
+    user_data_11::UserData11,
+    user_data_x::UserDataX,
+};
+#[cfg(feature = "regulation")]
+use crate::regulation::regulation::Regulation;
+use crate::trace::{self, Step};
 
 #[derive(Error, Debug)]
 pub enum SaveParseError {
@@ -18,6 +30,168 @@ pub enum SaveParseError {
     DekuError(#[from] DekuError),
     #[error(transparent)]
     IoError(#[from] io::Error),
+    #[error("Parsing would exceed {limit} ({max:#x} bytes)")]
+    LimitExceeded { limit: &'static str, max: usize },
+    #[error("Slot index {} is out of range!", .0)]
+    SlotIndexOutOfRange(usize),
+    #[error("Slot is {actual:#x} bytes, expected {expected:#x}")]
+    SlotSizeMismatch { expected: usize, actual: usize },
+    #[error("Entry {} doesn't exist!", .0)]
+    EntryNotFound(String),
+}
+
+/// A BND4 entry of the save, by index or by name. An Elden Ring save has the entries
+/// `USER_DATA000` to `USER_DATA011`: the character slots, then the profile summaries and
+/// settings, then the regulation.
+///
+/// # Example
+/// ```rust
+/// use er_save_lib::SaveEntry;
+/// assert_eq!(SaveEntry::from(10), SaveEntry::Index(10));
+/// assert_eq!(SaveEntry::from("USER_DATA010"), SaveEntry::Name("USER_DATA010"));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SaveEntry<'a> {
+    Index(usize),
+    Name(&'a str),
+}
+
+impl From<usize> for SaveEntry<'_> {
+    fn from(index: usize) -> Self {
+        SaveEntry::Index(index)
+    }
+}
+
+impl<'a> From<&'a str> for SaveEntry<'a> {
+    fn from(name: &'a str) -> Self {
+        SaveEntry::Name(name)
+    }
+}
+
+/// Options applied while parsing a save.
+///
+/// The default limits accept any save the game produces. Lowering them turns an absurd
+/// length field into `SaveParseError::LimitExceeded` instead of a multi-gigabyte
+/// allocation, for callers that load files they don't trust.
+///
+/// # Example
+/// ```rust
+/// use er_save_lib::{ParseOptions, Save, SaveApi, SaveParseError, SaveType};
+/// let bytes = SaveApi::new_empty(SaveType::PC).unwrap().to_vec().unwrap();
+/// let options = ParseOptions {
+///     max_alloc: 0x100000,
+///     ..Default::default()
+/// };
+/// let result = Save::from_slice_with(&bytes, &options);
+/// assert!(matches!(result, Err(SaveParseError::LimitExceeded { .. })));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ParseOptions {
+    /// Largest buffer the parser may allocate, including the input itself and the
+    /// decompressed regulation.
+    pub max_alloc: usize,
+    /// Largest section (character slot, `UserData10`, `UserData11`) the parser will accept.
+    pub max_slot_size: usize,
+    /// Slot count, section sizes and keys of the game the save belongs to.
+    pub profile: GameProfile,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            max_alloc: 0x10000000,
+            max_slot_size: 0x280010,
+            profile: GameProfile::ELDEN_RING,
+        }
+    }
+}
+
+impl ParseOptions {
+    /// Sets the number of character slots, for mods that give the game more than the
+    /// default 10. The profile summaries follow the same count.
+    ///
+    /// # Example
+    /// ```rust
+    /// use er_save_lib::{ParseOptions, SaveApi, SaveType};
+    /// let bytes = SaveApi::new_empty(SaveType::PC).unwrap().to_vec().unwrap();
+    /// let options = ParseOptions::default().slot_count(10);
+    /// let save_api = SaveApi::from_slice_with(&bytes, &options).unwrap();
+    /// assert_eq!(save_api.active_characters().len(), 10);
+    /// ```
+    pub fn slot_count(mut self, slot_count: usize) -> Self {
+        self.profile.slot_count = slot_count;
+        self
+    }
+}
+
+/// A section of a save, as reported to the progress callback of
+/// `Save::from_slice_with_progress`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SaveSection {
+    Header,
+    /// A character slot.
+    UserDataX(usize),
+    /// Profile summaries and settings.
+    UserData10,
+    /// The regulation.
+    UserData11,
+}
+
+// Reader that reports the section being parsed and the overall progress, in percent,
+// whenever either of them changes.
+struct ProgressReader<R, F> {
+    inner: R,
+    position: usize,
+    // Start offset of every section, in order
+    sections: Vec<(usize, SaveSection)>,
+    total: usize,
+    last: Option<(SaveSection, u32)>,
+    progress: F,
+}
+
+impl<R, F: FnMut(SaveSection, f32)> ProgressReader<R, F> {
+    fn new(inner: R, total: usize, is_ps: bool, profile: GameProfile, progress: F) -> Self {
+        let sizes = profile.sizes(is_ps);
+        let mut sections = vec![(0, SaveSection::Header)];
+        let mut start = 4 + sizes[0];
+        for i in 0..profile.slot_count {
+            sections.push((start, SaveSection::UserDataX(i)));
+            start += sizes[1];
+        }
+        sections.push((start, SaveSection::UserData10));
+        sections.push((start + sizes[2], SaveSection::UserData11));
+        Self {
+            inner,
+            position: 0,
+            sections,
+            total,
+            last: None,
+            progress,
+        }
+    }
+
+    fn report(&mut self) {
+        let section = self
+            .sections
+            .iter()
+            .rev()
+            .find(|(start, _)| *start <= self.position)
+            .map_or(SaveSection::Header, |(_, section)| *section);
+        let percent = (self.position * 100 / self.total.max(1)).min(100) as u32;
+        if self.last != Some((section, percent)) {
+            self.last = Some((section, percent));
+            (self.progress)(section, percent as f32);
+        }
+    }
+}
+
+impl<R: io::Read, F: FnMut(SaveSection, f32)> io::Read for ProgressReader<R, F> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.position += read;
+        self.report();
+        Ok(read)
+    }
 }
 
 /// A struct representing an Elden Ring save file.
@@ -27,17 +201,25 @@ pub enum SaveParseError {
 /// It includes functions for reading, parsing, and validating save files, ensuring that
 /// implementations handle the respective file formats correctly.
 ///
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+/// # Stability
+///
+/// The public items of `Save` follow semver: the `magic` field, the accessors
+/// (`slot_count`, `profile`), the slot and entry byte functions (`decrypt_slot_bytes`,
+/// `encrypt_slot_bytes`, `extract_entry`, `replace_entry`) and the read and write
+/// functions. Every other field is private
+/// layout that changes along with the parser, so edits go through `SaveApi`, or through
+/// `SaveApi::raw_mut` for the functions above.
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(
     endian = "endian",
-    ctx = "endian: Endian, is_ps: bool, size: [usize; 4]"
+    ctx = "endian: Endian, is_ps: bool, profile: GameProfile, max_alloc: usize"
 )]
 pub struct Save {
     // Magic
     pub magic: [u8; 4],
 
     // Header
-    #[deku(count = "size[0]")]
+    #[deku(count = "profile.sizes(is_ps)[0]")]
     pub(crate) header: Vec<u8>,
 
     // UserData 0-9
@@ -46,15 +228,15 @@ pub struct Save {
             deku::reader,
             Endian::Little,
             deku::byte_offset,
-            size[1], 
-            10, 
+            profile.sizes(is_ps)[1],
+            profile.slot_count,
             is_ps
         )",
         writer = "UserDataX::write(
             deku::writer,
             Endian::Little,
             deku::byte_offset,
-            size[1], 
+            profile.sizes(is_ps)[1],
             is_ps,
             &self.user_data_x
         )"
@@ -67,15 +249,17 @@ pub struct Save {
             deku::reader,
             Endian::Little,
             deku::byte_offset, 
-            size[2], 
-            is_ps
+            profile.sizes(is_ps)[2],
+            is_ps,
+            profile.slot_count
         )",
         writer = "UserData10::write(
             deku::writer,
             Endian::Little,
             deku::byte_offset, 
-            size[2], 
+            profile.sizes(is_ps)[2],
             is_ps,
+            profile.slot_count,
             &self.user_data_10
         )"
     )]
@@ -87,37 +271,105 @@ pub struct Save {
             deku::reader,
             Endian::Little,
             deku::byte_offset, 
-            size[3], 
-            is_ps
+            profile.sizes(is_ps)[3],
+            is_ps,
+            max_alloc,
+            profile.regulation_key
         )",
         writer = "UserData11::write(
             deku::writer,
             Endian::Little,
             deku::byte_offset, 
-            size[3], 
+            profile.sizes(is_ps)[3],
             is_ps,
             &self.user_data_11
         )"
     )]
     pub(crate) user_data_11: UserData11,
+
+    // Game the save was parsed as
+    #[deku(skip, default = "profile")]
+    pub(crate) profile: GameProfile,
+
+    // Allocation limit the save was parsed with, entries replaced later are held to it
+    #[deku(skip, default = "max_alloc")]
+    pub(crate) max_alloc: usize,
+}
+
+// Tracks which sections of a parsed save were edited since it was read.
+#[derive(Clone, Default, Debug)]
+pub(crate) struct DirtySlots {
+    pub(crate) user_data_x: Vec<bool>,
+    pub(crate) user_data_10: bool,
+    pub(crate) user_data_11: bool,
+}
+
+impl DirtySlots {
+    pub(crate) fn mark_user_data_x(&mut self, index: usize) {
+        if self.user_data_x.len() <= index {
+            self.user_data_x.resize(index + 1, false);
+        }
+        self.user_data_x[index] = true;
+    }
+
+    pub(crate) fn mark_user_data_10(&mut self) {
+        self.user_data_10 = true;
+    }
+
+    pub(crate) fn mark_user_data_11(&mut self) {
+        self.user_data_11 = true;
+    }
+
+    fn is_user_data_x_dirty(&self, index: usize) -> bool {
+        self.user_data_x.get(index).copied().unwrap_or(false)
+    }
 }
 
 impl Save {
     fn read<R: std::io::Read>(
         reader: &mut deku::reader::Reader<R>,
         is_ps: bool,
+        profile: GameProfile,
+        max_alloc: usize,
     ) -> Result<Self, DekuError> {
-        let sizes: [usize; 4] = if is_ps {
-            [0x6c, 0x280000, 0x60000, 0x240010]
-        } else {
-            [0x2fc, 0x280010, 0x60010, 0x240020]
-        };
         Ok(Self::from_reader_with_ctx(
             reader,
-            (Endian::Little, is_ps, sizes),
+            (Endian::Little, is_ps, profile, max_alloc),
         )?)
     }
 
+    /// Returns the number of character slots in the save.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use er_save_lib::{SaveApi, SaveType};
+    ///
+    /// fn main() {
+    ///     let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    ///     assert_eq!(save_api.raw().slot_count(), 10);
+    /// }
+    /// ```
+    pub fn slot_count(&self) -> usize {
+        self.user_data_x.len()
+    }
+
+    /// Returns the `GameProfile` the save was parsed with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use er_save_lib::{GameProfile, SaveApi, SaveType};
+    ///
+    /// fn main() {
+    ///     let save_api = SaveApi::new_empty(SaveType::PC).unwrap();
+    ///     assert_eq!(save_api.raw().profile(), GameProfile::ELDEN_RING);
+    /// }
+    /// ```
+    pub fn profile(&self) -> GameProfile {
+        self.profile
+    }
+
     /// Writes the save file to a byte vector and returns it as a `Vec<u8>`.
     ///
     /// This function converts the `Save` instance into a byte vector, which can then be
@@ -133,7 +385,7 @@ impl Save {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
     /// use er_save_lib::Save;
     ///
     /// fn main() {
@@ -146,18 +398,536 @@ impl Save {
     ///
     /// This function is safe to call as it only performs data formatting operations.
     pub fn write_to_vec(&self) -> Result<Vec<u8>, DekuError> {
-        let is_ps = self.header.len() == 0x6c;
-        let sizes: [usize; 4] = if is_ps {
-            [0x6c, 0x280000, 0x60000, 0x240010]
+        let is_ps = self.is_ps_layout();
+        let step = Step::enter("Save", "write", None, 0, 0);
+        let mut buffer = Vec::new();
+        let result = self.to_writer(
+            &mut Writer::new(Cursor::new(&mut buffer)),
+            (Endian::Little, is_ps, self.profile, usize::MAX),
+        );
+        step.finish(&result);
+        result?;
+        Ok(buffer)
+    }
+
+    // Writes the save file by copying the bytes it was parsed from and only re-encoding
+    // the sections marked as dirty. Untouched slots are kept byte for byte.
+    pub(crate) fn write_to_vec_reusing(
+        &self,
+        original: &[u8],
+        dirty: &DirtySlots,
+    ) -> Result<Vec<u8>, DekuError> {
+        if self.is_ps_layout() {
+            self.write_to_vec_reusing_in::<Playstation>(original, dirty)
         } else {
-            [0x2fc, 0x280010, 0x60010, 0x240020]
+            self.write_to_vec_reusing_in::<Pc>(original, dirty)
+        }
+    }
+
+    // Whether the stored checksum of every character slot of `original`, the bytes the
+    // save was parsed from, matches the slot. None for PlayStation slots, which have no
+    // checksum, and for slots past the end of `original`.
+    pub(crate) fn slot_checksums(&self, original: &[u8]) -> Vec<Option<bool>> {
+        let sizes = Pc::sizes(&self.profile);
+        let start = self.magic.len() + sizes[0];
+        (0..self.user_data_x.len())
+            .map(|index| {
+                if self.is_ps_layout() {
+                    return None;
+                }
+                let slot = original.get(start + sizes[1] * index..start + sizes[1] * (index + 1))?;
+                let (checksum, data) = slot.split_at(Pc::CHECKSUM_SIZE);
+                Some(md5::compute(data).0[..] == *checksum)
+            })
+            .collect()
+    }
+
+    fn write_to_vec_reusing_in<L: Layout>(
+        &self,
+        original: &[u8],
+        dirty: &DirtySlots,
+    ) -> Result<Vec<u8>, DekuError> {
+        let sizes = L::sizes(&self.profile);
+        let mut buffer = original.to_vec();
+
+        let mut start = self.magic.len() + sizes[0];
+        for (i, user_data_x) in self.user_data_x.iter().enumerate() {
+            if dirty.is_user_data_x_dirty(i) {
+                let mut slot = Vec::with_capacity(sizes[1]);
+                {
+                    let mut writer = Writer::new(Cursor::new(&mut slot));
+                    user_data_x.write_slot(
+                        &mut writer,
+                        Endian::Little,
+                        start,
+                        sizes[1],
+                        L::IS_PS,
+                    )?;
+                }
+                Self::copy_section(&mut buffer, start, &slot, sizes[1])?;
+            }
+            start += sizes[1];
+        }
+
+        if dirty.user_data_10 {
+            let mut section = Vec::with_capacity(sizes[2]);
+            {
+                let mut writer = Writer::new(Cursor::new(&mut section));
+                UserData10::write(
+                    &mut writer,
+                    Endian::Little,
+                    start,
+                    sizes[2],
+                    L::IS_PS,
+                    self.profile.slot_count,
+                    &self.user_data_10,
+                )?;
+            }
+            Self::copy_section(&mut buffer, start, &section, sizes[2])?;
+        }
+        start += sizes[2];
+
+        if dirty.user_data_11 {
+            let mut section = Vec::with_capacity(sizes[3]);
+            {
+                let mut writer = Writer::new(Cursor::new(&mut section));
+                UserData11::write(
+                    &mut writer,
+                    Endian::Little,
+                    start,
+                    sizes[3],
+                    L::IS_PS,
+                    &self.user_data_11,
+                )?;
+            }
+            Self::copy_section(&mut buffer, start, &section, sizes[3])?;
+        }
+
+        Ok(buffer)
+    }
+
+    fn copy_section(
+        buffer: &mut [u8],
+        start: usize,
+        section: &[u8],
+        size: usize,
+    ) -> Result<(), DekuError> {
+        if section.len() != size || buffer.len() < start + size {
+            return Err(DekuError::InvalidParam(Cow::from(format!(
+                "Section at {start:#x} was re-encoded to {:#x} bytes, expected {size:#x}",
+                section.len()
+            ))));
+        }
+        buffer[start..start + size].copy_from_slice(section);
+        Ok(())
+    }
+
+    // Builds a save without any character, laid out like the ones the game creates for a
+    // new profile. The bytes go through the regular parser, so the result is exactly what
+    // reading them back from a file gives. The regulation holds no params.
+    #[cfg(feature = "regulation")]
+    pub(crate) fn empty(is_ps: bool, profile: GameProfile) -> Result<Self, SaveParseError> {
+        if is_ps {
+            Self::empty_in::<Playstation>(profile)
+        } else {
+            Self::empty_in::<Pc>(profile)
+        }
+    }
+
+    #[cfg(feature = "regulation")]
+    fn empty_in<L: Layout>(profile: GameProfile) -> Result<Self, SaveParseError> {
+        let sizes = L::sizes(&profile);
+        let checksum_size = L::CHECKSUM_SIZE;
+
+        // The game leaves the slots of characters that were never created and the profile
+        // summaries zeroed
+        let mut sections = vec![vec![0; sizes[1]]; profile.slot_count];
+        sections.push(vec![0; sizes[2]]);
+
+        let version = Regulation::ver_size_map()
+            .keys()
+            .max()
+            .copied()
+            .unwrap_or_default();
+        let mut user_data_11 = vec![0; checksum_size];
+        user_data_11.extend(b" GER");
+        user_data_11.extend(2u32.to_le_bytes());
+        user_data_11.extend(version.to_le_bytes());
+        // Size of the section past this header
+        user_data_11.extend(((sizes[3] - checksum_size - 0x10) as u32).to_le_bytes());
+        user_data_11.extend(Regulation::encrypt_empty(version, &profile.regulation_key)?);
+        if user_data_11.len() > sizes[3] {
+            return Err(SaveParseError::SlotSizeMismatch {
+                expected: sizes[3],
+                actual: user_data_11.len(),
+            });
+        }
+        user_data_11.resize(sizes[3], 0);
+        sections.push(user_data_11);
+
+        if L::CHECKSUM_SIZE != 0 {
+            for section in &mut sections {
+                Util::update_checksum(section);
+            }
+        }
+
+        let mut bytes = if L::IS_PS {
+            Self::ps_header(sections.len())
+        } else {
+            Self::pc_header(&sections)
         };
-        let mut buffer = Vec::new();
+        for section in sections {
+            bytes.extend(section);
+        }
+
+        let mut cursor = Cursor::new(&bytes);
+        let mut reader = Reader::new(&mut cursor);
+        let max_alloc = ParseOptions::default().max_alloc;
+        Ok(Self::read(&mut reader, L::IS_PS, profile, max_alloc)?)
+    }
+
+    // BND4 header of a PC save, one entry per section named USER_DATA000 onwards. Flags
+    // and format are the ones the game writes.
+    #[cfg(feature = "regulation")]
+    fn pc_header(sections: &[Vec<u8>]) -> Vec<u8> {
+        // 12 UTF-16 characters and the terminator
+        const NAME_SIZE: usize = 26;
+        let count = sections.len();
+        let names_offset = 0x40 + 0x20 * count;
+        let data_offset = (names_offset + NAME_SIZE * count).next_multiple_of(0x10);
+
+        let mut header = Vec::with_capacity(data_offset);
+        header.extend(b"BND4");
+        header.extend([0, 0, 0, 0, 0, 0, 1, 0]);
+        header.extend((count as i32).to_le_bytes());
+        header.extend(0x40i64.to_le_bytes());
+        header.extend(b"00000001");
+        header.extend(0x20i64.to_le_bytes());
+        header.extend((data_offset as i64).to_le_bytes());
+        // Unicode names, names only format
+        header.extend([1, 0x20]);
+        header.extend([0; 14]);
+
+        let mut offset = data_offset;
+        for (index, section) in sections.iter().enumerate() {
+            header.extend([0x50, 0, 0, 0]);
+            header.extend((-1i32).to_le_bytes());
+            header.extend((section.len() as i64).to_le_bytes());
+            header.extend((offset as i32).to_le_bytes());
+            header.extend(((names_offset + NAME_SIZE * index) as i32).to_le_bytes());
+            header.extend([0; 8]);
+            offset += section.len();
+        }
+        for index in 0..count {
+            let name = format!("USER_DATA{index:03}");
+            header.extend(name.encode_utf16().chain([0]).flat_map(u16::to_le_bytes));
+        }
+        header.resize(data_offset, 0);
+        header
+    }
+
+    // Header of a PlayStation save as the game writes it, an id and a marker per section
+    #[cfg(feature = "regulation")]
+    fn ps_header(count: usize) -> Vec<u8> {
+        let mut header = vec![0xcb, 0x01, 0x9c, 0x2c];
+        for value in [0, 0x7f7f7f7f, 0] {
+            header.extend(u32::to_le_bytes(value));
+        }
+        for id in 7..7 + count as u32 {
+            header.extend(id.to_le_bytes());
+            header.extend(u32::to_le_bytes(0x7f7f7f7f));
+        }
+        header
+    }
+
+    /// Returns the bytes of the character slot at the specified index, as the game
+    /// stores them once decrypted and without their checksum.
+    ///
+    /// Elden Ring slots aren't encrypted, only prefixed with an md5 checksum on PC, so
+    /// the bytes are the same on both platforms. Together with `Save::encrypt_slot_bytes`
+    /// this allows editing slots with external tools while the crate takes care of the
+    /// container and checksums.
+    ///
+    /// # Errors
+    /// - `SaveParseError::SlotIndexOutOfRange` if there is no slot at `index`.
+    /// - `deku::DekuError` if the slot cannot be encoded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use er_save_lib::{Save, SaveApi, SaveType};
+    ///
+    /// fn main() {
+    ///     let bytes = SaveApi::new_empty(SaveType::PC).unwrap().to_vec().unwrap();
+    ///     let save = Save::from_slice(&bytes).expect("Failed to read save file!");
+    ///     let bytes = save.decrypt_slot_bytes(0).expect("Failed to read slot!");
+    ///     assert_eq!(bytes.len(), 0x280000);
+    /// }
+    /// ```
+    pub fn decrypt_slot_bytes(&self, index: usize) -> Result<Vec<u8>, SaveParseError> {
+        let user_data_x = self
+            .user_data_x
+            .get(index)
+            .ok_or(SaveParseError::SlotIndexOutOfRange(index))?;
+        if self.is_ps_layout() {
+            self.decrypt_slot_bytes_in::<Playstation>(user_data_x)
+        } else {
+            self.decrypt_slot_bytes_in::<Pc>(user_data_x)
+        }
+    }
+
+    fn decrypt_slot_bytes_in<L: Layout>(
+        &self,
+        user_data_x: &UserDataX,
+    ) -> Result<Vec<u8>, SaveParseError> {
+        let size = L::sizes(&self.profile)[1];
+        let mut slot = Vec::with_capacity(size);
         {
-            let mut temp_writer = Writer::new(Cursor::new(&mut buffer));
-            self.to_writer(&mut temp_writer, (Endian::Little, is_ps, sizes))?;
+            let mut writer = Writer::new(Cursor::new(&mut slot));
+            user_data_x.write_slot(&mut writer, Endian::Little, 0, size, L::IS_PS)?;
         }
-        Ok(buffer)
+        slot.drain(..L::CHECKSUM_SIZE);
+        Ok(slot)
+    }
+
+    /// Replaces the character slot at the specified index with `bytes`, in the format
+    /// returned by `Save::decrypt_slot_bytes`. The checksum is recomputed when the save is
+    /// written.
+    ///
+    /// # Errors
+    /// - `SaveParseError::SlotIndexOutOfRange` if there is no slot at `index`.
+    /// - `SaveParseError::SlotSizeMismatch` if `bytes` doesn't have the size of a slot.
+    /// - `deku::DekuError` if the bytes cannot be parsed as a slot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use er_save_lib::{Save, SaveApi, SaveType};
+    ///
+    /// fn main() {
+    ///     let bytes = SaveApi::new_empty(SaveType::PC).unwrap().to_vec().unwrap();
+    ///     let mut save = Save::from_slice(&bytes).expect("Failed to read save file!");
+    ///     let bytes = save.decrypt_slot_bytes(1).expect("Failed to read slot!");
+    ///     save.encrypt_slot_bytes(0, &bytes).expect("Failed to write slot!");
+    ///     assert_eq!(save.decrypt_slot_bytes(0).unwrap(), bytes);
+    /// }
+    /// ```
+    pub fn encrypt_slot_bytes(&mut self, index: usize, bytes: &[u8]) -> Result<(), SaveParseError> {
+        if index >= self.user_data_x.len() {
+            return Err(SaveParseError::SlotIndexOutOfRange(index));
+        }
+        self.user_data_x[index] = if self.is_ps_layout() {
+            self.slot_from_bytes::<Playstation>(bytes)?
+        } else {
+            self.slot_from_bytes::<Pc>(bytes)?
+        };
+        Ok(())
+    }
+
+    fn slot_from_bytes<L: Layout>(&self, bytes: &[u8]) -> Result<UserDataX, SaveParseError> {
+        let expected = L::slot_data_size(&self.profile);
+        if bytes.len() != expected {
+            return Err(SaveParseError::SlotSizeMismatch {
+                expected,
+                actual: bytes.len(),
+            });
+        }
+
+        let mut slot = vec![0; L::CHECKSUM_SIZE];
+        slot.extend_from_slice(bytes);
+        Ok(UserDataX::from_slot_bytes(Endian::Little, &slot, L::IS_PS)?)
+    }
+
+    /// Returns the bytes of a BND4 entry of the save, without their checksum. Character
+    /// slots are returned as by `Save::decrypt_slot_bytes`.
+    ///
+    /// Together with `Save::replace_entry` this allows working on a single entry, the
+    /// profile summaries or the regulation for instance, with external tools.
+    ///
+    /// # Errors
+    /// - `SaveParseError::EntryNotFound` if the save has no such entry.
+    /// - `deku::DekuError` if the entry cannot be encoded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use er_save_lib::{Save, SaveApi, SaveType};
+    ///
+    /// fn main() {
+    ///     let bytes = SaveApi::new_empty(SaveType::PC).unwrap().to_vec().unwrap();
+    ///     let save = Save::from_slice(&bytes).expect("Failed to read save file!");
+    ///     let entry = save.extract_entry("USER_DATA010").expect("Failed to read entry!");
+    ///     assert_eq!(entry, save.extract_entry(10).unwrap());
+    ///     assert!(save.extract_entry("USER_DATA012").is_err());
+    /// }
+    /// ```
+    pub fn extract_entry<'a>(
+        &self,
+        entry: impl Into<SaveEntry<'a>>,
+    ) -> Result<Vec<u8>, SaveParseError> {
+        let index = self.entry_index(entry.into())?;
+        if index < self.slot_count() {
+            self.decrypt_slot_bytes(index)
+        } else if self.is_ps_layout() {
+            self.extract_entry_in::<Playstation>(index)
+        } else {
+            self.extract_entry_in::<Pc>(index)
+        }
+    }
+
+    fn extract_entry_in<L: Layout>(&self, index: usize) -> Result<Vec<u8>, SaveParseError> {
+        let sizes = L::sizes(&self.profile);
+        let mut entry = Vec::new();
+        {
+            let mut writer = Writer::new(Cursor::new(&mut entry));
+            if index == self.slot_count() {
+                UserData10::write(
+                    &mut writer,
+                    Endian::Little,
+                    0,
+                    sizes[2],
+                    L::IS_PS,
+                    self.profile.slot_count,
+                    &self.user_data_10,
+                )?;
+            } else {
+                UserData11::write(
+                    &mut writer,
+                    Endian::Little,
+                    0,
+                    sizes[3],
+                    L::IS_PS,
+                    &self.user_data_11,
+                )?;
+            }
+        }
+        entry.drain(..L::CHECKSUM_SIZE);
+        Ok(entry)
+    }
+
+    /// Replaces a BND4 entry of the save with `bytes`, in the format returned by
+    /// `Save::extract_entry`. The checksum is recomputed when the save is written.
+    ///
+    /// # Errors
+    /// - `SaveParseError::EntryNotFound` if the save has no such entry.
+    /// - `SaveParseError::SlotSizeMismatch` if `bytes` doesn't have the size of the entry.
+    /// - `deku::DekuError` if the bytes cannot be parsed as the entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use er_save_lib::{Save, SaveApi, SaveType};
+    ///
+    /// fn main() {
+    ///     let bytes = SaveApi::new_empty(SaveType::PC).unwrap().to_vec().unwrap();
+    ///     let mut save = Save::from_slice(&bytes).expect("Failed to read save file!");
+    ///     let entry = save.extract_entry("USER_DATA010").expect("Failed to read entry!");
+    ///     save.replace_entry("USER_DATA010", &entry).expect("Failed to write entry!");
+    ///     assert_eq!(save.extract_entry(10).unwrap(), entry);
+    /// }
+    /// ```
+    pub fn replace_entry<'a>(
+        &mut self,
+        entry: impl Into<SaveEntry<'a>>,
+        bytes: &[u8],
+    ) -> Result<(), SaveParseError> {
+        let index = self.entry_index(entry.into())?;
+        if index < self.slot_count() {
+            self.encrypt_slot_bytes(index, bytes)
+        } else if self.is_ps_layout() {
+            self.replace_entry_in::<Playstation>(index, bytes)
+        } else {
+            self.replace_entry_in::<Pc>(index, bytes)
+        }
+    }
+
+    fn replace_entry_in<L: Layout>(
+        &mut self,
+        index: usize,
+        bytes: &[u8],
+    ) -> Result<(), SaveParseError> {
+        let sizes = L::sizes(&self.profile);
+        let size = if index == self.slot_count() {
+            sizes[2]
+        } else {
+            sizes[3]
+        };
+        let expected = size - L::CHECKSUM_SIZE;
+        if bytes.len() != expected {
+            return Err(SaveParseError::SlotSizeMismatch {
+                expected,
+                actual: bytes.len(),
+            });
+        }
+
+        let mut entry = vec![0; L::CHECKSUM_SIZE];
+        entry.extend_from_slice(bytes);
+        let mut cursor = Cursor::new(&entry);
+        let mut reader = Reader::new(&mut cursor);
+        if index == self.slot_count() {
+            self.user_data_10 = UserData10::read(
+                &mut reader,
+                Endian::Little,
+                0,
+                size,
+                L::IS_PS,
+                self.profile.slot_count,
+            )?;
+        } else {
+            self.user_data_11 = UserData11::read(
+                &mut reader,
+                Endian::Little,
+                0,
+                size,
+                L::IS_PS,
+                self.max_alloc,
+                self.profile.regulation_key,
+            )?;
+        }
+        Ok(())
+    }
+
+    // Index of an entry: the character slots come first, then UserData10 and UserData11
+    fn entry_index(&self, entry: SaveEntry) -> Result<usize, SaveParseError> {
+        let index = match entry {
+            SaveEntry::Index(index) => Some(index),
+            SaveEntry::Name(name) => name
+                .strip_prefix("USER_DATA")
+                .filter(|digits| digits.len() == 3)
+                .and_then(|digits| digits.parse().ok()),
+        };
+        index
+            .filter(|index| *index < self.slot_count() + 2)
+            .ok_or_else(|| {
+                SaveParseError::EntryNotFound(match entry {
+                    SaveEntry::Index(index) => index.to_string(),
+                    SaveEntry::Name(name) => name.to_string(),
+                })
+            })
+    }
+
+    // Resets a character slot and its profile summary to the zeroed state the game leaves
+    // for characters that were never created
+    pub(crate) fn clear_slot(&mut self, index: usize) -> Result<(), DekuError> {
+        self.user_data_x[index] = if self.is_ps_layout() {
+            self.empty_slot::<Playstation>()?
+        } else {
+            self.empty_slot::<Pc>()?
+        };
+        let summary = &mut self.user_data_10.profile_summary;
+        summary.profiles[index] = summary.profiles[index].zeroed(Endian::Little)?;
+        summary.active_profiles[index] = false;
+        Ok(())
+    }
+
+    fn empty_slot<L: Layout>(&self) -> Result<UserDataX, DekuError> {
+        UserDataX::empty(Endian::Little, L::sizes(&self.profile)[1], L::IS_PS)
+    }
+
+    // Whether the save was parsed with the PlayStation layout, which is the only one whose
+    // header has the PlayStation size
+    pub(crate) fn is_ps_layout(&self) -> bool {
+        self.header.len() == Playstation::sizes(&self.profile)[0]
     }
 
     /// Writes the save file to a specified path.
@@ -176,7 +946,7 @@ impl Save {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
     /// use er_save_lib::Save;
     ///
     /// fn main() {
@@ -188,6 +958,7 @@ impl Save {
     /// # Safety
     ///
     /// This function is safe to call as it only performs file writing and data formatting operations.
+    #[cfg(feature = "std")]
     pub fn write_to_path(&self, path: impl AsRef<Path>) -> Result<(), SaveParseError> {
         let path = path.as_ref();
         let file = if !path.exists() {
@@ -195,15 +966,13 @@ impl Save {
         } else {
             File::options().write(true).open(path)?
         };
-        let is_ps = self.header.len() == 0x6c;
-        let sizes: [usize; 4] = if is_ps {
-            [0x6c, 0x280000, 0x60000, 0x240010]
-        } else {
-            [0x2fc, 0x280010, 0x60010, 0x240020]
-        };
+        let is_ps = self.is_ps_layout();
 
         let mut writer = Writer::new(file);
-        self.to_writer(&mut writer, (Endian::Little, is_ps, sizes))?;
+        self.to_writer(
+            &mut writer,
+            (Endian::Little, is_ps, self.profile, usize::MAX),
+        )?;
 
         Ok(())
     }
@@ -226,7 +995,7 @@ impl Save {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
     /// use er_save_lib::Save;
     ///
     /// fn main() {
@@ -242,6 +1011,7 @@ impl Save {
     /// # Safety
     ///
     /// This function is safe to call as it only performs file reading and parsing operations.
+    #[cfg(feature = "std")]
     pub fn from_path(path: impl AsRef<Path>) -> Result<Self, SaveParseError> {
         let bytes = fs::read(path)?;
         Self::from_slice(&bytes)
@@ -264,7 +1034,7 @@ impl Save {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
     /// use er_save_lib::Save;
     ///
     /// fn main() {
@@ -283,11 +1053,147 @@ impl Save {
     /// This function is safe to call as it only performs parsing operations on the provided
     /// byte slice.
     pub fn from_slice(bytes: &[u8]) -> Result<Self, SaveParseError> {
-        let is_ps = Self::is_ps(bytes);
-        let mut cursor = Cursor::new(bytes);
+        Self::from_slice_with(bytes, &ParseOptions::default())
+    }
+
+    /// Parses a byte slice like `Save::from_slice`, enforcing the limits in `options`.
+    ///
+    /// # Errors
+    /// - `SaveParseError::LimitExceeded` if the input, one of its sections or the
+    ///   decompressed regulation is larger than the limits allow.
+    /// - `deku::DekuError` if the bytes cannot be parsed correctly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use er_save_lib::{ParseOptions, Save, SaveApi, SaveType};
+    ///
+    /// fn main() {
+    ///     let bytes = SaveApi::new_empty(SaveType::PC).unwrap().to_vec().unwrap();
+    ///     let save = Save::from_slice_with(&bytes, &ParseOptions::default())
+    ///         .expect("Failed to read save file!");
+    /// }
+    /// ```
+    pub fn from_slice_with(bytes: &[u8], options: &ParseOptions) -> Result<Self, SaveParseError> {
+        Self::from_slice_with_progress(bytes, options, |_, _| {})
+    }
+
+    /// Parses a byte slice like `Save::from_slice`, recording the offset, size and outcome
+    /// of every section and the version numbers of the save into a `ParseTrace`. The trace
+    /// is returned whether parsing succeeded or not, so it can go into a bug report about a
+    /// save that can't be read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use er_save_lib::{Save, SaveApi, SaveType};
+    ///
+    /// fn main() {
+    ///     let bytes = SaveApi::new_empty(SaveType::PC).unwrap().to_vec().unwrap();
+    ///     let (result, trace) = Save::from_slice_traced(&bytes[..0x1000000]);
+    ///     assert!(result.is_err());
+    ///     assert!(trace.error.is_some());
+    ///     let slot = trace.sections.iter().find(|section| section.index == Some(1));
+    ///     assert_eq!(slot.unwrap().size, 0x280010);
+    /// }
+    /// ```
+    pub fn from_slice_traced(bytes: &[u8]) -> (Result<Self, SaveParseError>, ParseTrace) {
+        let (result, sections) = trace::record(|| Self::from_slice(bytes));
+        let trace = ParseTrace::new(bytes, sections, &result);
+        (result, trace)
+    }
+
+    /// Reads a save file from the specified path like `Save::from_path`, calling
+    /// `progress` with the section being parsed and the overall progress in percent.
+    ///
+    /// Parsing takes a few seconds, GUI tools can use the callback to drive a progress
+    /// bar. It's called every time the section or the rounded percentage changes.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use er_save_lib::Save;
+    ///
+    /// fn main() {
+    ///     let save = Save::from_path_with_progress("./test/ER0000.sl2", |section, pct| {
+    ///         println!("{section:?}: {pct}%");
+    ///     })
+    ///     .expect("Failed to read save file!");
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_path_with_progress(
+        path: impl AsRef<Path>,
+        progress: impl FnMut(SaveSection, f32),
+    ) -> Result<Self, SaveParseError> {
+        let bytes = fs::read(path)?;
+        Self::from_slice_with_progress(&bytes, &ParseOptions::default(), progress)
+    }
+
+    /// Parses a byte slice like `Save::from_slice_with`, calling `progress` with the
+    /// section being parsed and the overall progress in percent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use er_save_lib::{ParseOptions, Save, SaveApi, SaveSection, SaveType};
+    ///
+    /// fn main() {
+    ///     let bytes = SaveApi::new_empty(SaveType::PC).unwrap().to_vec().unwrap();
+    ///     let mut sections = Vec::new();
+    ///     let save = Save::from_slice_with_progress(&bytes, &ParseOptions::default(), |section, _| {
+    ///         if sections.last() != Some(&section) {
+    ///             sections.push(section);
+    ///         }
+    ///     })
+    ///     .expect("Failed to read save file!");
+    ///     assert_eq!(sections.len(), 13);
+    ///     assert_eq!(sections[12], SaveSection::UserData11);
+    /// }
+    /// ```
+    pub fn from_slice_with_progress(
+        bytes: &[u8],
+        options: &ParseOptions,
+        progress: impl FnMut(SaveSection, f32),
+    ) -> Result<Self, SaveParseError> {
+        if bytes.len() > options.max_alloc {
+            return Err(SaveParseError::LimitExceeded {
+                limit: "max_alloc",
+                max: options.max_alloc,
+            });
+        }
+        let is_ps = Self::is_ps_with(bytes, &options.profile);
+        if options.profile.sizes(is_ps)[1..]
+            .iter()
+            .any(|size| *size > options.max_slot_size)
+        {
+            return Err(SaveParseError::LimitExceeded {
+                limit: "max_slot_size",
+                max: options.max_slot_size,
+            });
+        }
+
+        let mut cursor = ProgressReader::new(
+            Cursor::new(bytes),
+            bytes.len(),
+            is_ps,
+            options.profile,
+            progress,
+        );
         let mut reader = Reader::new(&mut cursor);
-        let save = Self::read(&mut reader, is_ps)?;
-        Ok(save)
+        let step = Step::enter("Save", "read", None, 0, bytes.len());
+        let result = Self::read(&mut reader, is_ps, options.profile, options.max_alloc);
+        step.finish(&result);
+        match result {
+            Ok(save) => Ok(save),
+            // Readers deep in the tree can only return a DekuError, they report a
+            // tripped allocation limit as OutOfMemory.
+            Err(DekuError::Io(io::ErrorKind::OutOfMemory)) => Err(SaveParseError::LimitExceeded {
+                limit: "max_alloc",
+                max: options.max_alloc,
+            }),
+            Err(err) => Err(err.into()),
+        }
     }
 
     /// Checks if the provided byte slice can be parsed as a valid `Save` structure.
@@ -299,10 +1205,10 @@ impl Save {
     /// # Examples
     ///
     /// ```
-    /// use er_save_lib::Save;
+    /// use er_save_lib::{Save, SaveApi, SaveType};
     ///
     /// fn main() {
-    ///     let bytes = std::fs::read("./test/ER0000.sl2").unwrap();
+    ///     let bytes = SaveApi::new_empty(SaveType::PC).unwrap().to_vec().unwrap();
     ///     assert!(Save::is(&bytes));
     /// }
     /// ```
@@ -323,7 +1229,7 @@ impl Save {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
     /// use er_save_lib::Save;
     ///
     /// fn main() {
@@ -337,24 +1243,31 @@ impl Save {
     /// This function is safe to call as it only performs validation operations on the provided
     /// byte slice.
     pub fn is_ps(bytes: &[u8]) -> bool {
-        let file_size = bytes.len();
-
-        let save_file_size = 0x1BA0080;
-        if file_size < save_file_size {
-            return false;
-        }
-
-        let user_data_11_offset = 0x1960070;
-        let user_data_11_size = 0x240010;
-        let user_data_11_end = user_data_11_offset + user_data_11_size;
-        let user_data_11 = &bytes[user_data_11_offset..user_data_11_end];
-        let digest = md5::compute(user_data_11);
+        Self::is_ps_with(bytes, &GameProfile::ELDEN_RING)
+    }
 
-        return digest
-            == md5::Digest([
-                0x2E, 0x88, 0x1A, 0x15, 0xAC, 0x05, 0x88, 0x8D, 0xF2, 0xC2, 0x6A, 0xEC, 0xC2, 0x90,
-                0x89, 0x23,
-            ]);
+    /// Checks if the provided byte slice is a PlayStation save of the game described by
+    /// `profile`. PlayStation saves have no BND4 magic, so they are told apart by their
+    /// size, which follows from the section sizes and slot count of the profile.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use er_save_lib::{GameProfile, Save, SaveApi, SaveType};
+    ///
+    /// fn main() {
+    ///     let bytes = SaveApi::new_empty(SaveType::Playstation).unwrap().to_vec().unwrap();
+    ///     let profile = GameProfile::ELDEN_RING;
+    ///     assert!(Save::is_ps_with(&bytes, &profile));
+    ///     let modded = GameProfile {
+    ///         slot_count: 12,
+    ///         ..profile
+    ///     };
+    ///     assert!(!Save::is_ps_with(&bytes, &modded));
+    /// }
+    /// ```
+    pub fn is_ps_with(bytes: &[u8], profile: &GameProfile) -> bool {
+        !Self::is_pc(bytes) && bytes.len() == profile.file_size(true)
     }
 
     /// Checks if the provided byte slice indicates a PC save file.
@@ -366,10 +1279,10 @@ impl Save {
     /// # Examples
     ///
     /// ```
-    /// use er_save_lib::Save;
+    /// use er_save_lib::{Save, SaveApi, SaveType};
     ///
     /// fn main() {
-    ///     let bytes = std::fs::read("./test/ER0000.sl2").unwrap();
+    ///     let bytes = SaveApi::new_empty(SaveType::PC).unwrap().to_vec().unwrap();
     ///     assert!(Save::is_pc(&bytes));
     /// }
     /// ```
@@ -386,3 +1299,81 @@ impl Save {
         &bytes[0..4] == b"BND4"
     }
 }
+
+#[cfg(feature = "regulation")]
+#[test]
+fn corrupt_slots_are_kept_until_deleted() {
+    use crate::{SaveApi, SaveApiError, SaveType, SlotState};
+
+    let mut bytes = SaveApi::new_empty(SaveType::PC).unwrap().to_vec().unwrap();
+    let sizes = GameProfile::ELDEN_RING.sizes(false);
+    let slot = 4 + sizes[0] + sizes[1]..4 + sizes[0] + sizes[1] * 2;
+    // Top byte of the first gaitem handle, 0x1 isn't a valid category
+    bytes[slot.start + 0x33] = 0x10;
+
+    let written = Save::from_slice(&bytes).unwrap().write_to_vec().unwrap();
+    assert_eq!(written[slot.clone()], bytes[slot.clone()]);
+
+    let mut save_api = SaveApi::from_slice(&bytes).unwrap();
+    assert_eq!(save_api.slot_state(1).unwrap(), SlotState::Corrupt);
+    assert!(matches!(
+        save_api.level(1),
+        Err(SaveApiError::CorruptSlot(1))
+    ));
+    assert_eq!(save_api.slot_state(0).unwrap(), SlotState::Empty);
+
+    save_api.delete_character(1).unwrap();
+    assert_eq!(save_api.slot_state(1).unwrap(), SlotState::Empty);
+    assert_eq!(save_api.level(1).unwrap(), 0);
+}
+
+#[cfg(feature = "regulation")]
+#[test]
+fn slot_checksums_are_verified() {
+    use crate::{SaveApi, SaveType, SlotIntegrity};
+
+    let mut bytes = SaveApi::new_empty(SaveType::PC).unwrap().to_vec().unwrap();
+    let sizes = GameProfile::ELDEN_RING.sizes(false);
+    bytes[4 + sizes[0] + sizes[1] * 3] ^= 0xff;
+
+    let integrity = SaveApi::from_slice(&bytes).unwrap().slot_integrity();
+    assert_eq!(integrity[3], SlotIntegrity::ChecksumMismatch);
+    assert_eq!(integrity[2], SlotIntegrity::Intact);
+}
+
+#[cfg(feature = "regulation")]
+#[test]
+fn replaced_entries_are_written_back() {
+    let bytes = crate::SaveApi::new_empty(crate::SaveType::PC)
+        .unwrap()
+        .to_vec()
+        .unwrap();
+    let mut save_api = crate::SaveApi::from_slice(&bytes).unwrap();
+    for index in 0..save_api.raw().slot_count() + 2 {
+        let name = format!("USER_DATA{index:03}");
+        let entry = save_api.raw().extract_entry(name.as_str()).unwrap();
+        save_api.raw_mut().replace_entry(index, &entry).unwrap();
+    }
+    assert_eq!(save_api.to_vec().unwrap(), bytes);
+}
+
+#[cfg(feature = "regulation")]
+#[test]
+fn ps_saves_are_detected_from_the_profile() {
+    // Two more slots, and two more entries in the header
+    let mut profile = GameProfile {
+        slot_count: 12,
+        ..GameProfile::ELDEN_RING
+    };
+    profile.ps_sizes[0] += 16;
+    let bytes = Save::empty(true, profile).unwrap().write_to_vec().unwrap();
+    assert!(!Save::is_ps(&bytes));
+
+    let options = ParseOptions {
+        profile,
+        ..Default::default()
+    };
+    let save = Save::from_slice_with(&bytes, &options).unwrap();
+    assert!(save.is_ps_layout());
+    assert_eq!(save.slot_count(), 12);
+}
@@ -1,4 +1,4 @@
-use std::io::Cursor;
+use std::{borrow::Cow, io::Cursor};
 
 use deku::ctx::Endian;
 use deku::prelude::*;
@@ -8,11 +8,12 @@ use super::user_data_x::{
     ActiveWeaponSlotsAndArmStyle, EquippedItemsItemIds, EquppedItemsGaitemHandles, FaceData,
 };
 use super::util::{MapId, Util};
+use crate::trace::Step;
 
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(
     endian = "endian",
-    ctx = "endian: Endian, start: usize, size: usize, is_ps: bool"
+    ctx = "endian: Endian, start: usize, size: usize, is_ps: bool, slot_count: usize"
 )]
 pub(crate) struct UserData10 {
     // Checksum (PC only)
@@ -35,6 +36,7 @@ pub(crate) struct UserData10 {
     pub(crate) menu_system_save_load: MenuSystemSaveLoad,
 
     // Profile Summary
+    #[deku(ctx = "slot_count")]
     pub(crate) profile_summary: ProfileSummary,
 
     gamedataman0xd0: u32,
@@ -50,12 +52,15 @@ pub(crate) struct UserData10 {
     game_man_0x118: u64,
 
     // Empty calories
-    #[deku(count = "size - (deku::byte_offset - start)")]
+    #[deku(
+        reader = "Util::read_bytes(deku::reader, Util::remaining(start + size, deku::byte_offset)?)",
+        writer = "Util::write_bytes(deku::writer, rest)"
+    )]
     pub(crate) rest: Vec<u8>,
 }
 
 // Settings
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct Settings {
     pub(crate) camera_speed: u8,
@@ -111,9 +116,29 @@ impl UserData10 {
         start: usize,
         size: usize,
         is_ps: bool,
+        slot_count: usize,
     ) -> Result<Self, DekuError> {
-        let user_data_10 = Self::from_reader_with_ctx(reader, (endian, start, size, is_ps))?;
-        Ok(user_data_10)
+        let step = Step::enter("UserData10", "read", None, start, size);
+        let user_data_10 =
+            Self::from_reader_with_ctx(reader, (endian, start, size, is_ps, slot_count));
+        step.finish(&user_data_10);
+        user_data_10
+    }
+
+    // Replaces the menu state and key bindings, blocks that carry their own size, while
+    // keeping the section size, see `Util::resize_rest`. Returns the number of bytes
+    // missing when the section has no room for longer blocks.
+    pub(crate) fn set_settings_blocks(
+        &mut self,
+        menu_system_save_load: MenuSystemSaveLoad,
+        key_config_save_load: KeyConfigSaveLoad,
+    ) -> Result<(), usize> {
+        let old_size = self.menu_system_save_load.data.len() + self.key_config_save_load.data.len();
+        let new_size = menu_system_save_load.data.len() + key_config_save_load.data.len();
+        Util::resize_rest(&mut self.rest, old_size, new_size)?;
+        self.menu_system_save_load = menu_system_save_load;
+        self.key_config_save_load = key_config_save_load;
+        Ok(())
     }
 
     pub(crate) fn write<W: std::io::Write>(
@@ -122,20 +147,60 @@ impl UserData10 {
         start: usize,
         size: usize,
         is_ps: bool,
+        slot_count: usize,
         user_data_10: &Self,
     ) -> Result<(), DekuError> {
-        if is_ps {
-            user_data_10.to_writer(writer, (endian, start, size, is_ps))?;
-            return Ok(());
-        }
+        let step = Step::enter("UserData10", "write", None, start, size);
+        let result = Self::write_inner(
+            writer,
+            endian,
+            start,
+            size,
+            is_ps,
+            slot_count,
+            user_data_10,
+        );
+        step.finish(&result);
+        result
+    }
 
-        let mut buffer = Vec::new();
+    fn write_inner<W: std::io::Write>(
+        writer: &mut deku::writer::Writer<W>,
+        endian: Endian,
+        start: usize,
+        size: usize,
+        is_ps: bool,
+        slot_count: usize,
+        user_data_10: &Self,
+    ) -> Result<(), DekuError> {
+        let mut buffer = Vec::with_capacity(size);
         {
             let mut temp_writer = Writer::new(Cursor::new(&mut buffer));
-            user_data_10.to_writer(&mut temp_writer, (endian, start, size, is_ps))?;
+            user_data_10
+                .to_writer(&mut temp_writer, (endian, start, size, is_ps, slot_count))?;
         }
 
-        Util::update_checksum(&mut buffer);
+        // The menu and key config blocks carry their own size. Copies of them from another
+        // save resize `rest` to match, see `set_settings_blocks`, so the sizes only differ
+        // for sections edited some other way. Zeros are then appended, or a zero tail is
+        // dropped; a tail that isn't all zeros is refused rather than dropped.
+        let parsed_size = buffer.len() - user_data_10.rest.len();
+        if parsed_size > size {
+            return Err(DekuError::InvalidParam(Cow::from(format!(
+                "Profile data takes {parsed_size:#x} bytes, more than the {size:#x} available"
+            ))));
+        }
+        if buffer.len() > size && buffer[size..].iter().any(|byte| *byte != 0) {
+            return Err(DekuError::InvalidParam(Cow::from(format!(
+                "Profile data grew by {:#x} bytes, over unparsed bytes at the end of the section",
+                buffer.len() - size
+            ))));
+        }
+        buffer.resize(size, 0);
+
+        if !is_ps {
+            Util::update_checksum(&mut buffer);
+        }
 
         writer.write_bytes(&buffer)?;
         Ok(())
@@ -143,26 +208,28 @@ impl UserData10 {
 }
 
 // Menu System Save Load
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct MenuSystemSaveLoad {
     unk0x0: u16,
     unk0x2: u16,
+    #[deku(assert = "*size <= Util::MAX_BLOCK_SIZE")]
     pub(crate) size: u32,
     #[deku(count = "size")]
     pub(crate) data: Vec<u8>,
 }
 
 // Profile Summary
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
-#[deku(endian = "endian", ctx = "endian: Endian")]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
+#[deku(endian = "endian", ctx = "endian: Endian, slot_count: usize")]
 pub(crate) struct ProfileSummary {
-    pub(crate) active_profiles: [bool; 10],
-    #[deku(count = "10")]
+    #[deku(count = "slot_count")]
+    pub(crate) active_profiles: Vec<bool>,
+    #[deku(count = "slot_count")]
     pub(crate) profiles: Vec<Profile>,
 }
 // Profile
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct Profile {
     #[deku(
@@ -191,8 +258,19 @@ pub(crate) struct Profile {
     profile_summary_character_0x298: u32,
 }
 
+impl Profile {
+    // A zeroed profile, as the game leaves it for slots without a character
+    pub(crate) fn zeroed(&self, endian: Endian) -> Result<Self, DekuError> {
+        let mut bytes = Vec::new();
+        self.to_writer(&mut Writer::new(Cursor::new(&mut bytes)), endian)?;
+        bytes.fill(0);
+        let mut cursor = Cursor::new(&bytes);
+        Self::from_reader_with_ctx(&mut Reader::new(&mut cursor), endian)
+    }
+}
+
 // Profile Equipment
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct ProfileEquipment {
     unk0x0: u64,
@@ -205,7 +283,7 @@ pub(crate) struct ProfileEquipment {
 }
 
 // PCOptionData
-#[derive(PartialEq, Debug, DekuRead, DekuWrite, Default)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite, Default)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct PCOptionData {
     unk0x0: u32,
@@ -220,11 +298,12 @@ pub(crate) struct PCOptionData {
 }
 
 // KeyConfigSaveLoad
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct KeyConfigSaveLoad {
     unk0x0: u16,
     unk0x2: u16,
+    #[deku(assert = "*size <= Util::MAX_BLOCK_SIZE")]
     pub(crate) size: u32,
     #[deku(count = "*size")]
     pub(crate) data: Vec<u8>,
@@ -6,11 +6,12 @@ use deku::prelude::*;
 use deku::{DekuRead, DekuWrite};
 
 use super::util::Util;
+use crate::trace::Step;
 
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(
     endian = "endian",
-    ctx = "endian: Endian, start: usize, file_size: usize, is_ps: bool"
+    ctx = "endian: Endian, start: usize, file_size: usize, is_ps: bool, max_alloc: usize, regulation_key: [u8; 32]"
 )]
 pub(crate) struct UserData11 {
     // Checksum (PC only)
@@ -27,10 +28,13 @@ pub(crate) struct UserData11 {
 
     pub(crate) size: u32,
 
-    #[deku(ctx = "RegulationIdentifier::Version(*version)")]
+    #[deku(ctx = "RegulationIdentifier::Version(*version), max_alloc, regulation_key")]
     pub(crate) regulation: Regulation,
 
-    #[deku(count = "file_size - (deku::byte_offset - start)")]
+    #[deku(
+        reader = "Util::read_bytes(deku::reader, Util::remaining(start + file_size, deku::byte_offset)?)",
+        writer = "Util::write_bytes(deku::writer, rest)"
+    )]
     rest: Vec<u8>,
 }
 
@@ -41,11 +45,16 @@ impl UserData11 {
         start: usize,
         size: usize,
         is_ps: bool,
+        max_alloc: usize,
+        regulation_key: [u8; 32],
     ) -> Result<Self, DekuError> {
-        Ok(Self::from_reader_with_ctx(
+        let step = Step::enter("UserData11", "read", None, start, size);
+        let user_data_11 = Self::from_reader_with_ctx(
             reader,
-            (endian, start, size, is_ps),
-        )?)
+            (endian, start, size, is_ps, max_alloc, regulation_key),
+        );
+        step.finish(&user_data_11);
+        user_data_11
     }
 
     pub(crate) fn write<W: std::io::Write>(
@@ -55,16 +64,33 @@ impl UserData11 {
         size: usize,
         is_ps: bool,
         user_data_11: &Self,
+    ) -> Result<(), DekuError> {
+        let step = Step::enter("UserData11", "write", None, start, size);
+        let result = Self::write_inner(writer, endian, start, size, is_ps, user_data_11);
+        step.finish(&result);
+        result
+    }
+
+    fn write_inner<W: std::io::Write>(
+        writer: &mut deku::writer::Writer<W>,
+        endian: Endian,
+        start: usize,
+        size: usize,
+        is_ps: bool,
+        user_data_11: &Self,
     ) -> Result<(), DekuError> {
         if is_ps {
-            user_data_11.to_writer(writer, (endian, start, size, is_ps))?;
+            user_data_11.to_writer(writer, (endian, start, size, is_ps, usize::MAX, [0; 32]))?;
             return Ok(());
         }
 
         let mut buffer = Vec::new();
         {
             let mut temp_writer = Writer::new(Cursor::new(&mut buffer));
-            user_data_11.to_writer(&mut temp_writer, (endian, start, size, is_ps))?;
+            user_data_11.to_writer(
+                &mut temp_writer,
+                (endian, start, size, is_ps, usize::MAX, [0; 32]),
+            )?;
         }
 
         Util::update_checksum(&mut buffer);
@@ -2,11 +2,18 @@ use deku::ctx::Endian;
 use deku::prelude::*;
 use deku::{DekuRead, DekuWrite};
 
-use std::io::Cursor;
-
-use super::util::{FloatVector3, FloatVector4, MapId, Util};
-
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+use std::{
+    borrow::Cow,
+    io::{self, Cursor},
+};
+
+use super::{
+    opaque::Opaque,
+    util::{FloatVector3, FloatVector4, MapId, Util},
+};
+use crate::trace::Step;
+
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian, end: usize, is_ps: bool")]
 pub(crate) struct UserDataX {
     // Checksum (PC only)
@@ -20,9 +27,9 @@ pub(crate) struct UserDataX {
     pub(crate) map_id: [u8; 4],
 
     // Could be just random data
-    unk0x8: [u8; 0x8],
+    pub(crate) unk0x8: Opaque<0x8>,
     // Definetly seemed like random data. Game is using random function to generate.
-    unk0x10: [u8; 0x10],
+    pub(crate) unk0x10: Opaque<0x10>,
 
     // Gaitem Map
     #[deku(count = "if *version <= 80 {0x13FE} else {0x1400}")]
@@ -131,7 +138,10 @@ pub(crate) struct UserDataX {
     unk_gamedataman_0x124_or_gamedataman_0x134: u32,
 
     // Event Flags
-    #[deku(bytes_read = "0x1BF99F")]
+    #[deku(
+        reader = "Util::read_bytes(deku::reader, 0x1BF99F)",
+        writer = "Util::write_bytes(deku::writer, event_flags)"
+    )]
     pub(crate) event_flags: Vec<u8>,
     #[deku(assert_eq = "0")]
     event_flags_terminator: u8,
@@ -206,11 +216,110 @@ pub(crate) struct UserDataX {
         )")]
     pub(crate) player_data_hash: PlayerGameDataHash,
 
-    #[deku(count = "end - deku::byte_offset")]
+    #[deku(
+        reader = "Util::read_bytes(deku::reader, Util::remaining(end, deku::byte_offset)?)",
+        writer = "Util::write_bytes(deku::writer, rest)"
+    )]
     pub(crate) rest: Vec<u8>,
+
+    // Bytes of a slot that failed to parse, such as one left half written by a crash
+    // during character creation. They are written back untouched, while the parsed
+    // fields hold an empty slot.
+    #[deku(skip, default = "None")]
+    pub(crate) corrupt: Option<Vec<u8>>,
 }
 
 impl UserDataX {
+    // Regions of the slot whose meaning isn't known, by name and in the order they're
+    // stored, followed by the unparsed bytes at the end of the slot
+    pub(crate) fn opaque_regions(&self) -> [(&'static str, &[u8]); 13] {
+        [
+            ("unk0x8", self.unk0x8.as_bytes()),
+            ("unk0x10", self.unk0x10.as_bytes()),
+            (
+                "player_game_data.unk0xc0",
+                self.player_game_data.unk0xc0.as_bytes(),
+            ),
+            (
+                "player_game_data.unk0xdd",
+                self.player_game_data.unk0xdd.as_bytes(),
+            ),
+            (
+                "player_game_data.unk0xfb",
+                self.player_game_data.unk0xfb.as_bytes(),
+            ),
+            (
+                "player_game_data.unk0x17c",
+                self.player_game_data.unk0x17c.as_bytes(),
+            ),
+            ("face_data.unk0x6c", self.face_data.unk0x6c.as_bytes()),
+            ("face_data.unk0xb1", self.face_data.unk0xb1.as_bytes()),
+            ("face_data.unk0x10e", self.face_data.unk0x10e.as_bytes()),
+            (
+                "trophy_equip_data.unk0x4",
+                self.trophy_equip_data.unk0x4.as_bytes(),
+            ),
+            (
+                "trophy_equip_data.unk0x14",
+                self.trophy_equip_data.unk0x14.as_bytes(),
+            ),
+            (
+                "trophy_equip_data.unk0x24",
+                self.trophy_equip_data.unk0x24.as_bytes(),
+            ),
+            ("rest", &self.rest),
+        ]
+    }
+
+    pub(crate) fn opaque_regions_mut(&mut self) -> [(&'static str, &mut [u8]); 13] {
+        [
+            ("unk0x8", self.unk0x8.as_bytes_mut()),
+            ("unk0x10", self.unk0x10.as_bytes_mut()),
+            (
+                "player_game_data.unk0xc0",
+                self.player_game_data.unk0xc0.as_bytes_mut(),
+            ),
+            (
+                "player_game_data.unk0xdd",
+                self.player_game_data.unk0xdd.as_bytes_mut(),
+            ),
+            (
+                "player_game_data.unk0xfb",
+                self.player_game_data.unk0xfb.as_bytes_mut(),
+            ),
+            (
+                "player_game_data.unk0x17c",
+                self.player_game_data.unk0x17c.as_bytes_mut(),
+            ),
+            ("face_data.unk0x6c", self.face_data.unk0x6c.as_bytes_mut()),
+            ("face_data.unk0xb1", self.face_data.unk0xb1.as_bytes_mut()),
+            ("face_data.unk0x10e", self.face_data.unk0x10e.as_bytes_mut()),
+            (
+                "trophy_equip_data.unk0x4",
+                self.trophy_equip_data.unk0x4.as_bytes_mut(),
+            ),
+            (
+                "trophy_equip_data.unk0x14",
+                self.trophy_equip_data.unk0x14.as_bytes_mut(),
+            ),
+            (
+                "trophy_equip_data.unk0x24",
+                self.trophy_equip_data.unk0x24.as_bytes_mut(),
+            ),
+            ("rest", &mut self.rest),
+        ]
+    }
+
+    // Replaces the unlocked regions while keeping the slot size, see `Util::resize_rest`.
+    // Returns the number of bytes missing when the slot has no room for the longer list.
+    pub(crate) fn set_region_ids(&mut self, ids: Vec<u32>) -> Result<(), usize> {
+        let old_size = self.unlocked_regions.ids.len() * 4;
+        Util::resize_rest(&mut self.rest, old_size, ids.len() * 4)?;
+        self.unlocked_regions.count = ids.len() as u32;
+        self.unlocked_regions.ids = ids;
+        Ok(())
+    }
+
     pub(crate) fn read<R: std::io::Read>(
         reader: &mut deku::reader::Reader<R>,
         endian: Endian,
@@ -222,12 +331,42 @@ impl UserDataX {
         let mut user_data_x_vec: Vec<Self> = Vec::with_capacity(count);
         for i in 0..count {
             let end = (start + size * i) + size;
-            let user_data_x = Self::from_reader_with_ctx(reader, (endian, end, is_ps))?;
+            let step = Step::enter("UserDataX", "read", Some(i), end - size, size);
+            let bytes = Util::read_bytes(reader, size)?;
+            let user_data_x = Self::from_slot_bytes(endian, &bytes, is_ps);
+            step.finish(&user_data_x);
+            let user_data_x = match user_data_x {
+                Ok(user_data_x) => user_data_x,
+                Err(DekuError::Io(io::ErrorKind::OutOfMemory)) => {
+                    return Err(DekuError::Io(io::ErrorKind::OutOfMemory))
+                }
+                // A broken slot doesn't keep the other characters from loading
+                Err(_) => Self {
+                    corrupt: Some(bytes),
+                    ..Self::empty(endian, size, is_ps)?
+                },
+            };
             user_data_x_vec.push(user_data_x)
         }
         Ok(user_data_x_vec)
     }
 
+    // Parses a single slot, checksum included on PC
+    pub(crate) fn from_slot_bytes(
+        endian: Endian,
+        bytes: &[u8],
+        is_ps: bool,
+    ) -> Result<Self, DekuError> {
+        let mut cursor = Cursor::new(bytes);
+        let mut reader = Reader::new(&mut cursor);
+        Self::from_reader_with_ctx(&mut reader, (endian, bytes.len(), is_ps))
+    }
+
+    // A zeroed slot, as the game leaves it for characters that were never created
+    pub(crate) fn empty(endian: Endian, size: usize, is_ps: bool) -> Result<Self, DekuError> {
+        Self::from_slot_bytes(endian, &vec![0; size], is_ps)
+    }
+
     pub(crate) fn write<W: std::io::Write>(
         writer: &mut deku::writer::Writer<W>,
         endian: Endian,
@@ -237,29 +376,143 @@ impl UserDataX {
         user_data_x_vec: &Vec<Self>,
     ) -> Result<(), DekuError> {
         for (i, user_data_x) in user_data_x_vec.iter().enumerate() {
-            let end = (start + size * i) + size;
+            user_data_x.write_slot(writer, endian, start + size * i, size, is_ps)?;
+        }
+        Ok(())
+    }
+
+    // Writes a single slot, including the checksum on PC
+    pub(crate) fn write_slot<W: std::io::Write>(
+        &self,
+        writer: &mut deku::writer::Writer<W>,
+        endian: Endian,
+        start: usize,
+        size: usize,
+        is_ps: bool,
+    ) -> Result<(), DekuError> {
+        let step = Step::enter("UserDataX", "write", None, start, size);
+        let result = self.write_slot_inner(writer, endian, start, size, is_ps);
+        step.finish(&result);
+        result
+    }
 
-            if is_ps {
-                user_data_x.to_writer(writer, (endian, end, is_ps))?;
-                continue;
-            }
+    fn write_slot_inner<W: std::io::Write>(
+        &self,
+        writer: &mut deku::writer::Writer<W>,
+        endian: Endian,
+        start: usize,
+        size: usize,
+        is_ps: bool,
+    ) -> Result<(), DekuError> {
+        if let Some(bytes) = &self.corrupt {
+            return Util::write_bytes(writer, bytes);
+        }
+        let mut buffer = Vec::with_capacity(size);
+        {
+            let mut temp_writer = Writer::new(Cursor::new(&mut buffer));
+            self.to_writer(&mut temp_writer, (endian, start + size, is_ps))?;
+        }
 
-            let mut buffer = Vec::new();
-            {
-                let mut temp_writer = Writer::new(Cursor::new(&mut buffer));
-                user_data_x.to_writer(&mut temp_writer, (endian, start + size * i, is_ps))?;
-            }
+        // Lists such as the unlocked regions grow and shrink the parsed data, while the
+        // slot keeps its size. Every structure up to the player data hash is a parsed field
+        // and moves with the list, `rest` only holds the bytes after it, which have no known
+        // structure: zero padding, or stale data the game left in its buffer. Edits of the
+        // regions resize `rest` to match, see `set_region_ids`, so the sizes only differ
+        // for slots edited some other way. Zeros are then appended, or a zero tail is
+        // dropped; a tail that isn't all zeros is refused rather than dropped.
+        let parsed_size = buffer.len() - self.rest.len();
+        if parsed_size > size {
+            return Err(DekuError::InvalidParam(Cow::from(format!(
+                "Slot data takes {parsed_size:#x} bytes, more than the {size:#x} available"
+            ))));
+        }
+        if buffer.len() > size && buffer[size..].iter().any(|byte| *byte != 0) {
+            return Err(DekuError::InvalidParam(Cow::from(format!(
+                "Slot data grew by {:#x} bytes, over unparsed bytes at the end of the slot",
+                buffer.len() - size
+            ))));
+        }
+        buffer.resize(size, 0);
 
+        if !is_ps {
             Util::update_checksum(&mut buffer);
-
-            writer.write_bytes(&buffer)?;
         }
+
+        writer.write_bytes(&buffer)?;
         Ok(())
     }
 }
 
+// Start of a slot, up to the death count. Read by `SaveApi::quick_stats` instead of the
+// whole slot, the fields it doesn't use are only parsed to get past them.
+#[allow(dead_code)]
+#[derive(Clone, PartialEq, Debug, DekuRead)]
+#[deku(endian = "endian", ctx = "endian: Endian, is_ps: bool")]
+pub(crate) struct UserDataXStats {
+    #[deku(skip, cond = "is_ps", count = "0x10")]
+    checksum: Vec<u8>,
+    version: u32,
+    map_id: [u8; 4],
+    unk0x8: [u8; 0x8],
+    unk0x10: [u8; 0x10],
+    #[deku(count = "if *version <= 80 {0x13FE} else {0x1400}")]
+    gaitem_map: Vec<Gaitem>,
+    pub(crate) player_game_data: PlayerGameData,
+    #[deku(count = "0xD")]
+    sp_effects: Vec<SPEffect>,
+    equipped_items_equip_index: EquippedItemsEquipIndex,
+    active_weapon_slots_and_arm_style: ActiveWeaponSlotsAndArmStyle,
+    equipped_items_item_id: EquippedItemsItemIds,
+    equipped_items_gaitem_handle: EquppedItemsGaitemHandles,
+    #[deku(ctx = "0xa80, 0x180")]
+    inventory_held: Invenotry,
+    equipped_spells: EquippedSpells,
+    equipped_items: EquippedItems,
+    equipped_gestures: EquippedGestures,
+    acquired_projectiles: AcquiredProjectiles,
+    equipped_armaments_and_items: EquippedArmamentsAndItems,
+    equipped_physics: EquippedPhysics,
+    #[deku(ctx = "false")]
+    face_data: FaceData,
+    #[deku(ctx = "0x780, 0x80")]
+    inventory_storage_box: Invenotry,
+    gestures: Gestures,
+    unlocked_regions: Regions,
+    horse: RideGameData,
+    #[deku(assert = "*control_byte_maybe == 1 || *control_byte_maybe == 0")]
+    control_byte_maybe: u8,
+    blood_stain: BloodStain,
+    unk_gamedataman_0x120_or_gamedataman_0x130: u32,
+    unk_gamedataman_0x88: u32,
+    menu_profile_save_load: MenuSaveLoad,
+    trophy_equip_data: TrophyEquipData,
+    gaitem_game_data: GaitemGameData,
+    tutorial_data: TutorialData,
+    gameman_0x8c: u8,
+    gameman_0x8d: u8,
+    gameman_0x8e: u8,
+    pub(crate) total_deaths_count: u32,
+}
+
+impl UserDataXStats {
+    #[cfg(feature = "std")]
+    pub(crate) fn read<R: std::io::Read>(
+        reader: &mut deku::reader::Reader<R>,
+        endian: Endian,
+        index: usize,
+        start: usize,
+        size: usize,
+        is_ps: bool,
+    ) -> Result<Self, DekuError> {
+        let step = Step::enter("UserDataXStats", "read", Some(index), start, size);
+        let stats = Self::from_reader_with_ctx(reader, (endian, is_ps));
+        step.finish(&stats);
+        stats
+    }
+}
+
 // Gaitem Map
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct Gaitem {
     #[deku(assert = "
@@ -317,7 +570,7 @@ pub(crate) struct Gaitem {
 }
 
 // Player
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct PlayerGameData {
     unk0x0: u32,
@@ -379,18 +632,18 @@ pub(crate) struct PlayerGameData {
     unk0xbd: u8,
     pub(crate) additional_talisman_slot_count: u8,
     pub(crate) summon_spirit_level: u8,
-    unk0xc0: [u8; 0x18],
+    pub(crate) unk0xc0: Opaque<0x18>,
     pub(crate) furl_calling_finger_on: bool,
     unk0xd9: u8,
     pub(crate) matchmaking_weapon_level: u8,
     pub(crate) white_chipher_ring_on: bool,
     pub(crate) blue_cipher_ring_on: bool,
-    unk0xdd: [u8; 0x1a],
+    pub(crate) unk0xdd: Opaque<0x1a>,
     pub(crate) great_rune_on: bool,
     unk0xf8: u8,
     pub(crate) max_crimson_flask_count: u8,
     pub(crate) max_cerulean_flask_count: u8,
-    unk0xfb: [u8; 0x15],
+    pub(crate) unk0xfb: Opaque<0x15>,
     #[deku(
         reader = "Util::read_wstring(deku::reader, 16)",
         writer = "Util::write_wstring(deku::writer, &password, 16)"
@@ -433,21 +686,21 @@ pub(crate) struct PlayerGameData {
     pub(crate) group_password5: String,
     #[deku(assert_eq = "0")]
     group_password5d_terminator: u16,
-    unk0x17c: [u8; 0x34],
+    pub(crate) unk0x17c: Opaque<0x34>,
 }
 
 // SPeffects
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct SPEffect {
-    sp_effect_id: i32,
-    remaining_time: f32,
+    pub(crate) sp_effect_id: i32,
+    pub(crate) remaining_time: f32,
     unk0x8: u32,
     unk0x10: u32,
 }
 
 // Equipped Items Equip Indexes
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct EquippedItemsEquipIndex {
     pub(crate) left_hand_armament1: u32,
@@ -475,7 +728,7 @@ pub(crate) struct EquippedItemsEquipIndex {
 }
 
 // Active weapon slot, arrow and bolt
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct ActiveWeaponSlotsAndArmStyle {
     pub(crate) arm_style: u32,
@@ -494,7 +747,7 @@ pub(crate) struct ActiveWeaponSlotsAndArmStyle {
 }
 
 // Equipped Items Param Ids
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct EquippedItemsItemIds {
     pub(crate) left_hand_armament1: u32,
@@ -522,7 +775,7 @@ pub(crate) struct EquippedItemsItemIds {
 }
 
 // Equipped Items GaitemHandles
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct EquppedItemsGaitemHandles {
     pub(crate) left_hand_armament1: u32,
@@ -550,34 +803,34 @@ pub(crate) struct EquppedItemsGaitemHandles {
 }
 
 // Inventory (Held and Storage Box)
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(
     endian = "endian",
     ctx = "endian: Endian, common_items_capacity: u32, key_items_capacity: u32"
 )]
 pub(crate) struct Invenotry {
     #[deku(assert = "*common_item_count <= common_items_capacity")]
-    common_item_count: u32,
+    pub(crate) common_item_count: u32,
     #[deku(count = "common_items_capacity")]
-    common_items: Vec<InvenotryItem>,
+    pub(crate) common_items: Vec<InvenotryItem>,
     #[deku(assert = "*key_item_count <= key_items_capacity")]
-    key_item_count: u32,
+    pub(crate) key_item_count: u32,
     #[deku(count = "key_items_capacity")]
-    key_items: Vec<InvenotryItem>,
+    pub(crate) key_items: Vec<InvenotryItem>,
     equip_index_counter: u32,
     aquistion_index_counter: u32,
 }
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct InvenotryItem {
-    gaitem_handle: u32,
+    pub(crate) gaitem_handle: u32,
     #[deku(assert = "*quantity <= 999")]
-    quantity: u32,
-    aqcuistion_index: u32,
+    pub(crate) quantity: u32,
+    pub(crate) aqcuistion_index: u32,
 }
 
 // Equipped Spells
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct EquippedSpells {
     #[deku(count = "14")]
@@ -585,7 +838,7 @@ pub(crate) struct EquippedSpells {
     #[deku(assert = "*active_index < 0xc || *active_index == 0xffffffff")]
     pub(crate) active_index: u32,
 }
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct Spell {
     pub(crate) spell_id: u32,
@@ -593,7 +846,7 @@ pub(crate) struct Spell {
 }
 
 // Equipped Items
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct EquippedItems {
     #[deku(count = "0xa")]
@@ -602,10 +855,9 @@ pub(crate) struct EquippedItems {
     pub(crate) active_quick_item_index: u32,
     #[deku(count = "0x6")]
     pub(crate) pouch_items: Vec<EquippedItem>,
-    unk0x84: u32,
-    unk0x88: u32,
+    pub(crate) great_rune: EquippedItem,
 }
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct EquippedItem {
     pub(crate) gaitem_handle: u32,
@@ -613,7 +865,7 @@ pub(crate) struct EquippedItem {
 }
 
 // Equipped Gestures
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct EquippedGestures {
     #[deku(count = "0x6")]
@@ -621,14 +873,15 @@ pub(crate) struct EquippedGestures {
 }
 
 // Aquired Projectiles
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct AcquiredProjectiles {
+    #[deku(assert = "*count <= Util::MAX_BLOCK_SIZE")]
     pub(crate) count: u32,
     #[deku(count = "*count")]
     projectiles: Vec<Projectile>,
 }
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct Projectile {
     pub(crate) id: u32,
@@ -636,7 +889,7 @@ pub(crate) struct Projectile {
 }
 
 // Equipped Weapons, Amor, Talisman and Items
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct EquippedArmamentsAndItems {
     pub(crate) left_hand_armament1: u32,
@@ -677,11 +930,11 @@ pub(crate) struct EquippedArmamentsAndItems {
     pub(crate) pouch4: u32,
     pub(crate) pouch5: u32,
     pub(crate) pouch6: u32,
-    unk0x98: u32,
+    pub(crate) great_rune: u32,
 }
 
 // Equipped Physics
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct EquippedPhysics {
     pub(crate) slot1: u32,
@@ -690,7 +943,7 @@ pub(crate) struct EquippedPhysics {
 }
 
 // Face Data
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian, in_profile_summary: bool")]
 pub(crate) struct FaceData {
     #[deku(assert = "*facedata0x150 == 0 || *facedata0x150 == -1")]
@@ -779,13 +1032,13 @@ pub(crate) struct FaceData {
     nose_bridge_width: u8,
     nose_height: u8,
     nose_slant: u8,
-    unk0x6c: [u8; 64],
+    pub(crate) unk0x6c: Opaque<64>,
     head_size: u8,
     chest_size: u8,
     abdomen_size: u8,
     arms_size: u8,
     legs_size: u8,
-    unk0xb1: [u8; 2],
+    pub(crate) unk0xb1: Opaque<2>,
     skin_color_r: u8,
     skin_color_g: u8,
     skin_color_b: u8,
@@ -877,7 +1130,7 @@ pub(crate) struct FaceData {
     eyepatch_color_r: u8,
     eyepatch_color_g: u8,
     eyepatch_color_b: u8,
-    unk0x10e: [u8; 0x12],
+    pub(crate) unk0x10e: Opaque<0x12>,
 
     #[deku(skip, cond = "in_profile_summary")]
     unk0x124: bool,
@@ -888,7 +1141,7 @@ pub(crate) struct FaceData {
 }
 
 // Gestures
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct Gestures {
     #[deku(count = "0x40")]
@@ -896,16 +1149,17 @@ pub(crate) struct Gestures {
 }
 
 // Regions
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct Regions {
+    #[deku(assert = "*count <= Util::MAX_BLOCK_SIZE")]
     pub(crate) count: u32,
     #[deku(count = "*count")]
     pub(crate) ids: Vec<u32>,
 }
 
 // Ride Game Data
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct RideGameData {
     pub(crate) coordinates: FloatVector3,
@@ -916,7 +1170,7 @@ pub(crate) struct RideGameData {
 }
 
 // BloodStain
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct BloodStain {
     pub(crate) coordinates: FloatVector3,
@@ -934,28 +1188,29 @@ pub(crate) struct BloodStain {
 }
 
 // Menu Save Load
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct MenuSaveLoad {
     unk0x0: u16,
     unk0x2: u16,
+    #[deku(assert = "*size <= Util::MAX_BLOCK_SIZE")]
     pub(crate) size: u32,
     #[deku(count = "*size")]
     pub(crate) data: Vec<u8>,
 }
 
 // Trophy Equip Data
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct TrophyEquipData {
     unk0x0: u32,
-    unk0x4: [u8; 0x10],
-    unk0x14: [u8; 0x10],
-    unk0x24: [u8; 0x10],
+    pub(crate) unk0x4: Opaque<0x10>,
+    pub(crate) unk0x14: Opaque<0x10>,
+    pub(crate) unk0x24: Opaque<0x10>,
 }
 
 // Gaitem Data
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct GaitemGameDataEntry {
     pub(crate) id: u32,
@@ -965,7 +1220,7 @@ pub(crate) struct GaitemGameDataEntry {
     #[deku(pad_bytes_after = "3")]
     unk0xc: u8,
 }
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct GaitemGameData {
     pub(crate) count: i64,
@@ -974,44 +1229,51 @@ pub(crate) struct GaitemGameData {
 }
 
 // Tutorial Data
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian, total_count: u32")]
 pub(crate) struct TutorialDataChunk {
     pub(crate) count: u32,
-    #[deku(skip, cond = "*count == 0", count = "(total_count-0x4)/4")]
+    #[deku(
+        skip,
+        cond = "*count == 0",
+        count = "total_count.saturating_sub(0x4) / 4"
+    )]
     pub(crate) ids: Vec<u32>,
 }
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct TutorialData {
     unk0x0: u16,
     unk0x2: u16,
+    #[deku(assert = "*size <= Util::MAX_BLOCK_SIZE")]
     pub(crate) size: u32,
     #[deku(ctx = "*size")]
     pub(crate) data: TutorialDataChunk,
 }
 
 // Field Area
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct FieldArea {
+    #[deku(assert = "*size <= Util::MAX_BLOCK_SIZE as i32")]
     pub(crate) size: i32,
     #[deku(bytes_read = "size")]
     pub(crate) data: Option<Vec<u32>>,
 }
 
 // World Area
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct WorldBlockChrData {
     magic: [u8; 4],
     pub(crate) map_id: MapId,
+    #[deku(assert = "*size <= Util::MAX_BLOCK_SIZE as i32")]
     pub(crate) size: i32,
     unk0xc: u32,
     #[deku(skip, cond = "*size < 1", count = "*size - 0x10")]
     pub(crate) data: Vec<u8>,
 }
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct WorldAreaChrData {
     magic: [u8; 4],
@@ -1022,7 +1284,7 @@ pub(crate) struct WorldAreaChrData {
     #[deku(until = "|d: &WorldBlockChrData| d.size < 1")]
     pub(crate) data: Vec<WorldBlockChrData>,
 }
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct WorldArea {
     pub(crate) size: i32,
@@ -1030,16 +1292,17 @@ pub(crate) struct WorldArea {
 }
 
 // World Geom Man
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct WorldGeomDataChunk {
     map_id: MapId,
+    #[deku(assert = "*size <= Util::MAX_BLOCK_SIZE as i32")]
     pub(crate) size: i32,
     unk_0x8: u64,
     #[deku(skip, cond = "*size < 1", count = "*size-0x10")]
     pub(crate) data: Vec<u8>,
 }
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct WorldGeomData {
     magic: [u8; 4],
@@ -1047,7 +1310,7 @@ pub(crate) struct WorldGeomData {
     #[deku(until = "|d: &WorldGeomDataChunk| d.size < 1")]
     pub(crate) data: Vec<WorldGeomDataChunk>,
 }
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct WorldGeomMan {
     pub(crate) size: i32,
@@ -1055,29 +1318,31 @@ pub(crate) struct WorldGeomMan {
 }
 
 // RendMan
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian, size: i32")]
 pub(crate) struct StageManEntry {
     #[deku(skip, cond = "size < 1", count = "size")]
     pub(crate) data: Vec<u8>,
 }
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian, size: i32")]
 pub(crate) struct StageMan {
+    #[deku(assert = "*count <= size")]
     count: i32,
     #[deku(skip, cond = "*count < 1", count = "*count", ctx = "(size-4)/(*count)")]
     pub(crate) data: Vec<StageManEntry>,
 }
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct RendMan {
+    #[deku(assert = "(0..=Util::MAX_BLOCK_SIZE as i32).contains(size)")]
     pub(crate) size: i32,
     #[deku(ctx = "*size")]
     pub(crate) data: StageMan,
 }
 
 // Player Coordinates
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct PlayerCoordinates {
     pub(crate) coordinates: FloatVector3,
@@ -1090,17 +1355,20 @@ pub(crate) struct PlayerCoordinates {
 }
 
 // NetMan
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct NetMan {
     #[deku(assert = "*unk0x0 == 2 || *unk0x0 == 0")]
     unk0x0: u32,
-    #[deku(count = "0x20000")]
+    #[deku(
+        reader = "Util::read_bytes(deku::reader, 0x20000)",
+        writer = "Util::write_bytes(deku::writer, data)"
+    )]
     pub(crate) data: Vec<u8>,
 }
 
 // World Area Weather
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct WorldAreaWeather {
     pub(crate) area_id: u16,
@@ -1110,7 +1378,7 @@ pub(crate) struct WorldAreaWeather {
 }
 
 // World Area Time
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct WorldAreaTime {
     pub(crate) hour: u32,
@@ -1119,7 +1387,7 @@ pub(crate) struct WorldAreaTime {
 }
 
 // Base Version
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct BaseVersion {
     pub(crate) base_version_copy: u32,
@@ -1130,21 +1398,21 @@ pub(crate) struct BaseVersion {
 }
 
 // PS5Activity
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct PS5Activity {
     data: [u8; 0x20],
 }
 
 // DLC
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct DLC {
     data: [u8; 0x32],
 }
 
 // Player Data Hash
-#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[derive(Clone, PartialEq, Debug, DekuRead, DekuWrite)]
 #[deku(endian = "endian", ctx = "endian: Endian")]
 pub(crate) struct PlayerGameDataHash {
     pub(crate) level: u32,
@@ -1423,3 +1691,57 @@ impl PlayerGameDataHash {
         input.wrapping_add(mod_product)
     }
 }
+
+#[cfg(feature = "regulation")]
+#[test]
+fn slots_keep_their_size_when_regions_are_added() {
+    use crate::{MockCharacter, MockSave, SaveApi};
+
+    let mock = MockSave::new(vec![MockCharacter {
+        level: 12,
+        ..Default::default()
+    }]);
+    let mut save_api = SaveApi::from_mock(&mock).unwrap();
+    let size = save_api.to_vec().unwrap().len();
+    let deaths = save_api.deaths(0).unwrap();
+    let time = save_api.time_of_day(0).unwrap();
+    let regions: Vec<u32> = (0..50).map(|region| 9_000_000 + region).collect();
+    for region in &regions {
+        save_api.add_region(0, *region).unwrap();
+    }
+
+    let bytes = save_api.to_vec().unwrap();
+    assert_eq!(bytes.len(), size);
+    let save_api = SaveApi::from_slice(&bytes).unwrap();
+    assert!(regions
+        .iter()
+        .all(|region| save_api.regions(0).unwrap().contains(region)));
+    assert_eq!(save_api.level(0).unwrap(), 12);
+    assert_eq!(save_api.deaths(0).unwrap(), deaths);
+    assert_eq!(save_api.time_of_day(0).unwrap(), time);
+}
+
+#[cfg(feature = "regulation")]
+#[test]
+fn non_zero_slot_tails_are_not_dropped() {
+    use crate::{MockCharacter, MockSave, SaveApi};
+
+    let mock = MockSave::new(vec![MockCharacter {
+        level: 1,
+        ..Default::default()
+    }]);
+    let mut save_api = SaveApi::from_mock(&mock).unwrap();
+    *save_api.raw_mut().user_data_x[0].rest.last_mut().unwrap() = 1;
+    let bytes = save_api.to_vec().unwrap();
+    assert!(matches!(
+        save_api.add_region(0, 9_000_000_u32),
+        Err(crate::SaveApiError::SlotFull(0, 4))
+    ));
+    assert_eq!(save_api.to_vec().unwrap(), bytes);
+
+    // Edits that bypass the region setters are refused on write instead
+    let user_data_x = &mut save_api.raw_mut().user_data_x[0];
+    user_data_x.unlocked_regions.ids.push(9_000_000);
+    user_data_x.unlocked_regions.count += 1;
+    assert!(save_api.to_vec().is_err());
+}
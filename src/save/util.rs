@@ -12,6 +12,11 @@ pub(crate) type MapId = [u8; 4];
 pub(crate) struct Util;
 
 impl Util {
+    // Upper bound for any length or count read from the file. Nothing inside a save is
+    // bigger than a character slot, so larger values only come from corrupt files and
+    // would otherwise turn into huge allocations.
+    pub(crate) const MAX_BLOCK_SIZE: u32 = 0x280010;
+
     pub(crate) fn read_wstring<R: std::io::Read>(
         reader: &mut deku::reader::Reader<R>,
         size: usize,
@@ -75,6 +80,57 @@ impl Util {
         return Ok(());
     }
 
+    // Reads a byte buffer in a single call. Deku reads Vec<u8> one element at a time,
+    // which dominates parse time for the large opaque blocks in a save.
+    pub(crate) fn read_bytes<R: std::io::Read>(
+        reader: &mut deku::reader::Reader<R>,
+        size: usize,
+    ) -> Result<Vec<u8>, DekuError> {
+        let mut buffer = vec![0; size];
+        let _ = reader.read_bytes(size, &mut buffer)?;
+        Ok(buffer)
+    }
+
+    // Number of bytes left until `end`, erroring instead of underflowing when the reader
+    // has already gone past it.
+    pub(crate) fn remaining(end: usize, offset: usize) -> Result<usize, DekuError> {
+        end.checked_sub(offset).ok_or_else(|| {
+            DekuError::Parse(Cow::from(format!(
+                "Read past the end of the block ({offset:#x} > {end:#x})"
+            )))
+        })
+    }
+
+    // Counterpart of `read_bytes`, writes the whole buffer in a single call.
+    pub(crate) fn write_bytes<W: std::io::Write>(
+        writer: &mut deku::writer::Writer<W>,
+        bytes: &[u8],
+    ) -> Result<(), DekuError> {
+        writer.write_bytes(bytes)
+    }
+
+    // Makes room for a field of a section growing from `old_size` to `new_size` bytes, or
+    // hands back the bytes it gave up, so the section keeps its size. `rest` holds the
+    // unparsed bytes at the end of the section and only gives up zeros: when it hasn't
+    // enough of them it's left unchanged and the number of bytes missing is returned.
+    pub(crate) fn resize_rest(
+        rest: &mut Vec<u8>,
+        old_size: usize,
+        new_size: usize,
+    ) -> Result<(), usize> {
+        if new_size > old_size {
+            let growth = new_size - old_size;
+            let padding = rest.iter().rev().take_while(|byte| **byte == 0).count();
+            if growth > padding {
+                return Err(growth - padding);
+            }
+            rest.truncate(rest.len() - growth);
+        } else {
+            rest.resize(rest.len() + old_size - new_size, 0);
+        }
+        Ok(())
+    }
+
     pub(crate) fn update_checksum(bytes: &mut Vec<u8>) {
         let digest = md5::compute(&bytes[0x10..]);
         for (i, byte) in digest.0.iter().enumerate() {
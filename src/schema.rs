@@ -0,0 +1,426 @@
+//! Description of the PC save layout, exported as a Kaitai Struct definition or an 010
+//! Editor template so saves can be browsed in hex tools.
+//!
+//! The description mirrors the deku structs of the crate field for field, with the same
+//! names, and has to be updated alongside them. It covers the container, the start of
+//! every character slot up to the held inventory and the header of the profile summaries.
+//! Everything after that is left as opaque bytes, sized so the following sections still
+//! line up.
+//!
+//! Run `cargo run --features schema --bin emit-schema -- kaitai` (or `010`) to print it.
+
+use std::fmt::Write;
+
+/// Type of a field.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Type {
+    U8,
+    U16,
+    U32,
+    U64,
+    I32,
+    F32,
+    /// Opaque bytes of a fixed size.
+    Bytes(usize),
+    /// UTF-16 string taking a fixed number of bytes.
+    WString(usize),
+    /// Another struct of the schema, by name.
+    Struct(&'static str),
+    /// Every byte left until the end of the enclosing struct, which must have a size.
+    Rest,
+}
+
+/// How many times a field repeats.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Repeat {
+    One,
+    Count(usize),
+    /// An expression over the previous fields of the struct.
+    Expr(&'static str),
+}
+
+/// A field of a struct. `cond` is an expression over the previous fields of the struct,
+/// the field is only present when it holds.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Field {
+    pub name: &'static str,
+    pub ty: Type,
+    pub repeat: Repeat,
+    pub cond: Option<&'static str>,
+}
+
+/// A struct of the schema. `size` is set for structs that always take the same number of
+/// bytes, such as the sections of the save.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Struct {
+    pub name: &'static str,
+    pub size: Option<usize>,
+    pub fields: &'static [Field],
+}
+
+// Shorthands for the field lists below
+const fn field(name: &'static str, ty: Type) -> Field {
+    Field {
+        name,
+        ty,
+        repeat: Repeat::One,
+        cond: None,
+    }
+}
+
+const fn array(name: &'static str, ty: Type, count: usize) -> Field {
+    Field {
+        name,
+        ty,
+        repeat: Repeat::Count(count),
+        cond: None,
+    }
+}
+
+const fn when(name: &'static str, ty: Type, cond: &'static str) -> Field {
+    Field {
+        name,
+        ty,
+        repeat: Repeat::One,
+        cond: Some(cond),
+    }
+}
+
+/// Every struct of the PC save, the root `save` first. Structs only reference the ones
+/// after them. Expressions use C operators.
+pub const SAVE: &[Struct] = &[
+    Struct {
+        name: "save",
+        size: None,
+        fields: &[
+            field("magic", Type::Bytes(4)),
+            field("header", Type::Bytes(0x2fc)),
+            array("user_data_x", Type::Struct("user_data_x"), 10),
+            field("user_data_10", Type::Struct("user_data_10")),
+            field("user_data_11", Type::Struct("user_data_11")),
+        ],
+    },
+    Struct {
+        name: "user_data_11",
+        size: Some(0x240020),
+        fields: &[
+            field("checksum", Type::Bytes(0x10)),
+            field("regulation", Type::Rest),
+        ],
+    },
+    Struct {
+        name: "user_data_10",
+        size: Some(0x60010),
+        fields: &[
+            field("checksum", Type::Bytes(0x10)),
+            field("version", Type::U32),
+            field("steam_id", Type::U64),
+            field("rest", Type::Rest),
+        ],
+    },
+    Struct {
+        name: "user_data_x",
+        size: Some(0x280010),
+        fields: &[
+            field("checksum", Type::Bytes(0x10)),
+            field("version", Type::U32),
+            field("map_id", Type::Bytes(4)),
+            field("unk0x8", Type::Bytes(0x8)),
+            field("unk0x10", Type::Bytes(0x10)),
+            Field {
+                name: "gaitem_map",
+                ty: Type::Struct("gaitem"),
+                repeat: Repeat::Expr("version <= 80 ? 0x13fe : 0x1400"),
+                cond: None,
+            },
+            field("player_game_data", Type::Struct("player_game_data")),
+            array("sp_effects", Type::Struct("sp_effect"), 0xd),
+            array("equipped_items_equip_index", Type::U32, 22),
+            array("active_weapon_slots_and_arm_style", Type::U32, 7),
+            array("equipped_items_item_id", Type::U32, 22),
+            array("equipped_items_gaitem_handle", Type::U32, 22),
+            field("inventory_held", Type::Struct("inventory_held")),
+            field("rest", Type::Rest),
+        ],
+    },
+    Struct {
+        name: "inventory_held",
+        size: None,
+        fields: &[
+            field("common_item_count", Type::U32),
+            array("common_items", Type::Struct("inventory_item"), 0xa80),
+            field("key_item_count", Type::U32),
+            array("key_items", Type::Struct("inventory_item"), 0x180),
+            field("equip_index_counter", Type::U32),
+            field("aquistion_index_counter", Type::U32),
+        ],
+    },
+    Struct {
+        name: "inventory_item",
+        size: None,
+        fields: &[
+            field("gaitem_handle", Type::U32),
+            field("quantity", Type::U32),
+            field("aqcuistion_index", Type::U32),
+        ],
+    },
+    Struct {
+        name: "sp_effect",
+        size: None,
+        fields: &[
+            field("sp_effect_id", Type::I32),
+            field("remaining_time", Type::F32),
+            field("unk0x8", Type::U32),
+            field("unk0x10", Type::U32),
+        ],
+    },
+    Struct {
+        name: "player_game_data",
+        size: None,
+        fields: &[
+            field("unk0x0", Type::U32),
+            field("unk0x4", Type::U32),
+            field("hp", Type::U32),
+            field("max_hp", Type::U32),
+            field("base_max_hp", Type::U32),
+            field("fp", Type::U32),
+            field("max_fp", Type::U32),
+            field("base_max_fp", Type::U32),
+            field("unk0x20", Type::U32),
+            field("sp", Type::U32),
+            field("max_sp", Type::U32),
+            field("base_max_sp", Type::U32),
+            field("unk0x30", Type::U32),
+            field("vigor", Type::U32),
+            field("mind", Type::U32),
+            field("endurance", Type::U32),
+            field("strength", Type::U32),
+            field("dexterity", Type::U32),
+            field("intelligence", Type::U32),
+            field("faith", Type::U32),
+            field("arcane", Type::U32),
+            field("unk0x54", Type::U32),
+            field("unk0x58", Type::U32),
+            field("unk0x5c", Type::U32),
+            field("level", Type::U32),
+            field("runes", Type::U32),
+            field("runes_memory", Type::U32),
+            field("unk0x6c", Type::U32),
+            field("poison_buildup", Type::U32),
+            field("rot_buildup", Type::U32),
+            field("bleed_buildup", Type::U32),
+            field("death_buildup", Type::U32),
+            field("frost_buildup", Type::U32),
+            field("sleep_buildup", Type::U32),
+            field("madness_buildup", Type::U32),
+            field("unk0x8c", Type::U32),
+            field("unk0x90", Type::U32),
+            field("character_name", Type::WString(32)),
+            field("terminator", Type::U16),
+            field("gender", Type::U8),
+            field("archetype", Type::U8),
+            field("unk0xb8", Type::U8),
+            field("unk0xb9", Type::U8),
+            field("voice_type", Type::U8),
+            field("gift", Type::U8),
+            field("unk0xbc", Type::U8),
+            field("unk0xbd", Type::U8),
+            field("additional_talisman_slot_count", Type::U8),
+            field("summon_spirit_level", Type::U8),
+            field("unk0xc0", Type::Bytes(0x18)),
+            field("furl_calling_finger_on", Type::U8),
+            field("unk0xd9", Type::U8),
+            field("matchmaking_weapon_level", Type::U8),
+            field("white_chipher_ring_on", Type::U8),
+            field("blue_cipher_ring_on", Type::U8),
+            field("unk0xdd", Type::Bytes(0x1a)),
+            field("great_rune_on", Type::U8),
+            field("unk0xf8", Type::U8),
+            field("max_crimson_flask_count", Type::U8),
+            field("max_cerulean_flask_count", Type::U8),
+            field("unk0xfb", Type::Bytes(0x15)),
+            field("password", Type::WString(16)),
+            field("password_terminator", Type::U16),
+            field("group_password1", Type::WString(16)),
+            field("group_password1d_terminator", Type::U16),
+            field("group_password2", Type::WString(16)),
+            field("group_password2d_terminator", Type::U16),
+            field("group_password3", Type::WString(16)),
+            field("group_password3d_terminator", Type::U16),
+            field("group_password4", Type::WString(16)),
+            field("group_password4d_terminator", Type::U16),
+            field("group_password5", Type::WString(16)),
+            field("group_password5d_terminator", Type::U16),
+            field("unk0x17c", Type::Bytes(0x34)),
+        ],
+    },
+    Struct {
+        name: "gaitem",
+        size: None,
+        fields: &[
+            field("gaitem_handle", Type::U32),
+            field("item_id", Type::U32),
+            when(
+                "unk0x10",
+                Type::I32,
+                "gaitem_handle != 0 && (gaitem_handle & 0xf0000000) != 0xc0000000",
+            ),
+            when(
+                "unk0x14",
+                Type::I32,
+                "gaitem_handle != 0 && (gaitem_handle & 0xf0000000) != 0xc0000000",
+            ),
+            when(
+                "gem_gaitem_handle",
+                Type::I32,
+                "gaitem_handle != 0 && (gaitem_handle & 0xf0000000) == 0x80000000",
+            ),
+            when(
+                "unk0x1c",
+                Type::U8,
+                "gaitem_handle != 0 && (gaitem_handle & 0xf0000000) == 0x80000000",
+            ),
+        ],
+    },
+];
+
+fn find(name: &str) -> &'static Struct {
+    SAVE.iter()
+        .find(|s| s.name == name)
+        .unwrap_or_else(|| panic!("Struct {name} is not in the schema"))
+}
+
+/// Returns the schema as a Kaitai Struct YAML definition.
+///
+/// # Example
+/// ```rust
+/// let ksy = er_save_lib::schema::kaitai();
+/// assert!(ksy.starts_with("meta:\n  id: er_save\n"));
+/// assert!(ksy.contains("  player_game_data:\n"));
+/// ```
+pub fn kaitai() -> String {
+    let mut out = String::new();
+    out.push_str("meta:\n  id: er_save\n  endian: le\n");
+    let (root, types) = SAVE.split_first().expect("The schema has a root");
+    out.push_str("seq:\n");
+    kaitai_fields(&mut out, root, "");
+    out.push_str("types:\n");
+    for ty in types {
+        let _ = writeln!(out, "  {}:", ty.name);
+        out.push_str("    seq:\n");
+        kaitai_fields(&mut out, ty, "    ");
+    }
+    out
+}
+
+fn kaitai_fields(out: &mut String, ty: &Struct, indent: &str) {
+    for field in ty.fields {
+        let _ = writeln!(out, "{indent}  - id: {}", field.name);
+        let mut attribute = |line: String| {
+            let _ = writeln!(out, "{indent}    {line}");
+        };
+        match field.ty {
+            Type::U8 => attribute("type: u1".to_string()),
+            Type::U16 => attribute("type: u2".to_string()),
+            Type::U32 => attribute("type: u4".to_string()),
+            Type::U64 => attribute("type: u8".to_string()),
+            Type::I32 => attribute("type: s4".to_string()),
+            Type::F32 => attribute("type: f4".to_string()),
+            Type::Bytes(size) => attribute(format!("size: {size:#x}")),
+            Type::WString(size) => {
+                attribute("type: strz".to_string());
+                attribute(format!("size: {size}"));
+                attribute("encoding: UTF-16LE".to_string());
+            }
+            Type::Struct(name) => {
+                attribute(format!("type: {name}"));
+                if let Some(size) = find(name).size {
+                    attribute(format!("size: {size:#x}"));
+                }
+            }
+            Type::Rest => attribute("size-eos: true".to_string()),
+        }
+        match field.repeat {
+            Repeat::One => {}
+            Repeat::Count(count) => {
+                attribute("repeat: expr".to_string());
+                attribute(format!("repeat-expr: {count:#x}"));
+            }
+            Repeat::Expr(expr) => {
+                attribute("repeat: expr".to_string());
+                attribute(format!("repeat-expr: '{}'", kaitai_expr(expr)));
+            }
+        }
+        if let Some(cond) = field.cond {
+            attribute(format!("if: '{}'", kaitai_expr(cond)));
+        }
+    }
+}
+
+// Kaitai spells the logical operators out
+fn kaitai_expr(expr: &str) -> String {
+    expr.replace("&&", "and").replace("||", "or")
+}
+
+/// Returns the schema as an 010 Editor binary template.
+///
+/// # Example
+/// ```rust
+/// let bt = er_save_lib::schema::template_010();
+/// assert!(bt.contains("typedef struct {\n"));
+/// assert!(bt.ends_with("save_t file;\n"));
+/// ```
+pub fn template_010() -> String {
+    let mut out = String::new();
+    out.push_str("LittleEndian();\n\n");
+    // 010 needs every struct defined before it is used. Types get a suffix so fields can
+    // share their name.
+    for ty in SAVE.iter().rev() {
+        out.push_str("typedef struct {\n");
+        if ty.size.is_some() {
+            out.push_str("    local int64 start = FTell();\n");
+        }
+        for field in ty.fields {
+            let (c_type, mut length) = match field.ty {
+                Type::U8 => ("uchar".to_string(), None),
+                Type::U16 => ("ushort".to_string(), None),
+                Type::U32 => ("uint".to_string(), None),
+                Type::U64 => ("uint64".to_string(), None),
+                Type::I32 => ("int".to_string(), None),
+                Type::F32 => ("float".to_string(), None),
+                Type::Bytes(size) => ("uchar".to_string(), Some(format!("{size:#x}"))),
+                Type::WString(size) => ("wchar_t".to_string(), Some((size / 2).to_string())),
+                Type::Struct(name) => (format!("{name}_t"), None),
+                Type::Rest => {
+                    let size = ty.size.expect("Rest fields need a sized struct");
+                    ("uchar".to_string(), Some(format!("start + {size:#x} - FTell()")))
+                }
+            };
+            let optimize = matches!(field.ty, Type::Struct(_)) && field.repeat != Repeat::One;
+            match field.repeat {
+                Repeat::One => {}
+                Repeat::Count(count) => length = Some(format!("{count:#x}")),
+                Repeat::Expr(expr) => length = Some(expr.to_string()),
+            }
+            let mut declaration = match length {
+                Some(length) => format!("{c_type} {}[{length}]", field.name),
+                None => format!("{c_type} {}", field.name),
+            };
+            // Structs of variable size can't be mapped as a single block
+            if optimize {
+                declaration.push_str(" <optimize=false>");
+            }
+            match field.cond {
+                Some(cond) => {
+                    let _ = writeln!(out, "    if ({cond}) {declaration};");
+                }
+                None => {
+                    let _ = writeln!(out, "    {declaration};");
+                }
+            }
+        }
+        let _ = writeln!(out, "}} {}_t;\n", ty.name);
+    }
+    out.push_str("save_t file;\n");
+    out
+}
@@ -0,0 +1,88 @@
+// Instrumentation of the parsing and serialization pipeline. Every section gets a
+// `tracing` span with its offset and size, closed by an event holding the elapsed time
+// and the error, if any. Compiles to nothing unless the `tracing` feature is enabled.
+// Independently of it, steps taken inside `record` are collected for
+// `Save::from_slice_traced`.
+
+use std::{cell::RefCell, fmt::Display};
+
+use crate::save::parse_trace::TracedSection;
+
+thread_local! {
+    // Steps taken on this thread, while `record` runs
+    static RECORDED: RefCell<Option<Vec<TracedSection>>> = const { RefCell::new(None) };
+}
+
+// Runs `f` and returns the steps it took, in the order they were entered
+pub(crate) fn record<T>(f: impl FnOnce() -> T) -> (T, Vec<TracedSection>) {
+    RECORDED.with(|recorded| *recorded.borrow_mut() = Some(Vec::new()));
+    let result = f();
+    let steps = RECORDED.with(|recorded| recorded.borrow_mut().take());
+    (result, steps.unwrap_or_default())
+}
+
+// A span entered for the duration of a single step, ended with `Step::finish`.
+#[must_use]
+pub(crate) struct Step {
+    // Position of the step in the recorded ones, while recording
+    recorded: Option<usize>,
+    #[cfg(feature = "tracing")]
+    _span: tracing::span::EnteredSpan,
+    #[cfg(feature = "tracing")]
+    start: std::time::Instant,
+}
+
+impl Step {
+    // `name` identifies the section or stage, `index` the character slot for `UserDataX`.
+    pub(crate) fn enter(
+        name: &'static str,
+        operation: &'static str,
+        index: Option<usize>,
+        offset: usize,
+        size: usize,
+    ) -> Self {
+        let recorded = RECORDED.with(|recorded| {
+            let mut recorded = recorded.borrow_mut();
+            let steps = recorded.as_mut()?;
+            steps.push(TracedSection {
+                name,
+                operation,
+                index,
+                offset,
+                size,
+                error: None,
+            });
+            Some(steps.len() - 1)
+        });
+        Step {
+            recorded,
+            #[cfg(feature = "tracing")]
+            _span: tracing::debug_span!("er_save_lib", name, operation, index, offset, size)
+                .entered(),
+            #[cfg(feature = "tracing")]
+            start: std::time::Instant::now(),
+        }
+    }
+
+    pub(crate) fn finish<T, E: Display>(self, result: &Result<T, E>) {
+        if let (Some(position), Err(err)) = (self.recorded, result) {
+            RECORDED.with(|recorded| {
+                if let Some(step) = recorded
+                    .borrow_mut()
+                    .as_mut()
+                    .and_then(|steps| steps.get_mut(position))
+                {
+                    step.error = Some(err.to_string());
+                }
+            });
+        }
+        #[cfg(feature = "tracing")]
+        {
+            let elapsed_us = self.start.elapsed().as_micros() as u64;
+            match result {
+                Ok(_) => tracing::debug!(elapsed_us, "done"),
+                Err(err) => tracing::warn!(elapsed_us, error = %err, "failed"),
+            }
+        }
+    }
+}
@@ -0,0 +1,454 @@
+//! Tests against real saves, which the doc examples can't rely on. They only run with the
+//! `fixture-tests` feature, on the saves named by `ER_SAVE_LIB_PC_SAVE` and
+//! `ER_SAVE_LIB_PS_SAVE`. A test whose save isn't supplied is skipped.
+//!
+//! ```sh
+//! ER_SAVE_LIB_PC_SAVE=test/ER0000.sl2 ER_SAVE_LIB_PS_SAVE=test/PS_Save.txt \
+//!     cargo test --features fixture-tests --test fixture
+//! ```
+#![cfg(feature = "fixture-tests")]
+
+use std::{env, fs, path::PathBuf};
+
+use er_save_lib::{
+    ammunition::AmmoSlot,
+    bnd4::Bnd4,
+    build_template::Strictness,
+    item::ItemCategory,
+    manifest::{ManifestEntry, Rejection},
+    sanitize::{Fix, Policy},
+    BuddyStoneParam::BuddyStoneParam,
+    Region, Save, SaveApi, SaveApiError, SaveType, StartingGift,
+    TalkParam::TalkParam,
+};
+
+// Path of the save named by `var`, or None when the variable isn't set
+fn fixture(var: &str) -> Option<PathBuf> {
+    let path = env::var_os(var).map(PathBuf::from);
+    if path.is_none() {
+        eprintln!("{var} isn't set, skipping");
+    }
+    path
+}
+
+fn pc_save() -> Option<(Vec<u8>, SaveApi)> {
+    let bytes = fs::read(fixture("ER_SAVE_LIB_PC_SAVE")?).unwrap();
+    let save_api = SaveApi::from_slice(&bytes).unwrap();
+    Some((bytes, save_api))
+}
+
+fn ps_save() -> Option<(Vec<u8>, SaveApi)> {
+    let bytes = fs::read(fixture("ER_SAVE_LIB_PS_SAVE")?).unwrap();
+    let save_api = SaveApi::from_slice(&bytes).unwrap();
+    Some((bytes, save_api))
+}
+
+fn first_active(save_api: &SaveApi) -> usize {
+    save_api
+        .active_characters()
+        .iter()
+        .position(|active| *active)
+        .expect("the save has no character")
+}
+
+#[test]
+fn pc_save_round_trips() {
+    let Some((bytes, save_api)) = pc_save() else {
+        return;
+    };
+    assert!(Save::is_pc(&bytes));
+    assert_eq!(save_api.platform(), SaveType::PC);
+    assert_eq!(save_api.to_vec().unwrap(), bytes);
+    assert_eq!(Bnd4::from_slice(&bytes).unwrap().to_vec().unwrap(), bytes);
+}
+
+#[test]
+fn to_vec_reuses_untouched_slots() {
+    let Some((bytes, mut save_api)) = pc_save() else {
+        return;
+    };
+    let index = first_active(&save_api);
+    save_api.set_vigor(index, 50).unwrap();
+    let edited = save_api.to_vec().unwrap();
+    assert_eq!(edited.len(), bytes.len());
+    assert_eq!(
+        SaveApi::from_slice(&edited).unwrap().vigor(index).unwrap(),
+        50
+    );
+
+    // The other slots weren't touched so they must be identical to the original
+    let slot = |other: usize| 0x300 + other * 0x280010..0x300 + (other + 1) * 0x280010;
+    for other in (0..10).filter(|other| *other != index) {
+        assert_eq!(edited[slot(other)], bytes[slot(other)]);
+    }
+}
+
+#[test]
+fn replaced_entries_are_read_back() {
+    let Some((_, mut save_api)) = pc_save() else {
+        return;
+    };
+    // USER_DATA011 holds the regulation, which is decompressed again on replace
+    for index in 0..save_api.raw().slot_count() + 2 {
+        let entry = save_api.raw().extract_entry(index).unwrap();
+        save_api.raw_mut().replace_entry(index, &entry).unwrap();
+        assert_eq!(save_api.raw().extract_entry(index).unwrap(), entry);
+    }
+    let regulation = save_api.raw().slot_count() + 1;
+    let written = SaveApi::from_slice(&save_api.to_vec().unwrap()).unwrap();
+    assert_eq!(
+        written.raw().extract_entry(regulation).unwrap(),
+        save_api.raw().extract_entry(regulation).unwrap()
+    );
+}
+
+#[test]
+fn ps_save_round_trips() {
+    let Some((bytes, save_api)) = ps_save() else {
+        return;
+    };
+    assert!(Save::is_ps(&bytes));
+    assert_eq!(save_api.platform(), SaveType::Playstation);
+    assert_eq!(save_api.to_vec().unwrap(), bytes);
+}
+
+#[test]
+fn edits_survive_a_write() {
+    let Some((_, mut save_api)) = pc_save() else {
+        return;
+    };
+    let index = first_active(&save_api);
+    let level = save_api.level(index).unwrap();
+    save_api.set_vigor(index, 60).unwrap();
+    save_api.set_character_name(index, "Fixture").unwrap();
+    save_api.set_event_flag(10000850, index, true).unwrap();
+
+    let save_api = SaveApi::from_slice(&save_api.to_vec().unwrap()).unwrap();
+    assert_eq!(save_api.vigor(index).unwrap(), 60);
+    assert_eq!(save_api.character_name(index).unwrap(), "Fixture");
+    assert!(save_api.get_event_flag(10000850, index).unwrap());
+    assert_eq!(save_api.level(index).unwrap(), level);
+    assert_eq!(save_api.profile(index).unwrap().name, "Fixture");
+}
+
+#[test]
+fn regulation_params_load() {
+    let Some((_, save_api)) = pc_save() else {
+        return;
+    };
+    assert!(!save_api.get_param::<TalkParam>().unwrap().rows.is_empty());
+    assert!(save_api
+        .regulation()
+        .diff(&save_api.regulation())
+        .unwrap()
+        .is_empty());
+    assert_eq!(
+        save_api
+            .starting_gift_items(StartingGift::StoneswordKey)
+            .unwrap(),
+        vec![(ItemCategory::Goods, 8000, 2)]
+    );
+}
+
+#[test]
+fn inventories_are_consistent() {
+    let Some((_, save_api)) = pc_save() else {
+        return;
+    };
+    for (index, active) in save_api.active_characters().iter().enumerate() {
+        if *active {
+            let issues = save_api.check_inventory(index).unwrap();
+            assert!(issues.is_empty(), "{index}: {issues:?}");
+        }
+    }
+}
+
+#[test]
+fn manifest_is_checked_against_params() {
+    let Some((_, mut save_api)) = pc_save() else {
+        return;
+    };
+    let index = first_active(&save_api);
+    let entries = ManifestEntry::from_csv(
+        "category,id,quantity\nGoods,10010,5\nGoods,123456789,1\nWeapon,1000000,1\n",
+    )
+    .unwrap();
+    let report = save_api.import_inventory(index, &entries).unwrap();
+    assert_eq!(report.applied, vec![entries[0]]);
+    assert_eq!(report.rejected[0], (entries[1], Rejection::UnknownItem));
+    assert_eq!(
+        report.rejected[1],
+        (entries[2], Rejection::UnsupportedCategory)
+    );
+}
+
+#[test]
+fn every_region_unlocks() {
+    let Some((_, mut save_api)) = pc_save() else {
+        return;
+    };
+    let index = first_active(&save_api);
+    save_api.unlock_all_regions(index).unwrap();
+    assert!(save_api
+        .regions(index)
+        .unwrap()
+        .contains(&Region::Caelid.into()));
+}
+
+#[test]
+fn added_regions_survive_a_write() {
+    let Some((bytes, mut save_api)) = pc_save() else {
+        return;
+    };
+    let index = first_active(&save_api);
+    let regions: Vec<u32> = (0..50).map(|region| 9_000_000 + region).collect();
+    for region in &regions {
+        save_api.add_region(index, *region).unwrap();
+    }
+    // The other slots either take the edit or refuse it, never fail on write
+    let active = save_api.active_characters().to_vec();
+    for other in (0..active.len()).filter(|other| *other != index && active[*other]) {
+        match save_api.add_region(other, 9_000_000_u32) {
+            Ok(()) | Err(SaveApiError::SlotFull(..)) => {}
+            Err(err) => panic!("{other}: {err}"),
+        }
+    }
+
+    let edited = save_api.to_vec().unwrap();
+    assert_eq!(edited.len(), bytes.len());
+    let edited = SaveApi::from_slice(&edited).unwrap();
+    let unlocked = edited.regions(index).unwrap();
+    assert!(regions.iter().all(|region| unlocked.contains(region)));
+    assert_eq!(edited.level(index).unwrap(), save_api.level(index).unwrap());
+    assert_eq!(
+        edited.deaths(index).unwrap(),
+        save_api.deaths(index).unwrap()
+    );
+}
+
+#[test]
+fn weapons_are_replaced_by_owned_ones() {
+    let Some((_, mut save_api)) = pc_save() else {
+        return;
+    };
+    let index = first_active(&save_api);
+    let mut weapons: Vec<u32> = save_api
+        .character(index)
+        .unwrap()
+        .owned_items()
+        .into_iter()
+        .filter(|(category, _)| *category == ItemCategory::Weapon)
+        .map(|(_, id)| id)
+        .collect();
+    weapons.sort_unstable();
+    weapons.dedup();
+    let [old_id, new_id, ..] = weapons[..] else {
+        return;
+    };
+
+    let replaced = save_api
+        .replace_item_everywhere(index, ItemCategory::Weapon, old_id, new_id)
+        .unwrap();
+    assert!(replaced > 0);
+    let owned = save_api.character(index).unwrap().owned_items();
+    assert!(!owned.contains(&(ItemCategory::Weapon, old_id)));
+    assert!(owned.contains(&(ItemCategory::Weapon, new_id)));
+}
+
+#[test]
+fn quick_stats_match_the_full_parse() {
+    let Some(path) = fixture("ER_SAVE_LIB_PC_SAVE") else {
+        return;
+    };
+    let save_api = SaveApi::from_path(&path).unwrap();
+    let stats = SaveApi::quick_stats(&path).unwrap();
+    let active = save_api.active_characters().iter().filter(|a| **a).count();
+    assert_eq!(stats.len(), active);
+    for character in stats {
+        assert_eq!(character.level, save_api.level(character.index).unwrap());
+        assert_eq!(character.deaths, save_api.deaths(character.index).unwrap());
+    }
+}
+
+#[test]
+fn opaque_regions_survive_a_write() {
+    let Some((_, mut save_api)) = pc_save() else {
+        return;
+    };
+    let index = first_active(&save_api);
+    let before: Vec<(&str, Vec<u8>)> = save_api
+        .character(index)
+        .unwrap()
+        .opaque_regions()
+        .into_iter()
+        .map(|(name, bytes)| (name, bytes.to_vec()))
+        .collect();
+
+    let mut chr = save_api.character_mut(index).unwrap();
+    for (name, _) in &before {
+        for byte in chr.opaque_region_mut(name).unwrap() {
+            *byte = !*byte;
+        }
+    }
+    let edited = SaveApi::from_slice(&save_api.to_vec().unwrap()).unwrap();
+    let character = edited.character(index).unwrap();
+    let after = character.opaque_regions();
+    assert_eq!(after.len(), before.len());
+    for ((name, before), (_, after)) in before.iter().zip(after) {
+        assert_eq!(before.len(), after.len(), "{name}");
+        assert!(before.iter().zip(after).all(|(a, b)| *a == !*b), "{name}");
+    }
+}
+
+#[test]
+fn stats_are_derived_from_params() {
+    let Some((_, save_api)) = pc_save() else {
+        return;
+    };
+    let index = first_active(&save_api);
+    let defenses = save_api.defenses(index).unwrap();
+    assert!(defenses.physical > 0. && defenses.physical < 100.);
+    assert!(defenses.immunity > 0);
+    let equip_load = save_api.equip_load(index).unwrap();
+    assert!(equip_load.max > 0.);
+    assert_eq!(save_api.roll_type(index).unwrap(), equip_load.roll_type());
+
+    let equipped = save_api.character(index).unwrap().equipped_items();
+    for slot in (0..6).filter(|slot| equipped[*slot].2 != u32::MAX) {
+        assert!(save_api.weapon_ar(index, slot).unwrap().total() > 0);
+    }
+}
+
+#[test]
+fn starting_gift_gives_its_items() {
+    let Some((_, mut save_api)) = pc_save() else {
+        return;
+    };
+    let index = first_active(&save_api);
+    save_api
+        .set_starting_gift(index, StartingGift::GoldenSeed)
+        .unwrap();
+    assert_eq!(
+        save_api.starting_gift(index).unwrap(),
+        Some(StartingGift::GoldenSeed)
+    );
+    let items = save_api.character(index).unwrap().owned_items();
+    assert!(items.contains(&(ItemCategory::Goods, 10010)));
+}
+
+#[test]
+fn cookbooks_unlock_recipes() {
+    let Some((_, mut save_api)) = pc_save() else {
+        return;
+    };
+    let index = first_active(&save_api);
+    save_api.unlock_cookbook(index, 9300).unwrap();
+    let recipes = save_api.craftable_recipes(index).unwrap();
+    assert!(recipes.iter().any(|recipe| recipe.unlock_flag == 67000));
+}
+
+#[test]
+fn ammunition_is_checked_against_params() {
+    let Some((_, mut save_api)) = pc_save() else {
+        return;
+    };
+    let index = first_active(&save_api);
+    // Arrow
+    assert!(matches!(
+        save_api.set_ammunition(index, AmmoSlot::Bolts1, Some(50000000)),
+        Err(SaveApiError::WrongAmmunition(AmmoSlot::Bolts1, 50000000))
+    ));
+    let items = save_api.character(index).unwrap().owned_items();
+    if items.contains(&(ItemCategory::Weapon, 50000000)) {
+        save_api
+            .set_ammunition(index, AmmoSlot::Arrows1, Some(50000000))
+            .unwrap();
+        assert_eq!(
+            save_api.ammunition(index).unwrap()[0],
+            (AmmoSlot::Arrows1, Some(50000000))
+        );
+    }
+}
+
+#[test]
+fn illegal_items_in_use_are_kept() {
+    let Some((_, mut save_api)) = pc_save() else {
+        return;
+    };
+    let index = first_active(&save_api);
+    save_api.remove_illegal_items(index).unwrap();
+    assert!(save_api
+        .find_illegal_items(index)
+        .unwrap()
+        .iter()
+        .all(|item| item.in_use));
+}
+
+#[test]
+fn sanitize_raises_runes_memory() {
+    let Some((_, mut save_api)) = pc_save() else {
+        return;
+    };
+    let index = first_active(&save_api);
+    save_api.set_runes_memory(index, 0).unwrap();
+    let report = save_api
+        .sanitize_for_online(index, Policy::default())
+        .unwrap();
+    // Classes start at level 10 at most, below it no runes were needed
+    let raised = report
+        .fixes
+        .iter()
+        .any(|fix| matches!(fix, Fix::RaisedRunesMemory { from: 0, .. }));
+    assert_eq!(raised, save_api.level(index).unwrap() > 10);
+}
+
+#[test]
+fn builds_apply_back() {
+    let Some((_, mut save_api)) = pc_save() else {
+        return;
+    };
+    let index = first_active(&save_api);
+    let build = save_api.export_build(index).unwrap();
+    save_api
+        .apply_build(index, &build, Strictness::AddMissing)
+        .unwrap();
+    assert_eq!(save_api.export_build(index).unwrap(), build);
+}
+
+#[test]
+fn params_export_to_csv() {
+    let Some((_, save_api)) = pc_save() else {
+        return;
+    };
+    let mut csv = Vec::new();
+    save_api
+        .regulation()
+        .export_param_csv::<BuddyStoneParam>(&mut csv)
+        .unwrap();
+    let csv = String::from_utf8(csv).unwrap();
+    assert!(csv.starts_with("ID,Name,disableParam_NT,"));
+}
+
+#[cfg(feature = "interop")]
+#[test]
+fn er_save_manager_exports_import() {
+    use er_save_lib::interop::ErSaveManagerCharacter;
+
+    let Some((_, mut save_api)) = pc_save() else {
+        return;
+    };
+    let index = first_active(&save_api);
+    let character = ErSaveManagerCharacter::from_json(
+        r#"{
+            "Name": "Tarnished", "Level": 9, "Vigor": 15, "Mind": 10, "Endurance": 11,
+            "Strength": 14, "Dexterity": 13, "Intelligence": 9, "Faith": 9, "Arcane": 7,
+            "Souls": 1200, "Class": 1,
+            "Inventory": [{ "Category": "goods", "Id": 10010, "Quantity": 5 }]
+        }"#,
+    )
+    .unwrap();
+    let report = save_api.import_er_save_manager(index, &character).unwrap();
+    assert!(report.rejected.is_empty());
+    assert_eq!(save_api.character_name(index).unwrap(), "Tarnished");
+    assert_eq!(save_api.level(index).unwrap(), 9);
+}